@@ -51,7 +51,7 @@ fn main() {
         println!("operator = {}", operator);
         let mut c = Equation::new(a, b, operator);
         println!("{} {} {} = {}", a, operator, b, c.get_sol());
-        if numbers_rus::integers::base::is_prime(c.get_sol()) {
+        if numbers_rus::integers::primality::is_prime(c.get_sol()) {
             println!("{} is prime", c.get_sol());
         } else {
             println!("{} is not prime", c.get_sol());
@@ -0,0 +1,84 @@
+//! The `error` module contains the crate-wide error type returned by fallible operations, such as the `checked_*` family in
+//! `integers::base`. Each error records the name of the operation that failed and an [`ErrorKind`] describing why.
+//!
+//! # Examples
+//! ```
+//! use numbers_rus::error::ErrorKind;
+//! use numbers_rus::integers::base;
+//!
+//! let err = base::checked_divide(1, 0).unwrap_err();
+//! assert_eq!(err.operation(), "divide");
+//! assert_eq!(err.kind(), ErrorKind::DivisionByZero);
+//! assert_eq!(err.to_string(), "divide failed: division by zero");
+//! ```
+use std::fmt;
+
+/// The reason an operation failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The result does not fit in the target type.
+    Overflow,
+    /// The divisor or modulus is zero.
+    DivisionByZero,
+    /// A negative exponent was given where only non-negative exponents are supported.
+    NegativeExponent,
+    /// A negative argument was given where only non-negative arguments are supported.
+    NegativeArgument,
+    /// The degree of a root is zero or negative.
+    InvalidRootDegree,
+    /// A system of congruences has no common solution.
+    InconsistentCongruences,
+    /// A value cannot be converted exactly into the target type.
+    NotRepresentable,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self {
+            ErrorKind::Overflow => "arithmetic overflow",
+            ErrorKind::DivisionByZero => "division by zero",
+            ErrorKind::NegativeExponent => "negative exponent",
+            ErrorKind::NegativeArgument => "negative argument",
+            ErrorKind::InvalidRootDegree => "root degree must be positive",
+            ErrorKind::InconsistentCongruences => "congruences have no common solution",
+            ErrorKind::NotRepresentable => "value is not representable in the target type",
+        };
+        write!(f, "{}", description)
+    }
+}
+
+/// `NumbersError` describes which operation failed and why.
+///
+/// # Methods
+///
+/// * `new` - creates a new error for the named operation.
+/// * `operation` - returns the name of the operation that failed.
+/// * `kind` - returns the reason the operation failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NumbersError {
+    operation: &'static str,
+    kind: ErrorKind,
+}
+
+impl NumbersError {
+    pub fn new(operation: &'static str, kind: ErrorKind) -> Self {
+        Self { operation, kind }
+    }
+
+    pub fn operation(&self) -> &'static str {
+        self.operation
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for NumbersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} failed: {}", self.operation, self.kind)
+    }
+}
+
+impl std::error::Error for NumbersError {}
@@ -14,6 +14,26 @@
 //! assert_eq!(c, 3);
 //! ```
 //!
+//! Every arithmetic operation also comes in a `checked_*` form returning a [`NumbersError`] that names the failing operation, and
+//! the operations that can overflow additionally have `wrapping_*`, `saturating_*` and `overflowing_*` forms mirroring the
+//! corresponding methods on the primitive integer types. `root` cannot overflow, so it only has a `checked_root` form.
+//!
+//! Roots are computed exactly on integers: `isqrt`, `icbrt` and `iroot` return floor roots for the whole i128 range, and
+//! `perfect_power` finds the largest exponent `k` such that a number is a `k`th power.
+//!
+//! ```
+//! use numbers_rus::integers::base;
+//!
+//! assert!(base::checked_factorial(40).is_err());
+//! assert_eq!(base::saturating_multiply(i128::MAX, 2), i128::MAX);
+//! assert_eq!(base::overflowing_add(i128::MAX, 1), (i128::MIN, true));
+//! ```
+//!
+//! Functions ending in `_big` accept [`BigInt`] values instead and never overflow.
+//!
+use crate::error::{ErrorKind, NumbersError};
+use crate::integers::big_integer::BigInt;
+use crate::integers::recurrence;
 
 /// `add` function: Takes in two i128 integers `left` and `right` as input and returns their sum as an i128 integer.
 pub fn add(left: i128, right: i128) -> i128 {
@@ -57,44 +77,62 @@ pub fn power(left: i128, right: i128) -> i128 {
     result
 }
 
-/// `root` function: Takes two i128 integers `left` and `right` as input. Returns the `right`th root of `left` as an i128 integer.
-/// Panics if `left` is negative or `right` is non-positive.
+/// `root` function: Takes two i128 integers `left` and `right` as input. Returns the `right`th root of `left`, rounded down,
+/// as an i128 integer. The root is computed exactly with `iroot`. Panics if `left` is negative or `right` is non-positive.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::base;
+///
+/// assert_eq!(base::root(27, 3), 3);
+/// assert_eq!(base::root(i128::MAX, 2), 13043817825332782212);
+/// ```
 pub fn root(left: i128, right: i128) -> i128 {
     // Check for edge cases
     if left < 0 || right <= 0 {
         panic!("Invalid input: left should be non-negative, and right should be positive");
     }
 
-    // Calculate the root
-    let root = 1.0 / right as f64;
-    (left as f64).powf(root).round() as i128
+    iroot(left, u32::try_from(right).unwrap_or(u32::MAX))
 }
-/// `factorial` function: Takes an i128 integer `number` as input and returns its factorial as an i128 integer. The function
-/// uses recursion to calculate the factorial. Note that it may cause stack overflow for large input values. Panics if the input value
-/// is negative. To avoid this behavior, ensure the input value is non-negative.
+/// `factorial` function: Takes an i128 integer `number` as input and returns its factorial as an i128 integer. Panics if the input
+/// value is negative or the result does not fit in an i128 (any `number` above 33). Use `checked_factorial` to handle these cases,
+/// `factorial_big` for larger inputs, or `combinatorics::FactorialTable` for factorials modulo a number.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::base;
+///
+/// assert_eq!(base::factorial(20), 2432902008176640000);
+/// ```
 pub fn factorial(number: i128) -> i128 {
-    if number == 0 {
-        1
-    } else {
-        number * factorial(number - 1)
+    if number < 0 {
+        panic!("Factorial of a negative number is not supported");
     }
+    checked_factorial(number).expect("Factorial does not fit in an i128")
 }
 /// `fibonacci` function: Takes an i128 integer `number` as input and returns the `number`th Fibonacci number as an i128 integer.
-/// It uses recursion to calculate the Fibonacci number. Note that it may cause stack overflow for large input values.
+/// It uses fast doubling from `recurrence::fibonacci`, so it takes time logarithmic in `number`. Panics if `number` is negative or
+/// the result does not fit in an i128 (any `number` above 184).
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::base;
+///
+/// assert_eq!(base::fibonacci(100), 354224848179261915075);
+/// ```
 pub fn fibonacci(number: i128) -> i128 {
-    if number == 0 {
-        0
-    } else if number == 1 {
-        1
-    } else {
-        fibonacci(number - 1) + fibonacci(number - 2)
+    if number < 0 {
+        panic!("Fibonacci of a negative index is not supported");
     }
+    recurrence::fibonacci(number).expect("Fibonacci number does not fit in an i128")
 }
 
 /// `is_prime` function: Takes an i128 integer `number` as input and returns a boolean indicating whether `number` is a prime or not.
 /// The function checks if the input `number` is less than or equal to 1, returning false if true, then iterates through all integers within
 /// the range of 2 to `number` (exclusive), checking if they are divisible by `number`. If a divisor is found, the function returns false.
-/// If no divisor is found, it returns true.
+/// If no divisor is found, it returns true. For large inputs use `primality::is_prime`, which is deterministic for the whole i128
+/// range and runs in polylogarithmic time.
 pub fn is_prime(number: i128) -> bool {
     if number <= 1 {
         return false;
@@ -118,25 +156,622 @@ pub fn is_odd(number: i128) -> bool {
 }
 
 /// `is_perfect_square` function: Takes an i128 integer `number` as input and returns a boolean indicating whether `number`
-/// is a perfect square or not. The function calculates the square root of the input number, rounds it to the nearest integer value, and
-/// then checks if the result squared is equal to the original number. If they are equal, it returns true; otherwise, it returns false.
+/// is a perfect square or not. The function computes the exact integer square root with `isqrt` and checks if it squared is equal
+/// to the original number. Negative numbers are never perfect squares.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::base;
+///
+/// assert!(base::is_perfect_square(9007199254740993 * 9007199254740993));
+/// assert!(!base::is_perfect_square(9007199254740993 * 9007199254740993 - 1));
+/// ```
 pub fn is_perfect_square(number: i128) -> bool {
-    let sqrt = (number as f64).sqrt() as i128;
+    if number < 0 {
+        return false;
+    }
+    let sqrt = isqrt(number);
     sqrt * sqrt == number
 }
 /// `is_perfect_cube` function: Takes an i128 integer `number` as input and returns a boolean indicating whether `number`
-/// is a perfect cube or not. The function calculates the cube root of the input number, rounds it to the nearest integer value, and
-/// then checks if the result cubed is equal to the original number. If they are equal, it returns true; otherwise, it returns false.
+/// is a perfect cube or not. The function computes the exact integer cube root with `icbrt` and checks if it cubed is equal to
+/// the original number. Negative numbers can be perfect cubes.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::base;
+///
+/// assert!(base::is_perfect_cube(-27));
+/// assert!(!base::is_perfect_cube(1 << 100));
+/// assert!(!base::is_perfect_cube(i128::MIN));
+/// assert!(!base::is_perfect_cube(i128::MIN + 1));
+/// ```
 pub fn is_perfect_cube(number: i128) -> bool {
-    let cbrt = (number as f64).cbrt() as i128;
-    cbrt * cbrt * cbrt == number
+    let cbrt = icbrt(number);
+    // The floor of the cube root of a number near i128::MIN cubes to below it, so the check must not overflow.
+    cbrt.checked_mul(cbrt)
+        .and_then(|square| square.checked_mul(cbrt))
+        == Some(number)
 }
 
 /// `is_perfect_power` function: Takes an i128 integer `number` as input and returns a boolean indicating whether
-/// `number` is a perfect power or not. The function calculates the square root of the input number, rounds it to the nearest integer
-/// value, and then checks if the result squared is equal to the original number. If they are equal, it returns true; otherwise, it returns
-/// false.
+/// `number` is a perfect power, that is `b^k` for some integer `b` and some `k >= 2`. See `perfect_power` for the details.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::base;
+///
+/// assert!(base::is_perfect_power(1 << 101));
+/// assert!(base::is_perfect_power(-243));
+/// assert!(!base::is_perfect_power(12));
+/// ```
 pub fn is_perfect_power(number: i128) -> bool {
-    let sqrt = (number as f64).sqrt() as i128;
-    sqrt * sqrt == number
+    perfect_power(number).is_some()
+}
+
+/// `perfect_power` function: Takes an i128 integer `number` as input and returns `Some((base, exponent))` with `exponent >= 2`
+/// as large as possible such that `base^exponent == number`, or `None` if `number` is not a perfect power. Negative numbers
+/// are only checked against odd exponents. The trivial cases are reported as `0 = 0^2`, `1 = 1^2` and `-1 = (-1)^3`.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::base;
+///
+/// assert_eq!(base::perfect_power(1024), Some((2, 10)));
+/// assert_eq!(base::perfect_power(36), Some((6, 2)));
+/// assert_eq!(base::perfect_power(-64), Some((-4, 3)));
+/// assert_eq!(base::perfect_power(i128::MIN), Some((-2, 127)));
+/// assert_eq!(base::perfect_power(72), None);
+/// ```
+pub fn perfect_power(number: i128) -> Option<(i128, u32)> {
+    match number {
+        0 | 1 => return Some((number, 2)),
+        -1 => return Some((-1, 3)),
+        _ => {}
+    }
+    let magnitude = number.unsigned_abs();
+    let max_exponent = 127 - magnitude.leading_zeros();
+    (2..=max_exponent)
+        .rev()
+        .filter(|&k| number > 0 || !k.is_multiple_of(2))
+        .find_map(|k| {
+            let root = iroot_unsigned(magnitude, k);
+            (root.pow(k) == magnitude).then(|| {
+                let root = root as i128;
+                (if number < 0 { -root } else { root }, k)
+            })
+        })
+}
+
+/// `isqrt` function: Takes an i128 integer `number` as input and returns the floor of its square root, computed exactly with
+/// Newton's method on integers. Panics if `number` is negative.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::base;
+///
+/// assert_eq!(base::isqrt(99), 9);
+/// assert_eq!(base::isqrt((1 << 60) * (1 << 60) - 1), (1 << 60) - 1);
+/// ```
+pub fn isqrt(number: i128) -> i128 {
+    iroot(number, 2)
+}
+
+/// `icbrt` function: Takes an i128 integer `number` as input and returns the floor of its cube root, computed exactly with
+/// Newton's method on integers. Negative inputs are supported, and are rounded down as well, so `icbrt(-9) == -3`.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::base;
+///
+/// assert_eq!(base::icbrt(26), 2);
+/// assert_eq!(base::icbrt(-27), -3);
+/// assert_eq!(base::icbrt(-26), -3);
+/// ```
+pub fn icbrt(number: i128) -> i128 {
+    iroot(number, 3)
+}
+
+/// `iroot` function: Takes an i128 integer `number` and a degree `k` as input and returns the floor of the `k`th root of
+/// `number`, computed exactly with Newton's method on integers. Negative numbers are supported for odd `k`, and are rounded down
+/// as well. Panics if `k` is zero, or if `k` is even and `number` is negative.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::base;
+///
+/// assert_eq!(base::iroot(1 << 100, 5), 1 << 20);
+/// assert_eq!(base::iroot((1 << 100) - 1, 5), (1 << 20) - 1);
+/// assert_eq!(base::iroot(-100, 3), -5);
+/// ```
+pub fn iroot(number: i128, k: u32) -> i128 {
+    if k == 0 {
+        panic!("Root of degree zero is not supported");
+    }
+    if number >= 0 {
+        return iroot_unsigned(number as u128, k) as i128;
+    }
+    if k.is_multiple_of(2) {
+        panic!("Even root of a negative number is not supported");
+    }
+    let magnitude = number.unsigned_abs();
+    let root = iroot_unsigned(magnitude, k);
+    if root.pow(k) == magnitude {
+        -(root as i128)
+    } else {
+        -(root as i128) - 1
+    }
+}
+
+/// Returns the floor of the `k`th root of `number` for `k >= 1`. Newton's iteration `x -> ((k - 1) x + number / x^(k - 1)) / k`
+/// decreases monotonically from any starting point above the root and stops exactly at the floor of the root.
+fn iroot_unsigned(number: u128, k: u32) -> u128 {
+    if number < 2 || k == 1 {
+        return number;
+    }
+    if k >= 128 {
+        return 1;
+    }
+    let bits = 128 - number.leading_zeros();
+    let mut x: u128 = 1 << bits.div_ceil(k);
+    loop {
+        let quotient = x.checked_pow(k - 1).map_or(0, |power| number / power);
+        let next = ((k - 1) as u128 * x + quotient) / k as u128;
+        if next >= x {
+            return x;
+        }
+        x = next;
+    }
+}
+
+/// `checked_add` function: Returns the sum of `left` and `right`, or an `Overflow` error if it does not fit in an i128.
+///
+/// # Example
+/// ```
+/// use numbers_rus::error::ErrorKind;
+/// use numbers_rus::integers::base;
+///
+/// assert_eq!(base::checked_add(1, 2), Ok(3));
+/// assert_eq!(base::checked_add(i128::MAX, 1).unwrap_err().kind(), ErrorKind::Overflow);
+/// ```
+pub fn checked_add(left: i128, right: i128) -> Result<i128, NumbersError> {
+    left.checked_add(right)
+        .ok_or(NumbersError::new("add", ErrorKind::Overflow))
+}
+/// `checked_subtract` function: Returns the difference of `left` and `right`, or an `Overflow` error if it does not fit in an i128.
+pub fn checked_subtract(left: i128, right: i128) -> Result<i128, NumbersError> {
+    left.checked_sub(right)
+        .ok_or(NumbersError::new("subtract", ErrorKind::Overflow))
+}
+/// `checked_multiply` function: Returns the product of `left` and `right`, or an `Overflow` error if it does not fit in an i128.
+pub fn checked_multiply(left: i128, right: i128) -> Result<i128, NumbersError> {
+    left.checked_mul(right)
+        .ok_or(NumbersError::new("multiply", ErrorKind::Overflow))
+}
+/// `checked_divide` function: Returns the quotient of `left` and `right`. Returns a `DivisionByZero` error if `right` is zero
+/// and an `Overflow` error for `i128::MIN / -1`.
+pub fn checked_divide(left: i128, right: i128) -> Result<i128, NumbersError> {
+    if right == 0 {
+        return Err(NumbersError::new("divide", ErrorKind::DivisionByZero));
+    }
+    left.checked_div(right)
+        .ok_or(NumbersError::new("divide", ErrorKind::Overflow))
+}
+/// `checked_modulo` function: Returns the remainder of `left` divided by `right`. Returns a `DivisionByZero` error if `right` is
+/// zero and an `Overflow` error for `i128::MIN % -1`.
+pub fn checked_modulo(left: i128, right: i128) -> Result<i128, NumbersError> {
+    if right == 0 {
+        return Err(NumbersError::new("modulo", ErrorKind::DivisionByZero));
+    }
+    left.checked_rem(right)
+        .ok_or(NumbersError::new("modulo", ErrorKind::Overflow))
+}
+/// `checked_power` function: Returns `left` raised to the power of `right`. Returns a `NegativeExponent` error if `right` is
+/// negative and an `Overflow` error if the result does not fit in an i128.
+///
+/// # Example
+/// ```
+/// use numbers_rus::error::ErrorKind;
+/// use numbers_rus::integers::base;
+///
+/// assert_eq!(base::checked_power(2, 126), Ok(1 << 126));
+/// assert_eq!(base::checked_power(2, 127).unwrap_err().kind(), ErrorKind::Overflow);
+/// assert_eq!(base::checked_power(2, -1).unwrap_err().kind(), ErrorKind::NegativeExponent);
+/// ```
+pub fn checked_power(left: i128, right: i128) -> Result<i128, NumbersError> {
+    if right < 0 {
+        return Err(NumbersError::new("power", ErrorKind::NegativeExponent));
+    }
+    let overflow = NumbersError::new("power", ErrorKind::Overflow);
+    match u32::try_from(right) {
+        Ok(exponent) => left.checked_pow(exponent).ok_or(overflow),
+        // Only 0, 1 and -1 survive exponents this large.
+        Err(_) => match left {
+            0 | 1 => Ok(left),
+            -1 => Ok(if right % 2 == 0 { 1 } else { -1 }),
+            _ => Err(overflow),
+        },
+    }
+}
+/// `checked_root` function: Returns the `right`th root of `left` as computed by `root`. Returns a `NegativeArgument` error if
+/// `left` is negative and an `InvalidRootDegree` error if `right` is not positive.
+pub fn checked_root(left: i128, right: i128) -> Result<i128, NumbersError> {
+    if left < 0 {
+        return Err(NumbersError::new("root", ErrorKind::NegativeArgument));
+    }
+    if right <= 0 {
+        return Err(NumbersError::new("root", ErrorKind::InvalidRootDegree));
+    }
+    Ok(root(left, right))
+}
+/// `checked_factorial` function: Returns the factorial of `number`. Returns a `NegativeArgument` error if `number` is negative and
+/// an `Overflow` error if the result does not fit in an i128 (any `number` above 33).
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::base;
+///
+/// assert_eq!(base::checked_factorial(5), Ok(120));
+/// assert!(base::checked_factorial(34).is_err());
+/// ```
+pub fn checked_factorial(number: i128) -> Result<i128, NumbersError> {
+    if number < 0 {
+        return Err(NumbersError::new("factorial", ErrorKind::NegativeArgument));
+    }
+    (2..=number)
+        .try_fold(1i128, |acc, x| acc.checked_mul(x))
+        .ok_or(NumbersError::new("factorial", ErrorKind::Overflow))
+}
+/// `checked_fibonacci` function: Returns the `number`th Fibonacci number. Returns a `NegativeArgument` error if `number` is
+/// negative and an `Overflow` error if the result does not fit in an i128 (any `number` above 184).
+pub fn checked_fibonacci(number: i128) -> Result<i128, NumbersError> {
+    if number < 0 {
+        return Err(NumbersError::new("fibonacci", ErrorKind::NegativeArgument));
+    }
+    recurrence::fibonacci(number)
+}
+
+/// `wrapping_add` function: Returns the sum of `left` and `right`, wrapping around at the boundary of the i128 type.
+pub fn wrapping_add(left: i128, right: i128) -> i128 {
+    left.wrapping_add(right)
+}
+/// `wrapping_subtract` function: Returns the difference of `left` and `right`, wrapping around at the boundary of the i128 type.
+pub fn wrapping_subtract(left: i128, right: i128) -> i128 {
+    left.wrapping_sub(right)
+}
+/// `wrapping_multiply` function: Returns the product of `left` and `right`, wrapping around at the boundary of the i128 type.
+pub fn wrapping_multiply(left: i128, right: i128) -> i128 {
+    left.wrapping_mul(right)
+}
+/// `wrapping_divide` function: Returns the quotient of `left` and `right`, wrapping `i128::MIN / -1` to `i128::MIN`.
+/// Panics if `right` is zero.
+pub fn wrapping_divide(left: i128, right: i128) -> i128 {
+    left.wrapping_div(right)
+}
+/// `wrapping_modulo` function: Returns the remainder of `left` divided by `right`, returning 0 for `i128::MIN % -1`.
+/// Panics if `right` is zero.
+pub fn wrapping_modulo(left: i128, right: i128) -> i128 {
+    left.wrapping_rem(right)
+}
+/// `wrapping_power` function: Returns `left` raised to the power of `right`, wrapping around at the boundary of the i128 type.
+/// Panics if the exponent is negative.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::base;
+///
+/// assert_eq!(base::wrapping_power(2, 127), i128::MIN);
+/// assert_eq!(base::wrapping_power(2, 128), 0);
+/// ```
+pub fn wrapping_power(left: i128, right: i128) -> i128 {
+    if right < 0 {
+        panic!("Exponentiation with negative exponent is not supported");
+    }
+
+    let mut base = left;
+    let mut result: i128 = 1;
+    let mut exponent = right;
+    while exponent > 0 {
+        if exponent % 2 == 1 {
+            result = result.wrapping_mul(base);
+        }
+        base = base.wrapping_mul(base);
+        exponent /= 2;
+    }
+    result
+}
+/// `wrapping_factorial` function: Returns the factorial of `number` modulo 2^128, interpreted as an i128. Panics if the input value
+/// is negative.
+pub fn wrapping_factorial(number: i128) -> i128 {
+    if number < 0 {
+        panic!("Factorial of a negative number is not defined");
+    }
+    let mut result: i128 = 1;
+    let mut i = 2;
+    // Once 2^128 divides the product every further term leaves it at zero.
+    while i <= number && result != 0 {
+        result = result.wrapping_mul(i);
+        i += 1;
+    }
+    result
+}
+/// `wrapping_fibonacci` function: Returns the `number`th Fibonacci number modulo 2^128, interpreted as an i128. It uses the fast
+/// doubling identities, so it runs in `O(log n)` steps. Panics if the input value is negative.
+pub fn wrapping_fibonacci(number: i128) -> i128 {
+    if number < 0 {
+        panic!("Fibonacci of a negative index is not supported");
+    }
+    let (mut a, mut b) = (0i128, 1i128); // F(k), F(k+1)
+    for i in (0..128 - number.leading_zeros()).rev() {
+        let c = a.wrapping_mul(b.wrapping_mul(2).wrapping_sub(a));
+        let d = a.wrapping_mul(a).wrapping_add(b.wrapping_mul(b));
+        if (number >> i) & 1 == 1 {
+            a = d;
+            b = c.wrapping_add(d);
+        } else {
+            a = c;
+            b = d;
+        }
+    }
+    a
+}
+
+/// `saturating_add` function: Returns the sum of `left` and `right`, saturating at `i128::MIN` or `i128::MAX` on overflow.
+pub fn saturating_add(left: i128, right: i128) -> i128 {
+    left.saturating_add(right)
+}
+/// `saturating_subtract` function: Returns the difference of `left` and `right`, saturating at `i128::MIN` or `i128::MAX` on
+/// overflow.
+pub fn saturating_subtract(left: i128, right: i128) -> i128 {
+    left.saturating_sub(right)
+}
+/// `saturating_multiply` function: Returns the product of `left` and `right`, saturating at `i128::MIN` or `i128::MAX` on overflow.
+pub fn saturating_multiply(left: i128, right: i128) -> i128 {
+    left.saturating_mul(right)
+}
+/// `saturating_divide` function: Returns the quotient of `left` and `right`, saturating `i128::MIN / -1` to `i128::MAX`.
+/// Panics if `right` is zero.
+pub fn saturating_divide(left: i128, right: i128) -> i128 {
+    left.saturating_div(right)
+}
+/// `saturating_modulo` function: Returns the remainder of `left` divided by `right`. A remainder can never exceed the i128
+/// range, so the one overflowing case, `i128::MIN % -1`, returns the exact result 0. Panics if `right` is zero.
+pub fn saturating_modulo(left: i128, right: i128) -> i128 {
+    left.wrapping_rem(right)
+}
+/// `saturating_power` function: Returns `left` raised to the power of `right`, saturating at `i128::MIN` or `i128::MAX` on
+/// overflow. Panics if the exponent is negative.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::base;
+///
+/// assert_eq!(base::saturating_power(10, 40), i128::MAX);
+/// assert_eq!(base::saturating_power(-10, 41), i128::MIN);
+/// ```
+pub fn saturating_power(left: i128, right: i128) -> i128 {
+    if right < 0 {
+        panic!("Exponentiation with negative exponent is not supported");
+    }
+    checked_power(left, right).unwrap_or(if left < 0 && right % 2 == 1 {
+        i128::MIN
+    } else {
+        i128::MAX
+    })
+}
+/// `saturating_factorial` function: Returns the factorial of `number`, saturating at `i128::MAX` on overflow. Panics if the input
+/// value is negative.
+pub fn saturating_factorial(number: i128) -> i128 {
+    if number < 0 {
+        panic!("Factorial of a negative number is not defined");
+    }
+    checked_factorial(number).unwrap_or(i128::MAX)
+}
+/// `saturating_fibonacci` function: Returns the `number`th Fibonacci number, saturating at `i128::MAX` on overflow. Panics if the
+/// input value is negative.
+pub fn saturating_fibonacci(number: i128) -> i128 {
+    if number < 0 {
+        panic!("Fibonacci of a negative index is not supported");
+    }
+    checked_fibonacci(number).unwrap_or(i128::MAX)
+}
+
+/// `overflowing_add` function: Returns the wrapped sum of `left` and `right` together with a boolean indicating whether an
+/// overflow occurred.
+pub fn overflowing_add(left: i128, right: i128) -> (i128, bool) {
+    left.overflowing_add(right)
+}
+/// `overflowing_subtract` function: Returns the wrapped difference of `left` and `right` together with a boolean indicating
+/// whether an overflow occurred.
+pub fn overflowing_subtract(left: i128, right: i128) -> (i128, bool) {
+    left.overflowing_sub(right)
+}
+/// `overflowing_multiply` function: Returns the wrapped product of `left` and `right` together with a boolean indicating whether
+/// an overflow occurred.
+pub fn overflowing_multiply(left: i128, right: i128) -> (i128, bool) {
+    left.overflowing_mul(right)
+}
+/// `overflowing_divide` function: Returns the wrapped quotient of `left` and `right` together with a boolean indicating whether
+/// an overflow occurred. Panics if `right` is zero.
+pub fn overflowing_divide(left: i128, right: i128) -> (i128, bool) {
+    left.overflowing_div(right)
+}
+/// `overflowing_modulo` function: Returns the wrapped remainder of `left` divided by `right` together with a boolean indicating
+/// whether an overflow occurred. Panics if `right` is zero.
+pub fn overflowing_modulo(left: i128, right: i128) -> (i128, bool) {
+    left.overflowing_rem(right)
+}
+/// `overflowing_power` function: Returns the wrapped value of `left` raised to the power of `right` together with a boolean
+/// indicating whether an overflow occurred. Panics if the exponent is negative.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::base;
+///
+/// assert_eq!(base::overflowing_power(3, 4), (81, false));
+/// assert_eq!(base::overflowing_power(2, 127), (i128::MIN, true));
+/// ```
+pub fn overflowing_power(left: i128, right: i128) -> (i128, bool) {
+    let wrapped = wrapping_power(left, right);
+    (wrapped, checked_power(left, right).is_err())
+}
+/// `overflowing_factorial` function: Returns the wrapped factorial of `number` together with a boolean indicating whether an
+/// overflow occurred. Panics if the input value is negative.
+pub fn overflowing_factorial(number: i128) -> (i128, bool) {
+    let wrapped = wrapping_factorial(number);
+    (wrapped, checked_factorial(number).is_err())
+}
+/// `overflowing_fibonacci` function: Returns the wrapped `number`th Fibonacci number together with a boolean indicating whether
+/// an overflow occurred. Panics if the input value is negative.
+pub fn overflowing_fibonacci(number: i128) -> (i128, bool) {
+    let wrapped = wrapping_fibonacci(number);
+    (wrapped, checked_fibonacci(number).is_err())
+}
+
+/// `add_big` function: Takes two `BigInt` integers `left` and `right` as input and returns their sum as a `BigInt`. Unlike `add`,
+/// the result never overflows.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::base;
+/// use numbers_rus::integers::big_integer::BigInt;
+///
+/// let a = BigInt::from(i128::MAX);
+/// let c = base::add_big(&a, &a);
+/// assert_eq!(c.to_string(), "340282366920938463463374607431768211454");
+/// ```
+pub fn add_big(left: &BigInt, right: &BigInt) -> BigInt {
+    left + right
+}
+
+/// `power_big` function: Takes two `BigInt` integers `left` and `right` as input and returns `left` raised to the power of `right`
+/// as a `BigInt`. Panics if the exponent is negative or does not fit in a `u64`.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::base;
+/// use numbers_rus::integers::big_integer::BigInt;
+///
+/// let c = base::power_big(&BigInt::from(10), &BigInt::from(40));
+/// assert_eq!(c.to_string(), "10000000000000000000000000000000000000000");
+/// ```
+pub fn power_big(left: &BigInt, right: &BigInt) -> BigInt {
+    if right.is_negative() {
+        panic!("Exponentiation with negative exponent is not supported");
+    }
+    let exponent = right.to_u64().expect("Exponent is too large");
+    left.pow(exponent)
+}
+
+/// `factorial_big` function: Takes a `BigInt` integer `number` as input and returns its factorial as a `BigInt`. The product is
+/// computed by binary splitting so that large factorials benefit from Karatsuba multiplication. Panics if the input value is
+/// negative or does not fit in a `u64`.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::base;
+/// use numbers_rus::integers::big_integer::BigInt;
+///
+/// let c = base::factorial_big(&BigInt::from(35));
+/// assert_eq!(c.to_string(), "10333147966386144929666651337523200000000");
+/// ```
+pub fn factorial_big(number: &BigInt) -> BigInt {
+    if number.is_negative() {
+        panic!("Factorial of a negative number is not defined");
+    }
+    let number = number.to_u64().expect("Factorial argument is too large");
+    if number < 2 {
+        return BigInt::one();
+    }
+    product_range(2, number)
+}
+
+/// Returns the product of all integers in `low..=high`, splitting the range in half so both operands stay balanced.
+fn product_range(low: u64, high: u64) -> BigInt {
+    if high - low < 8 {
+        return (low + 1..=high).fold(BigInt::from(low), |acc, x| acc * BigInt::from(x));
+    }
+    let middle = low + (high - low) / 2;
+    product_range(low, middle) * product_range(middle + 1, high)
+}
+
+/// `fibonacci_big` function: Takes a `BigInt` integer `number` as input and returns the `number`th Fibonacci number as a `BigInt`.
+/// It uses the fast doubling identities `F(2k) = F(k)(2F(k+1) - F(k))` and `F(2k+1) = F(k)^2 + F(k+1)^2`, so only
+/// `O(log n)` big multiplications are needed. Panics if the input value is negative.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::base;
+/// use numbers_rus::integers::big_integer::BigInt;
+///
+/// let c = base::fibonacci_big(&BigInt::from(200));
+/// assert_eq!(c.to_string(), "280571172992510140037611932413038677189525");
+/// ```
+pub fn fibonacci_big(number: &BigInt) -> BigInt {
+    if number.is_negative() {
+        panic!("Fibonacci of a negative index is not supported");
+    }
+    let mut a = BigInt::zero(); // F(k)
+    let mut b = BigInt::one(); // F(k+1)
+    for i in (0..number.bits()).rev() {
+        let c = &a * &(&(&b << 1) - &a);
+        let d = &(&a * &a) + &(&b * &b);
+        if number.bit(i) {
+            b = &c + &d;
+            a = d;
+        } else {
+            a = c;
+            b = d;
+        }
+    }
+    a
+}
+
+/// `is_prime_big` function: Takes a `BigInt` integer `number` as input and returns a boolean indicating whether `number` is a prime
+/// or not. Small factors are removed by trial division, then the Miller-Rabin test is run with the first thirteen primes as
+/// witnesses. This is deterministic for every `number` below 3.3 * 10^24 and a strong probable-prime test above that.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::base;
+/// use numbers_rus::integers::big_integer::BigInt;
+///
+/// let mersenne: BigInt = base::power_big(&BigInt::from(2), &BigInt::from(127)) - BigInt::one();
+/// assert!(base::is_prime_big(&mersenne));
+/// assert!(!base::is_prime_big(&(mersenne + BigInt::from(2))));
+/// ```
+pub fn is_prime_big(number: &BigInt) -> bool {
+    const WITNESSES: [u32; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+    if number <= &BigInt::one() {
+        return false;
+    }
+    for &p in WITNESSES.iter() {
+        let p = BigInt::from(p);
+        if number == &p {
+            return true;
+        }
+        if (number % &p).is_zero() {
+            return false;
+        }
+    }
+
+    // Write number - 1 = d * 2^s with d odd.
+    let one = BigInt::one();
+    let number_minus_one = number - &one;
+    let s = number_minus_one.trailing_zeros().unwrap();
+    let d = &number_minus_one >> s as usize;
+    'witness: for &a in WITNESSES.iter() {
+        let mut x = BigInt::from(a).modpow(&d, number);
+        if x == one || x == number_minus_one {
+            continue;
+        }
+        for _ in 1..s {
+            x = &(&x * &x) % number;
+            if x == number_minus_one {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
 }
@@ -0,0 +1,1034 @@
+//! The `big_integer` module provides `BigInt`, an arbitrary-precision signed integer. Values are stored as a sign and a
+//! little-endian vector of 32-bit limbs, so results never wrap or overflow the way `i128` does. Addition, subtraction,
+//! multiplication (schoolbook for small operands, Karatsuba for large ones), truncating division with remainder, parsing
+//! and formatting in any radix from 2 to 36 are supported.
+//!
+//! # Examples
+//! ```
+//! use numbers_rus::integers::big_integer::BigInt;
+//!
+//! let a = BigInt::from(i128::MAX);
+//! let b = &a * &a;
+//! assert_eq!(
+//!     b.to_string(),
+//!     "28948022309329048855892746252171976962977213799489202546401021394546514198529"
+//! );
+//! assert_eq!(BigInt::from_str_radix("ff", 16).unwrap(), BigInt::from(255));
+//! ```
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Shl, Shr, Sub, SubAssign,
+};
+use std::str::FromStr;
+
+/// Operand length (in limbs) above which multiplication switches from the schoolbook method to Karatsuba.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// `BigInt` is an arbitrary-precision signed integer.
+///
+/// # Example
+///
+/// ```
+/// use numbers_rus::integers::big_integer::BigInt;
+///
+/// let a: BigInt = "123456789012345678901234567890".parse().unwrap();
+/// let b = BigInt::from(10);
+/// let (q, r) = a.div_rem(&b);
+/// assert_eq!(q.to_string(), "12345678901234567890123456789");
+/// assert_eq!(r, BigInt::zero());
+/// ```
+///
+/// # Methods
+///
+/// * `new` - creates a `BigInt` from a sign and little-endian 32-bit limbs.
+/// * `zero` - returns a new `BigInt` equal to zero.
+/// * `one` - returns a new `BigInt` equal to one.
+/// * `from_str_radix` - parses a `BigInt` from a string in the given radix.
+/// * `to_str_radix` - formats the `BigInt` as a string in the given radix.
+/// * `div_rem` - returns the truncated quotient and the remainder of a division.
+/// * `pow` - raises the `BigInt` to a non-negative integer power.
+/// * `modpow` - modular exponentiation.
+/// * `abs`, `signum`, `is_zero`, `is_negative`, `is_positive`, `is_even`, `is_odd` - sign and parity helpers.
+/// * `bits` - returns the number of significant bits in the magnitude.
+/// * `to_i128`, `to_u64`, `to_f64` - conversions back to primitive types.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: Vec<u32>,
+}
+
+/// Error returned when a string cannot be parsed into a `BigInt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBigIntError {
+    message: &'static str,
+}
+
+impl fmt::Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ParseBigIntError {}
+
+impl BigInt {
+    /// Creates a `BigInt` from a sign flag and little-endian 32-bit limbs. Leading zero limbs are removed, and zero is
+    /// always stored as non-negative.
+    pub fn new(negative: bool, magnitude: Vec<u32>) -> Self {
+        let mut result = Self {
+            negative,
+            magnitude,
+        };
+        result.normalize();
+        result
+    }
+
+    /// zero - returns a `BigInt` equal to zero
+    pub fn zero() -> Self {
+        Self {
+            negative: false,
+            magnitude: Vec::new(),
+        }
+    }
+
+    /// one - returns a `BigInt` equal to one
+    pub fn one() -> Self {
+        Self {
+            negative: false,
+            magnitude: vec![1],
+        }
+    }
+
+    /// Parses a `BigInt` from `text` in the given `radix` (2 to 36). A leading `+` or `-` sign is accepted and
+    /// underscores between digits are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::big_integer::BigInt;
+    ///
+    /// let a = BigInt::from_str_radix("-zz", 36).unwrap();
+    /// assert_eq!(a, BigInt::from(-1295));
+    /// assert!(BigInt::from_str_radix("12", 2).is_err());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range 2 to 36.
+    pub fn from_str_radix(text: &str, radix: u32) -> Result<Self, ParseBigIntError> {
+        assert!((2..=36).contains(&radix), "Radix must be between 2 and 36");
+        let (negative, digits) = match text.as_bytes().first() {
+            Some(b'-') => (true, &text[1..]),
+            Some(b'+') => (false, &text[1..]),
+            _ => (false, text),
+        };
+        if digits.is_empty() || digits.starts_with('_') || digits.ends_with('_') {
+            return Err(ParseBigIntError {
+                message: "cannot parse integer from empty string",
+            });
+        }
+
+        // Consume as many digits as fit in a single limb at a time.
+        let (chunk_size, chunk_base) = radix_chunk(radix);
+        let mut magnitude: Vec<u32> = Vec::new();
+        let mut chunk: u32 = 0;
+        let mut chunk_len = 0;
+        for c in digits.chars().filter(|&c| c != '_') {
+            let digit = c.to_digit(radix).ok_or(ParseBigIntError {
+                message: "invalid digit found in string",
+            })?;
+            chunk = chunk * radix + digit;
+            chunk_len += 1;
+            if chunk_len == chunk_size {
+                mul_add_small(&mut magnitude, chunk_base, chunk);
+                chunk = 0;
+                chunk_len = 0;
+            }
+        }
+        if chunk_len > 0 {
+            mul_add_small(&mut magnitude, radix.pow(chunk_len), chunk);
+        }
+        Ok(Self::new(negative, magnitude))
+    }
+
+    /// Formats the `BigInt` in the given `radix` (2 to 36) using lowercase letters for digits above 9.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::big_integer::BigInt;
+    ///
+    /// let a = BigInt::from(-255);
+    /// assert_eq!(a.to_str_radix(16), "-ff");
+    /// assert_eq!(a.to_str_radix(2), "-11111111");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range 2 to 36.
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        assert!((2..=36).contains(&radix), "Radix must be between 2 and 36");
+        if self.is_zero() {
+            return "0".to_string();
+        }
+
+        // Peel off limb-sized chunks of digits from the least significant end.
+        let (chunk_size, chunk_base) = radix_chunk(radix);
+        let mut digits: Vec<u8> = Vec::new();
+        let mut magnitude = self.magnitude.clone();
+        while !magnitude.is_empty() {
+            let mut chunk = div_rem_small_in_place(&mut magnitude, chunk_base);
+            for _ in 0..chunk_size {
+                if magnitude.is_empty() && chunk == 0 {
+                    break;
+                }
+                let digit = std::char::from_digit(chunk % radix, radix).unwrap();
+                digits.push(digit as u8);
+                chunk /= radix;
+            }
+        }
+        if self.negative {
+            digits.push(b'-');
+        }
+        digits.reverse();
+        String::from_utf8(digits).unwrap()
+    }
+
+    /// Divides `self` by `other` and returns the quotient truncated towards zero together with the remainder, which
+    /// has the same sign as `self` (matching the behaviour of `/` and `%` on `i128`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::big_integer::BigInt;
+    ///
+    /// let (q, r) = BigInt::from(-7).div_rem(&BigInt::from(2));
+    /// assert_eq!(q, BigInt::from(-3));
+    /// assert_eq!(r, BigInt::from(-1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    pub fn div_rem(&self, other: &Self) -> (Self, Self) {
+        assert!(!other.is_zero(), "Division by zero");
+        let (quotient, remainder) = div_rem_magnitude(&self.magnitude, &other.magnitude);
+        (
+            Self::new(self.negative != other.negative, quotient),
+            Self::new(self.negative, remainder),
+        )
+    }
+
+    /// Returns `self` raised to the power `exponent`, using binary exponentiation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::big_integer::BigInt;
+    ///
+    /// let a = BigInt::from(10).pow(40);
+    /// assert_eq!(a.to_string(), format!("1{}", "0".repeat(40)));
+    /// ```
+    pub fn pow(&self, exponent: u64) -> Self {
+        let mut base = self.clone();
+        let mut result = Self::one();
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    /// Returns `self` raised to the power `exponent` modulo `modulus`. The result is always in `0..|modulus|`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::big_integer::BigInt;
+    ///
+    /// let a = BigInt::from(4).modpow(&BigInt::from(13), &BigInt::from(497));
+    /// assert_eq!(a, BigInt::from(445));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `exponent` is negative or `modulus` is zero.
+    pub fn modpow(&self, exponent: &Self, modulus: &Self) -> Self {
+        assert!(
+            !exponent.is_negative(),
+            "Exponentiation with negative exponent is not supported"
+        );
+        assert!(!modulus.is_zero(), "Modulus must not be zero");
+        let modulus = modulus.abs();
+        let mut base = self.rem_euclid(&modulus);
+        let mut result = Self::one().rem_euclid(&modulus);
+        for i in 0..exponent.bits() {
+            if exponent.bit(i) {
+                result = &(&result * &base) % &modulus;
+            }
+            base = &(&base * &base) % &modulus;
+        }
+        result
+    }
+
+    /// Returns the least non-negative remainder of `self` divided by `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    pub fn rem_euclid(&self, other: &Self) -> Self {
+        let remainder = self % other;
+        if remainder.is_negative() {
+            remainder + other.abs()
+        } else {
+            remainder
+        }
+    }
+
+    /// abs - returns the absolute value
+    pub fn abs(&self) -> Self {
+        Self {
+            negative: false,
+            magnitude: self.magnitude.clone(),
+        }
+    }
+
+    /// signum - returns -1, 0 or 1 depending on the sign of the number
+    pub fn signum(&self) -> i32 {
+        if self.is_zero() {
+            0
+        } else if self.negative {
+            -1
+        } else {
+            1
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn is_positive(&self) -> bool {
+        !self.negative && !self.is_zero()
+    }
+
+    pub fn is_even(&self) -> bool {
+        self.magnitude.first().is_none_or(|limb| limb & 1 == 0)
+    }
+
+    pub fn is_odd(&self) -> bool {
+        !self.is_even()
+    }
+
+    /// bits - returns the number of bits needed to represent the magnitude (zero for zero)
+    pub fn bits(&self) -> u64 {
+        match self.magnitude.last() {
+            Some(top) => self.magnitude.len() as u64 * 32 - top.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    /// bit - returns whether bit `index` of the magnitude is set
+    pub fn bit(&self, index: u64) -> bool {
+        let limb = (index / 32) as usize;
+        limb < self.magnitude.len() && (self.magnitude[limb] >> (index % 32)) & 1 == 1
+    }
+
+    /// trailing_zeros - returns the number of trailing zero bits of the magnitude, or `None` for zero
+    pub fn trailing_zeros(&self) -> Option<u64> {
+        let index = self.magnitude.iter().position(|&limb| limb != 0)?;
+        Some(index as u64 * 32 + self.magnitude[index].trailing_zeros() as u64)
+    }
+
+    /// Returns the value as an `i128` if it fits.
+    pub fn to_i128(&self) -> Option<i128> {
+        if self.magnitude.len() > 4 {
+            return None;
+        }
+        let magnitude = self
+            .magnitude
+            .iter()
+            .rev()
+            .fold(0u128, |acc, &limb| (acc << 32) | limb as u128);
+        if self.negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+
+    /// Returns the value as a `u64` if it is non-negative and fits.
+    pub fn to_u64(&self) -> Option<u64> {
+        if self.negative || self.magnitude.len() > 2 {
+            return None;
+        }
+        Some(
+            self.magnitude
+                .iter()
+                .rev()
+                .fold(0u64, |acc, &limb| (acc << 32) | limb as u64),
+        )
+    }
+
+    /// Returns the nearest `f64` approximation of the value (infinite if the magnitude exceeds `f64::MAX`).
+    pub fn to_f64(&self) -> f64 {
+        let bits = self.bits();
+        let value = if bits <= 128 {
+            // The magnitude fits a u128, whose conversion rounds to nearest.
+            self.magnitude
+                .iter()
+                .rev()
+                .fold(0u128, |acc, &limb| (acc << 32) | limb as u128) as f64
+        } else {
+            // Keep the top 64 bits plus a sticky bit so the final conversion rounds correctly.
+            let shift = bits - 64;
+            let top = (self.abs() >> shift as usize).to_u64().unwrap();
+            let sticky = self.trailing_zeros().unwrap() < shift;
+            let mantissa = (top as u128) << 1 | sticky as u128;
+            mantissa as f64 * 2f64.powi(shift.min(2000) as i32 - 1)
+        };
+        if self.negative {
+            -value
+        } else {
+            value
+        }
+    }
+
+    /// Removes leading zero limbs and clears the sign of zero.
+    fn normalize(&mut self) {
+        while self.magnitude.last() == Some(&0) {
+            self.magnitude.pop();
+        }
+        if self.magnitude.is_empty() {
+            self.negative = false;
+        }
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = self.to_str_radix(10);
+        match text.strip_prefix('-') {
+            Some(digits) => f.pad_integral(false, "", digits),
+            None => f.pad_integral(true, "", &text),
+        }
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(s, 10)
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_magnitude(&self.magnitude, &other.magnitude),
+            (true, true) => cmp_magnitude(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<u128> for BigInt {
+    fn from(value: u128) -> Self {
+        let magnitude = (0..4).map(|i| (value >> (32 * i)) as u32).collect();
+        Self::new(false, magnitude)
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(value: i128) -> Self {
+        let mut result = Self::from(value.unsigned_abs());
+        result.negative = value < 0;
+        result.normalize();
+        result
+    }
+}
+
+macro_rules! impl_from_primitive {
+    ($($signed:ty),* ; $($unsigned:ty),*) => {
+        $(impl From<$signed> for BigInt {
+            fn from(value: $signed) -> Self {
+                Self::from(value as i128)
+            }
+        })*
+        $(impl From<$unsigned> for BigInt {
+            fn from(value: $unsigned) -> Self {
+                Self::from(value as u128)
+            }
+        })*
+    };
+}
+
+impl_from_primitive!(i8, i16, i32, i64, isize; u8, u16, u32, u64, usize);
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(mut self) -> BigInt {
+        self.negative = !self.negative;
+        self.normalize();
+        self
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        -self.clone()
+    }
+}
+
+impl Add<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::new(
+                self.negative,
+                add_magnitude(&self.magnitude, &other.magnitude),
+            );
+        }
+        match cmp_magnitude(&self.magnitude, &other.magnitude) {
+            Ordering::Equal => BigInt::zero(),
+            Ordering::Greater => BigInt::new(
+                self.negative,
+                sub_magnitude(&self.magnitude, &other.magnitude),
+            ),
+            Ordering::Less => BigInt::new(
+                other.negative,
+                sub_magnitude(&other.magnitude, &self.magnitude),
+            ),
+        }
+    }
+}
+
+impl Sub<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: &BigInt) -> BigInt {
+        self + &(-other)
+    }
+}
+
+impl Mul<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: &BigInt) -> BigInt {
+        BigInt::new(
+            self.negative != other.negative,
+            mul_magnitude(&self.magnitude, &other.magnitude),
+        )
+    }
+}
+
+impl Div<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, other: &BigInt) -> BigInt {
+        self.div_rem(other).0
+    }
+}
+
+impl Rem<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, other: &BigInt) -> BigInt {
+        self.div_rem(other).1
+    }
+}
+
+/// Forwards the owned and mixed owned/borrowed operator forms, plus the `*Assign` form, to the `&BigInt op &BigInt`
+/// implementation.
+macro_rules! forward_binop {
+    ($($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident);* $(;)?) => {
+        $(
+            impl $trait<BigInt> for BigInt {
+                type Output = BigInt;
+
+                fn $method(self, other: BigInt) -> BigInt {
+                    (&self).$method(&other)
+                }
+            }
+
+            impl $trait<&BigInt> for BigInt {
+                type Output = BigInt;
+
+                fn $method(self, other: &BigInt) -> BigInt {
+                    (&self).$method(other)
+                }
+            }
+
+            impl $trait<BigInt> for &BigInt {
+                type Output = BigInt;
+
+                fn $method(self, other: BigInt) -> BigInt {
+                    self.$method(&other)
+                }
+            }
+
+            impl $assign_trait<BigInt> for BigInt {
+                fn $assign_method(&mut self, other: BigInt) {
+                    *self = (&*self).$method(&other);
+                }
+            }
+
+            impl $assign_trait<&BigInt> for BigInt {
+                fn $assign_method(&mut self, other: &BigInt) {
+                    *self = (&*self).$method(other);
+                }
+            }
+        )*
+    };
+}
+
+forward_binop!(
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+    Rem, rem, RemAssign, rem_assign;
+);
+
+impl Shl<usize> for BigInt {
+    type Output = BigInt;
+
+    /// Multiplies the value by `2^shift`.
+    fn shl(self, shift: usize) -> BigInt {
+        BigInt::new(self.negative, shl_magnitude(&self.magnitude, shift))
+    }
+}
+
+impl Shl<usize> for &BigInt {
+    type Output = BigInt;
+
+    fn shl(self, shift: usize) -> BigInt {
+        BigInt::new(self.negative, shl_magnitude(&self.magnitude, shift))
+    }
+}
+
+impl Shr<usize> for BigInt {
+    type Output = BigInt;
+
+    /// Divides the magnitude by `2^shift`, truncating towards zero.
+    fn shr(self, shift: usize) -> BigInt {
+        BigInt::new(self.negative, shr_magnitude(&self.magnitude, shift))
+    }
+}
+
+impl Shr<usize> for &BigInt {
+    type Output = BigInt;
+
+    fn shr(self, shift: usize) -> BigInt {
+        BigInt::new(self.negative, shr_magnitude(&self.magnitude, shift))
+    }
+}
+
+/// Returns the number of digits of `radix` that fit in one limb and `radix` raised to that number.
+fn radix_chunk(radix: u32) -> (u32, u32) {
+    let mut size = 1;
+    let mut base = radix;
+    while let Some(next) = base.checked_mul(radix) {
+        size += 1;
+        base = next;
+    }
+    (size, base)
+}
+
+fn trim(magnitude: &mut Vec<u32>) {
+    while magnitude.last() == Some(&0) {
+        magnitude.pop();
+    }
+}
+
+fn cmp_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut result = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u64;
+    for (i, &limb) in long.iter().enumerate() {
+        let sum = limb as u64 + *short.get(i).unwrap_or(&0) as u64 + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    if carry > 0 {
+        result.push(carry as u32);
+    }
+    result
+}
+
+/// Subtracts `b` from `a`, requiring `a >= b`.
+fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, &limb) in a.iter().enumerate() {
+        let mut diff = limb as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        borrow = 0;
+        if diff < 0 {
+            diff += 1 << 32;
+            borrow = 1;
+        }
+        result.push(diff as u32);
+    }
+    trim(&mut result);
+    result
+}
+
+/// Adds `b * 2^(32 * offset)` into `target` in place, growing it as needed.
+fn add_shifted_in_place(target: &mut Vec<u32>, b: &[u32], offset: usize) {
+    if target.len() < offset + b.len() {
+        target.resize(offset + b.len(), 0);
+    }
+    let mut carry = 0u64;
+    let mut i = 0;
+    while i < b.len() || carry > 0 {
+        if offset + i == target.len() {
+            target.push(0);
+        }
+        let sum = target[offset + i] as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+        target[offset + i] = sum as u32;
+        carry = sum >> 32;
+        i += 1;
+    }
+}
+
+fn mul_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        return mul_schoolbook(a, b);
+    }
+    karatsuba(a, b)
+}
+
+fn mul_schoolbook(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let product = x as u64 * y as u64 + result[i + j] as u64 + carry;
+            result[i + j] = product as u32;
+            carry = product >> 32;
+        }
+        result[i + b.len()] = carry as u32;
+    }
+    trim(&mut result);
+    result
+}
+
+/// Karatsuba multiplication: splits both operands at `m` limbs and uses three half-size products instead of four.
+fn karatsuba(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let m = a.len().max(b.len()) / 2;
+    let split = |x: &[u32]| -> (Vec<u32>, Vec<u32>) {
+        if x.len() <= m {
+            (x.to_vec(), Vec::new())
+        } else {
+            let mut low = x[..m].to_vec();
+            trim(&mut low);
+            (low, x[m..].to_vec())
+        }
+    };
+    let (a0, a1) = split(a);
+    let (b0, b1) = split(b);
+
+    let z0 = mul_magnitude(&a0, &b0);
+    let z2 = mul_magnitude(&a1, &b1);
+    let z1 = sub_magnitude(
+        &sub_magnitude(
+            &mul_magnitude(&add_magnitude(&a0, &a1), &add_magnitude(&b0, &b1)),
+            &z0,
+        ),
+        &z2,
+    );
+
+    let mut result = z0;
+    add_shifted_in_place(&mut result, &z1, m);
+    add_shifted_in_place(&mut result, &z2, 2 * m);
+    trim(&mut result);
+    result
+}
+
+/// Computes `magnitude = magnitude * factor + addend` in place.
+fn mul_add_small(magnitude: &mut Vec<u32>, factor: u32, addend: u32) {
+    let mut carry = addend as u64;
+    for limb in magnitude.iter_mut() {
+        let product = *limb as u64 * factor as u64 + carry;
+        *limb = product as u32;
+        carry = product >> 32;
+    }
+    if carry > 0 {
+        magnitude.push(carry as u32);
+    }
+}
+
+/// Divides `magnitude` by a single limb in place and returns the remainder.
+fn div_rem_small_in_place(magnitude: &mut Vec<u32>, divisor: u32) -> u32 {
+    let mut remainder = 0u64;
+    for limb in magnitude.iter_mut().rev() {
+        let current = (remainder << 32) | *limb as u64;
+        *limb = (current / divisor as u64) as u32;
+        remainder = current % divisor as u64;
+    }
+    trim(magnitude);
+    remainder as u32
+}
+
+fn shl_magnitude(magnitude: &[u32], shift: usize) -> Vec<u32> {
+    if magnitude.is_empty() {
+        return Vec::new();
+    }
+    let (limbs, bits) = (shift / 32, shift % 32);
+    let mut result = vec![0u32; limbs];
+    if bits == 0 {
+        result.extend_from_slice(magnitude);
+    } else {
+        let mut carry = 0u32;
+        for &limb in magnitude {
+            result.push((limb << bits) | carry);
+            carry = limb >> (32 - bits);
+        }
+        if carry > 0 {
+            result.push(carry);
+        }
+    }
+    result
+}
+
+fn shr_magnitude(magnitude: &[u32], shift: usize) -> Vec<u32> {
+    let (limbs, bits) = (shift / 32, shift % 32);
+    if limbs >= magnitude.len() {
+        return Vec::new();
+    }
+    let source = &magnitude[limbs..];
+    let mut result: Vec<u32> = if bits == 0 {
+        source.to_vec()
+    } else {
+        (0..source.len())
+            .map(|i| (source[i] >> bits) | (*source.get(i + 1).unwrap_or(&0) << (32 - bits)))
+            .collect()
+    };
+    trim(&mut result);
+    result
+}
+
+/// Long division of magnitudes (Knuth, TAOCP vol. 2, algorithm D). Returns `(quotient, remainder)`.
+fn div_rem_magnitude(u: &[u32], v: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if cmp_magnitude(u, v) == Ordering::Less {
+        return (Vec::new(), u.to_vec());
+    }
+    if v.len() == 1 {
+        let mut quotient = u.to_vec();
+        let remainder = div_rem_small_in_place(&mut quotient, v[0]);
+        let remainder = if remainder == 0 {
+            Vec::new()
+        } else {
+            vec![remainder]
+        };
+        return (quotient, remainder);
+    }
+
+    // Normalize so the top limb of the divisor has its high bit set.
+    let shift = v[v.len() - 1].leading_zeros() as usize;
+    let vn = shl_magnitude(v, shift);
+    let mut un = shl_magnitude(u, shift);
+    un.resize(u.len() + 1, 0);
+
+    let n = vn.len();
+    let m = u.len() - n;
+    let base = 1u64 << 32;
+    let mut quotient = vec![0u32; m + 1];
+    for j in (0..=m).rev() {
+        let numerator = ((un[j + n] as u64) << 32) | un[j + n - 1] as u64;
+        let mut qhat = numerator / vn[n - 1] as u64;
+        let mut rhat = numerator % vn[n - 1] as u64;
+        while qhat >= base || qhat * vn[n - 2] as u64 > ((rhat << 32) | un[j + n - 2] as u64) {
+            qhat -= 1;
+            rhat += vn[n - 1] as u64;
+            if rhat >= base {
+                break;
+            }
+        }
+
+        // Multiply and subtract qhat * vn from the current window of un.
+        let mut borrow = 0i64;
+        for i in 0..n {
+            let product = qhat * vn[i] as u64;
+            let diff = un[i + j] as i64 - borrow - (product & 0xFFFF_FFFF) as i64;
+            un[i + j] = diff as u32;
+            borrow = (product >> 32) as i64 - (diff >> 32);
+        }
+        let diff = un[j + n] as i64 - borrow;
+        un[j + n] = diff as u32;
+
+        if diff < 0 {
+            // qhat was one too large: add the divisor back.
+            qhat -= 1;
+            let mut carry = 0u64;
+            for i in 0..n {
+                let sum = un[i + j] as u64 + vn[i] as u64 + carry;
+                un[i + j] = sum as u32;
+                carry = sum >> 32;
+            }
+            un[j + n] = un[j + n].wrapping_add(carry as u32);
+        }
+        quotient[j] = qhat as u32;
+    }
+
+    trim(&mut quotient);
+    un.truncate(n);
+    let remainder = shr_magnitude(&un, shift);
+    (quotient, remainder)
+}
+
+#[cfg(test)]
+pub mod test_big_integer {
+    use super::*;
+
+    #[test]
+    fn test_from_and_to_i128() {
+        for value in [0, 1, -1, i128::MAX, i128::MIN, 1 << 64, -(1 << 100)] {
+            assert_eq!(BigInt::from(value).to_i128(), Some(value));
+        }
+        assert_eq!((BigInt::from(i128::MAX) + BigInt::one()).to_i128(), None);
+    }
+    #[test]
+    fn test_add_subtract() {
+        let a = BigInt::from(u128::MAX);
+        let b = &a + &a;
+        assert_eq!(b.to_string(), "680564733841876926926749214863536422910");
+        assert_eq!(&b - &a, a);
+        assert_eq!(BigInt::from(3) - BigInt::from(5), BigInt::from(-2));
+        assert_eq!(BigInt::from(-3) + BigInt::from(3), BigInt::zero());
+        assert!(!(BigInt::from(-3) + BigInt::from(3)).is_negative());
+    }
+    #[test]
+    fn test_multiply() {
+        let a = BigInt::from(-12345678901234567i64);
+        let b = BigInt::from(98765432109876543i64);
+        assert_eq!(
+            (a * b).to_i128(),
+            Some(-12345678901234567i128 * 98765432109876543i128)
+        );
+    }
+    #[test]
+    fn test_karatsuba_matches_schoolbook() {
+        let a: Vec<u32> = (0..150u32).map(|i| i.wrapping_mul(2654435761)).collect();
+        let b: Vec<u32> = (0..97u32)
+            .map(|i| i.wrapping_mul(40503).wrapping_add(7))
+            .collect();
+        let mut expected = mul_schoolbook(&a, &b);
+        trim(&mut expected);
+        assert_eq!(karatsuba(&a, &b), expected);
+        assert_eq!(mul_magnitude(&b, &a), expected);
+    }
+    #[test]
+    fn test_div_rem() {
+        let a = BigInt::from(10).pow(50) + BigInt::from(12345);
+        let b = BigInt::from(10).pow(20) + BigInt::from(7);
+        let (q, r) = a.div_rem(&b);
+        assert_eq!(&(&q * &b) + &r, a);
+        assert!(r < b);
+        let (q, r) = BigInt::from(7).div_rem(&BigInt::from(-2));
+        assert_eq!((q, r), (BigInt::from(-3), BigInt::from(1)));
+    }
+    #[test]
+    fn test_div_rem_add_back() {
+        // Exercises the rare "add back" branch of algorithm D.
+        let u = BigInt::new(false, vec![0, 0, 0x8000_0000, 0x7FFF_FFFF]);
+        let v = BigInt::new(false, vec![1, 0, 0x8000_0000]);
+        let (q, r) = u.div_rem(&v);
+        assert_eq!(&(&q * &v) + &r, u);
+        assert!(r < v);
+    }
+    #[test]
+    #[should_panic]
+    fn test_divide_by_zero() {
+        let _ = BigInt::one() / BigInt::zero();
+    }
+    #[test]
+    fn test_radix_round_trip() {
+        let a: BigInt = "-98765432109876543210987654321098765432109876543210"
+            .parse()
+            .unwrap();
+        for radix in 2..=36 {
+            let text = a.to_str_radix(radix);
+            assert_eq!(BigInt::from_str_radix(&text, radix).unwrap(), a);
+        }
+        assert_eq!(
+            BigInt::from_str_radix("1_000", 10).unwrap(),
+            BigInt::from(1000)
+        );
+        assert!("".parse::<BigInt>().is_err());
+        assert!("-".parse::<BigInt>().is_err());
+        assert!("12a".parse::<BigInt>().is_err());
+    }
+    #[test]
+    fn test_display_padding() {
+        assert_eq!(format!("{:>6}", BigInt::from(-42)), "   -42");
+        assert_eq!(format!("{:+}", BigInt::from(42)), "+42");
+    }
+    #[test]
+    fn test_ordering() {
+        let mut values: Vec<BigInt> = [5, -3, 0, 100, -100]
+            .iter()
+            .map(|&x| BigInt::from(x))
+            .collect();
+        values.sort();
+        let sorted: Vec<i128> = values.iter().map(|x| x.to_i128().unwrap()).collect();
+        assert_eq!(sorted, vec![-100, -3, 0, 5, 100]);
+    }
+    #[test]
+    fn test_shifts() {
+        let a = BigInt::from(-5);
+        assert_eq!(&a << 100, BigInt::from(-5i128 << 100));
+        assert_eq!((&a << 100) >> 99, BigInt::from(-10));
+        assert_eq!(BigInt::from(1) >> 1, BigInt::zero());
+    }
+    #[test]
+    fn test_modpow() {
+        let modulus = BigInt::from(1_000_000_007);
+        let a = BigInt::from(2).modpow(&BigInt::from(1_000_000_006), &modulus);
+        assert_eq!(a, BigInt::one());
+        assert_eq!(
+            BigInt::from(-2).modpow(&BigInt::from(3), &BigInt::from(5)),
+            BigInt::from(2)
+        );
+    }
+    #[test]
+    fn test_to_f64() {
+        assert_eq!(BigInt::from(-12345).to_f64(), -12345.0);
+        assert_eq!(BigInt::from(10).pow(300).to_f64(), 1e300);
+        assert_eq!(BigInt::from(10).pow(400).to_f64(), f64::INFINITY);
+        assert_eq!(BigInt::from(u128::MAX).to_f64(), u128::MAX as f64);
+        assert_eq!((-BigInt::from(1u128 << 127)).to_f64(), -(2f64.powi(127)));
+    }
+}
@@ -0,0 +1,838 @@
+//! The `combinatorics` module counts and enumerates arrangements of finite sets.
+//!
+//! * `binomial`, `permutations`, `multinomial`, `catalan`, `stirling_first`, `stirling_second`, `bell`, `partitions` and
+//!   `derangements` compute exact counts as i128, returning an `Overflow` error when the count does not fit. Intermediate values
+//!   never exceed the final result, so an error means the answer itself is too large.
+//! * `FactorialTable` precomputes factorials and their inverses modulo a number, and computes the same counts modulo it.
+//! * `Combinations`, `Permutations` and `Partitions` iterate over k-subsets, orderings and integer partitions in lexicographic
+//!   order.
+//!
+//! # Examples
+//! ```
+//! use numbers_rus::integers::combinatorics::{self, Combinations, FactorialTable};
+//!
+//! assert_eq!(combinatorics::binomial(52, 5), Ok(2598960));
+//! assert_eq!(combinatorics::catalan(10), Ok(16796));
+//! assert_eq!(combinatorics::partitions(100), Ok(190569292));
+//!
+//! let table = FactorialTable::new(1_000_000, 1_000_000_007);
+//! assert_eq!(table.binomial(1_000_000, 500_000), 996692777);
+//!
+//! let subsets: Vec<Vec<usize>> = Combinations::new(4, 2).collect();
+//! assert_eq!(subsets[0], vec![0, 1]);
+//! assert_eq!(subsets.len(), 6);
+//! ```
+use crate::error::{ErrorKind, NumbersError};
+use crate::integers::base;
+use crate::integers::mod_int::{DynModInt, Modulus};
+use crate::integers::modular::gcd;
+
+/// `binomial` function: Takes two u64 integers `n` and `k` as input and returns the binomial coefficient `C(n, k)`, the number
+/// of `k`-element subsets of an `n`-element set. `C(n, k)` is 0 for `k > n`. Returns an `Overflow` error if the result does not
+/// fit in an i128.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::combinatorics;
+///
+/// assert_eq!(combinatorics::binomial(5, 2), Ok(10));
+/// assert_eq!(combinatorics::binomial(2, 5), Ok(0));
+/// assert_eq!(combinatorics::binomial(130, 65), Ok(95067625827960698145584333020095113100));
+/// assert!(combinatorics::binomial(132, 66).is_err());
+/// ```
+pub fn binomial(n: u64, k: u64) -> Result<i128, NumbersError> {
+    if k > n {
+        return Ok(0);
+    }
+    let k = k.min(n - k);
+    let mut result: i128 = 1;
+    for i in 1..=k as i128 {
+        // result * (n - k + i) is divisible by i; dividing first keeps every step below the final value.
+        let g = gcd(result, i);
+        result = (result / g)
+            .checked_mul((n as i128 - k as i128 + i) / (i / g))
+            .ok_or(overflow("binomial"))?;
+    }
+    Ok(result)
+}
+
+/// `permutations` function: Takes two u64 integers `n` and `k` as input and returns the number of ordered arrangements of `k`
+/// elements chosen from `n`, `n! / (n - k)!`. The result is 0 for `k > n`. Returns an `Overflow` error if the result does not
+/// fit in an i128.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::combinatorics;
+///
+/// assert_eq!(combinatorics::permutations(10, 3), Ok(720));
+/// assert_eq!(combinatorics::permutations(3, 4), Ok(0));
+/// ```
+pub fn permutations(n: u64, k: u64) -> Result<i128, NumbersError> {
+    if k > n {
+        return Ok(0);
+    }
+    (n - k + 1..=n).try_fold(1i128, |product, factor| {
+        product
+            .checked_mul(factor as i128)
+            .ok_or(overflow("permutations"))
+    })
+}
+
+/// `multinomial` function: Takes a slice of u64 group sizes `k1, ..., km` as input and returns the multinomial coefficient
+/// `(k1 + ... + km)! / (k1! ... km!)`, the number of ways to split a set into labelled groups of those sizes. Returns an
+/// `Overflow` error if the result does not fit in an i128.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::combinatorics;
+///
+/// assert_eq!(combinatorics::multinomial(&[2, 1, 1]), Ok(12)); // arrangements of "AABC"
+/// assert_eq!(combinatorics::multinomial(&[]), Ok(1));
+/// ```
+pub fn multinomial(groups: &[u64]) -> Result<i128, NumbersError> {
+    let mut total: u64 = 0;
+    let mut result: i128 = 1;
+    for &size in groups {
+        total = total.checked_add(size).ok_or(overflow("multinomial"))?;
+        let ways = binomial(total, size).map_err(|_| overflow("multinomial"))?;
+        result = result.checked_mul(ways).ok_or(overflow("multinomial"))?;
+    }
+    Ok(result)
+}
+
+/// `catalan` function: Takes a u64 integer `n` as input and returns the `n`th Catalan number `C(2n, n) / (n + 1)`, which counts
+/// for example balanced strings of `n` pairs of parentheses. Returns an `Overflow` error if the result does not fit in an i128.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::combinatorics;
+///
+/// assert_eq!(combinatorics::catalan(0), Ok(1));
+/// assert_eq!(combinatorics::catalan(5), Ok(42));
+/// ```
+pub fn catalan(n: u64) -> Result<i128, NumbersError> {
+    let mut result: i128 = 1;
+    for i in 0..n as i128 {
+        // C(i + 1) = C(i) * 2 (2i + 1) / (i + 2)
+        let g = gcd(result, i + 2);
+        result = (result / g)
+            .checked_mul(2 * (2 * i + 1) / ((i + 2) / g))
+            .ok_or(overflow("catalan"))?;
+    }
+    Ok(result)
+}
+
+/// `stirling_first` function: Takes two u64 integers `n` and `k` as input and returns the unsigned Stirling number of the first
+/// kind `c(n, k)`, the number of permutations of `n` elements with exactly `k` cycles. Returns an `Overflow` error if the result
+/// does not fit in an i128.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::combinatorics;
+///
+/// assert_eq!(combinatorics::stirling_first(4, 2), Ok(11));
+/// assert_eq!(combinatorics::stirling_first(0, 0), Ok(1));
+/// ```
+pub fn stirling_first(n: u64, k: u64) -> Result<i128, NumbersError> {
+    if k == 1 && n > 0 {
+        // c(n, 1) = (n - 1)!, the cycles through all n elements.
+        return base::checked_factorial(i128::from(n - 1)).map_err(|_| overflow("stirling_first"));
+    }
+    // c(m, j) = (m - 1) c(m - 1, j) + c(m - 1, j - 1)
+    stirling_triangle(n, k, |m, _| m - 1).map_err(|_| overflow("stirling_first"))
+}
+
+/// `stirling_second` function: Takes two u64 integers `n` and `k` as input and returns the Stirling number of the second kind
+/// `S(n, k)`, the number of ways to partition an `n`-element set into `k` non-empty blocks. Returns an `Overflow` error if the
+/// result does not fit in an i128.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::combinatorics;
+///
+/// assert_eq!(combinatorics::stirling_second(4, 2), Ok(7));
+/// assert_eq!(combinatorics::stirling_second(3, 5), Ok(0));
+/// ```
+pub fn stirling_second(n: u64, k: u64) -> Result<i128, NumbersError> {
+    if k == 1 && n > 0 {
+        // S(n, 1) = 1, the single block.
+        return Ok(1);
+    }
+    // S(m, j) = j S(m - 1, j) + S(m - 1, j - 1)
+    stirling_triangle(n, k, |_, j| j).map_err(|_| overflow("stirling_second"))
+}
+
+/// Evaluates `T(m, j) = weight(m, j) T(m - 1, j) + T(m - 1, j - 1)` with `T(0, 0) = 1`, visiting only the cells that `T(n, k)`
+/// depends on. Both Stirling triangles grow along these paths, so no intermediate value exceeds the result. The callers
+/// handle `k = 1`, the one column where a long path can still end in a small value.
+fn stirling_triangle(
+    n: u64,
+    k: u64,
+    weight: impl Fn(u64, u64) -> u64,
+) -> Result<i128, NumbersError> {
+    if k > n {
+        return Ok(0);
+    }
+    if k == 0 {
+        return Ok(i128::from(n == 0));
+    }
+    let depth = n - k;
+    // T(n, k) >= S(n, k) >= k^(n - k): with the first k elements in separate blocks, each other element can join any of
+    // them. So the result overflows for k >= 2 and n - k >= 127, and the row below never holds more than 127 values.
+    if k >= 2 && depth >= 127 {
+        return Err(overflow("stirling"));
+    }
+    // row[d] holds T(m, m - d) for the current m, restricted to columns in k - (n - m)..=k.
+    let mut row: Vec<i128> = vec![0; depth as usize + 1];
+    row[0] = 1;
+    for m in 1..=n {
+        for d in (0..=depth.min(m)).rev() {
+            let j = m - d;
+            if j + (n - m) < k || j > k {
+                row[d as usize] = 0;
+                continue;
+            }
+            // T(m - 1, j) sits at depth d - 1 of the previous row and T(m - 1, j - 1) at depth d.
+            let stay = if d > 0 && j > 0 {
+                row[d as usize - 1]
+                    .checked_mul(weight(m, j) as i128)
+                    .ok_or(overflow("stirling"))?
+            } else {
+                0
+            };
+            let step = if j > 0 { row[d as usize] } else { 0 };
+            row[d as usize] = stay.checked_add(step).ok_or(overflow("stirling"))?;
+        }
+    }
+    Ok(row[depth as usize])
+}
+
+/// `bell` function: Takes a u64 integer `n` as input and returns the `n`th Bell number, the number of partitions of an
+/// `n`-element set, computed with the Bell triangle. Returns an `Overflow` error if the result does not fit in an i128.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::combinatorics;
+///
+/// assert_eq!(combinatorics::bell(0), Ok(1));
+/// assert_eq!(combinatorics::bell(5), Ok(52));
+/// ```
+pub fn bell(n: u64) -> Result<i128, NumbersError> {
+    let mut row: Vec<i128> = vec![1];
+    for _ in 0..n {
+        // Each row starts with the last entry of the previous one, which is the next Bell number.
+        let mut next = Vec::with_capacity(row.len() + 1);
+        next.push(*row.last().unwrap());
+        for value in row.iter() {
+            let sum = next
+                .last()
+                .unwrap()
+                .checked_add(*value)
+                .ok_or(overflow("bell"))?;
+            next.push(sum);
+        }
+        row = next;
+    }
+    Ok(row[0])
+}
+
+/// `partitions` function: Takes a u64 integer `n` as input and returns the number of partitions `p(n)` of `n` into positive
+/// integer parts, computed with Euler's pentagonal number recurrence in `O(n^1.5)` time. Returns an `Overflow` error if the
+/// result does not fit in an i128.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::combinatorics;
+///
+/// assert_eq!(combinatorics::partitions(0), Ok(1));
+/// assert_eq!(combinatorics::partitions(5), Ok(7));
+/// ```
+pub fn partitions(n: u64) -> Result<i128, NumbersError> {
+    let mut table: Vec<i128> = vec![1];
+    for m in 1..=n as usize {
+        // Positive and negative terms are summed separately so that only the final difference has to fit in an i128.
+        let (mut positive, mut negative) = (0u128, 0u128);
+        for (i, pentagonal) in generalized_pentagonals()
+            .take_while(|&g| g <= m)
+            .enumerate()
+        {
+            let term = table[m - pentagonal] as u128;
+            let sum = if i % 4 < 2 {
+                &mut positive
+            } else {
+                &mut negative
+            };
+            *sum = sum.checked_add(term).ok_or(overflow("partitions"))?;
+        }
+        let value = i128::try_from(positive - negative).map_err(|_| overflow("partitions"))?;
+        table.push(value);
+    }
+    Ok(table[n as usize])
+}
+
+/// `derangements` function: Takes a u64 integer `n` as input and returns the number of permutations of `n` elements with no
+/// fixed point, using `D(n) = n D(n - 1) + (-1)^n`. Returns an `Overflow` error if the result does not fit in an i128.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::combinatorics;
+///
+/// assert_eq!(combinatorics::derangements(0), Ok(1));
+/// assert_eq!(combinatorics::derangements(4), Ok(9));
+/// ```
+pub fn derangements(n: u64) -> Result<i128, NumbersError> {
+    (1..=n).try_fold(1i128, |previous, m| {
+        let sign = if m % 2 == 0 { 1 } else { -1 };
+        previous
+            .checked_mul(m as i128)
+            .and_then(|value| value.checked_add(sign))
+            .ok_or(overflow("derangements"))
+    })
+}
+
+/// Generalized pentagonal numbers `k (3k - 1) / 2` for `k = 1, -1, 2, -2, ...`.
+fn generalized_pentagonals() -> impl Iterator<Item = usize> {
+    (1usize..).flat_map(|k| [k * (3 * k - 1) / 2, k * (3 * k + 1) / 2])
+}
+
+fn overflow(operation: &'static str) -> NumbersError {
+    NumbersError::new(operation, ErrorKind::Overflow)
+}
+
+/// `FactorialTable` holds `0!, ..., limit!` and their inverses modulo a number, and evaluates the counts of this module modulo
+/// that number. Functions based on factorials require their arguments to stay within the table; `stirling_first`, `bell`,
+/// `partitions` and `derangements` use recurrences and accept any size.
+///
+/// # Example
+///
+/// ```
+/// use numbers_rus::integers::combinatorics::FactorialTable;
+///
+/// let table = FactorialTable::new(100, 998_244_353);
+/// assert_eq!(table.factorial(10), 3628800);
+/// assert_eq!(table.binomial(100, 50), 198626801);
+/// assert_eq!(table.catalan(50), 512803529);
+/// ```
+///
+/// # Methods
+///
+/// * `new` - precomputes the factorials up to `limit` modulo `modulus`.
+/// * `limit`, `modulus` - return the table size and the modulus.
+/// * `factorial`, `inverse_factorial` - look up `n!` and `1 / n!`.
+/// * `binomial`, `permutations`, `multinomial`, `catalan`, `stirling_first`, `stirling_second`, `bell`, `partitions`,
+///   `derangements` - modular versions of the functions of this module.
+#[derive(Debug, Clone)]
+pub struct FactorialTable {
+    modulus: Modulus,
+    factorials: Vec<DynModInt>,
+    inverse_factorials: Vec<DynModInt>,
+}
+
+impl FactorialTable {
+    /// Panics if `modulus` is zero or if `limit!` is not invertible modulo `modulus`, that is if `modulus` has a prime factor
+    /// not larger than `limit`. Any prime above `limit` works.
+    pub fn new(limit: usize, modulus: u64) -> Self {
+        let modulus = Modulus::new(modulus);
+        let mut factorials = Vec::with_capacity(limit + 1);
+        factorials.push(modulus.element(1));
+        for i in 1..=limit {
+            factorials.push(factorials[i - 1] * modulus.element(i as i128));
+        }
+        let mut inverse_factorials = vec![modulus.element(0); limit + 1];
+        inverse_factorials[limit] = factorials[limit]
+            .inverse()
+            .expect("Factorials up to the limit must be invertible modulo the modulus");
+        for i in (1..=limit).rev() {
+            inverse_factorials[i - 1] = inverse_factorials[i] * modulus.element(i as i128);
+        }
+        Self {
+            modulus,
+            factorials,
+            inverse_factorials,
+        }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.factorials.len() - 1
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.modulus.get()
+    }
+
+    /// Panics if `n` exceeds the limit.
+    pub fn factorial(&self, n: usize) -> u64 {
+        self.fact(n).value()
+    }
+
+    /// Panics if `n` exceeds the limit.
+    pub fn inverse_factorial(&self, n: usize) -> u64 {
+        self.inverse_fact(n).value()
+    }
+
+    /// Panics if `n` exceeds the limit.
+    pub fn binomial(&self, n: usize, k: usize) -> u64 {
+        if k > n {
+            return 0;
+        }
+        (self.fact(n) * self.inverse_fact(k) * self.inverse_fact(n - k)).value()
+    }
+
+    /// Panics if `n` exceeds the limit.
+    pub fn permutations(&self, n: usize, k: usize) -> u64 {
+        if k > n {
+            return 0;
+        }
+        (self.fact(n) * self.inverse_fact(n - k)).value()
+    }
+
+    /// Panics if the total size exceeds the limit.
+    pub fn multinomial(&self, groups: &[usize]) -> u64 {
+        let total: usize = groups.iter().sum();
+        groups
+            .iter()
+            .fold(self.fact(total), |result, &size| {
+                result * self.inverse_fact(size)
+            })
+            .value()
+    }
+
+    /// Panics if `2n` or `n + 1` exceeds the limit.
+    pub fn catalan(&self, n: usize) -> u64 {
+        // 1 / (n + 1) = n! / (n + 1)!
+        (self.fact(2 * n) * self.inverse_fact(n) * self.inverse_fact(n + 1)).value()
+    }
+
+    pub fn stirling_first(&self, n: usize, k: usize) -> u64 {
+        if k > n {
+            return 0;
+        }
+        // Row m of c(m, j), kept only for j <= k.
+        let mut row = vec![self.modulus.element(0); k + 1];
+        row[0] = self.modulus.element(1);
+        for m in 1..=n {
+            for j in (0..=k.min(m)).rev() {
+                let previous = if j > 0 {
+                    row[j - 1]
+                } else {
+                    self.modulus.element(0)
+                };
+                row[j] = row[j] * self.modulus.element(m as i128 - 1) + previous;
+            }
+        }
+        row[k].value()
+    }
+
+    /// Uses `S(n, k) = 1 / k! * sum (-1)^i C(k, i) (k - i)^n`. Panics if `k` exceeds the limit.
+    pub fn stirling_second(&self, n: usize, k: usize) -> u64 {
+        if k > n {
+            return 0;
+        }
+        let sum = (0..=k).fold(self.modulus.element(0), |sum, i| {
+            let term = self.modulus.element(self.binomial(k, i) as i128)
+                * self.modulus.element((k - i) as i128).pow(n as u64);
+            if i % 2 == 0 {
+                sum + term
+            } else {
+                sum - term
+            }
+        });
+        (sum * self.inverse_fact(k)).value()
+    }
+
+    pub fn bell(&self, n: usize) -> u64 {
+        let mut row = vec![self.modulus.element(1)];
+        for _ in 0..n {
+            let mut next = Vec::with_capacity(row.len() + 1);
+            next.push(*row.last().unwrap());
+            for &value in row.iter() {
+                next.push(*next.last().unwrap() + value);
+            }
+            row = next;
+        }
+        row[0].value()
+    }
+
+    pub fn partitions(&self, n: usize) -> u64 {
+        let mut table = vec![self.modulus.element(1)];
+        for m in 1..=n {
+            let value = generalized_pentagonals()
+                .take_while(|&g| g <= m)
+                .enumerate()
+                .fold(self.modulus.element(0), |sum, (i, pentagonal)| {
+                    if i % 4 < 2 {
+                        sum + table[m - pentagonal]
+                    } else {
+                        sum - table[m - pentagonal]
+                    }
+                });
+            table.push(value);
+        }
+        table[n].value()
+    }
+
+    pub fn derangements(&self, n: usize) -> u64 {
+        (1..=n)
+            .fold(self.modulus.element(1), |previous, m| {
+                let sign = if m % 2 == 0 { 1 } else { -1 };
+                previous * self.modulus.element(m as i128) + self.modulus.element(sign)
+            })
+            .value()
+    }
+
+    fn fact(&self, n: usize) -> DynModInt {
+        assert!(
+            n <= self.limit(),
+            "{} exceeds the factorial table limit {}",
+            n,
+            self.limit()
+        );
+        self.factorials[n]
+    }
+
+    fn inverse_fact(&self, n: usize) -> DynModInt {
+        assert!(
+            n <= self.limit(),
+            "{} exceeds the factorial table limit {}",
+            n,
+            self.limit()
+        );
+        self.inverse_factorials[n]
+    }
+}
+
+/// `Combinations` iterates over the `k`-element subsets of `0..n` as increasing index vectors, in lexicographic order.
+///
+/// # Example
+///
+/// ```
+/// use numbers_rus::integers::combinatorics::Combinations;
+///
+/// let all: Vec<Vec<usize>> = Combinations::new(4, 3).collect();
+/// assert_eq!(all, vec![vec![0, 1, 2], vec![0, 1, 3], vec![0, 2, 3], vec![1, 2, 3]]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Combinations {
+    n: usize,
+    current: Option<Vec<usize>>,
+}
+
+impl Combinations {
+    pub fn new(n: usize, k: usize) -> Self {
+        Self {
+            n,
+            current: (k <= n).then(|| (0..k).collect()),
+        }
+    }
+}
+
+impl Iterator for Combinations {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        let result = self.current.clone()?;
+        let current = self.current.as_mut().unwrap();
+        let k = current.len();
+        // Advance the rightmost index that still has room, then reset everything after it.
+        match (0..k).rev().find(|&i| current[i] < self.n - k + i) {
+            Some(i) => {
+                current[i] += 1;
+                for j in i + 1..k {
+                    current[j] = current[j - 1] + 1;
+                }
+            }
+            None => self.current = None,
+        }
+        Some(result)
+    }
+}
+
+/// `Permutations` iterates over the orderings of `0..n` in lexicographic order.
+///
+/// # Example
+///
+/// ```
+/// use numbers_rus::integers::combinatorics::Permutations;
+///
+/// let all: Vec<Vec<usize>> = Permutations::new(3).collect();
+/// assert_eq!(all[0], vec![0, 1, 2]);
+/// assert_eq!(all[1], vec![0, 2, 1]);
+/// assert_eq!(all[5], vec![2, 1, 0]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Permutations {
+    current: Option<Vec<usize>>,
+}
+
+impl Permutations {
+    pub fn new(n: usize) -> Self {
+        Self {
+            current: Some((0..n).collect()),
+        }
+    }
+}
+
+impl Iterator for Permutations {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        let result = self.current.clone()?;
+        let current = self.current.as_mut().unwrap();
+        // Find the last ascent, swap it with the rightmost larger element and reverse the tail.
+        match (1..current.len())
+            .rev()
+            .find(|&i| current[i - 1] < current[i])
+        {
+            Some(i) => {
+                let j = (i..current.len())
+                    .rev()
+                    .find(|&j| current[j] > current[i - 1])
+                    .unwrap();
+                current.swap(i - 1, j);
+                current[i..].reverse();
+            }
+            None => self.current = None,
+        }
+        Some(result)
+    }
+}
+
+/// `Partitions` iterates over the partitions of `n` into positive parts. Each partition is given as a non-decreasing vector,
+/// and the partitions come in lexicographic order, from all ones to `[n]`. The only partition of 0 is the empty one.
+///
+/// # Example
+///
+/// ```
+/// use numbers_rus::integers::combinatorics::Partitions;
+///
+/// let all: Vec<Vec<u64>> = Partitions::new(4).collect();
+/// assert_eq!(all, vec![vec![1, 1, 1, 1], vec![1, 1, 2], vec![1, 3], vec![2, 2], vec![4]]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Partitions {
+    parts: Vec<u64>,
+    /// Index of the last part of the partition produced most recently, or `None` when finished.
+    last: Option<usize>,
+    /// Whether the empty partition of 0 is still to be produced.
+    empty: bool,
+}
+
+impl Partitions {
+    pub fn new(n: u64) -> Self {
+        let mut parts = vec![0; n as usize + 1];
+        if n > 0 {
+            parts[1] = n;
+        }
+        Self {
+            parts,
+            last: Some(1),
+            empty: n == 0,
+        }
+    }
+}
+
+impl Iterator for Partitions {
+    type Item = Vec<u64>;
+
+    /// Kelleher's ascending composition algorithm: each step turns the last two parts `x - 1` and `y + 1` into as many copies
+    /// of `x` as fit, followed by the remainder.
+    fn next(&mut self) -> Option<Vec<u64>> {
+        if self.empty {
+            self.empty = false;
+            self.last = None;
+            return Some(Vec::new());
+        }
+        let mut k = self.last.filter(|&k| k != 0)?;
+        let x = self.parts[k - 1] + 1;
+        let mut y = self.parts[k] - 1;
+        k -= 1;
+        while x <= y {
+            self.parts[k] = x;
+            y -= x;
+            k += 1;
+        }
+        self.parts[k] = x + y;
+        self.last = Some(k);
+        Some(self.parts[..=k].to_vec())
+    }
+}
+
+#[cfg(test)]
+pub mod test_combinatorics {
+    use super::*;
+
+    #[test]
+    fn test_binomial_matches_pascal() {
+        let mut row: Vec<i128> = vec![1];
+        for n in 0..=127u64 {
+            for (k, &value) in row.iter().enumerate() {
+                assert_eq!(binomial(n, k as u64), Ok(value), "n = {}, k = {}", n, k);
+            }
+            let mut next = vec![1; row.len() + 1];
+            for k in 1..row.len() {
+                next[k] = row[k - 1] + row[k];
+            }
+            row = next;
+        }
+        assert_eq!(binomial(u64::MAX, 1), Ok(u64::MAX as i128));
+        assert_eq!(
+            binomial(u64::MAX, u64::MAX - 2),
+            Ok((u64::MAX as u128 * (u64::MAX as u128 - 1) / 2) as i128)
+        );
+        assert_eq!(
+            binomial(u64::MAX, 3).unwrap_err().kind(),
+            ErrorKind::Overflow
+        );
+    }
+    #[test]
+    fn test_counts() {
+        assert_eq!(
+            permutations(33, 33),
+            crate::integers::base::checked_factorial(33)
+        );
+        assert!(permutations(34, 34).is_err());
+        assert_eq!(permutations(5, 0), Ok(1));
+        assert_eq!(multinomial(&[3, 3, 3]), Ok(1680));
+        let catalans = [1, 1, 2, 5, 14, 42, 132, 429, 1430, 4862];
+        for (n, &c) in catalans.iter().enumerate() {
+            assert_eq!(catalan(n as u64), Ok(c));
+        }
+        for n in 0..70 {
+            let expected = binomial(2 * n, n).ok().map(|b| b / (n as i128 + 1));
+            if let Some(expected) = expected {
+                assert_eq!(catalan(n), Ok(expected));
+            }
+        }
+        assert!(catalan(100).is_err());
+        let bells = [1, 1, 2, 5, 15, 52, 203, 877, 4140, 21147];
+        for (n, &b) in bells.iter().enumerate() {
+            assert_eq!(bell(n as u64), Ok(b));
+        }
+        let derangement_counts = [1, 0, 1, 2, 9, 44, 265, 1854];
+        for (n, &d) in derangement_counts.iter().enumerate() {
+            assert_eq!(derangements(n as u64), Ok(d));
+        }
+        assert!(derangements(40).is_err());
+    }
+    #[test]
+    fn test_stirling() {
+        for n in 0..30u64 {
+            // Row sums: c(n, k) sums to n! and S(n, k) sums to the Bell number.
+            let first: i128 = (0..=n).map(|k| stirling_first(n, k).unwrap()).sum();
+            let second: i128 = (0..=n).map(|k| stirling_second(n, k).unwrap()).sum();
+            assert_eq!(
+                Ok(first),
+                crate::integers::base::checked_factorial(n as i128)
+            );
+            assert_eq!(Ok(second), bell(n));
+        }
+        assert_eq!(stirling_first(10, 3), Ok(1172700));
+        assert_eq!(stirling_second(10, 3), Ok(9330));
+        assert_eq!(stirling_second(5, 0), Ok(0));
+        assert_eq!(stirling_second(1000, 999), binomial(1000, 2));
+        assert_eq!(stirling_first(1000, 999), binomial(1000, 2));
+        assert!(stirling_second(200, 100).is_err());
+        // Deep paths are rejected or answered before the triangle is allocated.
+        assert!(stirling_second(u64::MAX, 2).is_err());
+        assert!(stirling_first(u64::MAX, 2).is_err());
+        assert_eq!(stirling_second(u64::MAX, 1), Ok(1));
+        assert!(stirling_first(u64::MAX, 1).is_err());
+        assert_eq!(
+            stirling_first(34, 1),
+            crate::integers::base::checked_factorial(33)
+        );
+        assert_eq!(stirling_second(u64::MAX, 0), Ok(0));
+        assert_eq!(stirling_second(128, 2), Ok(i128::MAX));
+        assert!(stirling_second(129, 2).is_err());
+    }
+    #[test]
+    fn test_partitions() {
+        let counts = [1, 1, 2, 3, 5, 7, 11, 15, 22, 30, 42];
+        for (n, &p) in counts.iter().enumerate() {
+            assert_eq!(partitions(n as u64), Ok(p));
+            assert_eq!(Partitions::new(n as u64).count() as i128, p);
+        }
+        assert_eq!(partitions(1000), Ok(24061467864032622473692149727991));
+        assert!(partitions(2000).is_err());
+    }
+    #[test]
+    fn test_factorial_table() {
+        let p = 1_000_000_007;
+        let table = FactorialTable::new(200, p);
+        assert_eq!(table.limit(), 200);
+        assert_eq!(table.modulus(), p);
+        let reduce = |value: i128| value.rem_euclid(p as i128) as u64;
+        for n in 0..40usize {
+            for k in 0..=n + 1 {
+                assert_eq!(
+                    table.binomial(n, k),
+                    reduce(binomial(n as u64, k as u64).unwrap())
+                );
+                assert_eq!(
+                    table.stirling_second(n, k),
+                    reduce(stirling_second(n as u64, k as u64).unwrap())
+                );
+            }
+        }
+        for n in 0..25usize {
+            assert_eq!(table.bell(n), reduce(bell(n as u64).unwrap()));
+            assert_eq!(
+                table.derangements(n),
+                reduce(derangements(n as u64).unwrap())
+            );
+            assert_eq!(
+                table.permutations(n, 3),
+                reduce(permutations(n as u64, 3).unwrap())
+            );
+            assert_eq!(table.catalan(n), reduce(catalan(n as u64).unwrap()));
+            for k in 0..=n {
+                assert_eq!(
+                    table.stirling_first(n, k),
+                    reduce(stirling_first(n as u64, k as u64).unwrap())
+                );
+            }
+        }
+        assert_eq!(table.partitions(1000), reduce(partitions(1000).unwrap()));
+        assert_eq!(table.multinomial(&[3, 3, 3]), 1680);
+        assert_eq!(
+            table.factorial(20) as i128 * table.inverse_factorial(20) as i128 % p as i128,
+            1
+        );
+    }
+    #[test]
+    #[should_panic]
+    fn test_factorial_table_limit() {
+        FactorialTable::new(10, 1_000_000_007).binomial(11, 1);
+    }
+    #[test]
+    #[should_panic]
+    fn test_factorial_table_small_modulus() {
+        FactorialTable::new(10, 7);
+    }
+    #[test]
+    fn test_iterators() {
+        for n in 0..8usize {
+            for k in 0..=n + 1 {
+                let all: Vec<Vec<usize>> = Combinations::new(n, k).collect();
+                assert_eq!(all.len() as i128, binomial(n as u64, k as u64).unwrap());
+                assert!(all.windows(2).all(|pair| pair[0] < pair[1]));
+            }
+            let all: Vec<Vec<usize>> = Permutations::new(n).collect();
+            assert_eq!(all.len() as i128, permutations(n as u64, n as u64).unwrap());
+            assert!(all.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+        assert_eq!(
+            Combinations::new(0, 0).collect::<Vec<_>>(),
+            vec![Vec::<usize>::new()]
+        );
+        assert_eq!(
+            Partitions::new(0).collect::<Vec<_>>(),
+            vec![Vec::<u64>::new()]
+        );
+        let all: Vec<Vec<u64>> = Partitions::new(12).collect();
+        assert!(all.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(all
+            .iter()
+            .all(|p| p.iter().sum::<u64>() == 12 && p.windows(2).all(|w| w[0] <= w[1])));
+    }
+}
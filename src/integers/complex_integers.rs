@@ -3,6 +3,11 @@
 //! perfect square, perfect cube, etc.). These functions operate on complex numbers and may panic in some cases if input constraints are not met (e.g.,
 //!  negative exponents or divisors, or non-integer roots), so it is important to ensure proper input values are provided.
 //!
+//! `Complex` is the ring of Gaussian integers `a + bi`. It has a Euclidean division (`div_rem`, rounding the quotient to the
+//! nearest Gaussian integer so that the remainder has at most half the norm of the divisor), hence `gcd`, `extended_gcd` and
+//! unique factorization into Gaussian primes up to the units `1`, `i`, `-1` and `-i`. `divide` is exact and returns `None`
+//! when the quotient is not a Gaussian integer, while the `/` and `%` operators give the Euclidean quotient and remainder.
+//!
 //! # Examples
 //! ```
 //! use numbers_rus::integers::complex_integers;
@@ -13,8 +18,25 @@
 //! let c = a.add(&b);
 //! let d = c.conjugate();
 //!  println!("{},{}", c,d);
+//!
+//! // Operators, parsing and conversions to and from the floating-point `Complex`.
+//! let e: complex_integers::Complex = "2-3i".parse().unwrap();
+//! assert_eq!(a * e + 1, complex_integers::Complex::new(9, 1));
+//!
+//! // Gaussian integer arithmetic: 5 = (2 + i)(2 - i) splits, 3 stays prime.
+//! let five = complex_integers::Complex::from_real(5);
+//! assert_eq!(five.divide(&complex_integers::Complex::new(2, 1)), Some(complex_integers::Complex::new(2, -1)));
+//! assert!(!five.is_prime() && complex_integers::Complex::from_real(3).is_prime());
 //! ```
+use crate::error::{ErrorKind, NumbersError};
+use crate::integers::big_integer::BigInt;
+use crate::integers::{factorization, primality, quadratic_residues};
+use crate::numbers::complex_floats::{self, ParseComplexError};
+use crate::rational::{rational_float, rational_integer};
+use crate::traits::{forward_copy_ops, Integer};
 use std::fmt;
+use std::ops;
+use std::str::FromStr;
 
 /// `Complex` is a struct that represents a complex number.
 /// It has two fields, `real` and `imag`, which are both `f64` values.
@@ -34,7 +56,16 @@ use std::fmt;
 /// * `add` - adds two complex numbers and returns the result as a new `Complex` instance as the sum of the two inputs.
 /// * `subtract` - subtracts two complex numbers and returns the result as a new `Complex` instance as the difference of the two inputs.
 /// * `multiply` - multiplies two complex numbers and returns the result as a new `Complex` instance as the product of the two inputs.
-/// * `divide` - divides two complex numbers and returns the result as a new `Complex` instance as the quotient of the two inputs.
+/// * `divide` - divides two complex numbers exactly, returning `None` when the quotient is not a Gaussian integer.
+/// * `norm` - returns `a^2 + b^2`, the square of the absolute value.
+/// * `div_rem` - returns the Euclidean quotient, rounded to the nearest Gaussian integer, and the remainder.
+/// * `is_divisible_by` - returns whether another Gaussian integer divides this one.
+/// * `gcd` - returns the normalized greatest common divisor of two Gaussian integers.
+/// * `extended_gcd` - returns the normalized greatest common divisor with its Bezout coefficients.
+/// * `is_unit` - returns whether the value is one of `1`, `i`, `-1` and `-i`.
+/// * `normalize` - returns the associate with a positive real part and a non-negative imaginary part.
+/// * `is_prime` - returns whether the value is a Gaussian prime.
+/// * `factorize` - returns the factorization into a unit and powers of normalized Gaussian primes.
 /// * `get_real` - returns the real part of the complex number.
 /// * `get_imag` - returns the imaginary part of the complex number.
 /// * `get` - returns the complex number as a tuple of the real and imaginary parts.
@@ -68,6 +99,112 @@ impl fmt::Display for Complex {
     }
 }
 
+impl FromStr for Complex {
+    type Err = ParseComplexError;
+
+    /// Parses `"2-3i"`, `"7"`, `"-i"` or the `Display` output `"2 - 3i"`.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        complex_floats::parse_parts(text).map(|(real, imag)| Self::new(real, imag))
+    }
+}
+
+impl From<i128> for Complex {
+    fn from(real: i128) -> Self {
+        Self::from_real(real)
+    }
+}
+
+impl TryFrom<complex_floats::Complex> for Complex {
+    type Error = NumbersError;
+
+    /// Succeeds when both parts are whole numbers that fit in an i128.
+    fn try_from(value: complex_floats::Complex) -> Result<Self, Self::Error> {
+        let convert = |part: f64| {
+            // 2^127 is exactly representable, so the range check is exact.
+            let limit = 2f64.powi(127);
+            if part.fract() == 0.0 && (-limit..limit).contains(&part) {
+                Ok(part as i128)
+            } else {
+                Err(NumbersError::new("try_from", ErrorKind::NotRepresentable))
+            }
+        };
+        Ok(Self::new(convert(value.real)?, convert(value.imag)?))
+    }
+}
+
+impl TryFrom<rational_float::Rational> for Complex {
+    type Error = NumbersError;
+
+    /// Succeeds when the quotient is a whole number that fits in an i128.
+    fn try_from(value: rational_float::Rational) -> Result<Self, Self::Error> {
+        Self::try_from(complex_floats::Complex::from(value))
+    }
+}
+
+impl<T: Integer> TryFrom<rational_integer::Rational<T>> for Complex {
+    type Error = NumbersError;
+
+    /// Succeeds when the rational is a whole number that fits in an i128.
+    fn try_from(value: rational_integer::Rational<T>) -> Result<Self, Self::Error> {
+        match value.get_numerator().to_i128() {
+            Some(real) if value.is_integer() => Ok(Self::from_real(real)),
+            _ => Err(NumbersError::new("try_from", ErrorKind::NotRepresentable)),
+        }
+    }
+}
+
+impl ops::Add for Complex {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Complex::add(&self, &other)
+    }
+}
+
+impl ops::Sub for Complex {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.subtract(&other)
+    }
+}
+
+impl ops::Mul for Complex {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.multiply(&other)
+    }
+}
+
+impl ops::Div for Complex {
+    type Output = Self;
+
+    /// The Euclidean quotient of `div_rem`, rounded to the nearest Gaussian integer. Panics if `other` is zero.
+    fn div(self, other: Self) -> Self {
+        self.div_rem(&other).0
+    }
+}
+
+impl ops::Rem for Complex {
+    type Output = Self;
+
+    /// The Euclidean remainder of `div_rem`, with at most half the norm of `other`. Panics if `other` is zero.
+    fn rem(self, other: Self) -> Self {
+        self.div_rem(&other).1
+    }
+}
+
+impl ops::Neg for Complex {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.real, -self.imag)
+    }
+}
+
+forward_copy_ops!(Complex, i128, Complex::zero(), Complex::one());
+
 impl Complex {
     pub fn new(real: i128, imag: i128) -> Self {
         Self { real, imag }
@@ -156,29 +293,293 @@ impl Complex {
         (self.real.pow(2) + self.imag.pow(2)) as f64
     }
 
-    /// The divide function divides two complex numbers and returns the result as a new `Complex` instance as the quotient of the two inputs.
+    /// The divide function divides two complex numbers exactly and returns the quotient as a new `Complex` instance, or `None`
+    /// if `other` is zero, the quotient is not a Gaussian integer or it does not fit in an i128. The division is done with
+    /// BigInt products, so it is exact for parts of any size.
     ///
     /// # Arguments
     ///
-    /// * `other` - A reference to another `Complex` instance to be divided by the current instance.
+    /// * `other` - A reference to another `Complex` instance to divide the current instance by.
     ///
     /// # Example
     ///
     /// ```
     /// use numbers_rus::integers::complex_integers::Complex;
     ///
-    /// let a = Complex::new(1, 2);
-    /// let b = Complex::new(3, 4);
-    /// let c = a.divide(&b);
-    /// assert_eq!(c.get_real(), 0);
-    /// assert_eq!(c.get_imag(), 0);
+    /// let a = Complex::new(-5, 10);
+    /// let b = Complex::new(1, 2);
+    /// assert_eq!(a.divide(&b), Some(Complex::new(3, 4)));
+    /// assert_eq!(b.divide(&Complex::new(3, 4)), None);
     /// ```
-    pub fn divide(&self, other: &Self) -> Self {
-        let real = (self.real * other.real + self.imag * other.imag)
-            / (other.real.pow(2) + other.imag.pow(2));
-        let imag = (self.imag * other.real - self.real * other.imag)
-            / (other.real.pow(2) + other.imag.pow(2));
-        Self::new(real, imag)
+    pub fn divide(&self, other: &Self) -> Option<Self> {
+        if *other == Self::zero() {
+            return None;
+        }
+        let norm = other.big_norm();
+        let (real, imag) = self.big_multiply_conjugate(other);
+        let ((real, real_rest), (imag, imag_rest)) = (real.div_rem(&norm), imag.div_rem(&norm));
+        if real_rest.is_zero() && imag_rest.is_zero() {
+            Some(Self::new(real.to_i128()?, imag.to_i128()?))
+        } else {
+            None
+        }
+    }
+
+    /// The norm function returns `a^2 + b^2`, the square of the absolute value of `a + bi`. The norm is multiplicative,
+    /// and it is the size that the Euclidean division decreases. Panics if the norm does not fit in an i128, which needs
+    /// a part above `2^63` in absolute value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::complex_integers::Complex;
+    ///
+    /// let a = Complex::new(3, -4);
+    /// assert_eq!(a.norm(), 25);
+    /// ```
+    pub fn norm(&self) -> i128 {
+        self.real
+            .checked_mul(self.real)
+            .zip(self.imag.checked_mul(self.imag))
+            .and_then(|(real, imag)| real.checked_add(imag))
+            .expect("The norm does not fit in an i128")
+    }
+
+    /// Returns the norm exactly, for parts of any size.
+    fn big_norm(&self) -> BigInt {
+        let (real, imag) = (BigInt::from(self.real), BigInt::from(self.imag));
+        &(&real * &real) + &(&imag * &imag)
+    }
+
+    /// Returns the parts of `self` times the conjugate of `other` exactly, for parts of any size.
+    fn big_multiply_conjugate(&self, other: &Self) -> (BigInt, BigInt) {
+        let (a, b) = (BigInt::from(self.real), BigInt::from(self.imag));
+        let (c, d) = (BigInt::from(other.real), BigInt::from(other.imag));
+        (&(&a * &c) + &(&b * &d), &(&b * &c) - &(&a * &d))
+    }
+
+    /// The div_rem function returns `(quotient, remainder)` with `self = quotient * other + remainder`, where the quotient is
+    /// `self / other` with each part rounded to the nearest integer, so that the remainder has at most half the norm of
+    /// `other`. The division is done with BigInt products, so it is exact for parts of any size. Panics if `other` is zero
+    /// or if the quotient does not fit in an i128, which only happens when dividing a part of `i128::MIN` by a unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to another `Complex` instance to divide the current instance by.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::complex_integers::Complex;
+    ///
+    /// let a = Complex::new(27, 23);
+    /// let b = Complex::new(8, 1);
+    /// let (quotient, remainder) = a.div_rem(&b);
+    /// assert_eq!(quotient, Complex::new(4, 2));
+    /// assert_eq!(remainder, Complex::new(-3, 3));
+    /// assert!(2 * remainder.norm() <= b.norm());
+    /// ```
+    pub fn div_rem(&self, other: &Self) -> (Self, Self) {
+        assert!(*other != Self::zero(), "division by zero");
+        let norm = other.big_norm();
+        let (real, imag) = self.big_multiply_conjugate(other);
+        let (real, imag) = (
+            big_round_divide(&real, &norm),
+            big_round_divide(&imag, &norm),
+        );
+        // self - quotient * other, whose parts fit even when the products do not.
+        let (c, d) = (BigInt::from(other.real), BigInt::from(other.imag));
+        let remainder = (
+            &(&BigInt::from(self.real) - &(&real * &c)) + &(&imag * &d),
+            &(&BigInt::from(self.imag) - &(&real * &d)) - &(&imag * &c),
+        );
+        let fit = |part: BigInt| {
+            part.to_i128()
+                .expect("The quotient does not fit in an i128")
+        };
+        (
+            Self::new(fit(real), fit(imag)),
+            Self::new(fit(remainder.0), fit(remainder.1)),
+        )
+    }
+
+    /// The is_divisible_by function returns whether `self = q * other` for some Gaussian integer `q`. Zero is only divisible
+    /// by itself among the divisors of zero norm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::complex_integers::Complex;
+    ///
+    /// assert!(Complex::from_real(2).is_divisible_by(&Complex::new(1, 1)));
+    /// assert!(!Complex::from_real(3).is_divisible_by(&Complex::new(1, 1)));
+    /// ```
+    pub fn is_divisible_by(&self, other: &Self) -> bool {
+        if *other == Self::zero() {
+            *self == Self::zero()
+        } else {
+            self.divide(other).is_some()
+        }
+    }
+
+    /// The gcd function returns the greatest common divisor of two Gaussian integers, normalized to a positive real part and
+    /// a non-negative imaginary part. The gcd of zero and zero is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::complex_integers::Complex;
+    ///
+    /// let a = Complex::new(4, 7);
+    /// let b = Complex::new(-1, 8);
+    /// assert_eq!(a.gcd(&b), Complex::new(3, 2));
+    /// ```
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (*self, *other);
+        while b != Self::zero() {
+            (a, b) = (b, a.div_rem(&b).1);
+        }
+        a.normalize()
+    }
+
+    /// The extended_gcd function returns `(g, x, y)` where `g` is the normalized greatest common divisor of `self` and `other`
+    /// and `self * x + other * y == g`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::complex_integers::Complex;
+    ///
+    /// let a = Complex::new(4, 7);
+    /// let b = Complex::new(-1, 8);
+    /// let (g, x, y) = a.extended_gcd(&b);
+    /// assert_eq!(g, Complex::new(3, 2));
+    /// assert_eq!(a * x + b * y, g);
+    /// ```
+    pub fn extended_gcd(&self, other: &Self) -> (Self, Self, Self) {
+        let (mut old_r, mut r) = (*self, *other);
+        let (mut old_s, mut s) = (Self::one(), Self::zero());
+        let (mut old_t, mut t) = (Self::zero(), Self::one());
+        while r != Self::zero() {
+            let quotient = old_r.div_rem(&r).0;
+            (old_r, r) = (r, old_r - quotient * r);
+            (old_s, s) = (s, old_s - quotient * s);
+            (old_t, t) = (t, old_t - quotient * t);
+        }
+        let unit = old_r.normalizing_unit();
+        (old_r * unit, old_s * unit, old_t * unit)
+    }
+
+    /// The is_unit function returns whether the value is invertible, that is one of `1`, `i`, `-1` and `-i`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::complex_integers::Complex;
+    ///
+    /// assert!(Complex::new(0, -1).is_unit());
+    /// assert!(!Complex::new(1, 1).is_unit());
+    /// ```
+    pub fn is_unit(&self) -> bool {
+        matches!(self.get(), (1, 0) | (-1, 0) | (0, 1) | (0, -1))
+    }
+
+    /// The normalize function returns the associate of the value, its product with a unit, that has a positive real part and
+    /// a non-negative imaginary part. Every non-zero Gaussian integer has exactly one such associate, and zero stays zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::complex_integers::Complex;
+    ///
+    /// assert_eq!(Complex::new(-2, 3).normalize(), Complex::new(3, 2));
+    /// assert_eq!(Complex::new(0, -5).normalize(), Complex::new(5, 0));
+    /// ```
+    pub fn normalize(&self) -> Self {
+        self.multiply(&self.normalizing_unit())
+    }
+
+    /// Returns the unit that rotates the value into the quadrant `real > 0, imag >= 0`, or one for zero.
+    fn normalizing_unit(&self) -> Self {
+        match (self.real.signum(), self.imag.signum()) {
+            (1, 0 | 1) | (0, 0) => Self::one(),
+            (-1 | 0, 1) => Self::new(0, -1),
+            (-1, -1 | 0) => Self::new(-1, 0),
+            _ => Self::new(0, 1),
+        }
+    }
+
+    /// The is_prime function returns whether the value is a Gaussian prime. These are the associates of `1 + i`, of the
+    /// rational primes `p = 3 (mod 4)`, and of the `a + bi` whose norm is a rational prime `p = 1 (mod 4)`. Panics if both
+    /// parts are non-zero and the norm does not fit in an i128.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::complex_integers::Complex;
+    ///
+    /// assert!(Complex::new(0, -7).is_prime());
+    /// assert!(Complex::new(4, 5).is_prime());
+    /// assert!(!Complex::from_real(13).is_prime());
+    /// ```
+    pub fn is_prime(&self) -> bool {
+        match self.get() {
+            (0, part) | (part, 0) => {
+                // A part of 3 (mod 4) is below 2^127, so it fits back in an i128.
+                let part = part.unsigned_abs();
+                part % 4 == 3 && primality::is_prime(part as i128)
+            }
+            _ => primality::is_prime(self.norm()),
+        }
+    }
+
+    /// The factorize function returns `(unit, factors)` where `factors` lists each normalized Gaussian prime dividing the
+    /// value with its exponent, in increasing order of norm, and `unit` times the product of the prime powers is the value.
+    /// The rational primes dividing the norm are found with `factorization::factorize`. Panics if the value is zero or its
+    /// norm does not fit in an i128.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::complex_integers::Complex;
+    /// use numbers_rus::traits::Ring;
+    ///
+    /// // 6 = -i (1 + i)^2 3
+    /// let (unit, factors) = Complex::from_real(6).factorize();
+    /// assert_eq!(unit, Complex::new(0, -1));
+    /// assert_eq!(factors, vec![(Complex::new(1, 1), 2), (Complex::new(3, 0), 1)]);
+    ///
+    /// let (unit, factors) = Complex::new(-11, 27).factorize();
+    /// let product = factors.iter().fold(unit, |total, (prime, exponent)| total * Ring::pow(prime, *exponent));
+    /// assert_eq!(product, Complex::new(-11, 27));
+    /// ```
+    pub fn factorize(&self) -> (Self, Vec<(Self, u32)>) {
+        assert!(*self != Self::zero(), "cannot factorize zero");
+        let mut rest = *self;
+        let mut factors = Vec::new();
+        for (p, _) in factorization::factorize(self.norm()) {
+            let primes = match p % 4 {
+                2 => vec![Self::new(1, 1)],
+                3 => vec![Self::from_real(p)],
+                _ => {
+                    let (x, y) = quadratic_residues::two_squares_of_prime(p);
+                    let prime = Self::new(x, y);
+                    vec![prime, prime.conjugate().normalize()]
+                }
+            };
+            for prime in primes {
+                let mut exponent = 0;
+                while let Some(quotient) = rest.divide(&prime) {
+                    rest = quotient;
+                    exponent += 1;
+                }
+                if exponent > 0 {
+                    factors.push((prime, exponent));
+                }
+            }
+        }
+        factors.sort_by_key(|(prime, _)| prime.norm());
+        (rest, factors)
     }
 
     /// The conjugate function returns the conjugate of the complex number.  The conjugate is the same as the original complex number except the sign of the imaginary part is changed.
@@ -200,21 +601,19 @@ impl Complex {
         Self::new(real, imag)
     }
 
-    /// The inverse function returns the inverse of the complex number.  The inverse is the reciprocal of the complex number.
+    /// The inverse function returns the inverse of the complex number, which is a Gaussian integer only for the units
+    /// `1`, `i`, `-1` and `-i`, so it returns `None` for every other value.
     ///
     /// # Example
     ///
     /// ```
     /// use numbers_rus::integers::complex_integers::Complex;
     ///
-    /// let a = Complex::new(1, 2);
-    /// let b = a.inverse();
-    /// assert_eq!(b.get_real(), 0);
-    /// assert_eq!(b.get_imag(), 0);
-    pub fn inverse(&self) -> Self {
-        let real = self.real / (self.real.pow(2) + self.imag.pow(2));
-        let imag = -self.imag / (self.real.pow(2) + self.imag.pow(2));
-        Self::new(real, imag)
+    /// assert_eq!(Complex::new(0, 1).inverse(), Some(Complex::new(0, -1)));
+    /// assert_eq!(Complex::new(1, 2).inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        self.is_unit().then(|| self.conjugate())
     }
 
     pub fn display(&self) {
@@ -269,9 +668,32 @@ impl Complex {
         Self::new(self.real, self.imag)
     }
 }
+/// Returns `numerator / denominator` rounded to the nearest integer, with halves rounded down, for a positive denominator.
+fn big_round_divide(numerator: &BigInt, denominator: &BigInt) -> BigInt {
+    let remainder = numerator.rem_euclid(denominator);
+    let quotient = &(numerator - &remainder) / denominator;
+    if remainder > denominator - &remainder {
+        &quotient + &BigInt::one()
+    } else {
+        quotient
+    }
+}
+
+/// Returns `numerator / denominator` rounded to the nearest integer, with halves rounded down, for a positive denominator.
+pub(crate) fn round_divide(numerator: i128, denominator: i128) -> i128 {
+    let quotient = numerator.div_euclid(denominator);
+    let remainder = numerator.rem_euclid(denominator);
+    if remainder > denominator - remainder {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
 #[cfg(test)]
 pub mod test_complex {
     use super::*;
+    use crate::traits::{self, Ring};
     #[test]
     fn test_add() {
         let a = Complex::new(1, 2);
@@ -297,8 +719,9 @@ pub mod test_complex {
     fn test_divide() {
         let a = Complex::new(1, 2);
         let b = Complex::new(3, 4);
-        let c = a.divide(&b);
-        assert_eq!(c.get(), (0, 0));
+        assert_eq!(a.divide(&b), None);
+        assert_eq!(Complex::new(-5, 10).divide(&a), Some(b));
+        assert_eq!(a.divide(&Complex::zero()), None);
     }
     #[test]
     fn test_modulus() {
@@ -315,8 +738,16 @@ pub mod test_complex {
     #[test]
     fn test_inverse() {
         let a = Complex::new(1, 2);
-        let b = a.inverse();
-        assert_eq!(b.get(), (0, 0));
+        assert_eq!(a.inverse(), None);
+        for unit in [
+            Complex::new(1, 0),
+            Complex::new(0, 1),
+            Complex::new(-1, 0),
+            Complex::new(0, -1),
+        ] {
+            assert_eq!(unit * unit.inverse().unwrap(), Complex::one());
+        }
+        assert_eq!(Complex::zero().inverse(), None);
     }
     #[test]
     fn test_display() {
@@ -385,4 +816,223 @@ pub mod test_complex {
         let b = a.copy();
         assert_eq!(b.get(), (1, 2));
     }
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_operators() {
+        let a = Complex::new(1, 2);
+        let b = Complex::new(3, 4);
+        assert_eq!(a + b, a.add(&b));
+        assert_eq!(&a - &b, a.subtract(&b));
+        assert_eq!(a * b, a.multiply(&b));
+        assert_eq!(b / a, Complex::new(2, 0));
+        assert_eq!(-a, Complex::new(-1, -2));
+        assert_eq!(3 * a - 1, Complex::new(2, 6));
+        let mut c = a;
+        c *= &b;
+        c += 5;
+        assert_eq!(c, Complex::new(0, 10));
+        assert_eq!([a, b].iter().sum::<Complex>(), Complex::new(4, 6));
+        assert_eq!(
+            [a, a, a].into_iter().product::<Complex>(),
+            Complex::new(-11, -2)
+        );
+    }
+    #[test]
+    fn test_div_rem() {
+        for a in -12..=12 {
+            for b in -12..=12 {
+                let numerator = Complex::new(7 * a + 3, 5 * b - 2);
+                for divisor in [Complex::new(a, b), Complex::new(3, -2), Complex::new(0, 4)] {
+                    if divisor == Complex::zero() {
+                        continue;
+                    }
+                    let (quotient, remainder) = numerator.div_rem(&divisor);
+                    assert_eq!(quotient * divisor + remainder, numerator);
+                    assert!(2 * remainder.norm() <= divisor.norm());
+                    assert_eq!((numerator * divisor).divide(&divisor), Some(numerator));
+                    let exact = numerator.divide(&divisor);
+                    assert_eq!(exact.is_some(), remainder == Complex::zero());
+                    assert_eq!(numerator.is_divisible_by(&divisor), exact.is_some());
+                }
+            }
+        }
+        assert!(Complex::zero().is_divisible_by(&Complex::zero()));
+        assert!(!Complex::one().is_divisible_by(&Complex::zero()));
+        assert_eq!(Complex::new(7, 0) % Complex::new(2, 0), Complex::new(1, 0));
+    }
+    #[test]
+    #[should_panic]
+    fn test_div_rem_by_zero() {
+        Complex::one().div_rem(&Complex::zero());
+    }
+    #[test]
+    fn test_large_parts() {
+        // Parts above 2^63 have norms and cross products beyond an i128, but the quotients still fit.
+        let numerator = Complex::new((1 << 100) + 3, -(1 << 99) + 5);
+        let divisor = Complex::new((1 << 66) + 1, 1 << 65);
+        let (quotient, remainder) = numerator.div_rem(&divisor);
+        assert_eq!(quotient * divisor + remainder, numerator);
+        assert!(remainder.real.unsigned_abs() < 1 << 66 && remainder.imag.unsigned_abs() < 1 << 66);
+        assert_eq!((quotient * divisor).divide(&divisor), Some(quotient));
+        assert_eq!(numerator.divide(&divisor), None);
+        let big = Complex::new(i128::MAX, i128::MIN + 1);
+        let unit = Complex::new(0, -1);
+        assert_eq!(
+            big.div_rem(&unit),
+            (Complex::new(i128::MAX, i128::MAX), Complex::zero())
+        );
+        assert_eq!(big.divide(&big), Some(Complex::one()));
+        assert_eq!(
+            Complex::new(i128::MIN, 0).divide(&Complex::from_real(-1)),
+            None
+        );
+        assert_eq!(
+            Complex::new(i128::MIN, 0).divide(&Complex::from_real(2)),
+            Some(Complex::new(i128::MIN / 2, 0))
+        );
+        assert_eq!(
+            Complex::new(i128::MAX, 0).gcd(&Complex::new(i128::MAX, i128::MAX)),
+            Complex::from_real(i128::MAX)
+        );
+        assert!(!Complex::new(i128::MIN, 0).is_prime());
+        assert!(
+            Complex::new(0, i128::MAX).is_prime()
+                == (i128::MAX % 4 == 3 && primality::is_prime(i128::MAX))
+        );
+    }
+    #[test]
+    #[should_panic(expected = "does not fit in an i128")]
+    fn test_norm_overflow() {
+        Complex::new(1 << 64, 0).norm();
+    }
+    #[test]
+    fn test_gcd() {
+        for a in -9..=9 {
+            for b in -9..=9 {
+                let left = Complex::new(a, b);
+                let right = Complex::new(b - 3, 2 * a + 1) * Complex::new(2, 1);
+                let g = left.gcd(&right);
+                let (h, x, y) = left.extended_gcd(&right);
+                assert_eq!(g, h);
+                assert_eq!(left * x + right * y, g);
+                assert_eq!(g, g.normalize());
+                if g != Complex::zero() {
+                    assert!(left.is_divisible_by(&g) && right.is_divisible_by(&g));
+                    let (u, v) = (left.divide(&g).unwrap(), right.divide(&g).unwrap());
+                    assert!(u.gcd(&v) == Complex::one());
+                }
+            }
+        }
+        assert_eq!(Complex::zero().gcd(&Complex::zero()), Complex::zero());
+        assert_eq!(
+            Complex::zero().gcd(&Complex::new(0, -3)),
+            Complex::new(3, 0)
+        );
+        assert_eq!(
+            Complex::from_real(5).gcd(&Complex::new(1, 3)),
+            Complex::new(2, 1)
+        );
+        assert_eq!(
+            traits::gcd(&Complex::new(6, 8), &Complex::new(3, 4)).normalize(),
+            Complex::new(3, 4)
+        );
+    }
+    #[test]
+    fn test_normalize() {
+        let z = Complex::new(3, 2);
+        for unit in [
+            Complex::new(1, 0),
+            Complex::new(0, 1),
+            Complex::new(-1, 0),
+            Complex::new(0, -1),
+        ] {
+            assert!(unit.is_unit());
+            assert_eq!((z * unit).normalize(), z);
+            assert_eq!(unit.normalize(), Complex::one());
+        }
+        assert_eq!(Complex::new(0, 4).normalize(), Complex::new(4, 0));
+        assert_eq!(Complex::zero().normalize(), Complex::zero());
+        assert!(!Complex::zero().is_unit() && !Complex::new(1, 1).is_unit());
+    }
+    #[test]
+    fn test_primes_and_factorization() {
+        // Brute force: a Gaussian integer of norm > 1 is prime when no Gaussian integer of smaller norm > 1 divides it.
+        let small: Vec<Complex> = (-12..=12)
+            .flat_map(|a| (-12..=12).map(move |b| Complex::new(a, b)))
+            .filter(|z| z.norm() > 1)
+            .collect();
+        for z in small.iter().filter(|z| z.norm() <= 100) {
+            let expected = !small
+                .iter()
+                .any(|d| d.norm() < z.norm() && z.is_divisible_by(d));
+            assert_eq!(z.is_prime(), expected, "{}", z);
+        }
+        assert!(!Complex::zero().is_prime() && !Complex::one().is_prime());
+        for z in [
+            Complex::new(-11, 27),
+            Complex::from_real(360),
+            Complex::new(0, -1),
+            Complex::new(1_000_000_007, 998_244_353),
+            Complex::new(1 << 40, 3),
+            Complex::from_real(2_147_483_647) * Complex::new(5, 4) * Complex::new(5, 4),
+        ] {
+            let (unit, factors) = z.factorize();
+            assert!(unit.is_unit());
+            let mut product = unit;
+            for (prime, exponent) in &factors {
+                assert!(prime.is_prime() && *prime == prime.normalize());
+                product *= Ring::pow(prime, *exponent);
+            }
+            assert_eq!(product, z);
+            assert!(factors
+                .windows(2)
+                .all(|pair| pair[0].0.norm() <= pair[1].0.norm()));
+        }
+        // 65 = -(2 + i)(1 + 2i)(3 + 2i)(2 + 3i)
+        let (unit, factors) = Complex::from_real(65).factorize();
+        assert_eq!(unit, Complex::new(-1, 0));
+        assert_eq!(
+            factors,
+            vec![
+                (Complex::new(2, 1), 1),
+                (Complex::new(1, 2), 1),
+                (Complex::new(3, 2), 1),
+                (Complex::new(2, 3), 1)
+            ]
+        );
+        for p in [5, 13, 1_000_000_009, 1267650600228229401496703205653] {
+            let (x, y) = quadratic_residues::two_squares_of_prime(p);
+            let prime = Complex::new(x, y);
+            assert_eq!(prime.norm(), p);
+            assert_eq!(prime, prime.normalize());
+        }
+    }
+    #[test]
+    fn test_parse() {
+        assert_eq!("2-3i".parse(), Ok(Complex::new(2, -3)));
+        assert_eq!("-7".parse(), Ok(Complex::new(-7, 0)));
+        assert_eq!("i".parse(), Ok(Complex::new(0, 1)));
+        assert_eq!(
+            Complex::new(4, -5).to_string().parse(),
+            Ok(Complex::new(4, -5))
+        );
+        for text in ["1.5", "2+3", "1e3i", ""] {
+            assert!(text.parse::<Complex>().is_err(), "{:?}", text);
+        }
+    }
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Complex::from(5), Complex::new(5, 0));
+        let exact = complex_floats::Complex::new(3.0, -4.0);
+        assert_eq!(Complex::try_from(exact), Ok(Complex::new(3, -4)));
+        assert!(Complex::try_from(complex_floats::Complex::new(0.5, 0.0)).is_err());
+        assert!(Complex::try_from(complex_floats::Complex::new(f64::NAN, 0.0)).is_err());
+        assert!(Complex::try_from(complex_floats::Complex::new(1e40, 0.0)).is_err());
+        let whole = rational_integer::Rational::new(-6, 3);
+        assert_eq!(Complex::try_from(whole), Ok(Complex::new(-2, 0)));
+        assert!(Complex::try_from(rational_integer::Rational::new(1, 2)).is_err());
+        let float = rational_float::Rational::new(-9.0, 3.0);
+        assert_eq!(Complex::try_from(float), Ok(Complex::new(-3, 0)));
+        assert!(Complex::try_from(rational_float::Rational::new(1.0, 2.0)).is_err());
+    }
 }
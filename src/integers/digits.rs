@@ -0,0 +1,387 @@
+//! The `digits` module works with the digits of an integer in any base: splitting a number into digits and back, digit sums,
+//! digital roots, reversal and palindromes, and formatting and parsing in radix 2 to 36 with optional grouping separators.
+//!
+//! The sign of a number is ignored by the digit functions, except that `reverse_digits` keeps it.
+//!
+//! # Examples
+//! ```
+//! use numbers_rus::integers::digits;
+//!
+//! assert_eq!(digits::digits_in_base(2024, 10), vec![2, 0, 2, 4]);
+//! assert_eq!(digits::digit_sum(2024, 10), 8);
+//! assert!(digits::is_palindrome(0b1001, 2));
+//! assert_eq!(digits::format_radix_grouped(-3735928559, 16, 4, '_'), "-dead_beef");
+//! assert_eq!(digits::parse_radix("-dead_beef", 16), Ok(-3735928559));
+//! ```
+use std::fmt;
+
+use crate::error::{ErrorKind, NumbersError};
+
+/// Error returned when a string cannot be parsed into an i128 by `parse_radix` or `parse_radix_grouped`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDigitsError {
+    message: &'static str,
+}
+
+impl fmt::Display for ParseDigitsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ParseDigitsError {}
+
+/// `digits_in_base` function: Takes an i128 integer `number` and a base as input and returns the digits of `|number|` in that
+/// base, most significant first. Zero has the single digit 0. Panics if `base` is less than 2.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::digits;
+///
+/// assert_eq!(digits::digits_in_base(255, 16), vec![15, 15]);
+/// assert_eq!(digits::digits_in_base(-6, 2), vec![1, 1, 0]);
+/// assert_eq!(digits::digits_in_base(0, 7), vec![0]);
+/// ```
+pub fn digits_in_base(number: i128, base: u32) -> Vec<u32> {
+    assert!(base >= 2, "Base must be at least 2");
+    let mut n = number.unsigned_abs();
+    let mut digits = Vec::new();
+    loop {
+        digits.push((n % base as u128) as u32);
+        n /= base as u128;
+        if n == 0 {
+            break;
+        }
+    }
+    digits.reverse();
+    digits
+}
+
+/// `from_digits` function: Takes a slice of digits, most significant first, and a base as input and returns the non-negative
+/// number they represent. An empty slice is 0. Returns an `Overflow` error if the number does not fit in an i128. Panics if
+/// `base` is less than 2 or a digit is not less than `base`.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::digits;
+///
+/// assert_eq!(digits::from_digits(&[1, 0, 1, 1], 2), Ok(11));
+/// assert_eq!(digits::from_digits(&[15, 15], 16), Ok(255));
+/// assert!(digits::from_digits(&[1; 40], 10).is_err());
+/// ```
+pub fn from_digits(digits: &[u32], base: u32) -> Result<i128, NumbersError> {
+    assert!(base >= 2, "Base must be at least 2");
+    digits.iter().try_fold(0i128, |acc, &digit| {
+        assert!(digit < base, "Digit must be less than the base");
+        acc.checked_mul(base as i128)
+            .and_then(|acc| acc.checked_add(digit as i128))
+            .ok_or(NumbersError::new("from_digits", ErrorKind::Overflow))
+    })
+}
+
+/// `num_digits` function: Takes an i128 integer `number` and a base as input and returns the number of digits of `|number|` in
+/// that base. Zero has one digit. Panics if `base` is less than 2.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::digits;
+///
+/// assert_eq!(digits::num_digits(999, 10), 3);
+/// assert_eq!(digits::num_digits(i128::MIN, 2), 128);
+/// ```
+pub fn num_digits(number: i128, base: u32) -> u32 {
+    assert!(base >= 2, "Base must be at least 2");
+    let mut n = number.unsigned_abs() / base as u128;
+    let mut count = 1;
+    while n > 0 {
+        n /= base as u128;
+        count += 1;
+    }
+    count
+}
+
+/// `digit_sum` function: Takes an i128 integer `number` and a base as input and returns the sum of the digits of `|number|` in
+/// that base. Panics if `base` is less than 2.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::digits;
+///
+/// assert_eq!(digits::digit_sum(-9875, 10), 29);
+/// assert_eq!(digits::digit_sum(255, 2), 8);
+/// ```
+pub fn digit_sum(number: i128, base: u32) -> i128 {
+    digits_in_base(number, base)
+        .into_iter()
+        .map(|digit| digit as i128)
+        .sum()
+}
+
+/// `digital_root` function: Takes an i128 integer `number` and a base as input and returns the single digit left after
+/// repeatedly summing the digits of `|number|` in that base. It equals `1 + (|number| - 1) mod (base - 1)` for non-zero numbers.
+/// Panics if `base` is less than 2.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::digits;
+///
+/// assert_eq!(digits::digital_root(9875, 10), 2); // 9 + 8 + 7 + 5 = 29, 2 + 9 = 11, 1 + 1 = 2
+/// assert_eq!(digits::digital_root(0, 10), 0);
+/// ```
+pub fn digital_root(number: i128, base: u32) -> i128 {
+    assert!(base >= 2, "Base must be at least 2");
+    let n = number.unsigned_abs();
+    if n == 0 {
+        0
+    } else {
+        (1 + (n - 1) % (base as u128 - 1)) as i128
+    }
+}
+
+/// `reverse_digits` function: Takes an i128 integer `number` and a base as input and returns the number whose digits in that base
+/// are those of `number` in reverse order, keeping the sign. Trailing zeros become leading zeros and are dropped. Returns an
+/// `Overflow` error if the result does not fit in an i128. Panics if `base` is less than 2.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::digits;
+///
+/// assert_eq!(digits::reverse_digits(-1230, 10), Ok(-321));
+/// assert_eq!(digits::reverse_digits(0b1101, 2), Ok(0b1011));
+/// assert!(digits::reverse_digits(i128::MAX - 1, 10).is_err());
+/// ```
+pub fn reverse_digits(number: i128, base: u32) -> Result<i128, NumbersError> {
+    let mut digits = digits_in_base(number, base);
+    digits.reverse();
+    let reversed = from_digits(&digits, base)
+        .map_err(|_| NumbersError::new("reverse_digits", ErrorKind::Overflow))?;
+    Ok(if number < 0 { -reversed } else { reversed })
+}
+
+/// `is_palindrome` function: Takes an i128 integer `number` and a base as input and returns whether the digits of `|number|` in
+/// that base read the same in both directions. Panics if `base` is less than 2.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::digits;
+///
+/// assert!(digits::is_palindrome(12321, 10));
+/// assert!(!digits::is_palindrome(10, 10));
+/// assert!(digits::is_palindrome(-7, 10));
+/// ```
+pub fn is_palindrome(number: i128, base: u32) -> bool {
+    let digits = digits_in_base(number, base);
+    digits.iter().eq(digits.iter().rev())
+}
+
+/// `format_radix` function: Takes an i128 integer `number` and a radix from 2 to 36 as input and returns `number` written in that
+/// radix, using lowercase letters for digits above 9 and a leading `-` for negative numbers. Panics if `radix` is not in the
+/// range 2 to 36.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::digits;
+///
+/// assert_eq!(digits::format_radix(255, 16), "ff");
+/// assert_eq!(digits::format_radix(-35, 36), "-z");
+/// assert_eq!(digits::format_radix(i128::MIN, 2), format!("-1{}", "0".repeat(127)));
+/// ```
+pub fn format_radix(number: i128, radix: u32) -> String {
+    format_radix_grouped(number, radix, 0, '_')
+}
+
+/// `format_radix_grouped` function: Takes an i128 integer `number`, a radix from 2 to 36, a group size and a separator as input
+/// and returns `number` written in that radix with `separator` between every `group_size` digits, counted from the right. A
+/// group size of 0 disables grouping. Panics if `radix` is not in the range 2 to 36.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::digits;
+///
+/// assert_eq!(digits::format_radix_grouped(1234567, 10, 3, ','), "1,234,567");
+/// assert_eq!(digits::format_radix_grouped(-0b1011001, 2, 4, ' '), "-101 1001");
+/// ```
+pub fn format_radix_grouped(
+    number: i128,
+    radix: u32,
+    group_size: usize,
+    separator: char,
+) -> String {
+    assert!((2..=36).contains(&radix), "Radix must be between 2 and 36");
+    let digits = digits_in_base(number, radix);
+    let mut text = String::new();
+    if number < 0 {
+        text.push('-');
+    }
+    for (i, &digit) in digits.iter().enumerate() {
+        if group_size > 0 && i > 0 && (digits.len() - i).is_multiple_of(group_size) {
+            text.push(separator);
+        }
+        text.push(std::char::from_digit(digit, radix).unwrap());
+    }
+    text
+}
+
+/// `parse_radix` function: Takes a string and a radix from 2 to 36 as input and returns the i128 integer it represents. A leading
+/// `+` or `-` sign is accepted, letters may be upper or lower case, and single underscores between digits are ignored, as in
+/// Rust literals. Panics if `radix` is not in the range 2 to 36.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::digits;
+///
+/// assert_eq!(digits::parse_radix("-ZZ", 36), Ok(-1295));
+/// assert_eq!(digits::parse_radix("1_000_000", 10), Ok(1000000));
+/// assert!(digits::parse_radix("12", 2).is_err());
+/// ```
+pub fn parse_radix(text: &str, radix: u32) -> Result<i128, ParseDigitsError> {
+    parse_radix_grouped(text, radix, '_')
+}
+
+/// `parse_radix_grouped` function: Takes a string, a radix from 2 to 36 and a separator as input and returns the i128 integer the
+/// string represents, ignoring single separators between digits. Accepts everything `format_radix_grouped` produces with the
+/// same separator. Panics if `radix` is not in the range 2 to 36, or if `separator` is a sign or a digit in the radix.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::digits;
+///
+/// assert_eq!(digits::parse_radix_grouped("1,234,567", 10, ','), Ok(1234567));
+/// assert!(digits::parse_radix_grouped("1,,234", 10, ',').is_err());
+/// ```
+pub fn parse_radix_grouped(
+    text: &str,
+    radix: u32,
+    separator: char,
+) -> Result<i128, ParseDigitsError> {
+    assert!((2..=36).contains(&radix), "Radix must be between 2 and 36");
+    assert!(
+        separator.to_digit(radix).is_none() && separator != '-' && separator != '+',
+        "Separator must not be a sign or a digit"
+    );
+    let (negative, body) = match text.as_bytes().first() {
+        Some(b'-') => (true, &text[1..]),
+        Some(b'+') => (false, &text[1..]),
+        _ => (false, text),
+    };
+    if body.is_empty() {
+        return Err(ParseDigitsError {
+            message: "cannot parse integer from empty string",
+        });
+    }
+    let mut magnitude: u128 = 0;
+    let mut previous_was_digit = false;
+    for c in body.chars() {
+        if c == separator {
+            if !previous_was_digit {
+                return Err(ParseDigitsError {
+                    message: "misplaced separator",
+                });
+            }
+            previous_was_digit = false;
+            continue;
+        }
+        let digit = c.to_digit(radix).ok_or(ParseDigitsError {
+            message: "invalid digit found in string",
+        })?;
+        magnitude = magnitude
+            .checked_mul(radix as u128)
+            .and_then(|m| m.checked_add(digit as u128))
+            .ok_or(ParseDigitsError {
+                message: "number too large to fit in an i128",
+            })?;
+        previous_was_digit = true;
+    }
+    if !previous_was_digit {
+        return Err(ParseDigitsError {
+            message: "misplaced separator",
+        });
+    }
+    let too_large = ParseDigitsError {
+        message: "number too large to fit in an i128",
+    };
+    if negative {
+        0i128.checked_sub_unsigned(magnitude).ok_or(too_large)
+    } else {
+        i128::try_from(magnitude).map_err(|_| too_large)
+    }
+}
+
+#[cfg(test)]
+pub mod test_digits {
+    use super::*;
+
+    #[test]
+    fn test_digits_round_trip() {
+        for base in [2, 3, 10, 16, 36, 1000, u32::MAX] {
+            for number in [0, 1, 9, 10, 255, 123456789, i128::MAX, -987654321] {
+                let digits = digits_in_base(number, base);
+                assert_eq!(digits.len() as u32, num_digits(number, base));
+                assert_eq!(from_digits(&digits, base), Ok(number.abs()));
+                assert!(digits[0] != 0 || number == 0);
+            }
+        }
+        assert_eq!(digits_in_base(i128::MIN, 2).len(), 128);
+        assert!(from_digits(&digits_in_base(i128::MIN, 2), 2).is_err());
+    }
+    #[test]
+    #[should_panic]
+    fn test_from_digits_invalid_digit() {
+        let _ = from_digits(&[1, 2], 2);
+    }
+    #[test]
+    fn test_digital_root_matches_repeated_sums() {
+        for base in 2..20 {
+            for number in 0..2000 {
+                let mut n = number;
+                while n >= base as i128 {
+                    n = digit_sum(n, base);
+                }
+                assert_eq!(digital_root(number, base), n, "{} in base {}", number, base);
+            }
+        }
+    }
+    #[test]
+    fn test_reverse_and_palindrome() {
+        for number in -1000i128..1000 {
+            let reversed = reverse_digits(number, 10).unwrap();
+            let expected: String = number.abs().to_string().chars().rev().collect();
+            assert_eq!(reversed.abs(), expected.parse::<i128>().unwrap());
+            assert_eq!(is_palindrome(number, 10), reversed == number);
+        }
+        assert_eq!(reverse_digits(i128::MAX, 2), Ok(i128::MAX));
+        assert_eq!(reverse_digits(i128::MIN, 2), Ok(-1));
+    }
+    #[test]
+    fn test_format_and_parse() {
+        for radix in 2..=36 {
+            for number in [0, 1, -1, 35, 36, 1 << 64, i128::MAX, i128::MIN] {
+                let text = format_radix(number, radix);
+                assert_eq!(parse_radix(&text, radix), Ok(number));
+                let grouped = format_radix_grouped(number, radix, 3, ' ');
+                assert_eq!(parse_radix_grouped(&grouped, radix, ' '), Ok(number));
+                assert_eq!(grouped.replace(' ', ""), text);
+            }
+        }
+        assert_eq!(format_radix(-255, 16), format!("-{:x}", 255));
+        assert_eq!(format_radix(1 << 100, 2), format!("{:b}", 1i128 << 100));
+        assert_eq!(format_radix_grouped(123, 10, 3, ','), "123");
+        assert_eq!(format_radix_grouped(1234, 10, 3, ','), "1,234");
+    }
+    #[test]
+    fn test_parse_errors() {
+        for text in ["", "-", "+", "_1", "1_", "1__0", "-_1", "1 0", "g"] {
+            assert!(parse_radix(text, 16).is_err(), "{:?}", text);
+        }
+        assert_eq!(
+            parse_radix("170141183460469231731687303715884105728", 10)
+                .unwrap_err()
+                .to_string(),
+            "number too large to fit in an i128"
+        );
+        assert_eq!(
+            parse_radix("-170141183460469231731687303715884105728", 10),
+            Ok(i128::MIN)
+        );
+    }
+}
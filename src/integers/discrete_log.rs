@@ -0,0 +1,306 @@
+//! The `discrete_log` module inverts modular exponentiation: it computes multiplicative orders, primitive roots, the
+//! Carmichael function and discrete logarithms.
+//!
+//! `discrete_log` reduces the problem to the subgroup generated by the base, splits it along the prime factorization of the
+//! base's order (Pohlig-Hellman) and solves each prime-order piece with baby-step giant-step. Its cost is dominated by the
+//! square root of the largest prime factor of that order, so it is fast whenever the order is smooth, for example modulo
+//! `998244353 = 119 * 2^23 + 1`.
+//!
+//! # Examples
+//! ```
+//! use numbers_rus::integers::discrete_log;
+//!
+//! assert_eq!(discrete_log::multiplicative_order(2, 7), Some(3));
+//! assert_eq!(discrete_log::primitive_root(7), Some(3));
+//! assert_eq!(discrete_log::carmichael_lambda(15), 4);
+//! assert_eq!(discrete_log::discrete_log(3, 13, 17), Some(4)); // 3^4 = 81 = 4 * 17 + 13
+//! ```
+use std::collections::HashMap;
+
+use crate::integers::factorization;
+use crate::integers::modular::{self, mod_inverse, mod_mul, mod_pow};
+
+/// `carmichael_lambda` function: Takes an i128 integer `number` as input and returns the Carmichael function of `|number|`, the
+/// smallest exponent `m` such that `a^m = 1 (mod |number|)` for every `a` coprime to it. It always divides `euler_phi`. Returns 0
+/// for 0.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::discrete_log;
+///
+/// assert_eq!(discrete_log::carmichael_lambda(1), 1);
+/// assert_eq!(discrete_log::carmichael_lambda(561), 80); // the smallest Carmichael number
+/// assert_eq!(discrete_log::carmichael_lambda(1 << 10), 256);
+/// ```
+pub fn carmichael_lambda(number: i128) -> i128 {
+    if number == 0 {
+        return 0;
+    }
+    factorization::factorize(number)
+        .into_iter()
+        .map(|(p, e)| match (p, e) {
+            (2, 1) => 1,
+            (2, 2) => 2,
+            (2, _) => 1 << (e - 2),
+            _ => (p - 1) * p.pow(e - 1),
+        })
+        .fold(1, modular::lcm)
+}
+
+/// `multiplicative_order` function: Takes two i128 integers `number` and `modulus` as input and returns the smallest positive
+/// `k` with `number^k = 1 (mod |modulus|)`, or `None` if `number` and `modulus` are not coprime. Panics if `modulus` is zero.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::discrete_log;
+///
+/// assert_eq!(discrete_log::multiplicative_order(10, 7), Some(6)); // 1/7 = 0.(142857)
+/// assert_eq!(discrete_log::multiplicative_order(-1, 1_000_000_007), Some(2));
+/// assert_eq!(discrete_log::multiplicative_order(6, 9), None);
+/// ```
+pub fn multiplicative_order(number: i128, modulus: i128) -> Option<i128> {
+    let modulus = positive_modulus(modulus);
+    if modular::gcd(number, modulus) != 1 {
+        return None;
+    }
+    Some(order_dividing(number, carmichael_lambda(modulus), modulus))
+}
+
+/// `primitive_root` function: Takes an i128 integer `modulus` as input and returns the smallest primitive root modulo
+/// `|modulus|`, a number whose multiplicative order equals `euler_phi(|modulus|)`. Primitive roots exist only for 1, 2, 4, `p^k`
+/// and `2p^k` with `p` an odd prime; `None` is returned otherwise. Modulo 1 the root is 0. Panics if `modulus` is zero.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::discrete_log;
+///
+/// assert_eq!(discrete_log::primitive_root(998244353), Some(3));
+/// assert_eq!(discrete_log::primitive_root(50), Some(3));
+/// assert_eq!(discrete_log::primitive_root(8), None);
+/// ```
+pub fn primitive_root(modulus: i128) -> Option<i128> {
+    let modulus = positive_modulus(modulus);
+    match modulus {
+        1 => return Some(0),
+        2 => return Some(1),
+        4 => return Some(3),
+        _ => {}
+    }
+    let odd = if modulus % 4 == 2 {
+        modulus / 2
+    } else {
+        modulus
+    };
+    let factors = factorization::factorize(odd);
+    if odd % 2 == 0 || factors.len() != 1 {
+        return None;
+    }
+    let phi = factorization::euler_phi(modulus);
+    let phi_factors = factorization::factorize(phi);
+    (2..modulus).find(|&g| {
+        modular::gcd(g, modulus) == 1
+            && phi_factors
+                .keys()
+                .all(|&q| mod_pow(g, phi / q, modulus) != 1)
+    })
+}
+
+/// `discrete_log` function: Takes three i128 integers `base`, `target` and `modulus` as input and returns the smallest `x >= 0`
+/// with `base^x = target (mod |modulus|)`, or `None` if there is none. The base does not need to be coprime to the modulus.
+/// Uses Pohlig-Hellman with baby-step giant-step, so time and memory grow with the square root of the largest prime factor of
+/// the order of `base`. Panics if `modulus` is zero.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::discrete_log;
+///
+/// let x = discrete_log::discrete_log(3, 123456789, 998244353).unwrap();
+/// assert_eq!(numbers_rus::integers::modular::mod_pow(3, x, 998244353), 123456789);
+///
+/// assert_eq!(discrete_log::discrete_log(2, 0, 64), Some(6));
+/// assert_eq!(discrete_log::discrete_log(2, 3, 7), None); // the powers of 2 modulo 7 are 1, 2 and 4
+/// ```
+pub fn discrete_log(base: i128, target: i128, modulus: i128) -> Option<i128> {
+    let mut modulus = positive_modulus(modulus);
+    let base = base.rem_euclid(modulus);
+    let mut target = target.rem_euclid(modulus);
+
+    // Exponents below the bit length of the modulus are checked directly; above it the common factors of `base` and the
+    // modulus are divided out, leaving `coefficient * base^(x - shift) = target` with `base` coprime to the modulus.
+    let bits = 128 - modulus.leading_zeros() as i128;
+    let mut power = 1 % modulus;
+    for x in 0..bits {
+        if power == target {
+            return Some(x);
+        }
+        power = mod_mul(power, base, modulus);
+    }
+    let mut coefficient = 1 % modulus;
+    let mut shift = 0;
+    loop {
+        let g = modular::gcd(base, modulus);
+        if g == 1 {
+            break;
+        }
+        if target % g != 0 {
+            return None;
+        }
+        modulus /= g;
+        target /= g;
+        coefficient = mod_mul(coefficient, base / g, modulus);
+        shift += 1;
+    }
+    let target = mod_mul(target, mod_inverse(coefficient, modulus)?, modulus);
+    coprime_discrete_log(base, target, modulus).map(|x| x + shift)
+}
+
+/// Returns the smallest `x >= 0` with `base^x = target (mod modulus)` for `base` coprime to `modulus`.
+fn coprime_discrete_log(base: i128, target: i128, modulus: i128) -> Option<i128> {
+    let order = multiplicative_order(base, modulus)?;
+    let mut congruences = Vec::new();
+    for (q, e) in factorization::factorize(order) {
+        // Project onto the subgroup of order q^e and find the exponent one base-q digit at a time.
+        let prime_power = q.pow(e);
+        let g = mod_pow(base, order / prime_power, modulus);
+        let h = mod_pow(target, order / prime_power, modulus);
+        let generator = mod_pow(g, prime_power / q, modulus);
+        let g_inverse = mod_inverse(g, modulus)?;
+        let mut x = 0;
+        let mut digit_weight = 1;
+        for j in 0..e {
+            let remaining = mod_mul(mod_pow(g_inverse, x, modulus), h, modulus);
+            let projected = mod_pow(remaining, q.pow(e - 1 - j), modulus);
+            let digit = baby_step_giant_step(generator, projected, q, modulus)?;
+            x += digit * digit_weight;
+            digit_weight *= q;
+        }
+        congruences.push((x, prime_power));
+    }
+    let (x, _) = modular::crt(&congruences).ok()?;
+    // Every projection may be solvable while `target` still lies outside the subgroup generated by `base`.
+    (mod_pow(base, x, modulus) == target).then_some(x)
+}
+
+/// Returns the `x` in `0..order` with `base^x = target (mod modulus)`, where `base` has multiplicative order `order`.
+fn baby_step_giant_step(base: i128, target: i128, order: i128, modulus: i128) -> Option<i128> {
+    let steps = crate::integers::base::isqrt(order - 1) + 1;
+    let mut baby_steps = HashMap::new();
+    let mut power = 1 % modulus;
+    for j in 0..steps {
+        baby_steps.entry(power).or_insert(j);
+        power = mod_mul(power, base, modulus);
+    }
+    let giant_step = mod_inverse(power, modulus)?;
+    let mut current = target;
+    for i in 0..steps {
+        if let Some(j) = baby_steps.get(&current) {
+            return Some(i * steps + j);
+        }
+        current = mod_mul(current, giant_step, modulus);
+    }
+    None
+}
+
+/// Returns the order of `number` modulo `modulus`, given a multiple `exponent` of it.
+fn order_dividing(number: i128, exponent: i128, modulus: i128) -> i128 {
+    let mut order = exponent;
+    for q in factorization::factorize(exponent).into_keys() {
+        while order % q == 0 && mod_pow(number, order / q, modulus) == 1 {
+            order /= q;
+        }
+    }
+    order
+}
+
+fn positive_modulus(modulus: i128) -> i128 {
+    assert_ne!(modulus, 0, "Modulus must not be zero!");
+    assert_ne!(
+        modulus,
+        i128::MIN,
+        "Modulus must fit in an i128 when made positive"
+    );
+    modulus.abs()
+}
+
+#[cfg(test)]
+pub mod test_discrete_log {
+    use super::*;
+
+    #[test]
+    fn test_carmichael_lambda() {
+        for n in 1..150i128 {
+            let brute = (1..=n)
+                .find(|&m| {
+                    (1..n)
+                        .filter(|&a| modular::gcd(a, n) == 1)
+                        .all(|a| mod_pow(a, m, n) == 1 % n)
+                })
+                .unwrap();
+            assert_eq!(carmichael_lambda(n), brute, "n = {}", n);
+            assert_eq!(carmichael_lambda(-n), brute);
+        }
+        assert_eq!(carmichael_lambda(0), 0);
+        assert_eq!(carmichael_lambda(i128::MAX), i128::MAX - 1);
+    }
+    #[test]
+    fn test_multiplicative_order() {
+        for n in 1..100i128 {
+            for a in -5..n {
+                let brute = if modular::gcd(a, n) == 1 {
+                    (1..=n).find(|&k| mod_pow(a, k, n) == 1 % n)
+                } else {
+                    None
+                };
+                assert_eq!(multiplicative_order(a, n), brute, "a = {}, n = {}", a, n);
+            }
+        }
+        assert_eq!(multiplicative_order(43, i128::MAX), Some(i128::MAX - 1));
+    }
+    #[test]
+    fn test_primitive_root() {
+        for n in 1..200i128 {
+            let phi = factorization::euler_phi(n);
+            let brute = (0..n.max(2)).find(|&g| multiplicative_order(g, n) == Some(phi));
+            let brute = if n == 1 { Some(0) } else { brute };
+            assert_eq!(primitive_root(n), brute, "n = {}", n);
+        }
+        assert_eq!(primitive_root(1_000_000_007), Some(5));
+        assert_eq!(primitive_root(2 * 3i128.pow(40)), Some(5));
+    }
+    #[test]
+    fn test_discrete_log_small() {
+        for n in 1..40i128 {
+            for base in 0..n {
+                for target in 0..n {
+                    let brute = (0..2 * n).find(|&x| mod_pow(base, x, n) == target);
+                    assert_eq!(
+                        discrete_log(base, target, n),
+                        brute,
+                        "{}^x = {} (mod {})",
+                        base,
+                        target,
+                        n
+                    );
+                }
+            }
+        }
+    }
+    #[test]
+    fn test_discrete_log_large() {
+        // 2^64 - 2^32 + 1 has p - 1 = 2^32 * 3 * 5 * 17 * 257 * 65537.
+        let p = 18446744069414584321;
+        let g = primitive_root(p).unwrap();
+        for x in [0, 1, 12345, p - 2, 1 << 40] {
+            let target = mod_pow(g, x, p);
+            assert_eq!(discrete_log(g, target, p), Some(x));
+        }
+        // 2 is not invertible modulo 2^100: its powers reach 0 from x = 100 on.
+        assert_eq!(discrete_log(2, 0, 1 << 100), Some(100));
+        assert_eq!(discrete_log(6, 9 * 5, 1 << 80), None);
+        let n = 3i128.pow(40) * 1024;
+        let target = mod_pow(5, 123456789, n);
+        let x = discrete_log(5, target, n).unwrap();
+        assert_eq!(mod_pow(5, x, n), target);
+        assert_eq!(x, 123456789 % multiplicative_order(5, n).unwrap());
+    }
+}
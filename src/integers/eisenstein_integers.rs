@@ -0,0 +1,588 @@
+//! The `eisenstein_integers` module provides the Eisenstein integers `a + bω`, where `ω = (-1 + √-3)/2` is a primitive cube
+//! root of unity, so that `ω^2 = -1 - ω`. They form the ring of integers of `Q(√-3)` and, like the Gaussian integers of
+//! `complex_integers`, a Euclidean ring: rounding the exact quotient to the nearest lattice point leaves a remainder of at
+//! most three quarters of the norm of the divisor. There are six units, `±1`, `±ω` and `±ω^2`, and every non-zero value has
+//! exactly one associate with `a > b >= 0`, which `normalize` returns.
+//!
+//! The Eisenstein primes are the associates of `1 - ω` (norm 3), of the rational primes `p = 2 (mod 3)`, and of the
+//! `a + bω` whose norm is a rational prime `p = 1 (mod 3)`.
+//!
+//! # Examples
+//! ```
+//! use numbers_rus::integers::eisenstein_integers::Eisenstein;
+//!
+//! let omega = Eisenstein::omega();
+//! assert_eq!(omega * omega * omega, Eisenstein::one());
+//! assert_eq!(omega.norm(), 1);
+//!
+//! // 7 = (3 + ω)(2 - ω) splits, 5 stays prime.
+//! let seven = Eisenstein::from_integer(7);
+//! assert_eq!(seven.divide(&Eisenstein::new(3, 1)), Some(Eisenstein::new(2, -1)));
+//! assert!(!seven.is_prime() && Eisenstein::from_integer(5).is_prime());
+//! ```
+use crate::integers::complex_integers::round_divide;
+use crate::integers::{base, factorization, primality, quadratic_residues};
+use crate::traits::forward_copy_ops;
+use std::fmt;
+use std::ops;
+
+/// `Eisenstein` is the Eisenstein integer `a + bω` with i128 coordinates.
+///
+/// # Example
+///
+/// ```
+/// use numbers_rus::integers::eisenstein_integers::Eisenstein;
+///
+/// let a = Eisenstein::new(4, 1);
+/// let b = Eisenstein::new(1, -2);
+/// assert_eq!(a * b, Eisenstein::new(6, -5));
+/// assert_eq!(a.to_string(), "4 + 1ω");
+/// ```
+///
+/// # Methods
+///
+/// * `new` - creates `a + bω` from its coordinates.
+/// * `get` - returns the coordinates `(a, b)`.
+/// * `zero`, `one`, `omega` - return `0`, `1` and `ω`.
+/// * `from_integer` - returns a rational integer as an Eisenstein integer.
+/// * `add`, `subtract`, `multiply` - the ring operations.
+/// * `conjugate` - returns `a + bω^2`, the complex conjugate.
+/// * `norm` - returns `a^2 - ab + b^2`, the square of the absolute value.
+/// * `trace` - returns `2a - b`, the sum of the value and its conjugate.
+/// * `divide` - divides exactly, returning `None` when the quotient is not an Eisenstein integer.
+/// * `div_rem` - returns the Euclidean quotient, rounded to the nearest Eisenstein integer, and the remainder.
+/// * `is_divisible_by` - returns whether another Eisenstein integer divides this one.
+/// * `gcd` - returns the normalized greatest common divisor.
+/// * `extended_gcd` - returns the normalized greatest common divisor with its Bezout coefficients.
+/// * `units` - returns the six units.
+/// * `is_unit` - returns whether the value is a unit.
+/// * `normalize` - returns the associate with `a > b >= 0`.
+/// * `is_prime` - returns whether the value is an Eisenstein prime.
+/// * `factorize` - returns the factorization into a unit and powers of normalized Eisenstein primes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Eisenstein {
+    a: i128,
+    b: i128,
+}
+
+impl fmt::Display for Eisenstein {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.b < 0 {
+            write!(f, "{} - {}ω", self.a, -self.b)
+        } else {
+            write!(f, "{} + {}ω", self.a, self.b)
+        }
+    }
+}
+
+impl From<i128> for Eisenstein {
+    fn from(value: i128) -> Self {
+        Self::from_integer(value)
+    }
+}
+
+impl ops::Add for Eisenstein {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Eisenstein::add(&self, &other)
+    }
+}
+
+impl ops::Sub for Eisenstein {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.subtract(&other)
+    }
+}
+
+impl ops::Mul for Eisenstein {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.multiply(&other)
+    }
+}
+
+impl ops::Div for Eisenstein {
+    type Output = Self;
+
+    /// The Euclidean quotient of `div_rem`, rounded to the nearest Eisenstein integer. Panics if `other` is zero.
+    fn div(self, other: Self) -> Self {
+        self.div_rem(&other).0
+    }
+}
+
+impl ops::Rem for Eisenstein {
+    type Output = Self;
+
+    /// The Euclidean remainder of `div_rem`, with at most three quarters of the norm of `other`. Panics if `other` is zero.
+    fn rem(self, other: Self) -> Self {
+        self.div_rem(&other).1
+    }
+}
+
+impl ops::Neg for Eisenstein {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.a, -self.b)
+    }
+}
+
+forward_copy_ops!(Eisenstein, i128, Eisenstein::zero(), Eisenstein::one());
+
+impl Eisenstein {
+    pub fn new(a: i128, b: i128) -> Self {
+        Self { a, b }
+    }
+
+    pub fn get(&self) -> (i128, i128) {
+        (self.a, self.b)
+    }
+
+    pub fn zero() -> Self {
+        Self::new(0, 0)
+    }
+
+    pub fn one() -> Self {
+        Self::new(1, 0)
+    }
+
+    /// The omega function returns `ω = (-1 + √-3)/2`, a primitive cube root of unity.
+    pub fn omega() -> Self {
+        Self::new(0, 1)
+    }
+
+    pub fn from_integer(value: i128) -> Self {
+        Self::new(value, 0)
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        Self::new(self.a + other.a, self.b + other.b)
+    }
+
+    pub fn subtract(&self, other: &Self) -> Self {
+        Self::new(self.a - other.a, self.b - other.b)
+    }
+
+    /// multiply function multiplies two Eisenstein integers, using `ω^2 = -1 - ω`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::eisenstein_integers::Eisenstein;
+    ///
+    /// let a = Eisenstein::new(2, 3);
+    /// let b = Eisenstein::new(1, -1);
+    /// assert_eq!(a.multiply(&b), Eisenstein::new(5, 4));
+    /// ```
+    pub fn multiply(&self, other: &Self) -> Self {
+        let shared = self.b * other.b;
+        Self::new(
+            self.a * other.a - shared,
+            self.a * other.b + self.b * other.a - shared,
+        )
+    }
+
+    /// The conjugate function returns the complex conjugate `a + bω^2 = (a - b) - bω`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::eisenstein_integers::Eisenstein;
+    ///
+    /// let a = Eisenstein::new(3, 1);
+    /// assert_eq!(a.conjugate(), Eisenstein::new(2, -1));
+    /// assert_eq!(a * a.conjugate(), Eisenstein::from_integer(a.norm()));
+    /// ```
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.a - self.b, -self.b)
+    }
+
+    /// The norm function returns `a^2 - ab + b^2`, the product of the value and its conjugate. The norm is multiplicative,
+    /// and it is the size that the Euclidean division decreases.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::eisenstein_integers::Eisenstein;
+    ///
+    /// assert_eq!(Eisenstein::new(3, 1).norm(), 7);
+    /// ```
+    pub fn norm(&self) -> i128 {
+        self.a * self.a - self.a * self.b + self.b * self.b
+    }
+
+    /// The trace function returns `2a - b`, the sum of the value and its conjugate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::eisenstein_integers::Eisenstein;
+    ///
+    /// assert_eq!(Eisenstein::omega().trace(), -1);
+    /// ```
+    pub fn trace(&self) -> i128 {
+        2 * self.a - self.b
+    }
+
+    /// The divide function divides two Eisenstein integers exactly and returns the quotient, or `None` if `other` is zero
+    /// or the quotient is not an Eisenstein integer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::eisenstein_integers::Eisenstein;
+    ///
+    /// let a = Eisenstein::new(6, -5);
+    /// assert_eq!(a.divide(&Eisenstein::new(4, 1)), Some(Eisenstein::new(1, -2)));
+    /// assert_eq!(a.divide(&Eisenstein::new(2, 0)), None);
+    /// ```
+    pub fn divide(&self, other: &Self) -> Option<Self> {
+        if *other == Self::zero() {
+            return None;
+        }
+        let norm = other.norm();
+        let (a, b) = self.multiply(&other.conjugate()).get();
+        if a % norm == 0 && b % norm == 0 {
+            Some(Self::new(a / norm, b / norm))
+        } else {
+            None
+        }
+    }
+
+    /// The div_rem function returns `(quotient, remainder)` with `self = quotient * other + remainder`, where the quotient is
+    /// `self / other` with each coordinate rounded to the nearest integer, so that the remainder has at most three quarters
+    /// of the norm of `other`. Panics if `other` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::eisenstein_integers::Eisenstein;
+    ///
+    /// let a = Eisenstein::new(17, 5);
+    /// let b = Eisenstein::new(3, -2);
+    /// let (quotient, remainder) = a.div_rem(&b);
+    /// assert_eq!(quotient * b + remainder, a);
+    /// assert!(4 * remainder.norm() <= 3 * b.norm());
+    /// ```
+    pub fn div_rem(&self, other: &Self) -> (Self, Self) {
+        assert!(*other != Self::zero(), "division by zero");
+        let norm = other.norm();
+        let (a, b) = self.multiply(&other.conjugate()).get();
+        let quotient = Self::new(round_divide(a, norm), round_divide(b, norm));
+        (quotient, self.subtract(&quotient.multiply(other)))
+    }
+
+    /// The is_divisible_by function returns whether `self = q * other` for some Eisenstein integer `q`. Zero is only
+    /// divisible by itself among the divisors of zero norm.
+    pub fn is_divisible_by(&self, other: &Self) -> bool {
+        if *other == Self::zero() {
+            *self == Self::zero()
+        } else {
+            self.divide(other).is_some()
+        }
+    }
+
+    /// The gcd function returns the greatest common divisor of two Eisenstein integers, normalized so that `a > b >= 0`.
+    /// The gcd of zero and zero is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::eisenstein_integers::Eisenstein;
+    ///
+    /// let g = Eisenstein::new(3, 1);
+    /// let a = g * Eisenstein::new(5, 2);
+    /// let b = g * Eisenstein::new(-1, 4);
+    /// assert_eq!(a.gcd(&b), g);
+    /// ```
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (*self, *other);
+        while b != Self::zero() {
+            (a, b) = (b, a.div_rem(&b).1);
+        }
+        a.normalize()
+    }
+
+    /// The extended_gcd function returns `(g, x, y)` where `g` is the normalized greatest common divisor of `self` and
+    /// `other` and `self * x + other * y == g`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::eisenstein_integers::Eisenstein;
+    ///
+    /// let a = Eisenstein::new(11, 4);
+    /// let b = Eisenstein::new(-3, 7);
+    /// let (g, x, y) = a.extended_gcd(&b);
+    /// assert_eq!(a * x + b * y, g);
+    /// assert_eq!(g, a.gcd(&b));
+    /// ```
+    pub fn extended_gcd(&self, other: &Self) -> (Self, Self, Self) {
+        let (mut old_r, mut r) = (*self, *other);
+        let (mut old_s, mut s) = (Self::one(), Self::zero());
+        let (mut old_t, mut t) = (Self::zero(), Self::one());
+        while r != Self::zero() {
+            let quotient = old_r.div_rem(&r).0;
+            (old_r, r) = (r, old_r - quotient * r);
+            (old_s, s) = (s, old_s - quotient * s);
+            (old_t, t) = (t, old_t - quotient * t);
+        }
+        let unit = old_r.normalizing_unit();
+        (old_r * unit, old_s * unit, old_t * unit)
+    }
+
+    /// The units function returns the six units `1`, `-ω^2`, `ω`, `-1`, `ω^2` and `-ω`, the sixth roots of unity in
+    /// counterclockwise order.
+    pub fn units() -> [Self; 6] {
+        [
+            Self::new(1, 0),
+            Self::new(1, 1),
+            Self::new(0, 1),
+            Self::new(-1, 0),
+            Self::new(-1, -1),
+            Self::new(0, -1),
+        ]
+    }
+
+    /// The is_unit function returns whether the value is invertible, that is whether its norm is one.
+    pub fn is_unit(&self) -> bool {
+        Self::units().contains(self)
+    }
+
+    /// The normalize function returns the associate of the value, its product with a unit, that satisfies `a > b >= 0`,
+    /// which are the values with an argument in `[0, π/3)`. Every non-zero Eisenstein integer has exactly one such
+    /// associate, and zero stays zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::eisenstein_integers::Eisenstein;
+    ///
+    /// assert_eq!(Eisenstein::new(-1, 2).normalize(), Eisenstein::new(3, 1));
+    /// assert_eq!(Eisenstein::omega().normalize(), Eisenstein::one());
+    /// ```
+    pub fn normalize(&self) -> Self {
+        self.multiply(&self.normalizing_unit())
+    }
+
+    /// Returns the unit that rotates the value into the sector `a > b >= 0`, or one for zero.
+    fn normalizing_unit(&self) -> Self {
+        Self::units()
+            .into_iter()
+            .find(|unit| {
+                let (a, b) = self.multiply(unit).get();
+                a > b && b >= 0
+            })
+            .unwrap_or_else(Self::one)
+    }
+
+    /// The is_prime function returns whether the value is an Eisenstein prime: an associate of `1 - ω`, of a rational
+    /// prime `p = 2 (mod 3)`, or a value whose norm is a rational prime `p = 1 (mod 3)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::eisenstein_integers::Eisenstein;
+    ///
+    /// assert!(Eisenstein::new(1, -1).is_prime());
+    /// assert!(Eisenstein::new(0, 11).is_prime());
+    /// assert!(!Eisenstein::from_integer(13).is_prime());
+    /// ```
+    pub fn is_prime(&self) -> bool {
+        let norm = self.norm();
+        if primality::is_prime(norm) {
+            return true;
+        }
+        let p = base::isqrt(norm);
+        p * p == norm
+            && p % 3 == 2
+            && primality::is_prime(p)
+            && self.is_divisible_by(&Self::from_integer(p))
+    }
+
+    /// The factorize function returns `(unit, factors)` where `factors` lists each normalized Eisenstein prime dividing the
+    /// value with its exponent, in increasing order of norm, and `unit` times the product of the prime powers is the value.
+    /// Panics if the value is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::eisenstein_integers::Eisenstein;
+    ///
+    /// // 3 = -ω^2 (1 - ω)^2, and 2 + ω is the normalized associate of 1 - ω.
+    /// let (unit, factors) = Eisenstein::from_integer(3).factorize();
+    /// assert_eq!(factors, vec![(Eisenstein::new(2, 1), 2)]);
+    /// assert_eq!(unit * Eisenstein::new(2, 1) * Eisenstein::new(2, 1), Eisenstein::from_integer(3));
+    /// ```
+    pub fn factorize(&self) -> (Self, Vec<(Self, u32)>) {
+        assert!(*self != Self::zero(), "cannot factorize zero");
+        let mut rest = *self;
+        let mut factors = Vec::new();
+        for (p, _) in factorization::factorize(self.norm()) {
+            let primes = match p % 3 {
+                0 => vec![Self::new(2, 1)],
+                2 => vec![Self::from_integer(p)],
+                _ => {
+                    let prime = split_prime(p);
+                    vec![prime, prime.conjugate().normalize()]
+                }
+            };
+            for prime in primes {
+                let mut exponent = 0;
+                while let Some(quotient) = rest.divide(&prime) {
+                    rest = quotient;
+                    exponent += 1;
+                }
+                if exponent > 0 {
+                    factors.push((prime, exponent));
+                }
+            }
+        }
+        factors.sort_by_key(|(prime, _)| prime.norm());
+        (rest, factors)
+    }
+}
+
+/// Returns the normalized Eisenstein prime of norm `p` for a prime `p = 1 (mod 3)`. Cornacchia's algorithm writes
+/// `p = x^2 + 3y^2` by running the Euclidean algorithm on `p` and a square root of `-3` modulo `p` until the remainder drops
+/// below the square root of `p`, and then `x + y√-3 = (x + y) + 2yω` has norm `p`.
+fn split_prime(p: i128) -> Eisenstein {
+    let root = quadratic_residues::sqrt_mod_prime(p - 3, p)
+        .expect("-3 is a square modulo a prime p = 1 (mod 3)");
+    let limit = base::isqrt(p);
+    let (mut a, mut b) = (p, root);
+    while b > limit {
+        (a, b) = (b, a % b);
+    }
+    let y = base::isqrt((p - b * b) / 3);
+    Eisenstein::new(b + y, 2 * y).normalize()
+}
+
+#[cfg(test)]
+pub mod test_eisenstein {
+    use super::*;
+    use crate::traits::{self, Ring};
+
+    #[test]
+    fn test_arithmetic() {
+        let omega = Eisenstein::omega();
+        assert_eq!(omega * omega, Eisenstein::new(-1, -1));
+        assert_eq!(omega * omega + omega + 1, Eisenstein::zero());
+        assert_eq!(omega.conjugate(), omega * omega);
+        for a in -6..=6 {
+            for b in -6..=6 {
+                let x = Eisenstein::new(a, b);
+                let y = Eisenstein::new(b - 2, a + 3);
+                assert_eq!((x * y).norm(), x.norm() * y.norm());
+                assert_eq!(x + x.conjugate(), Eisenstein::from_integer(x.trace()));
+                assert_eq!(x * x.conjugate(), Eisenstein::from_integer(x.norm()));
+                assert_eq!(-x + x, Eisenstein::zero());
+            }
+        }
+        assert_eq!(Eisenstein::new(2, -5).to_string(), "2 - 5ω");
+        assert_eq!(Ring::pow(&Eisenstein::new(1, 1), 6), Eisenstein::one());
+    }
+    #[test]
+    fn test_div_rem() {
+        for a in -10..=10 {
+            for b in -10..=10 {
+                let numerator = Eisenstein::new(5 * a - 1, 3 * b + 2);
+                for divisor in [
+                    Eisenstein::new(a, b),
+                    Eisenstein::new(2, 5),
+                    Eisenstein::new(0, -3),
+                ] {
+                    if divisor == Eisenstein::zero() {
+                        continue;
+                    }
+                    let (quotient, remainder) = numerator.div_rem(&divisor);
+                    assert_eq!(quotient * divisor + remainder, numerator);
+                    assert!(4 * remainder.norm() <= 3 * divisor.norm());
+                    assert_eq!((numerator * divisor).divide(&divisor), Some(numerator));
+                    assert_eq!(
+                        numerator.divide(&divisor).is_some(),
+                        remainder == Eisenstein::zero()
+                    );
+                    assert_eq!(numerator / divisor, quotient);
+                    assert_eq!(numerator % divisor, remainder);
+                }
+            }
+        }
+        assert_eq!(Eisenstein::one().divide(&Eisenstein::zero()), None);
+        assert!(Eisenstein::zero().is_divisible_by(&Eisenstein::zero()));
+    }
+    #[test]
+    fn test_gcd_and_units() {
+        for a in -7..=7 {
+            for b in -7..=7 {
+                let left = Eisenstein::new(a, b) * Eisenstein::new(2, 1);
+                let right = Eisenstein::new(b + 1, 2 * a - 3);
+                let (g, x, y) = left.extended_gcd(&right);
+                assert_eq!(g, left.gcd(&right));
+                assert_eq!(left * x + right * y, g);
+                assert_eq!(g, g.normalize());
+                assert!(left.is_divisible_by(&g) && right.is_divisible_by(&g));
+            }
+        }
+        assert_eq!(
+            traits::gcd(&Eisenstein::new(6, 0), &Eisenstein::new(4, 2)).normalize(),
+            Eisenstein::new(2, 1) * 2
+        );
+        let x = Eisenstein::new(5, 2);
+        let associates: Vec<Eisenstein> =
+            Eisenstein::units().iter().map(|unit| x * *unit).collect();
+        for (index, unit) in Eisenstein::units().iter().enumerate() {
+            assert!(unit.is_unit() && unit.norm() == 1);
+            assert_eq!(associates[index].normalize(), x);
+        }
+        assert!(
+            associates
+                .iter()
+                .filter(|y| y.get().0 > y.get().1 && y.get().1 >= 0)
+                .count()
+                == 1
+        );
+        assert_eq!(Eisenstein::zero().normalize(), Eisenstein::zero());
+    }
+    #[test]
+    fn test_primes_and_factorization() {
+        let small: Vec<Eisenstein> = (-10..=10)
+            .flat_map(|a| (-10..=10).map(move |b| Eisenstein::new(a, b)))
+            .filter(|z| z.norm() > 1)
+            .collect();
+        for z in small.iter().filter(|z| z.norm() <= 60) {
+            let expected = !small
+                .iter()
+                .any(|d| d.norm() < z.norm() && z.is_divisible_by(d));
+            assert_eq!(z.is_prime(), expected, "{}", z);
+        }
+        for z in [
+            Eisenstein::new(-11, 27),
+            Eisenstein::from_integer(360),
+            Eisenstein::new(0, -1),
+            Eisenstein::new(1_000_000_007, 998_244_353),
+            Eisenstein::from_integer(2_147_483_647) * Eisenstein::new(5, 1) * Eisenstein::new(5, 1),
+        ] {
+            let (unit, factors) = z.factorize();
+            assert!(unit.is_unit());
+            let mut product = unit;
+            for (prime, exponent) in &factors {
+                assert!(prime.is_prime() && *prime == prime.normalize());
+                product *= Ring::pow(prime, *exponent);
+            }
+            assert_eq!(product, z);
+            assert!(factors
+                .windows(2)
+                .all(|pair| pair[0].0.norm() <= pair[1].0.norm()));
+        }
+        for p in [7, 13, 1_000_000_009, 1267650600228229401496703205901] {
+            let prime = split_prime(p);
+            assert_eq!(prime.norm(), p);
+            assert_eq!(prime, prime.normalize());
+        }
+    }
+}
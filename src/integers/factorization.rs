@@ -0,0 +1,540 @@
+//! The `factorization` module splits integers into their prime factors and derives the classical arithmetic functions from
+//! the result. `factorize` works for the whole i128 range by combining three methods:
+//!
+//! * trial division removes every prime factor below 1000,
+//! * Pollard's rho (with Brent's cycle detection) finds the remaining factors of up to about twelve digits,
+//! * Lenstra's elliptic curve method (ECM), on Montgomery curves with Suyama's parametrization and a two-stage search,
+//!   splits the stubborn cofactors made of two large primes.
+//!
+//! Each cofactor is checked with `primality::is_prime` before any further work is done on it.
+//!
+//! # Examples
+//! ```
+//! use numbers_rus::integers::factorization;
+//!
+//! let factors = factorization::factorize(360);
+//! assert_eq!(factors.into_iter().collect::<Vec<_>>(), vec![(2, 3), (3, 2), (5, 1)]);
+//! assert_eq!(factorization::divisors(12), vec![1, 2, 3, 4, 6, 12]);
+//! assert_eq!(factorization::euler_phi(36), 12);
+//! ```
+use crate::error::{ErrorKind, NumbersError};
+use crate::integers::modular::{gcd_unsigned as gcd, Montgomery};
+use crate::integers::primality;
+use crate::integers::primes;
+use std::collections::BTreeMap;
+
+/// Every prime below this bound is removed by trial division.
+const TRIAL_DIVISION_LIMIT: u64 = 1000;
+
+/// Pollard's rho gives up once its cycle length passes this bound, leaving the cofactor to ECM.
+const RHO_ITERATION_LIMIT: u64 = 1 << 20;
+
+/// ECM schedule as (stage 1 bound, number of curves); stage 2 runs up to 100 times the stage 1 bound.
+const ECM_SCHEDULE: [(u64, u32); 6] = [
+    (2_000, 25),
+    (11_000, 90),
+    (50_000, 300),
+    (250_000, 700),
+    (1_000_000, 1_800),
+    (3_000_000, 5_100),
+];
+
+/// `factorize` function: Takes an i128 integer `number` as input and returns its prime factorization as a map from each prime
+/// to its exponent. The sign of `number` is ignored, and 0, 1 and -1 have an empty factorization.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::factorization;
+///
+/// // 2^64 + 1 = 274177 * 67280421310721
+/// let factors = factorization::factorize(18446744073709551617);
+/// assert_eq!(factors.get(&274177), Some(&1));
+/// assert_eq!(factors.get(&67280421310721), Some(&1));
+///
+/// let factors = factorization::factorize(-(1 << 20) * 81);
+/// assert_eq!(factors.into_iter().collect::<Vec<_>>(), vec![(2, 20), (3, 4)]);
+/// ```
+pub fn factorize(number: i128) -> BTreeMap<i128, u32> {
+    let mut factors = BTreeMap::new();
+    let mut n = number.unsigned_abs();
+    if n < 2 {
+        return factors;
+    }
+
+    for p in primes::primes_below(TRIAL_DIVISION_LIMIT) {
+        let p = p as u128;
+        if p * p > n {
+            break;
+        }
+        while n.is_multiple_of(p) {
+            *factors.entry(p as i128).or_insert(0) += 1;
+            n /= p;
+        }
+    }
+
+    // Everything left is odd and below 2^127, so it fits both i128 and the Montgomery arithmetic.
+    let mut stack = vec![n];
+    while let Some(m) = stack.pop() {
+        if m == 1 {
+            continue;
+        }
+        if primality::is_prime(m as i128) {
+            *factors.entry(m as i128).or_insert(0) += 1;
+            continue;
+        }
+        let root = m.isqrt();
+        if root * root == m {
+            stack.push(root);
+            stack.push(root);
+            continue;
+        }
+        let divisor = find_factor(m);
+        stack.push(divisor);
+        stack.push(m / divisor);
+    }
+    factors
+}
+
+/// `divisors` function: Takes an i128 integer `number` as input and returns all positive divisors of `number` in increasing
+/// order. Returns an empty vector for 0. Panics for `i128::MIN`, whose divisor `2^127` does not fit in an i128.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::factorization;
+///
+/// assert_eq!(factorization::divisors(-28), vec![1, 2, 4, 7, 14, 28]);
+/// assert_eq!(factorization::divisors(i128::MIN + 1).last(), Some(&i128::MAX));
+/// ```
+pub fn divisors(number: i128) -> Vec<i128> {
+    if number == 0 {
+        return Vec::new();
+    }
+    if number == i128::MIN {
+        panic!("The divisor 2^127 of i128::MIN does not fit in an i128");
+    }
+    let mut result = vec![1i128];
+    for (p, e) in factorize(number) {
+        let current = result.len();
+        let mut power = 1i128;
+        for _ in 0..e {
+            power *= p;
+            for i in 0..current {
+                result.push(result[i] * power);
+            }
+        }
+    }
+    result.sort_unstable();
+    result
+}
+
+/// `num_divisors` function: Takes an i128 integer `number` as input and returns the number of positive divisors of `number`
+/// (often written `d(n)` or `tau(n)`). Returns 0 for 0.
+pub fn num_divisors(number: i128) -> i128 {
+    if number == 0 {
+        return 0;
+    }
+    factorize(number).values().map(|&e| e as i128 + 1).product()
+}
+
+/// `sigma` function: Takes an i128 integer `number` and an exponent `k` as input and returns the sum of the `k`th powers of the
+/// positive divisors of `number`, so `sigma(n, 1)` is the sum of divisors and `sigma(n, 0)` the number of divisors. Returns
+/// an `Overflow` error if the sum does not fit in an i128, and 0 for 0.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::factorization;
+///
+/// assert_eq!(factorization::sigma(12, 1), Ok(28));
+/// assert_eq!(factorization::sigma(12, 2), Ok(210));
+/// ```
+pub fn sigma(number: i128, k: u32) -> Result<i128, NumbersError> {
+    if number == 0 {
+        return Ok(0);
+    }
+    let overflow = NumbersError::new("sigma", ErrorKind::Overflow);
+    let mut result: i128 = 1;
+    for (p, e) in factorize(number) {
+        // 1 + p^k + p^2k + ... + p^ek
+        let p_k = p.checked_pow(k).ok_or(overflow)?;
+        let mut term: i128 = 1;
+        let mut sum: i128 = 1;
+        for _ in 0..e {
+            term = term.checked_mul(p_k).ok_or(overflow)?;
+            sum = sum.checked_add(term).ok_or(overflow)?;
+        }
+        result = result.checked_mul(sum).ok_or(overflow)?;
+    }
+    Ok(result)
+}
+
+/// `euler_phi` function: Takes an i128 integer `number` as input and returns Euler's totient of `|number|`, the number of
+/// integers in `1..=|number|` that are coprime to it. Returns 0 for 0.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::factorization;
+///
+/// assert_eq!(factorization::euler_phi(1), 1);
+/// assert_eq!(factorization::euler_phi(97), 96);
+/// assert_eq!(factorization::euler_phi(100), 40);
+/// ```
+pub fn euler_phi(number: i128) -> i128 {
+    if number == 0 {
+        return 0;
+    }
+    factorize(number)
+        .into_iter()
+        .fold(1i128, |acc, (p, e)| acc * (p - 1) * p.pow(e - 1))
+}
+
+/// `mobius` function: Takes an i128 integer `number` as input and returns the Möbius function of `|number|`: 0 if it has a
+/// squared prime factor, otherwise 1 or -1 for an even or odd number of prime factors. Returns 0 for 0.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::factorization;
+///
+/// assert_eq!(factorization::mobius(30), -1);
+/// assert_eq!(factorization::mobius(12), 0);
+/// assert_eq!(factorization::mobius(1), 1);
+/// ```
+pub fn mobius(number: i128) -> i32 {
+    if number == 0 {
+        return 0;
+    }
+    let factors = factorize(number);
+    if factors.values().any(|&e| e > 1) {
+        0
+    } else if factors.len().is_multiple_of(2) {
+        1
+    } else {
+        -1
+    }
+}
+
+/// `radical` function: Takes an i128 integer `number` as input and returns the product of the distinct primes dividing
+/// `|number|`. Returns 0 for 0.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::factorization;
+///
+/// assert_eq!(factorization::radical(504), 42);
+/// ```
+pub fn radical(number: i128) -> i128 {
+    if number == 0 {
+        return 0;
+    }
+    factorize(number).keys().product()
+}
+
+/// Returns a non-trivial divisor of the odd composite `n`, which must not be a perfect square.
+fn find_factor(n: u128) -> u128 {
+    let montgomery = Montgomery::new(n);
+    for c in 1..=3 {
+        if let Some(divisor) = pollard_brent(n, &montgomery, c) {
+            return divisor;
+        }
+    }
+    let mut sigma = 6;
+    for &(b1, curves) in ECM_SCHEDULE.iter() {
+        let stage_one_primes = primes::primes_below(b1 + 1);
+        for _ in 0..curves {
+            if let Some(divisor) = ecm(n, &montgomery, sigma, b1, 100 * b1, &stage_one_primes) {
+                return divisor;
+            }
+            sigma += 1;
+        }
+    }
+    panic!("Failed to factor {}", n);
+}
+
+/// Pollard's rho with Brent's cycle detection on `x -> x^2 + c`. Returns `None` if no factor turns up within the iteration
+/// limit or if the cycle closes without separating the factors.
+fn pollard_brent(n: u128, montgomery: &Montgomery, c: u128) -> Option<u128> {
+    const BATCH: u64 = 128;
+    let c = montgomery.encode(c);
+    let step = |x: u128| montgomery.add(montgomery.multiply(x, x), c);
+
+    let mut y = montgomery.encode(2);
+    let mut x = y;
+    let mut saved = y;
+    let mut product = montgomery.one();
+    let mut divisor = 1;
+    let mut r: u64 = 1;
+    while divisor == 1 {
+        x = y;
+        for _ in 0..r {
+            y = step(y);
+        }
+        let mut k = 0;
+        while k < r && divisor == 1 {
+            saved = y;
+            for _ in 0..BATCH.min(r - k) {
+                y = step(y);
+                product = montgomery.multiply(product, montgomery.subtract(x, y));
+            }
+            divisor = gcd(product, n);
+            k += BATCH;
+        }
+        r *= 2;
+        if r > RHO_ITERATION_LIMIT {
+            return None;
+        }
+    }
+
+    if divisor == n {
+        // The batch overshot: replay it one step at a time.
+        loop {
+            saved = step(saved);
+            divisor = gcd(montgomery.subtract(x, saved), n);
+            if divisor != 1 {
+                break;
+            }
+        }
+    }
+    if divisor == n {
+        None
+    } else {
+        Some(divisor)
+    }
+}
+
+/// A point on a Montgomery curve in projective `(X : Z)` coordinates, stored in Montgomery form.
+#[derive(Debug, Clone, Copy)]
+struct Point {
+    x: u128,
+    z: u128,
+}
+
+/// The Montgomery curve `B y^2 = x^3 + A x^2 + x` modulo `n`, described by `(A + 2) / 4 = a24 / c` so that no modular
+/// inversion is needed.
+struct Curve<'a> {
+    montgomery: &'a Montgomery,
+    a24: u128,
+    c: u128,
+}
+
+impl Curve<'_> {
+    fn double(&self, p: Point) -> Point {
+        let m = self.montgomery;
+        let sum = m.add(p.x, p.z);
+        let sum = m.multiply(sum, sum);
+        let difference = m.subtract(p.x, p.z);
+        let difference = m.multiply(difference, difference);
+        let t = m.subtract(sum, difference);
+        let scaled = m.multiply(self.c, difference);
+        Point {
+            x: m.multiply(sum, scaled),
+            z: m.multiply(t, m.add(scaled, m.multiply(self.a24, t))),
+        }
+    }
+
+    /// Returns `p + q` given the difference `p - q`.
+    fn add(&self, p: Point, q: Point, difference: Point) -> Point {
+        let m = self.montgomery;
+        let u = m.multiply(m.subtract(p.x, p.z), m.add(q.x, q.z));
+        let v = m.multiply(m.add(p.x, p.z), m.subtract(q.x, q.z));
+        let sum = m.add(u, v);
+        let diff = m.subtract(u, v);
+        Point {
+            x: m.multiply(difference.z, m.multiply(sum, sum)),
+            z: m.multiply(difference.x, m.multiply(diff, diff)),
+        }
+    }
+
+    /// Montgomery ladder computing `[k] p` for `k >= 1`.
+    fn multiply(&self, p: Point, k: u64) -> Point {
+        let mut r0 = p;
+        let mut r1 = self.double(p);
+        for i in (0..63 - k.leading_zeros()).rev() {
+            if (k >> i) & 1 == 1 {
+                r0 = self.add(r1, r0, p);
+                r1 = self.double(r1);
+            } else {
+                r1 = self.add(r0, r1, p);
+                r0 = self.double(r0);
+            }
+        }
+        r0
+    }
+}
+
+/// Runs one ECM curve (chosen by `sigma`) with stage 1 bound `b1` and stage 2 bound `b2`. `primes` must hold every prime up
+/// to `b1`.
+fn ecm(
+    n: u128,
+    montgomery: &Montgomery,
+    sigma: u128,
+    b1: u64,
+    b2: u64,
+    primes: &[u64],
+) -> Option<u128> {
+    let m = montgomery;
+    let found = |value: u128| {
+        let divisor = gcd(value, n);
+        if divisor != 1 && divisor != n {
+            Some(divisor)
+        } else {
+            None
+        }
+    };
+
+    // Suyama's parametrization: u = sigma^2 - 5, v = 4 sigma, starting point (u^3 : v^3) and
+    // (A + 2) / 4 = (v - u)^3 (3u + v) / (16 u^3 v).
+    let s = m.encode(sigma);
+    let u = m.subtract(m.multiply(s, s), m.encode(5));
+    let v = m.multiply(m.encode(4), s);
+    let u3 = m.multiply(m.multiply(u, u), u);
+    let v_minus_u = m.subtract(v, u);
+    let curve = Curve {
+        montgomery,
+        a24: m.multiply(
+            m.multiply(m.multiply(v_minus_u, v_minus_u), v_minus_u),
+            m.add(m.multiply(m.encode(3), u), v),
+        ),
+        c: m.multiply(m.multiply(m.encode(16), u3), v),
+    };
+    if gcd(curve.c, n) != 1 {
+        return found(curve.c);
+    }
+    let mut q = Point {
+        x: u3,
+        z: m.multiply(m.multiply(v, v), v),
+    };
+
+    // Stage 1: multiply by every prime power up to b1.
+    for &p in primes {
+        let mut power = p;
+        while power * p <= b1 {
+            power *= p;
+        }
+        q = curve.multiply(q, power);
+    }
+    let divisor = gcd(q.z, n);
+    if divisor == n {
+        return None;
+    }
+    if divisor != 1 {
+        return Some(divisor);
+    }
+
+    // Stage 2: look for a single prime between b1 and b2 by comparing [B]Q with [d]Q for B - d and B + d in each block.
+    const D: u64 = 2310;
+    let half = (D / 2) as usize;
+    let mut baby = vec![q; half + 1];
+    baby[2] = curve.double(q);
+    for d in 3..=half {
+        baby[d] = curve.add(baby[d - 1], q, baby[d - 2]);
+    }
+    let coprime: Vec<usize> = (1..=half)
+        .filter(|&d| gcd(d as u128, D as u128) == 1)
+        .collect();
+    let giant = curve.double(baby[half]);
+    let start = D * (b1 / D).max(2);
+    let mut previous = curve.multiply(q, start - D);
+    let mut current = curve.multiply(q, start);
+    let mut product = m.one();
+    let mut block = start;
+    while block <= b2 + D {
+        for &d in coprime.iter() {
+            let difference = m.subtract(
+                m.multiply(current.x, baby[d].z),
+                m.multiply(baby[d].x, current.z),
+            );
+            product = m.multiply(product, difference);
+        }
+        let next = curve.add(current, giant, previous);
+        previous = current;
+        current = next;
+        block += D;
+    }
+    found(product)
+}
+
+#[cfg(test)]
+pub mod test_factorization {
+    use super::*;
+
+    fn product(factors: &BTreeMap<i128, u32>) -> i128 {
+        factors.iter().map(|(&p, &e)| p.pow(e)).product()
+    }
+
+    #[test]
+    fn test_small_numbers() {
+        assert!(factorize(0).is_empty());
+        assert!(factorize(1).is_empty());
+        assert!(factorize(-1).is_empty());
+        for n in 2..5000 {
+            let factors = factorize(n);
+            assert_eq!(product(&factors), n);
+            assert!(factors.keys().all(|&p| primality::is_prime(p)));
+        }
+    }
+    #[test]
+    fn test_extremes() {
+        let factors = factorize(i128::MIN);
+        assert_eq!(factors.into_iter().collect::<Vec<_>>(), vec![(2, 127)]);
+        let factors = factorize(i128::MAX);
+        assert_eq!(
+            factors.into_iter().collect::<Vec<_>>(),
+            vec![(i128::MAX, 1)]
+        );
+        // 2^126 - 1 = 3 * 7^2 * 19 * 43 * 73 * 127 * 337 * 5419 * 92737 * 649657 * 77158673929
+        let factors = factorize((1 << 126) - 1);
+        assert_eq!(product(&factors), (1 << 126) - 1);
+        assert_eq!(factors.get(&7), Some(&2));
+        assert_eq!(factors.get(&77158673929), Some(&1));
+    }
+    #[test]
+    fn test_rho_sized_factors() {
+        let p: i128 = 1_000_000_007;
+        let q: i128 = 998_244_353;
+        let r: i128 = 999_999_937;
+        let factors = factorize(p * q * r);
+        assert_eq!(
+            factors.into_iter().collect::<Vec<_>>(),
+            vec![(q, 1), (r, 1), (p, 1)]
+        );
+        let factors = factorize(p * p * q);
+        assert_eq!(factors.get(&p), Some(&2));
+    }
+    #[test]
+    fn test_ecm_sized_factors() {
+        // Two 18-digit primes, out of reach of Pollard's rho within its iteration limit.
+        let p: i128 = 100000000000000003;
+        let q: i128 = 999999999999999989;
+        let factors = factorize(p * q);
+        assert_eq!(
+            factors.into_iter().collect::<Vec<_>>(),
+            vec![(p, 1), (q, 1)]
+        );
+    }
+    #[test]
+    fn test_perfect_powers() {
+        let p: i128 = 4294967311; // smallest prime above 2^32
+        let factors = factorize(p * p * p);
+        assert_eq!(factors.into_iter().collect::<Vec<_>>(), vec![(p, 3)]);
+    }
+    #[test]
+    fn test_arithmetic_functions() {
+        assert_eq!(num_divisors(720720), 240);
+        assert_eq!(sigma(6, 1), Ok(12));
+        assert_eq!(sigma(1, 5), Ok(1));
+        assert!(sigma(i128::MAX - 1, 1).is_err());
+        assert_eq!(euler_phi(-10), 4);
+        assert_eq!(mobius(2 * 3 * 5 * 7), 1);
+        assert_eq!(radical(-72), 6);
+        assert_eq!(divisors(1), vec![1]);
+        assert!(divisors(0).is_empty());
+        let n = 5040;
+        let ds = divisors(n);
+        assert_eq!(ds.len() as i128, num_divisors(n));
+        assert_eq!(ds.iter().sum::<i128>(), sigma(n, 1).unwrap());
+    }
+    #[test]
+    #[should_panic(expected = "does not fit in an i128")]
+    fn test_divisors_of_min() {
+        divisors(i128::MIN);
+    }
+}
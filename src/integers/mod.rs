@@ -6,7 +6,21 @@
 //!
 //! modules:
 //! * `base`
+//! * `big_integer`
+//! * `combinatorics`
 //! * `complex_integers`
+//! * `digits`
+//! * `discrete_log`
+//! * `eisenstein_integers`
+//! * `factorization`
+//! * `mod_int`
+//! * `modular`
+//! * `numerals`
+//! * `primality`
+//! * `primes`
+//! * `quadratic_integers`
+//! * `quadratic_residues`
+//! * `recurrence`
 //!
 //! # Examples
 //! ```
@@ -14,4 +28,18 @@
 //! use numbers_rus::integers::complex_integers;
 //! ```
 pub mod base;
+pub mod big_integer;
+pub mod combinatorics;
 pub mod complex_integers;
+pub mod digits;
+pub mod discrete_log;
+pub mod eisenstein_integers;
+pub mod factorization;
+pub mod mod_int;
+pub mod modular;
+pub mod numerals;
+pub mod primality;
+pub mod primes;
+pub mod quadratic_integers;
+pub mod quadratic_residues;
+pub mod recurrence;
@@ -0,0 +1,620 @@
+//! The `mod_int` module provides value types for arithmetic in the ring of integers modulo `n` (Z/nZ), so that long chains
+//! of additions and multiplications stay reduced without calling `base::modulo` after every step.
+//!
+//! * `ModInt<M>` fixes the modulus at compile time through a const generic parameter.
+//! * `DynModInt` carries a runtime `Modulus`, for moduli that are only known while the program runs.
+//!
+//! Both support moduli up to `u64::MAX`. Values modulo an odd number are stored in Montgomery form, which replaces the
+//! 128-by-64-bit division in every multiplication with two multiplications and a subtraction; even moduli fall back to a
+//! plain remainder.
+//!
+//! # Examples
+//! ```
+//! use numbers_rus::integers::mod_int::{DynModInt, ModInt, Modulus};
+//!
+//! type Mint = ModInt<1_000_000_007>;
+//! let a = Mint::new(-1);
+//! assert_eq!(a.value(), 1_000_000_006);
+//! assert_eq!((a * a).value(), 1);
+//! assert_eq!((Mint::new(1) / Mint::new(2) * Mint::new(2)).value(), 1);
+//!
+//! let modulus = Modulus::new(998_244_353);
+//! let b = DynModInt::new(3, modulus);
+//! assert_eq!(b.pow(998_244_352).value(), 1);
+//! ```
+use crate::integers::modular;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::num::ParseIntError;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// `Modulus` holds a modulus together with the constants needed for Montgomery reduction (`R = 2^64`). It is computed once
+/// and then shared by every `DynModInt` that uses it.
+///
+/// # Example
+///
+/// ```
+/// use numbers_rus::integers::mod_int::Modulus;
+///
+/// let modulus = Modulus::new(12);
+/// let a = modulus.parse("-5").unwrap();
+/// assert_eq!(a.value(), 7);
+/// assert_eq!(a.modulus(), modulus);
+/// ```
+///
+/// # Methods
+///
+/// * `new` - precomputes the reduction constants for a modulus; panics if it is zero.
+/// * `get` - returns the modulus as a u64.
+/// * `element` - creates a `DynModInt` with this modulus from an i128.
+/// * `parse` - parses a decimal string into a `DynModInt` with this modulus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Modulus {
+    modulus: u64,
+    /// `modulus^-1 mod 2^64` for odd moduli, unused for even ones.
+    inverse: u64,
+    /// `2^128 mod modulus` for odd moduli, unused for even ones.
+    r2: u64,
+}
+
+impl Modulus {
+    pub const fn new(modulus: u64) -> Self {
+        assert!(modulus != 0, "Modulus must not be zero!");
+        if modulus.is_multiple_of(2) {
+            return Self {
+                modulus,
+                inverse: 0,
+                r2: 0,
+            };
+        }
+        // Newton's iteration doubles the number of correct low bits each step, starting from the three bits given by
+        // modulus * modulus = 1 (mod 8).
+        let mut inverse = modulus;
+        let mut i = 0;
+        while i < 5 {
+            inverse = inverse.wrapping_mul(2u64.wrapping_sub(modulus.wrapping_mul(inverse)));
+            i += 1;
+        }
+        let m = modulus as u128;
+        let r2 = ((u128::MAX % m + 1) % m) as u64;
+        Self {
+            modulus,
+            inverse,
+            r2,
+        }
+    }
+
+    pub const fn get(&self) -> u64 {
+        self.modulus
+    }
+
+    pub fn element(self, value: i128) -> DynModInt {
+        DynModInt::new(value, self)
+    }
+
+    pub fn parse(self, text: &str) -> Result<DynModInt, ParseIntError> {
+        text.trim().parse::<i128>().map(|value| self.element(value))
+    }
+
+    const fn is_montgomery(&self) -> bool {
+        self.modulus % 2 == 1
+    }
+
+    /// Montgomery reduction: returns `t / 2^64 mod modulus` for `t < modulus * 2^64`. Since `t` and `q * modulus` agree in
+    /// their low 64 bits, their difference is exactly the difference of the high halves.
+    const fn redc(&self, t: u128) -> u64 {
+        let q = (t as u64).wrapping_mul(self.inverse);
+        let t_high = (t >> 64) as u64;
+        let qm_high = ((q as u128 * self.modulus as u128) >> 64) as u64;
+        if t_high >= qm_high {
+            t_high - qm_high
+        } else {
+            t_high.wrapping_sub(qm_high).wrapping_add(self.modulus)
+        }
+    }
+
+    /// Maps a value in `0..modulus` to its internal representation.
+    const fn encode(&self, value: u64) -> u64 {
+        if self.is_montgomery() {
+            self.redc(value as u128 * self.r2 as u128)
+        } else {
+            value
+        }
+    }
+
+    /// Maps an internal representation back to its value in `0..modulus`.
+    const fn decode(&self, value: u64) -> u64 {
+        if self.is_montgomery() {
+            self.redc(value as u128)
+        } else {
+            value
+        }
+    }
+
+    const fn reduce(&self, value: i128) -> u64 {
+        self.encode(value.rem_euclid(self.modulus as i128) as u64)
+    }
+
+    const fn add(&self, left: u64, right: u64) -> u64 {
+        let (sum, overflow) = left.overflowing_add(right);
+        if overflow || sum >= self.modulus {
+            sum.wrapping_sub(self.modulus)
+        } else {
+            sum
+        }
+    }
+
+    const fn subtract(&self, left: u64, right: u64) -> u64 {
+        if left >= right {
+            left - right
+        } else {
+            left.wrapping_sub(right).wrapping_add(self.modulus)
+        }
+    }
+
+    const fn multiply(&self, left: u64, right: u64) -> u64 {
+        let product = left as u128 * right as u128;
+        if self.is_montgomery() {
+            self.redc(product)
+        } else {
+            (product % self.modulus as u128) as u64
+        }
+    }
+
+    const fn negate(&self, value: u64) -> u64 {
+        if value == 0 {
+            0
+        } else {
+            self.modulus - value
+        }
+    }
+
+    const fn pow(&self, base: u64, exponent: u64) -> u64 {
+        let mut base = base;
+        let mut exponent = exponent;
+        let mut result = self.encode(1 % self.modulus);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = self.multiply(result, base);
+            }
+            base = self.multiply(base, base);
+            exponent >>= 1;
+        }
+        result
+    }
+
+    fn inverse(&self, value: u64) -> Option<u64> {
+        let value = self.decode(value);
+        modular::mod_inverse(value as i128, self.modulus as i128)
+            .map(|inverse| self.encode(inverse as u64))
+    }
+}
+
+/// `ModInt` is an integer modulo the compile-time constant `M`. Every value is kept reduced into `0..M`.
+///
+/// # Example
+///
+/// ```
+/// use numbers_rus::integers::mod_int::ModInt;
+///
+/// type Mint = ModInt<7>;
+/// let a: Mint = "10".parse().unwrap();
+/// assert_eq!(a, Mint::new(3));
+/// assert_eq!((a + Mint::new(5)).to_string(), "1");
+/// assert_eq!(a.inverse(), Some(Mint::new(5)));
+/// let total: Mint = (1..=6).map(Mint::new).product();
+/// assert_eq!(total, Mint::new(-1)); // Wilson's theorem
+/// ```
+///
+/// # Methods
+///
+/// * `new` - creates a `ModInt` from an i128, reducing it into `0..M`.
+/// * `value` - returns the representative in `0..M`.
+/// * `modulus` - returns `M`.
+/// * `pow` - raises the value to a non-negative integer power.
+/// * `inverse` - returns the multiplicative inverse, or `None` if the value is not coprime to `M`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ModInt<const M: u64> {
+    value: u64,
+}
+
+impl<const M: u64> ModInt<M> {
+    const MODULUS: Modulus = Modulus::new(M);
+
+    pub const fn new(value: i128) -> Self {
+        Self {
+            value: Self::MODULUS.reduce(value),
+        }
+    }
+
+    pub const fn value(&self) -> u64 {
+        Self::MODULUS.decode(self.value)
+    }
+
+    pub const fn modulus() -> u64 {
+        M
+    }
+
+    pub const fn pow(self, exponent: u64) -> Self {
+        Self {
+            value: Self::MODULUS.pow(self.value, exponent),
+        }
+    }
+
+    pub fn inverse(self) -> Option<Self> {
+        Self::MODULUS
+            .inverse(self.value)
+            .map(|value| Self { value })
+    }
+}
+
+impl<const M: u64> Add for ModInt<M> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            value: Self::MODULUS.add(self.value, other.value),
+        }
+    }
+}
+
+impl<const M: u64> Sub for ModInt<M> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            value: Self::MODULUS.subtract(self.value, other.value),
+        }
+    }
+}
+
+impl<const M: u64> Mul for ModInt<M> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self {
+            value: Self::MODULUS.multiply(self.value, other.value),
+        }
+    }
+}
+
+impl<const M: u64> Div for ModInt<M> {
+    type Output = Self;
+
+    /// Multiplies by the inverse of `other`. Panics if `other` is not invertible modulo `M`.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Self) -> Self {
+        self * other
+            .inverse()
+            .expect("Division by a value that is not invertible modulo M")
+    }
+}
+
+impl<const M: u64> Neg for ModInt<M> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            value: Self::MODULUS.negate(self.value),
+        }
+    }
+}
+
+impl<const M: u64> Sum for ModInt<M> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(0), |total, value| total + value)
+    }
+}
+
+impl<const M: u64> Product for ModInt<M> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(1), |total, value| total * value)
+    }
+}
+
+impl<const M: u64> fmt::Display for ModInt<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value(), f)
+    }
+}
+
+impl<const M: u64> fmt::Debug for ModInt<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (mod {})", self.value(), M)
+    }
+}
+
+impl<const M: u64> FromStr for ModInt<M> {
+    type Err = ParseIntError;
+
+    /// Parses a decimal integer that fits in an i128 and reduces it modulo `M`.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        text.trim().parse::<i128>().map(Self::new)
+    }
+}
+
+macro_rules! impl_from_primitive {
+    ($($primitive:ty),*) => {
+        $(impl<const M: u64> From<$primitive> for ModInt<M> {
+            fn from(value: $primitive) -> Self {
+                Self::new(value as i128)
+            }
+        })*
+    };
+}
+
+impl_from_primitive!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+/// `DynModInt` is an integer modulo a `Modulus` chosen at runtime. Combining two values with different moduli panics.
+///
+/// # Example
+///
+/// ```
+/// use numbers_rus::integers::mod_int::{DynModInt, Modulus};
+///
+/// let modulus = Modulus::new(1 << 61);
+/// let a = DynModInt::new(3, modulus);
+/// assert_eq!(a.pow(40).value(), 3u64.pow(40) % (1 << 61));
+/// assert_eq!((-a).value(), (1 << 61) - 3);
+/// ```
+///
+/// # Methods
+///
+/// * `new` - creates a `DynModInt` from an i128 and a `Modulus`, reducing the value.
+/// * `value` - returns the representative in `0..modulus`.
+/// * `modulus` - returns the `Modulus`.
+/// * `pow` - raises the value to a non-negative integer power.
+/// * `inverse` - returns the multiplicative inverse, or `None` if the value is not coprime to the modulus.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DynModInt {
+    value: u64,
+    modulus: Modulus,
+}
+
+impl DynModInt {
+    pub fn new(value: i128, modulus: Modulus) -> Self {
+        Self {
+            value: modulus.reduce(value),
+            modulus,
+        }
+    }
+
+    pub fn value(&self) -> u64 {
+        self.modulus.decode(self.value)
+    }
+
+    pub fn modulus(&self) -> Modulus {
+        self.modulus
+    }
+
+    pub fn pow(self, exponent: u64) -> Self {
+        Self {
+            value: self.modulus.pow(self.value, exponent),
+            modulus: self.modulus,
+        }
+    }
+
+    pub fn inverse(self) -> Option<Self> {
+        self.modulus.inverse(self.value).map(|value| Self {
+            value,
+            modulus: self.modulus,
+        })
+    }
+
+    fn check_modulus(&self, other: &Self) {
+        assert_eq!(
+            self.modulus.get(),
+            other.modulus.get(),
+            "Values with different moduli cannot be combined"
+        );
+    }
+}
+
+impl Add for DynModInt {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.check_modulus(&other);
+        Self {
+            value: self.modulus.add(self.value, other.value),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl Sub for DynModInt {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.check_modulus(&other);
+        Self {
+            value: self.modulus.subtract(self.value, other.value),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl Mul for DynModInt {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.check_modulus(&other);
+        Self {
+            value: self.modulus.multiply(self.value, other.value),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl Div for DynModInt {
+    type Output = Self;
+
+    /// Multiplies by the inverse of `other`. Panics if `other` is not invertible modulo the shared modulus.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Self) -> Self {
+        self.check_modulus(&other);
+        self * other
+            .inverse()
+            .expect("Division by a value that is not invertible modulo the modulus")
+    }
+}
+
+impl Neg for DynModInt {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            value: self.modulus.negate(self.value),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl fmt::Display for DynModInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value(), f)
+    }
+}
+
+impl fmt::Debug for DynModInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (mod {})", self.value(), self.modulus.get())
+    }
+}
+
+macro_rules! forward_assign {
+    ($type:ty, [$($generics:tt)*]) => {
+        impl<$($generics)*> AddAssign for $type {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<$($generics)*> SubAssign for $type {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl<$($generics)*> MulAssign for $type {
+            fn mul_assign(&mut self, other: Self) {
+                *self = *self * other;
+            }
+        }
+
+        impl<$($generics)*> DivAssign for $type {
+            fn div_assign(&mut self, other: Self) {
+                *self = *self / other;
+            }
+        }
+    };
+}
+
+forward_assign!(ModInt<M>, [const M: u64]);
+forward_assign!(DynModInt, []);
+
+#[cfg(test)]
+pub mod test_mod_int {
+    use super::*;
+    use crate::integers::modular::{mod_mul, mod_pow};
+
+    type Prime = ModInt<1_000_000_007>;
+    type Large = ModInt<18446744073709551557>; // largest prime below 2^64
+    type Even = ModInt<{ 1 << 40 }>;
+
+    #[test]
+    fn test_reduction() {
+        assert_eq!(Prime::new(1_000_000_007).value(), 0);
+        assert_eq!(Prime::new(-1).value(), 1_000_000_006);
+        assert_eq!(
+            Large::new(i128::MIN).value(),
+            i128::MIN.rem_euclid(18446744073709551557) as u64
+        );
+        assert_eq!(Even::new(-1).value(), (1 << 40) - 1);
+        assert_eq!(ModInt::<1>::new(5).value(), 0);
+        assert_eq!(ModInt::<1>::new(5).pow(0).value(), 0);
+        assert_eq!(Prime::default().value(), 0);
+        assert_eq!(Prime::from(7u8), Prime::new(7));
+    }
+    #[test]
+    fn test_arithmetic_matches_modular() {
+        let m = 18446744073709551557i128;
+        let values = [
+            0,
+            1,
+            2,
+            m - 1,
+            m / 2,
+            12345678901234567,
+            -987654321987654321,
+        ];
+        for &a in values.iter() {
+            for &b in values.iter() {
+                let (x, y) = (Large::new(a), Large::new(b));
+                assert_eq!((x + y).value() as i128, (a + b).rem_euclid(m));
+                assert_eq!((x - y).value() as i128, (a - b).rem_euclid(m));
+                assert_eq!((x * y).value() as i128, mod_mul(a, b, m));
+                if b.rem_euclid(m) != 0 {
+                    assert_eq!((x / y * y), x);
+                }
+            }
+            assert_eq!(
+                Large::new(a).pow(1 << 40).value() as i128,
+                mod_pow(a, 1 << 40, m)
+            );
+            assert_eq!((-Large::new(a)).value() as i128, (-a).rem_euclid(m));
+        }
+    }
+    #[test]
+    fn test_even_modulus() {
+        let a = Even::new(3);
+        assert_eq!(a.pow(100).value() as i128, mod_pow(3, 100, 1 << 40));
+        assert_eq!(Even::new(2).inverse(), None);
+        assert_eq!((a * a.inverse().unwrap()).value(), 1);
+        let modulus = Modulus::new(u64::MAX - 1);
+        let b = DynModInt::new(-2, modulus);
+        assert_eq!((b * b).value(), 4);
+    }
+    #[test]
+    fn test_assign_ops() {
+        let mut a = Prime::new(10);
+        a += Prime::new(5);
+        a -= Prime::new(20);
+        a *= Prime::new(3);
+        assert_eq!(a, Prime::new(-15));
+        a /= Prime::new(-15);
+        assert_eq!(a.value(), 1);
+    }
+    #[test]
+    #[should_panic]
+    fn test_divide_by_zero() {
+        let _ = Prime::new(1) / Prime::new(0);
+    }
+    #[test]
+    fn test_parse_and_display() {
+        let a: Prime = " -1 ".parse().unwrap();
+        assert_eq!(a.to_string(), "1000000006");
+        assert_eq!(format!("{:?}", a), "1000000006 (mod 1000000007)");
+        assert_eq!(format!("{:>5}", Prime::new(42)), "   42");
+        assert!("abc".parse::<Prime>().is_err());
+    }
+    #[test]
+    fn test_dyn_mod_int() {
+        let modulus = Modulus::new(998_244_353);
+        let a = modulus.element(5);
+        let mut b = modulus.parse("998244354").unwrap();
+        assert_eq!(b.value(), 1);
+        b += a;
+        assert_eq!(b.value(), 6);
+        assert_eq!((a / b * b), a);
+        assert_eq!(a.pow(998_244_352).value(), 1);
+        assert_eq!(a.to_string(), "5");
+        assert_eq!(format!("{:?}", -a), "998244348 (mod 998244353)");
+    }
+    #[test]
+    #[should_panic]
+    fn test_dyn_mixed_moduli() {
+        let _ = DynModInt::new(1, Modulus::new(7)) + DynModInt::new(1, Modulus::new(11));
+    }
+}
@@ -0,0 +1,508 @@
+//! The `modular` module collects the number theory that the rest of the crate builds on: greatest common divisors, least
+//! common multiples, the extended Euclidean algorithm, modular inverses, overflow-safe modular multiplication and
+//! exponentiation, and a Chinese Remainder Theorem solver that also handles moduli which are not pairwise coprime.
+//!
+//! All functions take i128 values. Modular results are always reduced into `0..|modulus|`, and products are formed with
+//! 256-bit intermediates so that nothing overflows even for moduli close to `i128::MAX`.
+//!
+//! # Examples
+//! ```
+//! use numbers_rus::integers::modular;
+//!
+//! assert_eq!(modular::gcd(84, -36), 12);
+//! assert_eq!(modular::mod_inverse(3, 11), Some(4));
+//! assert_eq!(modular::mod_pow(2, 100, 1_000_000_007), 976371285);
+//! assert_eq!(modular::crt(&[(2, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+//! ```
+use crate::error::{ErrorKind, NumbersError};
+
+/// `gcd` function: Takes two i128 integers `left` and `right` as input and returns their greatest common divisor, which is
+/// always non-negative. `gcd(0, 0)` is 0. Uses the binary (Stein's) algorithm. Panics if the result is 2^127, which only
+/// happens when both inputs are `i128::MIN` or 0.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::modular;
+///
+/// assert_eq!(modular::gcd(48, 18), 6);
+/// assert_eq!(modular::gcd(-7, 0), 7);
+/// ```
+pub fn gcd(left: i128, right: i128) -> i128 {
+    i128::try_from(gcd_unsigned(left.unsigned_abs(), right.unsigned_abs()))
+        .expect("gcd does not fit in an i128")
+}
+
+/// `lcm` function: Takes two i128 integers `left` and `right` as input and returns their least common multiple, which is
+/// always non-negative. The lcm of anything with 0 is 0. Panics if the result does not fit in an i128.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::modular;
+///
+/// assert_eq!(modular::lcm(4, 6), 12);
+/// assert_eq!(modular::lcm(-3, 5), 15);
+/// ```
+pub fn lcm(left: i128, right: i128) -> i128 {
+    if left == 0 || right == 0 {
+        return 0;
+    }
+    let divisor = gcd(left, right);
+    (left / divisor)
+        .checked_mul(right)
+        .and_then(|product| product.checked_abs())
+        .expect("lcm does not fit in an i128")
+}
+
+/// `extended_gcd` function: Takes two i128 integers `left` and `right` as input and returns `(g, x, y)` where `g` is their
+/// non-negative greatest common divisor and `left * x + right * y == g`. The coefficients are the minimal ones produced by the
+/// extended Euclidean algorithm, so `|x| <= |right / g|` and `|y| <= |left / g|`. Panics if the greatest common divisor is
+/// `2^127`, which does not fit in an i128, as for `extended_gcd(i128::MIN, 0)`.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::modular;
+///
+/// let (g, x, y) = modular::extended_gcd(240, 46);
+/// assert_eq!(g, 2);
+/// assert_eq!(240 * x + 46 * y, 2);
+/// assert_eq!(modular::extended_gcd(i128::MIN, 1), (1, 0, 1));
+/// assert_eq!(modular::extended_gcd(i128::MIN, -1), (1, 0, -1));
+/// ```
+pub fn extended_gcd(left: i128, right: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (left, right);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    let (mut old_t, mut t) = (0i128, 1i128);
+    // Only the last cofactors, `right / g` and `left / g` up to sign, and the quotient `i128::MIN / -1` can overflow. The
+    // cofactors are discarded and the wrapped quotient still leaves a zero remainder, so wrapping keeps the result exact.
+    while r != 0 {
+        let quotient = old_r.wrapping_div(r);
+        (old_r, r) = (r, old_r.wrapping_sub(quotient.wrapping_mul(r)));
+        (old_s, s) = (s, old_s.wrapping_sub(quotient.wrapping_mul(s)));
+        (old_t, t) = (t, old_t.wrapping_sub(quotient.wrapping_mul(t)));
+    }
+    if old_r == i128::MIN {
+        panic!("The greatest common divisor 2^127 does not fit in an i128");
+    }
+    if old_r < 0 {
+        (-old_r, -old_s, -old_t)
+    } else {
+        (old_r, old_s, old_t)
+    }
+}
+
+/// `mod_inverse` function: Takes two i128 integers `number` and `modulus` as input and returns the inverse of `number` modulo
+/// `modulus` in `0..|modulus|`, or `None` if `number` and `modulus` are not coprime. Panics if `modulus` is zero.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::modular;
+///
+/// assert_eq!(modular::mod_inverse(-3, 11), Some(7));
+/// assert_eq!(modular::mod_inverse(6, 9), None);
+/// ```
+pub fn mod_inverse(number: i128, modulus: i128) -> Option<i128> {
+    assert_ne!(modulus, 0, "Modulus must not be zero!");
+    let modulus = modulus.unsigned_abs();
+    let number = reduce(number, modulus);
+    if modulus == 1 {
+        return Some(0);
+    }
+    match i128::try_from(modulus) {
+        Ok(modulus) => {
+            let (g, x, _) = extended_gcd(number as i128, modulus);
+            if g == 1 {
+                Some(x.rem_euclid(modulus))
+            } else {
+                None
+            }
+        }
+        // |modulus| = 2^127: exactly the odd numbers are invertible. Newton's iteration x -> x * (2 - a * x) doubles the
+        // number of correct low bits each step, starting from the three bits given by a * a = 1 (mod 8).
+        Err(_) => {
+            if number.is_multiple_of(2) {
+                return None;
+            }
+            let mut inverse = number;
+            for _ in 0..6 {
+                inverse = inverse.wrapping_mul(2u128.wrapping_sub(number.wrapping_mul(inverse)));
+            }
+            Some((inverse % modulus) as i128)
+        }
+    }
+}
+
+/// `mod_mul` function: Takes three i128 integers `left`, `right` and `modulus` as input and returns `left * right` reduced
+/// into `0..|modulus|`. The product is formed without overflow for any inputs. Panics if `modulus` is zero.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::modular;
+///
+/// let m = i128::MAX;
+/// assert_eq!(modular::mod_mul(m - 1, m - 1, m), 1);
+/// assert_eq!(modular::mod_mul(-2, 3, 7), 1);
+/// ```
+pub fn mod_mul(left: i128, right: i128, modulus: i128) -> i128 {
+    assert_ne!(modulus, 0, "Modulus must not be zero!");
+    let modulus = modulus.unsigned_abs();
+    mul_mod(reduce(left, modulus), reduce(right, modulus), modulus) as i128
+}
+
+/// `mod_pow` function: Takes three i128 integers `base`, `exponent` and `modulus` as input and returns `base` raised to the
+/// power of `exponent`, reduced into `0..|modulus|`. Odd moduli use Montgomery multiplication, even ones binary
+/// exponentiation with overflow-safe products. Panics if the exponent is negative or `modulus` is zero.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::modular;
+///
+/// assert_eq!(modular::mod_pow(4, 13, 497), 445);
+/// assert_eq!(modular::mod_pow(3, i128::MAX - 1, i128::MAX), 1); // Fermat's little theorem for 2^127 - 1
+/// ```
+pub fn mod_pow(base: i128, exponent: i128, modulus: i128) -> i128 {
+    if exponent < 0 {
+        panic!("Exponentiation with negative exponent is not supported");
+    }
+    assert_ne!(modulus, 0, "Modulus must not be zero!");
+    let modulus = modulus.unsigned_abs();
+    let mut base = reduce(base, modulus);
+    let mut exponent = exponent as u128;
+    if modulus == 1 {
+        return 0;
+    }
+    if !modulus.is_multiple_of(2) && modulus < 1 << 127 {
+        let montgomery = Montgomery::new(modulus);
+        let result = montgomery.pow(montgomery.encode(base), exponent);
+        return montgomery.multiply(result, 1) as i128;
+    }
+
+    let mut result = 1;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent >>= 1;
+    }
+    result as i128
+}
+
+/// `crt` function: Takes a slice of congruences `x = residue (mod modulus)` given as `(residue, modulus)` pairs and returns
+/// `(x, m)`, where `m` is the least common multiple of the moduli and `x` in `0..m` is the unique solution modulo `m`. The
+/// moduli do not need to be pairwise coprime. An empty slice yields `(0, 1)`.
+///
+/// Returns an `InconsistentCongruences` error if the system has no solution, a `DivisionByZero` error if a modulus is zero
+/// and an `Overflow` error if `m` does not fit in an i128.
+///
+/// # Example
+/// ```
+/// use numbers_rus::error::ErrorKind;
+/// use numbers_rus::integers::modular;
+///
+/// // Non-coprime moduli: x = 3 (mod 4) and x = 5 (mod 6) gives x = 11 (mod 12).
+/// assert_eq!(modular::crt(&[(3, 4), (5, 6)]), Ok((11, 12)));
+/// // x = 1 (mod 4) and x = 2 (mod 6) would make x both odd and even.
+/// let err = modular::crt(&[(1, 4), (2, 6)]).unwrap_err();
+/// assert_eq!(err.kind(), ErrorKind::InconsistentCongruences);
+/// ```
+pub fn crt(congruences: &[(i128, i128)]) -> Result<(i128, i128), NumbersError> {
+    let mut x: i128 = 0;
+    let mut m: i128 = 1;
+    for &(residue, modulus) in congruences {
+        if modulus == 0 {
+            return Err(NumbersError::new("crt", ErrorKind::DivisionByZero));
+        }
+        let n = i128::try_from(modulus.unsigned_abs())
+            .map_err(|_| NumbersError::new("crt", ErrorKind::Overflow))?;
+        let r = residue.rem_euclid(n);
+
+        // Solve x + m * t = r (mod n), which is solvable exactly when g = gcd(m, n) divides r - x.
+        let g = gcd(m, n);
+        let difference = r - x;
+        if difference % g != 0 {
+            return Err(NumbersError::new("crt", ErrorKind::InconsistentCongruences));
+        }
+        let combined = (m / g)
+            .checked_mul(n)
+            .ok_or(NumbersError::new("crt", ErrorKind::Overflow))?;
+        let step = n / g;
+        let inverse = mod_inverse(m / g, step).expect("m / g and n / g are coprime");
+        let t = mod_mul(difference / g, inverse, step);
+        let offset = mod_mul(m, t, combined) as u128;
+        x = add_mod(x as u128, offset, combined as u128) as i128;
+        m = combined;
+    }
+    Ok((x, m))
+}
+
+/// Returns `value` reduced into `0..modulus`.
+pub(crate) fn reduce(value: i128, modulus: u128) -> u128 {
+    let remainder = value.unsigned_abs() % modulus;
+    if value < 0 && remainder != 0 {
+        modulus - remainder
+    } else {
+        remainder
+    }
+}
+
+/// Binary gcd of two unsigned integers.
+pub(crate) fn gcd_unsigned(a: u128, b: u128) -> u128 {
+    let (mut a, mut b) = (a, b);
+    if a == 0 || b == 0 {
+        return a | b;
+    }
+    let shift = (a | b).trailing_zeros();
+    a >>= a.trailing_zeros();
+    while b != 0 {
+        b >>= b.trailing_zeros();
+        if a > b {
+            std::mem::swap(&mut a, &mut b);
+        }
+        b -= a;
+    }
+    a << shift
+}
+
+/// Returns the full 256-bit product of `a` and `b` as `(high, low)` halves.
+pub(crate) fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_high, a_low) = (a >> 64, a & MASK);
+    let (b_high, b_low) = (b >> 64, b & MASK);
+    let low_low = a_low * b_low;
+    let low_high = a_low * b_high;
+    let high_low = a_high * b_low;
+    let high_high = a_high * b_high;
+    let middle = (low_low >> 64) + (low_high & MASK) + (high_low & MASK);
+    let low = (low_low & MASK) | (middle << 64);
+    let high = high_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64);
+    (high, low)
+}
+
+/// Returns `(a + b) mod m` for `a, b < m` without overflowing.
+pub(crate) fn add_mod(a: u128, b: u128, m: u128) -> u128 {
+    let (sum, overflow) = a.overflowing_add(b);
+    if overflow || sum >= m {
+        sum.wrapping_sub(m)
+    } else {
+        sum
+    }
+}
+
+/// Returns `(a * b) mod m` for any `m > 0` without overflowing.
+pub(crate) fn mul_mod(a: u128, b: u128, m: u128) -> u128 {
+    let (mut a, mut b) = (a % m, b % m);
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+    let mut result = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = add_mod(result, a, m);
+        }
+        a = add_mod(a, a, m);
+        b >>= 1;
+    }
+    result
+}
+
+/// Montgomery arithmetic modulo an odd `modulus` below 2^127, with `R = 2^128`. Values are kept in Montgomery form
+/// `x * R mod modulus` so that each multiplication needs a single reduction instead of a 256-by-128-bit division.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Montgomery {
+    modulus: u128,
+    /// `-modulus^-1 mod 2^128`
+    negated_inverse: u128,
+    /// `R^2 mod modulus`, used to convert into Montgomery form.
+    r_squared: u128,
+}
+
+impl Montgomery {
+    pub(crate) fn new(modulus: u128) -> Self {
+        debug_assert!(!modulus.is_multiple_of(2) && modulus < 1 << 127);
+        // Newton iteration doubles the number of correct low bits each step; m * m = 1 mod 8 gives the first three.
+        let mut inverse = modulus;
+        for _ in 0..6 {
+            inverse = inverse.wrapping_mul(2u128.wrapping_sub(modulus.wrapping_mul(inverse)));
+        }
+        let r = (u128::MAX % modulus + 1) % modulus;
+        Self {
+            modulus,
+            negated_inverse: inverse.wrapping_neg(),
+            r_squared: mul_mod(r, r, modulus),
+        }
+    }
+
+    /// Returns `high * 2^128 + low` divided by `R` modulo `modulus`; requires the input to be below `modulus * R`.
+    fn reduce(&self, high: u128, low: u128) -> u128 {
+        let q = low.wrapping_mul(self.negated_inverse);
+        let (q_high, _) = widening_mul(q, self.modulus);
+        // low + q * modulus is a multiple of 2^128, so the low half carries exactly when low is non-zero.
+        let t = high + q_high + (low != 0) as u128;
+        if t >= self.modulus {
+            t - self.modulus
+        } else {
+            t
+        }
+    }
+
+    pub(crate) fn encode(&self, x: u128) -> u128 {
+        let (high, low) = widening_mul(x % self.modulus, self.r_squared);
+        self.reduce(high, low)
+    }
+
+    pub(crate) fn one(&self) -> u128 {
+        self.encode(1)
+    }
+
+    pub(crate) fn multiply(&self, a: u128, b: u128) -> u128 {
+        let (high, low) = widening_mul(a, b);
+        self.reduce(high, low)
+    }
+
+    pub(crate) fn add(&self, a: u128, b: u128) -> u128 {
+        add_mod(a, b, self.modulus)
+    }
+
+    pub(crate) fn subtract(&self, a: u128, b: u128) -> u128 {
+        if a >= b {
+            a - b
+        } else {
+            a + (self.modulus - b)
+        }
+    }
+
+    /// Returns `a / 2` modulo the (odd) modulus.
+    pub(crate) fn half(&self, a: u128) -> u128 {
+        if a.is_multiple_of(2) {
+            a / 2
+        } else {
+            (a + self.modulus) / 2
+        }
+    }
+
+    pub(crate) fn pow(&self, base: u128, exponent: u128) -> u128 {
+        let mut base = base;
+        let mut exponent = exponent;
+        let mut result = self.one();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = self.multiply(result, base);
+            }
+            base = self.multiply(base, base);
+            exponent >>= 1;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+pub mod test_modular {
+    use super::*;
+
+    #[test]
+    fn test_mul_mod() {
+        let m = i128::MAX as u128;
+        assert_eq!(mul_mod(m - 1, m - 1, m), 1);
+        assert_eq!(mul_mod(u128::MAX, u128::MAX, u128::MAX - 1), 1);
+        let montgomery = Montgomery::new(m);
+        let a = montgomery.encode(m - 2);
+        let b = montgomery.encode(3);
+        // Multiplying by a raw 1 leaves Montgomery form.
+        assert_eq!(montgomery.multiply(montgomery.multiply(a, b), 1), m - 6);
+    }
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(gcd(-12, -18), 6);
+        assert_eq!(gcd(i128::MIN, 6), 2);
+        assert_eq!(gcd(i128::MAX, i128::MAX - 1), 1);
+        assert_eq!(lcm(0, 5), 0);
+        assert_eq!(lcm(21, 6), 42);
+    }
+    #[test]
+    #[should_panic]
+    fn test_gcd_overflow() {
+        gcd(i128::MIN, 0);
+    }
+    #[test]
+    #[should_panic]
+    fn test_extended_gcd_overflow() {
+        extended_gcd(i128::MIN, 0);
+    }
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [
+            (0, 0),
+            (0, 7),
+            (7, 0),
+            (-240, 46),
+            (240, -46),
+            (17, 5),
+            (i128::MAX, 1 << 100),
+            (i128::MIN, 1),
+            (i128::MIN, -1),
+            (1, i128::MIN),
+            (i128::MIN, 3),
+            (i128::MIN, i128::MAX),
+            (i128::MIN, -(1 << 100)),
+        ] {
+            let (g, x, y) = extended_gcd(a, b);
+            if g != 0 {
+                assert!(x.unsigned_abs() <= (b / g).unsigned_abs().max(1));
+                assert!(y.unsigned_abs() <= (a / g).unsigned_abs().max(1));
+            }
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a.wrapping_mul(x).wrapping_add(b.wrapping_mul(y)), g);
+        }
+    }
+    #[test]
+    fn test_mod_inverse() {
+        for a in 1..97 {
+            let inverse = mod_inverse(a, 97).unwrap();
+            assert_eq!(a * inverse % 97, 1);
+        }
+        assert_eq!(mod_inverse(5, 1), Some(0));
+        assert_eq!(mod_inverse(0, 7), None);
+        let m = i128::MAX;
+        assert_eq!(mod_mul(mod_inverse(m - 5, m).unwrap(), m - 5, m), 1);
+        let inverse = mod_inverse(3, i128::MIN).unwrap();
+        assert_eq!(mod_mul(inverse, 3, i128::MIN), 1);
+        assert_eq!(mod_inverse(4, i128::MIN), None);
+    }
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(mod_pow(0, 0, 7), 1);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+        assert_eq!(mod_pow(-2, 3, 5), 2);
+        assert_eq!(mod_pow(3, 200, 1 << 64), mod_pow(3, 200, -(1 << 64)));
+        // 2^127 - 1 is prime, so a^(p-1) = 1 for every a not divisible by it.
+        assert_eq!(mod_pow(12345678901234567890, i128::MAX - 1, i128::MAX), 1);
+        // Even moduli take the generic path.
+        assert_eq!(
+            mod_pow(3, 1000, 1 << 100),
+            3i128.wrapping_pow(1000) & ((1 << 100) - 1)
+        );
+    }
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[]), Ok((0, 1)));
+        assert_eq!(crt(&[(-1, 5)]), Ok((4, 5)));
+        assert_eq!(crt(&[(0, 4), (0, 6), (0, 10)]), Ok((0, 60)));
+        let (p, q) = (1_000_000_007, 998_244_353);
+        let (x, m) = crt(&[(5, p), (7, q), (11, 1 << 40)]).unwrap();
+        assert_eq!(m, p * q * (1 << 40));
+        assert_eq!((x % p, x % q, x % (1 << 40)), (5, 7, 11));
+        assert_eq!(
+            crt(&[(1, 0)]).unwrap_err().kind(),
+            ErrorKind::DivisionByZero
+        );
+        assert_eq!(crt(&[(0, 1 << 100), (0, 3 << 100)]), Ok((0, 3 << 100)));
+        assert_eq!(
+            crt(&[(0, 1 << 100), (0, (1 << 40) - 1)])
+                .unwrap_err()
+                .kind(),
+            ErrorKind::Overflow
+        );
+    }
+}
@@ -0,0 +1,353 @@
+//! The `numerals` module converts integers to and from Roman numerals and English words.
+//!
+//! * `to_roman` and `from_roman` handle the standard subtractive notation for 1 to 3999. Parsing only accepts the canonical
+//!   form, so `from_roman(to_roman(n))` is the only way to reach `n`.
+//! * `to_words` and `from_words` use short-scale American English without "and" ("one hundred twenty-three") and cover the
+//!   whole i128 range, up to undecillions.
+//!
+//! # Examples
+//! ```
+//! use numbers_rus::integers::numerals;
+//!
+//! assert_eq!(numerals::to_roman(1994), Some("MCMXCIV".to_string()));
+//! assert_eq!(numerals::from_roman("MCMXCIV"), Ok(1994));
+//! assert_eq!(numerals::to_words(-123), "minus one hundred twenty-three");
+//! assert_eq!(numerals::from_words("one hundred twenty-three"), Ok(123));
+//! ```
+use std::fmt;
+
+/// Error returned when a string cannot be parsed by `from_roman` or `from_words`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseNumeralError {
+    message: &'static str,
+}
+
+impl fmt::Display for ParseNumeralError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ParseNumeralError {}
+
+const ROMAN: [(i128, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// Scale words for 10^3, 10^6, ..., 10^36, the largest power of a thousand below `i128::MAX`.
+const SCALES: [&str; 12] = [
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+];
+
+/// `to_roman` function: Takes an i128 integer `number` as input and returns its Roman numeral in standard subtractive notation,
+/// or `None` if it is not in the range 1 to 3999.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::numerals;
+///
+/// assert_eq!(numerals::to_roman(2024), Some("MMXXIV".to_string()));
+/// assert_eq!(numerals::to_roman(3999), Some("MMMCMXCIX".to_string()));
+/// assert_eq!(numerals::to_roman(0), None);
+/// ```
+pub fn to_roman(number: i128) -> Option<String> {
+    if !(1..=3999).contains(&number) {
+        return None;
+    }
+    let mut rest = number;
+    let mut numeral = String::new();
+    for (value, symbol) in ROMAN {
+        while rest >= value {
+            numeral.push_str(symbol);
+            rest -= value;
+        }
+    }
+    Some(numeral)
+}
+
+/// `from_roman` function: Takes a string as input and returns the number its Roman numeral represents. Lowercase letters are
+/// accepted, but the numeral must be in the canonical form produced by `to_roman`, so "IIII" and "IC" are rejected.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::numerals;
+///
+/// assert_eq!(numerals::from_roman("xlii"), Ok(42));
+/// assert!(numerals::from_roman("IC").is_err());
+/// assert!(numerals::from_roman("").is_err());
+/// ```
+pub fn from_roman(text: &str) -> Result<i128, ParseNumeralError> {
+    let upper = text.to_ascii_uppercase();
+    let mut rest = upper.as_str();
+    let mut number = 0;
+    for (value, symbol) in ROMAN {
+        while let Some(remaining) = rest.strip_prefix(symbol) {
+            number += value;
+            rest = remaining;
+        }
+    }
+    if !rest.is_empty() || to_roman(number).as_deref() != Some(upper.as_str()) {
+        return Err(ParseNumeralError {
+            message: "invalid Roman numeral",
+        });
+    }
+    Ok(number)
+}
+
+/// `to_words` function: Takes an i128 integer `number` as input and returns it written out in English words, with hyphenated
+/// tens ("forty-two"), no "and", and "minus" for negative numbers.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::numerals;
+///
+/// assert_eq!(numerals::to_words(0), "zero");
+/// assert_eq!(numerals::to_words(1_000_017), "one million seventeen");
+/// assert_eq!(numerals::to_words(-2_500_000_000), "minus two billion five hundred million");
+/// ```
+pub fn to_words(number: i128) -> String {
+    if number == 0 {
+        return ONES[0].to_string();
+    }
+    let mut groups = Vec::new();
+    let mut n = number.unsigned_abs();
+    while n > 0 {
+        groups.push((n % 1000) as usize);
+        n /= 1000;
+    }
+    let mut words = Vec::new();
+    if number < 0 {
+        words.push("minus".to_string());
+    }
+    for (scale, &group) in groups.iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        words.push(group_to_words(group));
+        if scale > 0 {
+            words.push(SCALES[scale - 1].to_string());
+        }
+    }
+    words.join(" ")
+}
+
+/// Returns the words for a number in `1..1000`.
+fn group_to_words(group: usize) -> String {
+    let mut words = Vec::new();
+    if group >= 100 {
+        words.push(format!("{} hundred", ONES[group / 100]));
+    }
+    match group % 100 {
+        0 => {}
+        rest @ 1..=19 => words.push(ONES[rest].to_string()),
+        rest if rest % 10 == 0 => words.push(TENS[rest / 10].to_string()),
+        rest => words.push(format!("{}-{}", TENS[rest / 10], ONES[rest % 10])),
+    }
+    words.join(" ")
+}
+
+/// `from_words` function: Takes a string of English number words as input and returns the number they name. Words are separated
+/// by spaces or hyphens and are case-insensitive, the word "and" is ignored, and "minus" or "negative" may lead. Each
+/// scale word must be preceded by a group from 1 to 999 and scales must decrease, so "thousand million" is rejected.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::numerals;
+///
+/// assert_eq!(numerals::from_words("Forty-Two"), Ok(42));
+/// assert_eq!(numerals::from_words("two thousand and twenty-four"), Ok(2024));
+/// assert_eq!(numerals::from_words("negative seven hundred"), Ok(-700));
+/// assert!(numerals::from_words("twenty twenty").is_err());
+/// ```
+pub fn from_words(text: &str) -> Result<i128, ParseNumeralError> {
+    let invalid = ParseNumeralError {
+        message: "invalid number words",
+    };
+    let lower = text.to_lowercase();
+    let mut words = lower
+        .split(|c: char| c.is_whitespace() || c == '-')
+        .filter(|word| !word.is_empty())
+        .peekable();
+    let negative = matches!(words.peek(), Some(&"minus") | Some(&"negative"));
+    if negative {
+        words.next();
+    }
+    let words: Vec<&str> = words.filter(|&word| word != "and").collect();
+    if words == ["zero"] {
+        return Ok(0);
+    }
+    if words.is_empty() {
+        return Err(invalid);
+    }
+
+    let mut total: u128 = 0;
+    let mut group: u128 = 0;
+    let mut last_scale = SCALES.len() + 1;
+    for word in words {
+        if let Some(value) = ONES.iter().position(|&w| w == word).filter(|&v| v > 0) {
+            // A units word may only follow nothing, "hundred" or a multiple of ten from twenty on.
+            let tail = group % 100;
+            let allowed = if value < 10 {
+                tail == 0 || (tail >= 20 && tail.is_multiple_of(10))
+            } else {
+                tail == 0
+            };
+            if !allowed {
+                return Err(invalid);
+            }
+            group += value as u128;
+        } else if let Some(value) = TENS.iter().position(|&w| w == word).filter(|&v| v >= 2) {
+            if !group.is_multiple_of(100) {
+                return Err(invalid);
+            }
+            group += 10 * value as u128;
+        } else if word == "hundred" {
+            if !(1..10).contains(&group) {
+                return Err(invalid);
+            }
+            group *= 100;
+        } else if let Some(scale) = SCALES.iter().position(|&w| w == word) {
+            if group == 0 || scale >= last_scale {
+                return Err(invalid);
+            }
+            total = group
+                .checked_mul(1000u128.pow(scale as u32 + 1))
+                .and_then(|value| total.checked_add(value))
+                .ok_or(ParseNumeralError {
+                    message: "number too large to fit in an i128",
+                })?;
+            group = 0;
+            last_scale = scale;
+        } else {
+            return Err(invalid);
+        }
+    }
+    let magnitude = total + group;
+    let too_large = ParseNumeralError {
+        message: "number too large to fit in an i128",
+    };
+    if negative {
+        0i128.checked_sub_unsigned(magnitude).ok_or(too_large)
+    } else {
+        i128::try_from(magnitude).map_err(|_| too_large)
+    }
+}
+
+#[cfg(test)]
+pub mod test_numerals {
+    use super::*;
+
+    #[test]
+    fn test_roman_round_trip() {
+        for number in 1..4000 {
+            let numeral = to_roman(number).unwrap();
+            assert_eq!(from_roman(&numeral), Ok(number));
+            assert_eq!(from_roman(&numeral.to_lowercase()), Ok(number));
+        }
+        assert_eq!(to_roman(4000), None);
+        assert_eq!(to_roman(-1), None);
+        for text in ["IIII", "VV", "IL", "MMMM", "XM", "ABC", "I I", "CMC"] {
+            assert!(from_roman(text).is_err(), "{}", text);
+        }
+    }
+    #[test]
+    fn test_words_round_trip() {
+        let mut numbers: Vec<i128> = (-1100..1100).collect();
+        numbers.extend([
+            1_000_000,
+            1_000_001,
+            999_999_999_999,
+            10i128.pow(36),
+            i128::MAX,
+            i128::MIN,
+            i128::MIN + 1,
+        ]);
+        for number in numbers {
+            let words = to_words(number);
+            assert_eq!(from_words(&words), Ok(number), "{}", words);
+        }
+    }
+    #[test]
+    fn test_words() {
+        assert_eq!(to_words(7), "seven");
+        assert_eq!(to_words(15), "fifteen");
+        assert_eq!(to_words(90), "ninety");
+        assert_eq!(to_words(101), "one hundred one");
+        assert_eq!(to_words(1_010_000), "one million ten thousand");
+        assert!(to_words(i128::MAX)
+            .starts_with("one hundred seventy undecillion one hundred forty-one decillion"));
+    }
+    #[test]
+    fn test_from_words_errors() {
+        for text in [
+            "",
+            "minus",
+            "zero zero",
+            "one zero",
+            "three twenty",
+            "twenty twenty",
+            "eleven five",
+            "hundred",
+            "twelve hundred",
+            "thousand",
+            "one thousand one million",
+            "one million million",
+            "nine hundred undecillion",
+            "one hundred seventy-two undecillion",
+            "fourty",
+        ] {
+            assert!(from_words(text).is_err(), "{:?}", text);
+        }
+        assert_eq!(from_words("Nine Hundred AND Ninety-Nine"), Ok(999));
+    }
+}
@@ -0,0 +1,256 @@
+//! The `primality` module provides fast, deterministic primality testing for the whole i128 range. Unlike
+//! `base::is_prime`, which uses trial division and becomes unusable above roughly 10^12, `is_prime` here runs in
+//! polylogarithmic time:
+//!
+//! * numbers below 2^64 are checked with Miller-Rabin using a witness set proven to be deterministic for that range,
+//! * larger numbers are checked with the Baillie-PSW test (a strong base-2 Miller-Rabin test followed by a strong Lucas
+//!   test with Selfridge parameters), for which no counterexample is known.
+//!
+//! All modular multiplications go through the Montgomery arithmetic of the `modular` module with 256-bit intermediate
+//! products, so nothing overflows even for moduli close to `i128::MAX`.
+//!
+//! # Examples
+//! ```
+//! use numbers_rus::integers::primality;
+//!
+//! assert!(primality::is_prime(i128::MAX)); // 2^127 - 1 is a Mersenne prime
+//! assert!(!primality::is_prime(3215031751)); // strong pseudoprime to bases 2, 3, 5 and 7
+//! ```
+use crate::integers::modular::Montgomery;
+use crate::integers::quadratic_residues::jacobi;
+
+/// Witnesses for which Miller-Rabin is deterministic for every n < 2^64 (Jim Sinclair, 2011).
+const WITNESSES_64: [i128; 7] = [2, 325, 9375, 28178, 450775, 9780504, 1795265022];
+
+/// Primes used for trial division before running the probabilistic tests.
+const SMALL_PRIMES: [i128; 25] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+
+/// `is_prime` function: Takes an i128 integer `number` as input and returns a boolean indicating whether `number` is a prime
+/// or not. Small factors are removed by trial division, numbers below 2^64 are then tested with a deterministic Miller-Rabin
+/// witness set and larger numbers with the Baillie-PSW test.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::primality;
+///
+/// assert!(primality::is_prime(1_000_000_007));
+/// assert!(primality::is_prime(170141183460469231731687303715884105727));
+/// assert!(!primality::is_prime(1_000_000_007 * 998_244_353));
+/// assert!(!primality::is_prime(-7));
+/// ```
+pub fn is_prime(number: i128) -> bool {
+    if number < 2 {
+        return false;
+    }
+    for &p in SMALL_PRIMES.iter() {
+        if number == p {
+            return true;
+        }
+        if number % p == 0 {
+            return false;
+        }
+    }
+    if number < 101 * 101 {
+        return true;
+    }
+    if number < 1 << 64 {
+        miller_rabin(number, &WITNESSES_64)
+    } else {
+        miller_rabin(number, &[2]) && is_strong_lucas_probable_prime(number)
+    }
+}
+
+/// `miller_rabin` function: Returns whether `number` is a strong probable prime to every base in `witnesses`. Bases that are a
+/// multiple of `number` are skipped. A `false` result proves that `number` is composite; a `true` result is only a proof of
+/// primality when the witness set is known to be deterministic for the size of `number`.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::primality;
+///
+/// assert!(primality::miller_rabin(2047, &[2])); // 2047 = 23 * 89 fools base 2 but not base 3
+/// assert!(!primality::miller_rabin(2047, &[3]));
+/// assert!(!primality::miller_rabin(2047, &[2, 3]));
+/// ```
+pub fn miller_rabin(number: i128, witnesses: &[i128]) -> bool {
+    if number < 2 {
+        return false;
+    }
+    if number % 2 == 0 {
+        return number == 2;
+    }
+    if number == 3 {
+        return true;
+    }
+
+    let n = number as u128;
+    let montgomery = Montgomery::new(n);
+    let one = montgomery.one();
+    let minus_one = montgomery.encode(n - 1);
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+
+    'witness: for &a in witnesses.iter() {
+        let a = a.rem_euclid(number) as u128;
+        if a == 0 {
+            continue;
+        }
+        let mut x = montgomery.pow(montgomery.encode(a), d);
+        if x == one || x == minus_one {
+            continue;
+        }
+        for _ in 1..s {
+            x = montgomery.multiply(x, x);
+            if x == minus_one {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// `is_strong_lucas_probable_prime` function: Returns whether `number` is a strong Lucas probable prime using Selfridge's
+/// parameters: `D` is the first of 5, -7, 9, -11, ... with Jacobi symbol `(D/n) = -1`, `P = 1` and `Q = (1 - D) / 4`.
+/// Together with a base-2 Miller-Rabin test this forms the Baillie-PSW test used by `is_prime`.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::primality;
+///
+/// assert!(primality::is_strong_lucas_probable_prime(1_000_000_007));
+/// assert!(primality::is_strong_lucas_probable_prime(5459)); // smallest strong Lucas pseudoprime
+/// assert!(!primality::is_strong_lucas_probable_prime(5461));
+/// ```
+pub fn is_strong_lucas_probable_prime(number: i128) -> bool {
+    if number < 2 {
+        return false;
+    }
+    if number % 2 == 0 {
+        return number == 2;
+    }
+    let root = number.isqrt();
+    if root * root == number {
+        // No suitable D exists for perfect squares.
+        return false;
+    }
+
+    // Selfridge's method A for choosing D.
+    let mut d: i128 = 5;
+    loop {
+        match jacobi(d, number) {
+            -1 => break,
+            0 if d.abs() != number => return false,
+            _ => {}
+        }
+        d = if d > 0 { -(d + 2) } else { -d + 2 };
+    }
+    let q = (1 - d) / 4;
+
+    let n = number as u128;
+    let montgomery = Montgomery::new(n);
+    let encode = |x: i128| montgomery.encode(x.rem_euclid(number) as u128);
+    let d_m = encode(d);
+    let q_m = encode(q);
+    let zero = 0;
+
+    // Write n + 1 = k * 2^s with k odd, then compute U_k, V_k and Q^k by walking the bits of k.
+    let s = (n + 1).trailing_zeros();
+    let k = (n + 1) >> s;
+    let mut u = montgomery.one();
+    let mut v = montgomery.one(); // V_1 = P = 1
+    let mut q_k = q_m;
+    for i in (0..127 - k.leading_zeros()).rev() {
+        u = montgomery.multiply(u, v);
+        v = montgomery.subtract(montgomery.multiply(v, v), montgomery.add(q_k, q_k));
+        q_k = montgomery.multiply(q_k, q_k);
+        if (k >> i) & 1 == 1 {
+            let next_u = montgomery.half(montgomery.add(u, v));
+            let next_v = montgomery.half(montgomery.add(montgomery.multiply(d_m, u), v));
+            u = next_u;
+            v = next_v;
+            q_k = montgomery.multiply(q_k, q_m);
+        }
+    }
+
+    if u == zero || v == zero {
+        return true;
+    }
+    for _ in 1..s {
+        v = montgomery.subtract(montgomery.multiply(v, v), montgomery.add(q_k, q_k));
+        if v == zero {
+            return true;
+        }
+        q_k = montgomery.multiply(q_k, q_k);
+    }
+    false
+}
+
+#[cfg(test)]
+pub mod test_primality {
+    use super::*;
+    use crate::integers::base;
+
+    #[test]
+    fn test_matches_trial_division() {
+        for n in -10..20_000 {
+            assert_eq!(is_prime(n), base::is_prime(n), "n = {}", n);
+        }
+    }
+    #[test]
+    fn test_strong_pseudoprimes() {
+        // Strong pseudoprimes to several small bases, and Carmichael numbers.
+        for n in [
+            2047,
+            1373653,
+            25326001,
+            3215031751,
+            2152302898747,
+            3474749660383,
+            341550071728321,
+        ] {
+            assert!(!is_prime(n), "n = {}", n);
+        }
+        for n in [
+            561,
+            41041,
+            825265,
+            321197185,
+            5394826801,
+            232250619601,
+            9746347772161,
+        ] {
+            assert!(!is_prime(n), "n = {}", n);
+        }
+        // 3825123056546413051 is a strong pseudoprime to every prime base up to 23.
+        assert!(!is_prime(3825123056546413051));
+    }
+    #[test]
+    fn test_large_primes() {
+        assert!(is_prime((1 << 61) - 1));
+        assert!(is_prime((1 << 89) - 1));
+        assert!(is_prime((1 << 107) - 1));
+        assert!(is_prime(i128::MAX));
+        assert!(is_prime(18446744073709551629)); // smallest prime above 2^64
+        assert!(!is_prime(18446744073709551617)); // 2^64 + 1 = 274177 * 67280421310721
+    }
+    #[test]
+    fn test_large_composites() {
+        let p: i128 = 18446744073709551557; // largest prime below 2^64
+        let q: i128 = 9223372036854775783; // largest prime below 2^63
+        assert!(is_prime(p) && is_prime(q));
+        assert!(!is_prime(p * q));
+        assert!(!is_prime(i128::MAX - 1));
+        assert!(!is_prime(((1 << 61) - 1) * ((1 << 61) - 1)));
+    }
+    #[test]
+    fn test_strong_lucas_pseudoprimes() {
+        for n in [5459, 5777, 10877, 16109, 18971] {
+            assert!(is_strong_lucas_probable_prime(n));
+            assert!(!miller_rabin(n, &[2]));
+            assert!(!is_prime(n));
+        }
+    }
+}
@@ -0,0 +1,417 @@
+//! The `primes` module generates and counts primes. It is built around a segmented Sieve of Eratosthenes, which sieves
+//! one fixed-size window at a time so memory stays bounded no matter how far the range extends:
+//!
+//! * `primes_below` returns every prime below a bound,
+//! * `primes_in_range` lazily yields the primes in an arbitrary `[low, high)` window and `primes` yields every prime,
+//! * `nth_prime` and `prime_pi` locate and count primes, with `prime_pi` using the Meissel-Lehmer method so that it does
+//!   not need to enumerate the primes it counts,
+//! * `next_prime` and `prev_prime` step to neighbouring primes anywhere in the i128 range using `primality::is_prime`.
+//!
+//! # Examples
+//! ```
+//! use numbers_rus::integers::primes;
+//!
+//! assert_eq!(primes::primes_below(20), vec![2, 3, 5, 7, 11, 13, 17, 19]);
+//! assert_eq!(primes::primes().nth(999), Some(7919));
+//! assert_eq!(primes::prime_pi(1_000_000), 78498);
+//! assert_eq!(primes::next_prime(1_000_000), Some(1_000_003));
+//! ```
+use crate::integers::base;
+use crate::integers::primality;
+use std::collections::HashMap;
+
+/// Number of integers sieved per segment.
+const SEGMENT_SIZE: u64 = 1 << 15;
+
+/// Primes whose product is `PHI_PRIMORIAL`, used to answer `phi(x, a)` for small `a` by table lookup.
+const PHI_PRIMES: [u64; 6] = [2, 3, 5, 7, 11, 13];
+const PHI_PRIMORIAL: u64 = 30030;
+
+/// Largest base prime the sieve will generate. Segments above `MAX_BASE_PRIME^2` (about 1.8 * 10^13) are scanned with
+/// `primality::is_prime` instead, which is cheaper than building a base prime table of that size.
+const MAX_BASE_PRIME: u64 = 1 << 22;
+
+/// Below this bound `prime_pi` simply counts the primes with the sieve.
+const PRIME_PI_SIEVE_LIMIT: u64 = 1 << 20;
+
+/// `primes_below` function: Takes a u64 integer `limit` as input and returns every prime strictly below `limit` in increasing
+/// order.
+pub fn primes_below(limit: u64) -> Vec<u64> {
+    primes_in_range(0, limit).collect()
+}
+
+/// `primes_in_range` function: Returns an iterator over the primes `p` with `low <= p < high` in increasing order. The range is
+/// sieved one segment at a time, so memory use stays bounded by the segment size plus the base primes up to `sqrt(high)`.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::primes;
+///
+/// let window: Vec<u64> = primes::primes_in_range(1_000_000_000, 1_000_000_100).collect();
+/// assert_eq!(window, vec![1_000_000_007, 1_000_000_009, 1_000_000_021, 1_000_000_033, 1_000_000_087, 1_000_000_093, 1_000_000_097]);
+/// ```
+pub fn primes_in_range(low: u64, high: u64) -> PrimeIter {
+    PrimeIter {
+        low: low.max(2),
+        high,
+        base_primes: Vec::new(),
+        base_limit: 1,
+        segment: Vec::new(),
+        index: 0,
+    }
+}
+
+/// `primes` function: Returns a lazy iterator over every prime representable as a u64, starting from 2.
+pub fn primes() -> PrimeIter {
+    primes_in_range(2, u64::MAX)
+}
+
+/// Iterator over the primes in a half-open range, produced by a segmented Sieve of Eratosthenes. Created by `primes` and
+/// `primes_in_range`. Segments beyond about 1.8 * 10^13 are scanned with `primality::is_prime` instead of being sieved.
+#[derive(Debug, Clone)]
+pub struct PrimeIter {
+    /// Start of the next segment to sieve.
+    low: u64,
+    /// Exclusive upper bound of the whole range.
+    high: u64,
+    /// Every prime up to `base_limit`, used to cross off composites in each segment.
+    base_primes: Vec<u64>,
+    base_limit: u64,
+    /// Primes found in the current segment and the position of the next one to yield.
+    segment: Vec<u64>,
+    index: usize,
+}
+
+impl PrimeIter {
+    /// Sieves the segment starting at `self.low` and advances `self.low` past it.
+    fn sieve_next_segment(&mut self) {
+        let start = self.low;
+        let end = start.saturating_add(SEGMENT_SIZE).min(self.high);
+        self.index = 0;
+        self.low = end;
+        let root = (end - 1).isqrt();
+        if root > MAX_BASE_PRIME {
+            self.segment = (start..end)
+                .filter(|&n| primality::is_prime(n as i128))
+                .collect();
+            return;
+        }
+        if root > self.base_limit {
+            // Grow the base primes geometrically so that long ranges only rebuild them a few times.
+            self.base_limit = root.saturating_mul(2).min(MAX_BASE_PRIME);
+            self.base_primes = simple_sieve(self.base_limit);
+        }
+
+        let mut is_composite = vec![false; (end - start) as usize];
+        for &p in self.base_primes.iter() {
+            if p > root {
+                break;
+            }
+            let first = (p * p).max(start.div_ceil(p) * p);
+            for multiple in (first..end).step_by(p as usize) {
+                is_composite[(multiple - start) as usize] = true;
+            }
+        }
+        self.segment = is_composite
+            .iter()
+            .enumerate()
+            .filter(|&(_, &composite)| !composite)
+            .map(|(i, _)| start + i as u64)
+            .collect();
+    }
+}
+
+impl Iterator for PrimeIter {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        loop {
+            if let Some(&p) = self.segment.get(self.index) {
+                self.index += 1;
+                return Some(p);
+            }
+            if self.low >= self.high {
+                return None;
+            }
+            self.sieve_next_segment();
+        }
+    }
+}
+
+/// `nth_prime` function: Takes a u64 integer `n` as input and returns the `n`th prime, counting from `nth_prime(1) == 2`.
+/// A close estimate of the answer is located with `prime_pi` and the remaining distance is covered with the sieve. Panics if
+/// `n` is zero.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::primes;
+///
+/// assert_eq!(primes::nth_prime(1), 2);
+/// assert_eq!(primes::nth_prime(10_000), 104729);
+/// assert_eq!(primes::nth_prime(10_000_000), 179424673);
+/// ```
+pub fn nth_prime(n: u64) -> u64 {
+    assert!(n > 0, "Primes are counted from 1");
+    if n < 100_000 {
+        return primes().nth(n as usize - 1).unwrap();
+    }
+
+    // Cipolla's asymptotic expansion of p_n, which is accurate to a fraction of a percent in this range.
+    let x = n as f64;
+    let (ln, ln_ln) = (x.ln(), x.ln().ln());
+    let estimate = (x * (ln + ln_ln - 1.0 + (ln_ln - 2.0) / ln)) as u64;
+    let count = prime_pi(estimate);
+    if count < n {
+        primes_in_range(estimate + 1, u64::MAX)
+            .nth((n - count - 1) as usize)
+            .unwrap()
+    } else {
+        // Walk back from the largest prime <= estimate.
+        let mut p = prev_prime(estimate as i128 + 1).unwrap();
+        for _ in 0..count - n {
+            p = prev_prime(p).unwrap();
+        }
+        p as u64
+    }
+}
+
+/// `prime_pi` function: Takes a u64 integer `x` as input and returns the number of primes less than or equal to `x`. Small
+/// inputs are counted with the sieve; larger ones use Lehmer's formula
+/// `pi(x) = phi(x, a) + (b + a - 2)(b - a + 1) / 2 - sum(pi(x / p_i)) - ...` with `a = pi(x^(1/4))` and `b = pi(x^(1/2))`,
+/// which runs in roughly `O(x^(3/4))` time and `O(x^(1/2))` memory.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::primes;
+///
+/// assert_eq!(primes::prime_pi(1), 0);
+/// assert_eq!(primes::prime_pi(100), 25);
+/// assert_eq!(primes::prime_pi(10_000_000_000), 455052511);
+/// ```
+pub fn prime_pi(x: u64) -> u64 {
+    if x < PRIME_PI_SIEVE_LIMIT {
+        return primes_in_range(2, x + 1).count() as u64;
+    }
+    Lehmer::new(x).pi(x)
+}
+
+/// `next_prime` function: Takes an i128 integer `number` as input and returns the smallest prime strictly greater than
+/// `number`, or `None` if there is no such prime within the i128 range.
+pub fn next_prime(number: i128) -> Option<i128> {
+    if number < 2 {
+        return Some(2);
+    }
+    let mut candidate = if number % 2 == 0 {
+        number.checked_add(1)?
+    } else {
+        number.checked_add(2)?
+    };
+    while !primality::is_prime(candidate) {
+        candidate = candidate.checked_add(2)?;
+    }
+    Some(candidate)
+}
+
+/// `prev_prime` function: Takes an i128 integer `number` as input and returns the largest prime strictly less than `number`,
+/// or `None` if `number` is 2 or less.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::primes;
+///
+/// assert_eq!(primes::prev_prime(100), Some(97));
+/// assert_eq!(primes::prev_prime(3), Some(2));
+/// assert_eq!(primes::prev_prime(2), None);
+/// assert_eq!(primes::prev_prime(i128::MAX), Some(i128::MAX - 24));
+/// ```
+pub fn prev_prime(number: i128) -> Option<i128> {
+    if number <= 2 {
+        return None;
+    }
+    if number == 3 {
+        return Some(2);
+    }
+    let mut candidate = if number % 2 == 0 {
+        number - 1
+    } else {
+        number - 2
+    };
+    while !primality::is_prime(candidate) {
+        candidate -= 2;
+    }
+    Some(candidate)
+}
+
+/// Plain Sieve of Eratosthenes returning every prime up to and including `limit`.
+fn simple_sieve(limit: u64) -> Vec<u64> {
+    let limit = limit as usize;
+    let mut is_composite = vec![false; limit + 1];
+    let mut primes = Vec::new();
+    for i in 2..=limit {
+        if !is_composite[i] {
+            primes.push(i as u64);
+            for j in (i * i..=limit).step_by(i) {
+                is_composite[j] = true;
+            }
+        }
+    }
+    primes
+}
+
+/// State for the Meissel-Lehmer prime counting function: the primes up to `sqrt(x)`, a table of `pi` for small arguments
+/// and the lookup tables used to shortcut `phi(x, a)` for `a <= 6`.
+struct Lehmer {
+    primes: Vec<u64>,
+    /// `pi_table[n]` is `pi(n)` for `n < pi_table.len()`.
+    pi_table: Vec<u32>,
+    /// `phi_table[a][r]` is the number of integers in `1..=r` not divisible by the first `a` primes.
+    phi_table: Vec<Vec<u32>>,
+    cache: HashMap<u64, u64>,
+}
+
+impl Lehmer {
+    fn new(x: u64) -> Self {
+        let table_limit = x.isqrt().max(PRIME_PI_SIEVE_LIMIT) + 1;
+        let primes = simple_sieve(table_limit);
+        let mut pi_table = vec![0u32; table_limit as usize + 1];
+        let mut count = 0;
+        let mut next = primes.iter().peekable();
+        for (n, entry) in pi_table.iter_mut().enumerate() {
+            if next.peek() == Some(&&(n as u64)) {
+                count += 1;
+                next.next();
+            }
+            *entry = count;
+        }
+
+        let mut phi_table = vec![(0..=PHI_PRIMORIAL as u32).collect::<Vec<u32>>()];
+        for &p in PHI_PRIMES.iter() {
+            let previous = phi_table.last().unwrap();
+            let next_row = (0..=PHI_PRIMORIAL as usize)
+                .map(|r| previous[r] - previous[r / p as usize])
+                .collect();
+            phi_table.push(next_row);
+        }
+
+        Self {
+            primes,
+            pi_table,
+            phi_table,
+            cache: HashMap::new(),
+        }
+    }
+
+    fn pi(&mut self, x: u64) -> u64 {
+        if (x as usize) < self.pi_table.len() {
+            return self.pi_table[x as usize] as u64;
+        }
+        if let Some(&count) = self.cache.get(&x) {
+            return count;
+        }
+
+        let a = self.pi(base::iroot(x as i128, 4) as u64);
+        let b = self.pi(x.isqrt());
+        let c = self.pi(base::iroot(x as i128, 3) as u64);
+        let mut sum = self.phi(x, a as usize) + (b + a - 2) * (b - a + 1) / 2;
+        for i in (a + 1)..=b {
+            let w = x / self.primes[i as usize - 1];
+            sum -= self.pi(w);
+            if i <= c {
+                let limit = self.pi(w.isqrt());
+                for j in i..=limit {
+                    sum -= self.pi(w / self.primes[j as usize - 1]) - (j - 1);
+                }
+            }
+        }
+        self.cache.insert(x, sum);
+        sum
+    }
+
+    /// Counts the integers in `1..=x` that are not divisible by any of the first `a` primes.
+    fn phi(&mut self, x: u64, a: usize) -> u64 {
+        if a <= PHI_PRIMES.len() {
+            let table = &self.phi_table[a];
+            let period = table[PHI_PRIMORIAL as usize] as u64;
+            return (x / PHI_PRIMORIAL) * period + table[(x % PHI_PRIMORIAL) as usize] as u64;
+        }
+        let p = self.primes[a - 1];
+        if x <= p {
+            return 1;
+        }
+        if p * p >= x && (x as usize) < self.pi_table.len() {
+            // Only 1 and the primes above p_a survive.
+            return self.pi_table[x as usize] as u64 - a as u64 + 1;
+        }
+        self.phi(x, a - 1) - self.phi(x / p, a - 1)
+    }
+}
+
+#[cfg(test)]
+pub mod test_primes {
+    use super::*;
+
+    #[test]
+    fn test_primes_below() {
+        assert!(primes_below(0).is_empty());
+        assert!(primes_below(2).is_empty());
+        assert_eq!(primes_below(3), vec![2]);
+        assert_eq!(primes_below(100_000).len(), 9592);
+    }
+    #[test]
+    fn test_segments_match_simple_sieve() {
+        let expected: Vec<u64> = simple_sieve(300_000)
+            .into_iter()
+            .filter(|&p| p >= 12_345)
+            .collect();
+        let actual: Vec<u64> = primes_in_range(12_345, 300_001).collect();
+        assert_eq!(actual, expected);
+    }
+    #[test]
+    fn test_range_near_u64_max() {
+        let window: Vec<u64> = primes_in_range(u64::MAX - 100, u64::MAX).collect();
+        assert_eq!(
+            window,
+            vec![
+                18446744073709551521,
+                18446744073709551533,
+                18446744073709551557
+            ]
+        );
+    }
+    #[test]
+    fn test_prime_pi() {
+        let known = [
+            (10, 4),
+            (1_000, 168),
+            (1 << 20, 82025),
+            (10_000_000, 664579),
+            (100_000_000, 5761455),
+            (1_000_000_000, 50847534),
+        ];
+        for (x, count) in known {
+            assert_eq!(prime_pi(x), count, "x = {}", x);
+        }
+        // Counts on both sides of a prime.
+        assert_eq!(prime_pi(1_000_000_007) - prime_pi(1_000_000_006), 1);
+    }
+    #[test]
+    fn test_nth_prime() {
+        assert_eq!(nth_prime(25), 97);
+        assert_eq!(nth_prime(100_000), 1299709);
+        assert_eq!(nth_prime(1_000_000), 15485863);
+        for n in [123_456, 654_321] {
+            assert_eq!(prime_pi(nth_prime(n)), n);
+            assert!(primality::is_prime(nth_prime(n) as i128));
+        }
+    }
+    #[test]
+    fn test_next_and_prev_prime() {
+        assert_eq!(next_prime(-5), Some(2));
+        assert_eq!(next_prime(2), Some(3));
+        assert_eq!(next_prime(13), Some(17));
+        assert_eq!(next_prime(i128::MAX - 18), Some(i128::MAX));
+        assert_eq!(next_prime(i128::MAX), None);
+        assert_eq!(prev_prime(17), Some(13));
+        assert_eq!(prev_prime(1 << 64), Some(18446744073709551557));
+    }
+}
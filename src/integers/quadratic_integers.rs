@@ -0,0 +1,975 @@
+//! The `quadratic_integers` module provides `QuadraticInteger<D>`, the ring of integers of the quadratic field `Q(√D)` for a
+//! squarefree `D` fixed at compile time, in the same way as `ModInt<M>` fixes its modulus. Its elements are written `a + bω`
+//! in the integral basis `1, ω`, where
+//!
+//! * `ω = √D` when `D = 2, 3 (mod 4)`, so that `ω^2 = D`, and
+//! * `ω = (1 + √D)/2` when `D = 1 (mod 4)`, so that `ω^2 = ω + (D - 1)/4`.
+//!
+//! `QuadraticInteger<-1>` is the Gaussian integers of `complex_integers` and `QuadraticInteger<-3>` the Eisenstein integers of
+//! `eisenstein_integers` (in the basis `1, -ω^2` instead of `1, ω`), and both convert to and from those types.
+//!
+//! The ring is norm-Euclidean, so that every division leaves a remainder of smaller absolute norm, for exactly 21 values of
+//! `D`: -11, -7, -3, -2, -1 and 2, 3, 5, 6, 7, 11, 13, 17, 19, 21, 29, 33, 37, 41, 57, 73. For these `div_rem`, `gcd` and
+//! `factorize` always succeed, while for other fields they return `None` whenever no suitable remainder exists, as for
+//! `2` and `1 + √-5` in `Z[√-5]`, which have no greatest common divisor.
+//!
+//! Imaginary fields (`D < 0`) have finitely many units, while the units of a real field are `±ε^k` for a fundamental unit
+//! `ε`, found from the periodic continued fraction of `ω`.
+//!
+//! # Examples
+//! ```
+//! use numbers_rus::integers::quadratic_integers::QuadraticInteger;
+//!
+//! type Golden = QuadraticInteger<5>; // Z[(1 + √5)/2]
+//! let phi = Golden::omega();
+//! assert_eq!(phi * phi, phi + Golden::one());
+//! assert_eq!(Golden::fundamental_unit(), Ok(phi));
+//!
+//! // 11 = (7 + 3√5)/2 (7 - 3√5)/2 splits in Q(√5).
+//! let (_, factors) = Golden::from_integer(11).factorize().unwrap();
+//! assert_eq!(factors.len(), 2);
+//! assert!(factors.iter().all(|(prime, _)| prime.norm().abs() == 11));
+//!
+//! // In Z[√-5], 2 is irreducible but not prime, and 6 = 2 * 3 = (1 + √-5)(1 - √-5).
+//! type Z5 = QuadraticInteger<-5>;
+//! assert!(!Z5::from_integer(2).is_prime());
+//! assert_eq!(Z5::from_integer(2).gcd(&Z5::new(1, 1)), None);
+//! ```
+use crate::error::{ErrorKind, NumbersError};
+use crate::integers::big_integer::BigInt;
+use crate::integers::eisenstein_integers::Eisenstein;
+use crate::integers::{
+    base, complex_integers, factorization, modular, primality, quadratic_residues,
+};
+use crate::rational::continued_fraction::ContinuedFraction;
+use std::fmt;
+use std::ops;
+
+/// The squarefree `D` whose ring of integers is norm-Euclidean.
+const NORM_EUCLIDEAN: [i128; 21] = [
+    -11, -7, -3, -2, -1, 2, 3, 5, 6, 7, 11, 13, 17, 19, 21, 29, 33, 37, 41, 57, 73,
+];
+
+/// How many values of the `ω` coordinate on each side of the exact quotient `div_rem` tries in a real field, where the
+/// quotients with a small remainder can lie far out along the hyperbola `|N| = 1`.
+const REAL_SEARCH_RADIUS: i128 = 16;
+
+/// `QuadraticInteger` is the algebraic integer `a + bω` of `Q(√D)` with i128 coordinates.
+///
+/// # Example
+///
+/// ```
+/// use numbers_rus::integers::quadratic_integers::QuadraticInteger;
+///
+/// type Z2 = QuadraticInteger<2>;
+/// let a = Z2::new(3, 2);
+/// assert_eq!(a.to_string(), "3 + 2√2");
+/// assert_eq!(a.norm(), 1);
+/// assert_eq!(a * a.conjugate(), Z2::one());
+///
+/// type Z7 = QuadraticInteger<-7>;
+/// assert_eq!(Z7::new(1, 1).to_string(), "(3 + 1√-7)/2");
+/// ```
+///
+/// # Methods
+///
+/// * `new` - creates `a + bω` from its coordinates.
+/// * `get` - returns the coordinates `(a, b)`.
+/// * `zero`, `one`, `omega` - return `0`, `1` and `ω`.
+/// * `from_integer` - returns a rational integer as an element of the ring.
+/// * `discriminant` - returns the discriminant of the field, `D` or `4D`.
+/// * `is_norm_euclidean` - returns whether the ring is Euclidean for the absolute value of the norm.
+/// * `add`, `subtract`, `multiply` - the ring operations.
+/// * `conjugate` - returns the Galois conjugate, which replaces `√D` by `-√D`.
+/// * `norm` - returns the product of the value and its conjugate.
+/// * `trace` - returns the sum of the value and its conjugate.
+/// * `units` - returns the roots of unity of the ring.
+/// * `fundamental_unit` - returns the fundamental unit of a real field.
+/// * `is_unit` - returns whether the value is a unit.
+/// * `divide` - divides exactly, returning `None` when the quotient is not in the ring.
+/// * `div_rem` - returns a quotient and a remainder of smaller absolute norm, if one is found.
+/// * `is_divisible_by` - returns whether another element divides this one.
+/// * `gcd` - returns the normalized greatest common divisor, if the Euclidean algorithm succeeds.
+/// * `extended_gcd` - returns the normalized greatest common divisor with its Bezout coefficients.
+/// * `normalize` - returns a canonical associate.
+/// * `is_prime` - returns whether the value generates a prime ideal.
+/// * `factorize` - returns the factorization into a unit and powers of normalized primes, for norm-Euclidean rings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct QuadraticInteger<const D: i128> {
+    a: i128,
+    b: i128,
+}
+
+impl<const D: i128> fmt::Display for QuadraticInteger<D> {
+    /// Writes the value in terms of `√D`, as `(p + q√D)/2` when it has half-integer coordinates.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (p, q) = self.halved_parts();
+        let sign = if q < 0 { '-' } else { '+' };
+        if p % 2 == 0 && q % 2 == 0 {
+            write!(f, "{} {} {}√{}", p / 2, sign, (q / 2).abs(), D)
+        } else {
+            write!(f, "({} {} {}√{})/2", p, sign, q.abs(), D)
+        }
+    }
+}
+
+impl<const D: i128> From<i128> for QuadraticInteger<D> {
+    fn from(value: i128) -> Self {
+        Self::from_integer(value)
+    }
+}
+
+impl From<complex_integers::Complex> for QuadraticInteger<-1> {
+    fn from(value: complex_integers::Complex) -> Self {
+        Self::new(value.get_real(), value.get_imag())
+    }
+}
+
+impl From<QuadraticInteger<-1>> for complex_integers::Complex {
+    fn from(value: QuadraticInteger<-1>) -> Self {
+        complex_integers::Complex::new(value.a, value.b)
+    }
+}
+
+impl From<Eisenstein> for QuadraticInteger<-3> {
+    /// The Eisenstein `ω = (-1 + √-3)/2` is `(1 + √-3)/2 - 1`.
+    fn from(value: Eisenstein) -> Self {
+        let (a, b) = value.get();
+        Self::new(a - b, b)
+    }
+}
+
+impl From<QuadraticInteger<-3>> for Eisenstein {
+    fn from(value: QuadraticInteger<-3>) -> Self {
+        Eisenstein::new(value.a + value.b, value.b)
+    }
+}
+
+impl<const D: i128> ops::Add for QuadraticInteger<D> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        QuadraticInteger::add(&self, &other)
+    }
+}
+
+impl<const D: i128> ops::Sub for QuadraticInteger<D> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.subtract(&other)
+    }
+}
+
+impl<const D: i128> ops::Mul for QuadraticInteger<D> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.multiply(&other)
+    }
+}
+
+impl<const D: i128> ops::Neg for QuadraticInteger<D> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.a, -self.b)
+    }
+}
+
+impl<const D: i128> QuadraticInteger<D> {
+    /// Fails to compile, once used, for a `D` that is zero or a perfect square, where `Q(√D)` is not a quadratic field.
+    const VALID: () = assert!(
+        D != 0 && (D < 0 || D.isqrt() * D.isqrt() != D),
+        "D must not be zero or a perfect square"
+    );
+
+    /// `(D - 1)/4`, the constant term of `ω^2 = ω + m` when `ω = (1 + √D)/2`.
+    const M: i128 = (D - 1).div_euclid(4);
+
+    pub fn new(a: i128, b: i128) -> Self {
+        let () = Self::VALID;
+        Self { a, b }
+    }
+
+    pub fn get(&self) -> (i128, i128) {
+        (self.a, self.b)
+    }
+
+    pub fn zero() -> Self {
+        Self::new(0, 0)
+    }
+
+    pub fn one() -> Self {
+        Self::new(1, 0)
+    }
+
+    /// The omega function returns the second basis element `ω`, which is `√D` or `(1 + √D)/2`.
+    pub fn omega() -> Self {
+        Self::new(0, 1)
+    }
+
+    pub fn from_integer(value: i128) -> Self {
+        Self::new(value, 0)
+    }
+
+    /// Returns whether `ω = (1 + √D)/2`, that is whether `D = 1 (mod 4)`.
+    const fn has_half_basis() -> bool {
+        D.rem_euclid(4) == 1
+    }
+
+    /// The discriminant function returns the discriminant of `Q(√D)`: `D` when `D = 1 (mod 4)` and `4D` otherwise. A rational
+    /// prime splits, ramifies or stays prime in the ring as the Kronecker symbol of the discriminant is 1, 0 or -1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::quadratic_integers::QuadraticInteger;
+    ///
+    /// assert_eq!(QuadraticInteger::<-7>::discriminant(), -7);
+    /// assert_eq!(QuadraticInteger::<3>::discriminant(), 12);
+    /// ```
+    pub fn discriminant() -> i128 {
+        if Self::has_half_basis() {
+            D
+        } else {
+            4 * D
+        }
+    }
+
+    /// The is_norm_euclidean function returns whether the ring is Euclidean for the absolute value of the norm, which holds
+    /// for exactly 21 squarefree values of `D`.
+    pub fn is_norm_euclidean() -> bool {
+        NORM_EUCLIDEAN.contains(&D)
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        Self::new(self.a + other.a, self.b + other.b)
+    }
+
+    pub fn subtract(&self, other: &Self) -> Self {
+        Self::new(self.a - other.a, self.b - other.b)
+    }
+
+    /// multiply function multiplies two elements, using `ω^2 = D` or `ω^2 = ω + (D - 1)/4`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::quadratic_integers::QuadraticInteger;
+    ///
+    /// let a = QuadraticInteger::<-5>::new(1, 1);
+    /// let b = QuadraticInteger::<-5>::new(1, -1);
+    /// assert_eq!(a.multiply(&b), QuadraticInteger::from_integer(6));
+    /// ```
+    pub fn multiply(&self, other: &Self) -> Self {
+        let shared = self.b * other.b;
+        let cross = self.a * other.b + self.b * other.a;
+        if Self::has_half_basis() {
+            Self::new(self.a * other.a + Self::M * shared, cross + shared)
+        } else {
+            Self::new(self.a * other.a + D * shared, cross)
+        }
+    }
+
+    /// The conjugate function returns the Galois conjugate, which replaces `√D` by `-√D`. For an imaginary field this is the
+    /// complex conjugate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::quadratic_integers::QuadraticInteger;
+    ///
+    /// assert_eq!(QuadraticInteger::<3>::new(2, 1).conjugate(), QuadraticInteger::new(2, -1));
+    /// // The conjugate of (1 + √5)/2 is (1 - √5)/2 = 1 - ω.
+    /// assert_eq!(QuadraticInteger::<5>::omega().conjugate(), QuadraticInteger::new(1, -1));
+    /// ```
+    pub fn conjugate(&self) -> Self {
+        if Self::has_half_basis() {
+            Self::new(self.a + self.b, -self.b)
+        } else {
+            Self::new(self.a, -self.b)
+        }
+    }
+
+    /// The norm function returns the product of the value and its conjugate, `a^2 - Db^2` or `a^2 + ab - (D - 1)/4 b^2`. It is
+    /// multiplicative, positive for non-zero values of an imaginary field, and `±1` exactly for the units.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::quadratic_integers::QuadraticInteger;
+    ///
+    /// assert_eq!(QuadraticInteger::<2>::new(1, 1).norm(), -1);
+    /// assert_eq!(QuadraticInteger::<-7>::new(1, 1).norm(), 4);
+    /// ```
+    pub fn norm(&self) -> i128 {
+        if Self::has_half_basis() {
+            self.a * self.a + self.a * self.b - Self::M * self.b * self.b
+        } else {
+            self.a * self.a - D * self.b * self.b
+        }
+    }
+
+    /// The norm computed exactly as a `BigInt`, for values such as large units whose norm does not fit in an i128.
+    fn big_norm(&self) -> BigInt {
+        let (a, b) = (BigInt::from(self.a), BigInt::from(self.b));
+        let (cross, factor) = if Self::has_half_basis() {
+            (&a * &b, Self::M)
+        } else {
+            (BigInt::from(0), D)
+        };
+        &(&(&a * &a) + &cross) - &(&(&b * &b) * &BigInt::from(factor))
+    }
+
+    /// The trace function returns the sum of the value and its conjugate, `2a` or `2a + b`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::quadratic_integers::QuadraticInteger;
+    ///
+    /// assert_eq!(QuadraticInteger::<5>::omega().trace(), 1);
+    /// ```
+    pub fn trace(&self) -> i128 {
+        if Self::has_half_basis() {
+            2 * self.a + self.b
+        } else {
+            2 * self.a
+        }
+    }
+
+    /// Returns `(p, q)` with `self = (p + q√D)/2`.
+    fn halved_parts(&self) -> (i128, i128) {
+        if Self::has_half_basis() {
+            (2 * self.a + self.b, self.b)
+        } else {
+            (2 * self.a, 2 * self.b)
+        }
+    }
+
+    /// The units function returns the roots of unity of the ring: the fourth roots for `D = -1`, the sixth roots for
+    /// `D = -3`, and `±1` otherwise. These are all the units of an imaginary field, while the units of a real field are the
+    /// products of `±1` with the powers of `fundamental_unit`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::quadratic_integers::QuadraticInteger;
+    ///
+    /// assert_eq!(QuadraticInteger::<-3>::units().len(), 6);
+    /// assert_eq!(QuadraticInteger::<7>::units().len(), 2);
+    /// ```
+    pub fn units() -> Vec<Self> {
+        match D {
+            -1 => vec![
+                Self::new(1, 0),
+                Self::new(0, 1),
+                Self::new(-1, 0),
+                Self::new(0, -1),
+            ],
+            -3 => vec![
+                Self::new(1, 0),
+                Self::new(0, 1),
+                Self::new(-1, 1),
+                Self::new(-1, 0),
+                Self::new(0, -1),
+                Self::new(1, -1),
+            ],
+            _ => vec![Self::new(1, 0), Self::new(-1, 0)],
+        }
+    }
+
+    /// The fundamental_unit function returns the smallest unit `ε > 1` of a real field, which generates all units up to sign.
+    /// It is read off the first convergent `p/q` of the continued fraction of `ω` with `N(p - qω) = ±1`, since then `p - qω`
+    /// is a small unit and its conjugate a large one.
+    ///
+    /// Returns an error of kind `NotRepresentable` for an imaginary field, which has no unit of infinite order, and of kind
+    /// `Overflow` when the unit does not fit in i128 coordinates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::error::ErrorKind;
+    /// use numbers_rus::integers::quadratic_integers::QuadraticInteger;
+    ///
+    /// assert_eq!(QuadraticInteger::<7>::fundamental_unit(), Ok(QuadraticInteger::new(8, 3)));
+    /// // (39 + 5√61)/2
+    /// assert_eq!(QuadraticInteger::<61>::fundamental_unit(), Ok(QuadraticInteger::new(17, 5)));
+    /// let unit = QuadraticInteger::<94>::fundamental_unit().unwrap();
+    /// assert_eq!(unit.to_string(), "2143295 + 221064√94");
+    /// assert_eq!(QuadraticInteger::<-2>::fundamental_unit().unwrap_err().kind(), ErrorKind::NotRepresentable);
+    /// ```
+    pub fn fundamental_unit() -> Result<Self, NumbersError> {
+        if D < 0 {
+            return Err(NumbersError::new(
+                "fundamental_unit",
+                ErrorKind::NotRepresentable,
+            ));
+        }
+        let overflow = || NumbersError::new("fundamental_unit", ErrorKind::Overflow);
+        let (p, q) = if Self::has_half_basis() {
+            (1, 2)
+        } else {
+            (0, 1)
+        };
+        let expansion = ContinuedFraction::from_quadratic_surd(p, D, q).map_err(|_| overflow())?;
+        for convergent in expansion.convergents::<i128>() {
+            let small = Self::new(convergent.get_numerator(), -convergent.get_denominator());
+            if small.big_norm().abs() == BigInt::from(1) {
+                return Ok(small.conjugate());
+            }
+        }
+        Err(overflow())
+    }
+
+    /// The is_unit function returns whether the value is invertible, that is whether its norm is `±1`.
+    pub fn is_unit(&self) -> bool {
+        self.big_norm().abs() == BigInt::from(1)
+    }
+
+    /// The divide function divides two elements exactly and returns the quotient, or `None` if `other` is zero or the
+    /// quotient is not in the ring.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::quadratic_integers::QuadraticInteger;
+    ///
+    /// type Z5 = QuadraticInteger<-5>;
+    /// assert_eq!(Z5::from_integer(6).divide(&Z5::new(1, 1)), Some(Z5::new(1, -1)));
+    /// assert_eq!(Z5::from_integer(6).divide(&Z5::from_integer(4)), None);
+    /// ```
+    pub fn divide(&self, other: &Self) -> Option<Self> {
+        if *other == Self::zero() {
+            return None;
+        }
+        let norm = other.norm();
+        let (a, b) = self.multiply(&other.conjugate()).get();
+        if a % norm == 0 && b % norm == 0 {
+            Some(Self::new(a / norm, b / norm))
+        } else {
+            None
+        }
+    }
+
+    /// The div_rem function returns `(quotient, remainder)` with `self = quotient * other + remainder` and
+    /// `|N(remainder)| < |N(other)|`, choosing the smallest remainder among the quotients near the exact one, or `None` if
+    /// `other` is zero or none of them gives a smaller remainder. For an imaginary field the candidates are the lattice
+    /// points around the exact quotient; for a real field they also follow the hyperbola `|N| = 1` through it, which is
+    /// where the good quotients of the harder fields such as `D = 73` lie. It always succeeds when `is_norm_euclidean`
+    /// holds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::quadratic_integers::QuadraticInteger;
+    ///
+    /// type Z73 = QuadraticInteger<73>;
+    /// let a = Z73::new(1234, -567);
+    /// let b = Z73::new(31, 9);
+    /// let (quotient, remainder) = a.div_rem(&b).unwrap();
+    /// assert_eq!(quotient * b + remainder, a);
+    /// assert!(remainder.norm().abs() < b.norm().abs());
+    /// ```
+    pub fn div_rem(&self, other: &Self) -> Option<(Self, Self)> {
+        if *other == Self::zero() {
+            return None;
+        }
+        // The exact quotient is (p + qω)/norm with a positive norm.
+        let (mut p, mut q) = self.multiply(&other.conjugate()).get();
+        let mut norm = other.norm();
+        if norm < 0 {
+            (p, q, norm) = (-p, -q, -norm);
+        }
+        let (a, b) = (p.div_euclid(norm), q.div_euclid(norm));
+        let s = p.rem_euclid(norm) as f64 / norm as f64;
+        let t = q.rem_euclid(norm) as f64 / norm as f64;
+        let root = (D.unsigned_abs() as f64).sqrt();
+        let radius = if D < 0 { 1 } else { REAL_SEARCH_RADIUS };
+        let mut best: Option<(i128, Self, Self)> = None;
+        for step in -radius..=radius + 1 {
+            let t = t - step as f64;
+            // |N(s + tω)| is smallest for s near the centre, or for a real field near the two asymptotes of |N| = 0.
+            let (centre, spread) = if Self::has_half_basis() {
+                (-t / 2.0, root * t / 2.0)
+            } else {
+                (0.0, root * t)
+            };
+            let targets = [centre, centre + spread, centre - spread];
+            let count = if D < 0 { 1 } else { 3 };
+            for target in &targets[..count] {
+                let floor = (s - target).floor() as i128;
+                for offset in [floor, floor + 1] {
+                    let quotient = Self::new(a + offset, b + step);
+                    let remainder = self.subtract(&quotient.multiply(other));
+                    let size = remainder.norm().abs();
+                    if best
+                        .as_ref()
+                        .is_none_or(|(smallest, _, _)| size < *smallest)
+                    {
+                        best = Some((size, quotient, remainder));
+                    }
+                }
+            }
+        }
+        best.filter(|(size, _, _)| *size < norm)
+            .map(|(_, quotient, remainder)| (quotient, remainder))
+    }
+
+    /// The is_divisible_by function returns whether `self = q * other` for some `q` in the ring. Zero is only divisible by
+    /// itself among the divisors of zero norm.
+    pub fn is_divisible_by(&self, other: &Self) -> bool {
+        if *other == Self::zero() {
+            *self == Self::zero()
+        } else {
+            self.divide(other).is_some()
+        }
+    }
+
+    /// The gcd function returns the normalized greatest common divisor of two elements by the Euclidean algorithm, or `None`
+    /// if some division step finds no smaller remainder, which can only happen outside the norm-Euclidean fields.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::quadratic_integers::QuadraticInteger;
+    ///
+    /// type Z2 = QuadraticInteger<2>;
+    /// let g = Z2::new(3, 1); // norm 7
+    /// let a = g * Z2::new(5, -2);
+    /// let b = g * Z2::new(1, 4);
+    /// assert_eq!(a.gcd(&b), Some(g.normalize()));
+    /// ```
+    pub fn gcd(&self, other: &Self) -> Option<Self> {
+        let (mut a, mut b) = (*self, *other);
+        while b != Self::zero() {
+            (a, b) = (b, a.div_rem(&b)?.1);
+        }
+        Some(a.normalize())
+    }
+
+    /// The extended_gcd function returns `(g, x, y)` where `g` is the normalized greatest common divisor of `self` and
+    /// `other` and `self * x + other * y == g`, or `None` when `gcd` would.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::quadratic_integers::QuadraticInteger;
+    ///
+    /// type Z11 = QuadraticInteger<-11>;
+    /// let a = Z11::new(7, 3);
+    /// let b = Z11::new(-2, 5);
+    /// let (g, x, y) = a.extended_gcd(&b).unwrap();
+    /// assert_eq!(a * x + b * y, g);
+    /// ```
+    pub fn extended_gcd(&self, other: &Self) -> Option<(Self, Self, Self)> {
+        let (mut old_r, mut r) = (*self, *other);
+        let (mut old_s, mut s) = (Self::one(), Self::zero());
+        let (mut old_t, mut t) = (Self::zero(), Self::one());
+        while r != Self::zero() {
+            let quotient = old_r.div_rem(&r)?.0;
+            (old_r, r) = (r, old_r - quotient * r);
+            (old_s, s) = (s, old_s - quotient * s);
+            (old_t, t) = (t, old_t - quotient * t);
+        }
+        let g = old_r.normalize();
+        let unit = g.divide(&old_r).unwrap_or_else(Self::one);
+        Some((g, old_s * unit, old_t * unit))
+    }
+
+    /// The normalize function returns a canonical associate, the same for all products of the value with a unit, and
+    /// leaves zero unchanged.
+    ///
+    /// * For `D = -1` and `D = -3` it is the associate with `a > 0` and `b >= 0`, which has an argument in `[0, π/2)` or
+    ///   `[0, π/3)`.
+    /// * For the other imaginary fields it is the associate with `b > 0`, or `b = 0` and `a > 0`.
+    /// * For a real field it is the associate whose two embeddings are closest in size, within a factor `ε` of each other
+    ///   for the fundamental unit `ε`, and whose first embedding `(p + q√D)/2` is positive. Ties go to the associate with
+    ///   the larger first embedding. If the fundamental unit does not fit in i128 only the sign is normalized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::quadratic_integers::QuadraticInteger;
+    ///
+    /// type Z2 = QuadraticInteger<2>;
+    /// let unit = Z2::new(1, 1);
+    /// let a = Z2::new(5, 1);
+    /// assert_eq!((a * unit * unit * unit).normalize(), a.normalize());
+    /// assert_eq!((-a).normalize(), a.normalize());
+    /// assert_eq!(unit.normalize(), Z2::one());
+    /// ```
+    pub fn normalize(&self) -> Self {
+        if *self == Self::zero() {
+            return *self;
+        }
+        if D < 0 {
+            let units = Self::units();
+            let in_sector = |value: &Self| {
+                if units.len() > 2 {
+                    value.a > 0 && value.b >= 0
+                } else {
+                    value.b > 0 || (value.b == 0 && value.a > 0)
+                }
+            };
+            return units
+                .iter()
+                .map(|unit| self.multiply(unit))
+                .find(in_sector)
+                .expect("exactly one associate lies in the sector");
+        }
+        let balanced = match Self::fundamental_unit() {
+            Ok(unit) => self.balance(&unit),
+            Err(_) => *self,
+        };
+        if balanced.surd_sign() < 0 {
+            -balanced
+        } else {
+            balanced
+        }
+    }
+
+    /// Multiplies a non-zero value of a real field by the power of the fundamental unit `unit` that brings the logarithm of
+    /// the ratio of its embeddings into `(-ln ε, ln ε]`, where multiplying by `ε` adds `2 ln ε`.
+    fn balance(&self, unit: &Self) -> Self {
+        // The unit has norm ±1, so its inverse is ± its conjugate and its log ratio is twice its log.
+        let sign = if unit.big_norm() == BigInt::from(1) {
+            1
+        } else {
+            -1
+        };
+        let inverse = unit.conjugate() * Self::from_integer(sign);
+        let width = 2.0 * unit.log_larger_embedding();
+        let steps = (-self.log_ratio() / width).round() as i128;
+        let power =
+            |base: &Self, exponent: i128| (0..exponent).fold(Self::one(), |total, _| total * *base);
+        let centre = if steps >= 0 {
+            *self * power(unit, steps)
+        } else {
+            *self * power(&inverse, -steps)
+        };
+        let tolerance = width * (0.5 + 1e-9);
+        let candidates: Vec<Self> = [centre * inverse, centre, centre * *unit]
+            .into_iter()
+            .filter(|candidate| candidate.log_ratio().abs() <= tolerance)
+            .collect();
+        // Of two candidates on the boundary, keep the one whose first embedding is the larger.
+        candidates
+            .iter()
+            .copied()
+            .find(|candidate| candidates.len() == 1 || candidate.embedding_order() > 0)
+            .unwrap_or(centre)
+    }
+
+    /// Returns `ln |σ1 / σ2|` for the embeddings `σ1, σ2 = (p ± q√D)/2` of a non-zero value of a real field, computed from
+    /// the larger embedding and the norm so that it does not suffer from cancellation.
+    fn log_ratio(&self) -> f64 {
+        let size = 2.0 * self.log_larger_embedding() - (self.norm().unsigned_abs() as f64).ln();
+        size * self.embedding_order() as f64
+    }
+
+    /// Returns `ln max(|σ1|, |σ2|) = ln (|p| + |q|√D)/2` for a value of a real field.
+    fn log_larger_embedding(&self) -> f64 {
+        let (p, q) = self.halved_parts();
+        ((p.unsigned_abs() as f64 + q.unsigned_abs() as f64 * (D as f64).sqrt()) / 2.0).ln()
+    }
+
+    /// Returns the sign of `|σ1| - |σ2|` for a real field, which is the sign of `pq` since `σ1^2 - σ2^2 = pq√D`.
+    fn embedding_order(&self) -> i128 {
+        let (p, q) = self.halved_parts();
+        p.signum() * q.signum()
+    }
+
+    /// Returns the sign of the first embedding `(p + q√D)/2` of a value of a real field.
+    fn surd_sign(&self) -> i128 {
+        let (p, q) = self.halved_parts();
+        if p.signum() == q.signum() || q == 0 {
+            p.signum()
+        } else if p == 0 {
+            q.signum()
+        } else {
+            p.signum() * (p * p - D * q * q).signum()
+        }
+    }
+
+    /// Returns a root of the minimal polynomial of `ω` modulo the prime `p`, `x^2 - D` or `x^2 - x - (D - 1)/4`, or `None`
+    /// if `p` stays prime in the ring.
+    fn root_modulo(p: i128) -> Option<i128> {
+        if !Self::has_half_basis() {
+            return quadratic_residues::sqrt_mod_prime(D.rem_euclid(p), p);
+        }
+        if p == 2 {
+            return (0..2).find(|t| (t * t - t - Self::M).rem_euclid(2) == 0);
+        }
+        let root = quadratic_residues::sqrt_mod_prime(D.rem_euclid(p), p)?;
+        Some(modular::mod_mul(root + 1, (p + 1) / 2, p))
+    }
+
+    /// The is_prime function returns whether the value generates a prime ideal: either its norm is `±p` for a rational
+    /// prime `p`, or it is an associate of a rational prime `p` that stays prime in the ring. Outside the norm-Euclidean
+    /// fields an element can be irreducible without being prime, like `2` in `Z[√-5]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::quadratic_integers::QuadraticInteger;
+    ///
+    /// type Z2 = QuadraticInteger<2>;
+    /// assert!(Z2::new(3, 1).is_prime());
+    /// assert!(Z2::from_integer(5).is_prime());
+    /// assert!(!Z2::from_integer(7).is_prime());
+    /// ```
+    pub fn is_prime(&self) -> bool {
+        let norm = self.norm().abs();
+        if primality::is_prime(norm) {
+            return true;
+        }
+        let p = base::isqrt(norm);
+        p * p == norm
+            && primality::is_prime(p)
+            && Self::root_modulo(p).is_none()
+            && self.is_divisible_by(&Self::from_integer(p))
+    }
+
+    /// The factorize function returns `(unit, factors)` where `factors` lists each normalized prime dividing the value with
+    /// its exponent, in increasing order of absolute norm, and `unit` times the product of the prime powers is the value.
+    /// The primes above each rational prime `p` dividing the norm are `p` itself when `p` stays prime, and otherwise the
+    /// gcd of `p` and `ω - t` for a root `t` of the minimal polynomial of `ω` modulo `p`, with its conjugate. Returns `None`
+    /// unless `is_norm_euclidean` holds, and panics if the value is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::integers::quadratic_integers::QuadraticInteger;
+    /// use numbers_rus::traits::Ring;
+    ///
+    /// type Z2 = QuadraticInteger<2>;
+    /// let value = Z2::new(17, -12);
+    /// let (unit, factors) = value.factorize().unwrap();
+    /// let product = factors.iter().fold(unit, |total, (prime, exponent)| total * Ring::pow(prime, *exponent));
+    /// assert_eq!(product, value);
+    /// assert!(QuadraticInteger::<-5>::from_integer(6).factorize().is_none());
+    /// ```
+    pub fn factorize(&self) -> Option<(Self, Vec<(Self, u32)>)> {
+        assert!(*self != Self::zero(), "cannot factorize zero");
+        if !Self::is_norm_euclidean() {
+            return None;
+        }
+        let mut rest = *self;
+        let mut factors = Vec::new();
+        for (p, _) in factorization::factorize(self.norm()) {
+            let primes = match Self::root_modulo(p) {
+                None => vec![Self::from_integer(p)],
+                Some(t) => {
+                    let prime = Self::from_integer(p).gcd(&Self::new(-t, 1))?;
+                    vec![prime, prime.conjugate().normalize()]
+                }
+            };
+            for prime in primes {
+                let mut exponent = 0;
+                while let Some(quotient) = rest.divide(&prime) {
+                    rest = quotient;
+                    exponent += 1;
+                }
+                if exponent > 0 {
+                    factors.push((prime, exponent));
+                }
+            }
+        }
+        factors.sort_by_key(|(prime, _)| prime.norm().abs());
+        Some((rest, factors))
+    }
+}
+
+#[cfg(test)]
+pub mod test_quadratic {
+    use super::*;
+    use crate::traits::Ring;
+
+    /// Checks the ring identities, Euclidean division, gcds and factorizations of a norm-Euclidean ring.
+    fn check_norm_euclidean<const D: i128>() {
+        type Q<const D: i128> = QuadraticInteger<D>;
+        assert!(Q::<D>::is_norm_euclidean());
+        let values: Vec<Q<D>> = (-6..=6)
+            .flat_map(|a| (-6..=6).map(move |b| Q::<D>::new(7 * a + 3, 5 * b - 2)))
+            .collect();
+        for (index, x) in values.iter().enumerate() {
+            let y = values[(index * 37 + 11) % values.len()];
+            assert_eq!((*x * y).norm(), x.norm() * y.norm());
+            assert_eq!(*x + x.conjugate(), Q::<D>::from_integer(x.trace()));
+            assert_eq!(*x * x.conjugate(), Q::<D>::from_integer(x.norm()));
+            let divisor = Q::<D>::new(x.get().1 / 3 + 1, y.get().0 / 4);
+            if divisor == Q::<D>::zero() {
+                continue;
+            }
+            let (quotient, remainder) = x.div_rem(&divisor).unwrap();
+            assert_eq!(quotient * divisor + remainder, *x);
+            assert!(remainder.norm().abs() < divisor.norm().abs());
+            assert_eq!((*x * divisor).divide(&divisor), Some(*x));
+            let (g, s, t) = x.extended_gcd(&y).unwrap();
+            assert_eq!(Some(g), x.gcd(&y));
+            assert_eq!(*x * s + y * t, g);
+            assert!(x.is_divisible_by(&g) && y.is_divisible_by(&g));
+            assert_eq!(g.normalize(), g);
+        }
+        for x in values.iter().filter(|x| x.norm() != 0).step_by(7) {
+            let (unit, factors) = x.factorize().unwrap();
+            assert!(unit.is_unit());
+            let mut product = unit;
+            for (prime, exponent) in &factors {
+                assert!(prime.is_prime(), "{} in Q(√{})", prime, D);
+                assert_eq!(prime.normalize(), *prime);
+                product = product * Ring::pow(prime, *exponent);
+            }
+            assert_eq!(product, *x);
+        }
+    }
+
+    #[test]
+    fn test_norm_euclidean_fields() {
+        check_norm_euclidean::<-11>();
+        check_norm_euclidean::<-7>();
+        check_norm_euclidean::<-3>();
+        check_norm_euclidean::<-2>();
+        check_norm_euclidean::<-1>();
+        check_norm_euclidean::<2>();
+        check_norm_euclidean::<3>();
+        check_norm_euclidean::<5>();
+        check_norm_euclidean::<6>();
+        check_norm_euclidean::<7>();
+        check_norm_euclidean::<11>();
+        check_norm_euclidean::<13>();
+        check_norm_euclidean::<17>();
+        check_norm_euclidean::<19>();
+        check_norm_euclidean::<21>();
+        check_norm_euclidean::<29>();
+        check_norm_euclidean::<33>();
+        check_norm_euclidean::<37>();
+        check_norm_euclidean::<41>();
+        check_norm_euclidean::<57>();
+        check_norm_euclidean::<73>();
+    }
+    #[test]
+    fn test_hard_euclidean_divisions() {
+        // Quotients whose exact value sits where the nearest lattice points all leave too large a remainder.
+        type Z73 = QuadraticInteger<73>;
+        type Z19 = QuadraticInteger<19>;
+        for numerator in 0..200 {
+            for denominator in [Z73::new(37, 11), Z73::new(-5, 3), Z73::new(250, -61)] {
+                let x = Z73::new(numerator * 13 - 900, numerator * 7 + 5);
+                let (quotient, remainder) = x.div_rem(&denominator).unwrap();
+                assert_eq!(quotient * denominator + remainder, x);
+                assert!(remainder.norm().abs() < denominator.norm().abs());
+            }
+            let x = Z19::new(numerator * 11 - 700, 3 - numerator * 5);
+            let denominator = Z19::new(13, 3);
+            let (quotient, remainder) = x.div_rem(&denominator).unwrap();
+            assert_eq!(quotient * denominator + remainder, x);
+            assert!(remainder.norm().abs() < denominator.norm().abs());
+        }
+    }
+    #[test]
+    fn test_units() {
+        assert_eq!(
+            QuadraticInteger::<2>::fundamental_unit(),
+            Ok(QuadraticInteger::new(1, 1))
+        );
+        assert_eq!(
+            QuadraticInteger::<3>::fundamental_unit(),
+            Ok(QuadraticInteger::new(2, 1))
+        );
+        assert_eq!(
+            QuadraticInteger::<6>::fundamental_unit(),
+            Ok(QuadraticInteger::new(5, 2))
+        );
+        assert_eq!(
+            QuadraticInteger::<13>::fundamental_unit(),
+            Ok(QuadraticInteger::new(1, 1))
+        );
+        assert_eq!(
+            QuadraticInteger::<21>::fundamental_unit(),
+            Ok(QuadraticInteger::new(2, 1))
+        );
+        assert_eq!(
+            QuadraticInteger::<29>::fundamental_unit(),
+            Ok(QuadraticInteger::new(2, 1))
+        );
+        assert_eq!(
+            QuadraticInteger::<73>::fundamental_unit(),
+            Ok(QuadraticInteger::new(943, 250))
+        );
+        let unit = QuadraticInteger::<991>::fundamental_unit().unwrap();
+        assert!(unit.is_unit());
+        assert_eq!(unit.get().1, 12055735790331359447442538767);
+        assert_eq!(QuadraticInteger::<-1>::units().len(), 4);
+        assert_eq!(QuadraticInteger::<-7>::units().len(), 2);
+        for unit in QuadraticInteger::<-3>::units() {
+            assert!(unit.is_unit());
+            assert_eq!(Ring::pow(&unit, 6), QuadraticInteger::one());
+        }
+        assert!(!QuadraticInteger::<-5>::new(1, 1).is_unit());
+        // The associates of a value of a real field all normalize the same way, including across the boundary case
+        // (1 + √3)/(1 - √3) = -(2 + √3).
+        type Z3 = QuadraticInteger<3>;
+        let unit = Z3::fundamental_unit().unwrap();
+        for value in [
+            Z3::new(1, 1),
+            Z3::new(4, -7),
+            Z3::new(0, 5),
+            Z3::from_integer(-3),
+        ] {
+            let normal = value.normalize();
+            let mut associate = value;
+            for _ in 0..6 {
+                associate = -(associate * unit);
+                assert_eq!(associate.normalize(), normal);
+                assert_eq!(associate.conjugate().conjugate().normalize(), normal);
+            }
+        }
+        assert_eq!(Z3::from_integer(-3).normalize(), Z3::from_integer(3));
+    }
+    #[test]
+    fn test_non_euclidean_fields() {
+        type Z5 = QuadraticInteger<-5>;
+        assert!(!Z5::is_norm_euclidean());
+        let (two, three) = (Z5::from_integer(2), Z5::from_integer(3));
+        let (alpha, beta) = (Z5::new(1, 1), Z5::new(1, -1));
+        assert_eq!(two * three, alpha * beta);
+        for value in [two, three, alpha, beta] {
+            assert!(!value.is_prime());
+        }
+        assert!(Z5::from_integer(11).is_prime());
+        assert_eq!(alpha.div_rem(&two), None);
+        assert_eq!(two.gcd(&alpha), None);
+        assert_eq!(Z5::from_integer(6).factorize(), None);
+        // Q(√-19) is a principal ideal domain without being norm-Euclidean.
+        type Z19 = QuadraticInteger<-19>;
+        assert!(!Z19::is_norm_euclidean());
+        assert!(Z19::new(2, 1).is_prime() == primality::is_prime(Z19::new(2, 1).norm()));
+        assert_eq!(
+            Z19::from_integer(6).gcd(&Z19::from_integer(4)),
+            Some(Z19::from_integer(2))
+        );
+    }
+    #[test]
+    fn test_display_and_conversions() {
+        assert_eq!(QuadraticInteger::<2>::new(3, -2).to_string(), "3 - 2√2");
+        assert_eq!(QuadraticInteger::<5>::new(3, 1).to_string(), "(7 + 1√5)/2");
+        assert_eq!(QuadraticInteger::<-3>::new(-1, 2).to_string(), "0 + 1√-3");
+        let gaussian = complex_integers::Complex::new(3, -4);
+        let converted = QuadraticInteger::<-1>::from(gaussian);
+        assert_eq!(converted.norm(), 25);
+        assert_eq!(
+            complex_integers::Complex::from(converted * converted),
+            gaussian * gaussian
+        );
+        let x = Eisenstein::new(5, 2);
+        let y = Eisenstein::new(-1, 3);
+        let product = QuadraticInteger::<-3>::from(x) * QuadraticInteger::<-3>::from(y);
+        assert_eq!(Eisenstein::from(product), x * y);
+        assert_eq!(QuadraticInteger::<-3>::from(x).norm(), x.norm());
+        assert_eq!(QuadraticInteger::<-7>::discriminant(), -7);
+        assert_eq!(QuadraticInteger::<-1>::discriminant(), -4);
+    }
+}
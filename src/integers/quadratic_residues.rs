@@ -0,0 +1,559 @@
+//! The `quadratic_residues` module decides which numbers are squares modulo `n` and finds their square roots.
+//!
+//! * `legendre`, `jacobi` and `kronecker` compute the residue symbols, each generalizing the previous one to more moduli.
+//! * `tonelli_shanks` and `cipolla` find a square root modulo an odd prime. `sqrt_mod_prime` picks whichever is faster for the
+//!   given prime.
+//! * `sqrt_mod` and `sqrt_mod_all` handle any modulus: roots modulo each prime power of the factorization are lifted with
+//!   Hensel's lemma and then combined with the Chinese Remainder Theorem.
+//!
+//! All arithmetic goes through `modular::mod_mul`, so moduli up to `i128::MAX` are supported.
+//!
+//! # Examples
+//! ```
+//! use numbers_rus::integers::quadratic_residues;
+//!
+//! assert_eq!(quadratic_residues::legendre(2, 7), 1);
+//! assert_eq!(quadratic_residues::sqrt_mod_prime(2, 7), Some(3));
+//! assert_eq!(quadratic_residues::sqrt_mod_all(4, 15), vec![2, 7, 8, 13]);
+//! assert_eq!(quadratic_residues::sqrt_mod(3, 8), None);
+//! ```
+use crate::integers::modular::{self, add_mod, mod_inverse, mod_mul, mod_pow};
+use crate::integers::primality;
+use crate::integers::{base, factorization};
+
+/// `legendre` function: Takes an i128 integer `a` and an odd prime `p` as input and returns the Legendre symbol `(a/p)`: 0 if `p`
+/// divides `a`, 1 if `a` is a non-zero square modulo `p` and -1 otherwise. Panics if `p` is not an odd prime.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::quadratic_residues;
+///
+/// assert_eq!(quadratic_residues::legendre(3, 11), 1); // 5 * 5 = 25 = 3 (mod 11)
+/// assert_eq!(quadratic_residues::legendre(2, 11), -1);
+/// assert_eq!(quadratic_residues::legendre(22, 11), 0);
+/// ```
+pub fn legendre(a: i128, p: i128) -> i32 {
+    assert!(
+        p > 2 && primality::is_prime(p),
+        "Modulus must be an odd prime"
+    );
+    jacobi(a, p)
+}
+
+/// `jacobi` function: Takes an i128 integer `a` and an odd positive i128 integer `n` as input and returns the Jacobi symbol
+/// `(a/n)`, the product of the Legendre symbols over the prime factors of `n`. It is computed with quadratic reciprocity, without
+/// factoring `n`. Panics if `n` is not odd and positive.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::quadratic_residues;
+///
+/// assert_eq!(quadratic_residues::jacobi(1001, 9907), -1);
+/// assert_eq!(quadratic_residues::jacobi(2, 15), 1); // 2 is not a square modulo 15 even though the symbol is 1
+/// ```
+pub fn jacobi(a: i128, n: i128) -> i32 {
+    assert!(
+        n > 0 && n % 2 == 1,
+        "Jacobi symbol requires an odd positive modulus"
+    );
+    jacobi_unsigned(modular::reduce(a, n as u128), n as u128)
+}
+
+/// `kronecker` function: Takes two i128 integers `a` and `n` as input and returns the Kronecker symbol `(a/n)`, which extends the
+/// Jacobi symbol to every `n`: `(a/2)` is 0 for even `a`, 1 for `a = 1, 7 (mod 8)` and -1 for `a = 3, 5 (mod 8)`, `(a/-1)` is the
+/// sign of `a` and `(a/0)` is 1 for `a = 1, -1` and 0 otherwise.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::quadratic_residues;
+///
+/// assert_eq!(quadratic_residues::kronecker(5, 12), -1);
+/// assert_eq!(quadratic_residues::kronecker(-1, -3), 1);
+/// assert_eq!(quadratic_residues::kronecker(3, 0), 0);
+/// ```
+pub fn kronecker(a: i128, n: i128) -> i32 {
+    if n == 0 {
+        return if a == 1 || a == -1 { 1 } else { 0 };
+    }
+    let mut result = if n < 0 && a < 0 { -1 } else { 1 };
+    let mut n = n.unsigned_abs();
+    let twos = n.trailing_zeros();
+    if twos > 0 {
+        if a % 2 == 0 {
+            return 0;
+        }
+        if twos % 2 == 1 && matches!(a.rem_euclid(8), 3 | 5) {
+            result = -result;
+        }
+        n >>= twos;
+    }
+    result * jacobi_unsigned(modular::reduce(a, n), n)
+}
+
+/// Returns the Jacobi symbol `(a/n)` for odd `n` and `a` in `0..n`.
+fn jacobi_unsigned(a: u128, n: u128) -> i32 {
+    let mut a = a;
+    let mut n = n;
+    let mut result = 1;
+    while a != 0 {
+        let twos = a.trailing_zeros();
+        a >>= twos;
+        if twos % 2 == 1 && (n % 8 == 3 || n % 8 == 5) {
+            result = -result;
+        }
+        std::mem::swap(&mut a, &mut n);
+        if a % 4 == 3 && n % 4 == 3 {
+            result = -result;
+        }
+        a %= n;
+    }
+    if n == 1 {
+        result
+    } else {
+        0
+    }
+}
+
+/// `tonelli_shanks` function: Takes an i128 integer `a` and a prime `p` as input and returns the smaller square root of `a`
+/// modulo `p`, or `None` if `a` is not a square modulo `p`. Writing `p - 1 = q 2^s` with `q` odd, the algorithm needs one
+/// exponentiation plus up to `s^2` multiplications. Panics if `p` is not a prime.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::quadratic_residues;
+///
+/// assert_eq!(quadratic_residues::tonelli_shanks(10, 13), Some(6));
+/// assert_eq!(quadratic_residues::tonelli_shanks(5, 13), None);
+/// ```
+pub fn tonelli_shanks(a: i128, p: i128) -> Option<i128> {
+    let a = prepare_prime_root(a, p)?;
+    if a < 2 || p == 2 {
+        return Some(a);
+    }
+    let s = (p - 1).trailing_zeros();
+    let q = (p - 1) >> s;
+    let z = (2..).find(|&z| jacobi(z, p) == -1).unwrap();
+
+    let mut m = s;
+    let mut c = mod_pow(z, q, p);
+    let mut t = mod_pow(a, q, p);
+    let mut root = mod_pow(a, (q + 1) / 2, p);
+    while t != 1 {
+        // Find the least i with t^(2^i) = 1; it is below m because t has order dividing 2^(m - 1).
+        let mut i = 0;
+        let mut power = t;
+        while power != 1 {
+            power = mod_mul(power, power, p);
+            i += 1;
+        }
+        let b = (0..m - i - 1).fold(c, |b, _| mod_mul(b, b, p));
+        m = i;
+        c = mod_mul(b, b, p);
+        t = mod_mul(t, c, p);
+        root = mod_mul(root, b, p);
+    }
+    Some(root.min(p - root))
+}
+
+/// `cipolla` function: Takes an i128 integer `a` and a prime `p` as input and returns the smaller square root of `a` modulo `p`,
+/// or `None` if `a` is not a square modulo `p`. It finds `t` such that `w = t^2 - a` is not a square and computes
+/// `(t + sqrt(w))^((p + 1) / 2)` in the field extension by `sqrt(w)`, which costs the same for every prime. Panics if `p` is not
+/// a prime.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::quadratic_residues;
+///
+/// assert_eq!(quadratic_residues::cipolla(10, 13), Some(6));
+/// assert_eq!(quadratic_residues::cipolla(-1, 1_000_000_009), Some(430477711));
+/// ```
+pub fn cipolla(a: i128, p: i128) -> Option<i128> {
+    let a = prepare_prime_root(a, p)?;
+    if a < 2 || p == 2 {
+        return Some(a);
+    }
+    let (t, w) = (1..)
+        .map(|t| (t, subtract(mod_mul(t, t, p), a, p)))
+        .find(|&(_, w)| jacobi(w, p) == -1)
+        .unwrap();
+
+    // Elements x + y sqrt(w) are stored as (x, y).
+    let multiply = |(x1, y1): (i128, i128), (x2, y2): (i128, i128)| {
+        (
+            add(mod_mul(x1, x2, p), mod_mul(mod_mul(y1, y2, p), w, p), p),
+            add(mod_mul(x1, y2, p), mod_mul(x2, y1, p), p),
+        )
+    };
+    let mut base = (t, 1);
+    let mut result = (1, 0);
+    let mut exponent = (p as u128).div_ceil(2);
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = multiply(result, base);
+        }
+        base = multiply(base, base);
+        exponent >>= 1;
+    }
+    let root = result.0;
+    Some(root.min(p - root))
+}
+
+/// `sqrt_mod_prime` function: Takes an i128 integer `a` and a prime `p` as input and returns the smaller square root of `a`
+/// modulo `p`, or `None` if `a` is not a square modulo `p`. Uses `tonelli_shanks` when `p - 1` has few factors of two and
+/// `cipolla` otherwise. Panics if `p` is not a prime.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::quadratic_residues;
+///
+/// let p = 170141183460469231731687303715884105727; // 2^127 - 1
+/// let root = quadratic_residues::sqrt_mod_prime(2, p).unwrap();
+/// assert_eq!(numbers_rus::integers::modular::mod_mul(root, root, p), 2);
+/// ```
+pub fn sqrt_mod_prime(a: i128, p: i128) -> Option<i128> {
+    if p == 2 {
+        return Some(a.rem_euclid(2));
+    }
+    let s = (p - 1).trailing_zeros();
+    let bits = 128 - p.leading_zeros();
+    if s * s > 3 * bits {
+        cipolla(a, p)
+    } else {
+        tonelli_shanks(a, p)
+    }
+}
+
+/// Returns `(x, y)` with `x^2 + y^2 = p` and `x, y > 0` for a prime `p = 1 (mod 4)`. The Hermite-Serret algorithm runs
+/// Euclid's algorithm on `p` and a square root of -1 modulo `p`, and the first remainder below `sqrt(p)` is `x`.
+pub(crate) fn two_squares_of_prime(p: i128) -> (i128, i128) {
+    let limit = base::isqrt(p);
+    let mut a = p;
+    let mut b = sqrt_mod_prime(p - 1, p).expect("-1 is a square modulo a prime p = 1 (mod 4)");
+    while b > limit {
+        (a, b) = (b, a % b);
+    }
+    (b, base::isqrt(p - b * b))
+}
+
+/// `sqrt_mod` function: Takes two i128 integers `a` and `n` as input and returns a square root of `a` modulo `|n|` in
+/// `0..|n|`, or `None` if `a` is not a square modulo `n`. The root is built from one root modulo each prime power of `n`.
+/// Panics if `n` is zero or `i128::MIN`.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::quadratic_residues;
+///
+/// let n = 1_000_000_007 * 998_244_353;
+/// let root = quadratic_residues::sqrt_mod(7, n).unwrap();
+/// assert_eq!(root * root % n, 7);
+/// ```
+pub fn sqrt_mod(a: i128, n: i128) -> Option<i128> {
+    let mut x = 0;
+    let mut modulus = 1;
+    for (q, roots) in prime_power_roots(a, n) {
+        let root = roots?.first()?;
+        (x, modulus) = combine(x, modulus, root, q);
+    }
+    Some(x)
+}
+
+/// `sqrt_mod_all` function: Takes two i128 integers `a` and `n` as input and returns every square root of `a` modulo `|n|`, sorted
+/// in increasing order. The result is empty if `a` is not a square modulo `n`. Note that the number of roots grows quickly when
+/// `a` and `n` share repeated prime factors: 0 has `2^50` square roots modulo `2^100`. Panics if `n` is zero or `i128::MIN`.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::quadratic_residues;
+///
+/// assert_eq!(quadratic_residues::sqrt_mod_all(1, 8), vec![1, 3, 5, 7]);
+/// assert_eq!(quadratic_residues::sqrt_mod_all(0, 12), vec![0, 6]);
+/// assert!(quadratic_residues::sqrt_mod_all(2, 9).is_empty());
+/// ```
+pub fn sqrt_mod_all(a: i128, n: i128) -> Vec<i128> {
+    let mut solutions = vec![0];
+    let mut modulus = 1;
+    for (q, roots) in prime_power_roots(a, n) {
+        let Some(roots) = roots else {
+            return Vec::new();
+        };
+        let mut next = Vec::new();
+        for &x in solutions.iter() {
+            for root in roots.iter() {
+                next.push(combine(x, modulus, root, q).0);
+            }
+        }
+        modulus *= q;
+        solutions = next;
+    }
+    solutions.sort_unstable();
+    solutions
+}
+
+/// `is_quadratic_residue` function: Takes two i128 integers `a` and `n` as input and returns whether `a` is a square modulo `|n|`.
+/// Panics if `n` is zero or `i128::MIN`.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::quadratic_residues;
+///
+/// assert!(quadratic_residues::is_quadratic_residue(4, 15));
+/// assert!(!quadratic_residues::is_quadratic_residue(2, 15));
+/// ```
+pub fn is_quadratic_residue(a: i128, n: i128) -> bool {
+    sqrt_mod(a, n).is_some()
+}
+
+/// The square roots of some value modulo a prime power `q = p^k`: the numbers `scale * (base + t * step)` for each `base` in
+/// `bases` and each `t` in `0..count`.
+struct RootSet {
+    bases: Vec<i128>,
+    scale: i128,
+    step: i128,
+    count: i128,
+}
+
+impl RootSet {
+    fn first(&self) -> Option<i128> {
+        self.bases.first().map(|&base| self.scale * base)
+    }
+
+    fn iter(&self) -> impl Iterator<Item = i128> + '_ {
+        self.bases.iter().flat_map(move |&base| {
+            (0..self.count).map(move |t| self.scale * (base + t * self.step))
+        })
+    }
+}
+
+/// Returns each prime power `q` of `|n|` together with the square roots of `a` modulo `q`, or `None` if there are none.
+fn prime_power_roots(a: i128, n: i128) -> impl Iterator<Item = (i128, Option<RootSet>)> {
+    assert_ne!(n, 0, "Modulus must not be zero!");
+    assert_ne!(
+        n,
+        i128::MIN,
+        "Modulus must fit in an i128 when made positive"
+    );
+    factorization::factorize(n)
+        .into_iter()
+        .map(move |(p, k)| (p.pow(k), prime_power_root_set(a, p, k)))
+}
+
+/// Returns the square roots of `a` modulo `p^k`.
+fn prime_power_root_set(a: i128, p: i128, k: u32) -> Option<RootSet> {
+    let q = p.pow(k);
+    let a = a.rem_euclid(q);
+    if a == 0 {
+        // x^2 = 0 exactly when p^ceil(k / 2) divides x.
+        return Some(RootSet {
+            bases: vec![0],
+            scale: p.pow(k.div_ceil(2)),
+            step: 1,
+            count: p.pow(k / 2),
+        });
+    }
+    // a = p^e b with b a unit: x = p^(e / 2) y where y^2 = b (mod p^(k - e)), and y is only fixed modulo p^(k - e).
+    let mut e = 0;
+    let mut b = a;
+    while b % p == 0 {
+        b /= p;
+        e += 1;
+    }
+    if e % 2 == 1 {
+        return None;
+    }
+    let bases = unit_roots(b, p, k - e);
+    if bases.is_empty() {
+        return None;
+    }
+    Some(RootSet {
+        bases,
+        scale: p.pow(e / 2),
+        step: p.pow(k - e),
+        count: p.pow(e / 2),
+    })
+}
+
+/// Returns the sorted square roots of a unit `b` modulo `p^k`, lifted from a root modulo `p` (or 8 for `p = 2`).
+fn unit_roots(b: i128, p: i128, k: u32) -> Vec<i128> {
+    let q = p.pow(k);
+    let b = b.rem_euclid(q);
+    let mut roots = if p == 2 {
+        match k {
+            1 => vec![1],
+            2 if b % 4 == 1 => vec![1, 3],
+            2 => vec![],
+            _ if b % 8 != 1 => vec![],
+            _ => {
+                // If r^2 = b (mod 2^i) but not modulo 2^(i + 1), then (r + 2^(i - 1))^2 = b (mod 2^(i + 1)).
+                let mut r: i128 = 1;
+                for i in 3..k {
+                    let m = 1 << (i + 1);
+                    if mod_mul(r, r, m) != b % m {
+                        r += 1 << (i - 1);
+                    }
+                }
+                let half = q / 2;
+                vec![r, q - r, (r + half) % q, (q - r + half) % q]
+            }
+        }
+    } else {
+        let Some(mut r) = sqrt_mod_prime(b, p) else {
+            return Vec::new();
+        };
+        // Hensel's lemma, doubling the precision each step: r <- r - (r^2 - b) / (2r).
+        let mut precision = p;
+        while precision < q {
+            precision = precision
+                .checked_mul(precision)
+                .map_or(q, |square| square.min(q));
+            let error = subtract(mod_mul(r, r, precision), b % precision, precision);
+            let inverse = mod_inverse(2 * r % precision, precision).unwrap();
+            r = subtract(r, mod_mul(error, inverse, precision), precision);
+        }
+        vec![r, q - r]
+    };
+    roots.sort_unstable();
+    roots.dedup();
+    roots
+}
+
+/// Reduces `a` modulo the prime `p`, returning `None` if it is not a square.
+fn prepare_prime_root(a: i128, p: i128) -> Option<i128> {
+    assert!(primality::is_prime(p), "Modulus must be a prime");
+    let a = a.rem_euclid(p);
+    if p > 2 && a > 1 && jacobi(a, p) != 1 {
+        return None;
+    }
+    Some(a)
+}
+
+/// Combines `x (mod m)` with `y (mod q)` for coprime `m` and `q`.
+fn combine(x: i128, m: i128, y: i128, q: i128) -> (i128, i128) {
+    modular::crt(&[(x, m), (y, q)])
+        .expect("prime powers are coprime and their product divides the modulus")
+}
+
+fn add(a: i128, b: i128, p: i128) -> i128 {
+    add_mod(a as u128, b as u128, p as u128) as i128
+}
+
+fn subtract(a: i128, b: i128, p: i128) -> i128 {
+    if a >= b {
+        a - b
+    } else {
+        a + (p - b)
+    }
+}
+
+#[cfg(test)]
+pub mod test_quadratic_residues {
+    use super::*;
+
+    /// Brute-force square roots modulo a small `n`.
+    fn brute_roots(a: i128, n: i128) -> Vec<i128> {
+        (0..n).filter(|x| (x * x - a).rem_euclid(n) == 0).collect()
+    }
+
+    #[test]
+    fn test_jacobi() {
+        assert_eq!(jacobi(1001, 9907), -1);
+        assert_eq!(jacobi(19, 45), 1);
+        assert_eq!(jacobi(8, 21), -1);
+        assert_eq!(jacobi(5, 21), 1);
+        assert_eq!(jacobi(6, 9), 0);
+        assert_eq!(jacobi(-1, 1), 1);
+    }
+    #[test]
+    fn test_symbols_match_euler_criterion() {
+        for p in [3, 5, 7, 11, 13, 97, 101] {
+            for a in -50..50 {
+                let euler = mod_pow(a, (p - 1) / 2, p);
+                let expected = if euler == p - 1 { -1 } else { euler as i32 };
+                assert_eq!(legendre(a, p), expected, "a = {}, p = {}", a, p);
+                assert_eq!(kronecker(a, p), expected);
+            }
+        }
+        // Kronecker symbols multiply over the factors of the modulus.
+        for a in -30..30 {
+            for n in [-12, -7, -2, -1, 2, 4, 8, 24, 45] {
+                let expected: i32 = factorization::factorize(n)
+                    .into_iter()
+                    .map(|(p, k)| kronecker(a, p).pow(k))
+                    .product::<i32>()
+                    * if n < 0 { kronecker(a, -1) } else { 1 };
+                assert_eq!(kronecker(a, n), expected, "a = {}, n = {}", a, n);
+            }
+        }
+    }
+    #[test]
+    #[should_panic]
+    fn test_legendre_composite() {
+        legendre(2, 15);
+    }
+    #[test]
+    fn test_prime_roots() {
+        for p in [2, 3, 5, 7, 13, 17, 97, 257, 65537] {
+            for a in 0..p.min(300) {
+                let expected = brute_roots(a, p).first().copied();
+                assert_eq!(tonelli_shanks(a, p), expected, "a = {}, p = {}", a, p);
+                assert_eq!(cipolla(a, p), expected, "a = {}, p = {}", a, p);
+                assert_eq!(sqrt_mod_prime(a, p), expected, "a = {}, p = {}", a, p);
+            }
+        }
+    }
+    #[test]
+    fn test_large_prime_roots() {
+        // Primes with p - 1 divisible by a large power of two exercise the Tonelli-Shanks loop.
+        for p in [
+            18446744069414584321,
+            4179340454199820289,
+            i128::MAX,
+            18446744073709551557,
+        ] {
+            for a in [2, 3, 5, 7, 123456789] {
+                let (first, second) = (tonelli_shanks(a, p), cipolla(a, p));
+                assert_eq!(first, second);
+                match first {
+                    Some(root) => assert_eq!(mod_mul(root, root, p), a),
+                    None => assert_eq!(jacobi(a, p), -1),
+                }
+            }
+        }
+    }
+    #[test]
+    fn test_composite_roots() {
+        for n in 1..200 {
+            for a in 0..n {
+                let expected = brute_roots(a, n);
+                assert_eq!(sqrt_mod_all(a, n), expected, "a = {}, n = {}", a, n);
+                assert_eq!(sqrt_mod(a, n).is_some(), !expected.is_empty());
+                if let Some(root) = sqrt_mod(a, n) {
+                    assert!(expected.contains(&root));
+                }
+            }
+        }
+        for (a, n) in [
+            (17, 1 << 20),
+            (4, 729 * 125),
+            (0, 3i128.pow(7)),
+            (9 * 4, 27 * 16),
+        ] {
+            let all = sqrt_mod_all(a, n);
+            assert!(!all.is_empty());
+            assert!(all.iter().all(|&x| (x * x - a).rem_euclid(n) == 0));
+        }
+        assert_eq!(sqrt_mod_all(0, 3i128.pow(7)).len(), 27);
+    }
+    #[test]
+    fn test_large_composite_roots() {
+        let n = (1 << 61) - 1;
+        let m = 1i128 << 60;
+        let root = sqrt_mod(2, n * 7).unwrap();
+        assert_eq!(mod_mul(root, root, n * 7), 2);
+        let root = sqrt_mod(17, m).unwrap();
+        assert_eq!(mod_mul(root, root, m), 17);
+        assert_eq!(sqrt_mod_all(17, m).len(), 4);
+        let q = 3i128.pow(79);
+        let root = sqrt_mod(7, q).unwrap();
+        assert_eq!(mod_mul(root, root, q), 7);
+    }
+}
@@ -0,0 +1,457 @@
+//! The `recurrence` module evaluates Fibonacci numbers, Lucas numbers and general linear recurrences with constant
+//! coefficients in logarithmic time.
+//!
+//! * `fibonacci` and `lucas` use fast doubling and are exact for every index whose result fits in an i128, including
+//!   negative indices.
+//! * `fibonacci_mod` and `lucas_mod` work for any index and any modulus.
+//! * `LinearRecurrence` computes the nth term of `a(n) = c1 a(n - 1) + ... + cd a(n - d)` with Kitamasa's method, exactly as a
+//!   `BigInt` or modulo a number.
+//! * `pisano_period` returns the period of the Fibonacci sequence modulo a number.
+//!
+//! # Examples
+//! ```
+//! use numbers_rus::integers::recurrence::{self, LinearRecurrence};
+//!
+//! assert_eq!(recurrence::fibonacci(100), Ok(354224848179261915075));
+//! assert_eq!(recurrence::lucas(10), Ok(123));
+//! assert_eq!(recurrence::fibonacci_mod(1 << 100, 1_000_000_007), 931794721);
+//! assert_eq!(recurrence::pisano_period(10), 60);
+//!
+//! // Tribonacci numbers: 0, 0, 1, 1, 2, 4, 7, 13, 24, ...
+//! let tribonacci = LinearRecurrence::new(vec![1, 1, 1], vec![0, 0, 1]);
+//! assert_eq!(tribonacci.nth(8), Ok(24));
+//! ```
+use crate::error::{ErrorKind, NumbersError};
+use crate::integers::big_integer::BigInt;
+use crate::integers::factorization;
+use crate::integers::modular::{add_mod, gcd_unsigned, mul_mod};
+
+/// Largest index whose Fibonacci number fits in an i128.
+const MAX_FIBONACCI_INDEX: u128 = 184;
+/// Largest index whose Lucas number fits in an i128.
+const MAX_LUCAS_INDEX: u128 = 182;
+
+/// `fibonacci` function: Takes an i128 integer `number` as input and returns the `number`th Fibonacci number, computed with
+/// fast doubling. Negative indices follow `F(-n) = (-1)^(n + 1) F(n)`. Returns an `Overflow` error if the result does not fit in
+/// an i128, which happens for `|number| > 184`.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::recurrence;
+///
+/// assert_eq!(recurrence::fibonacci(10), Ok(55));
+/// assert_eq!(recurrence::fibonacci(-10), Ok(-55));
+/// assert_eq!(recurrence::fibonacci(184), Ok(127127879743834334146972278486287885163));
+/// assert!(recurrence::fibonacci(185).is_err());
+/// ```
+pub fn fibonacci(number: i128) -> Result<i128, NumbersError> {
+    let n = number.unsigned_abs();
+    if n > MAX_FIBONACCI_INDEX {
+        return Err(NumbersError::new("fibonacci", ErrorKind::Overflow));
+    }
+    let value = fibonacci_pair(n).0 as i128;
+    Ok(if number < 0 && n.is_multiple_of(2) {
+        -value
+    } else {
+        value
+    })
+}
+
+/// `lucas` function: Takes an i128 integer `number` as input and returns the `number`th Lucas number (2, 1, 3, 4, 7, ...),
+/// computed with fast doubling. Negative indices follow `L(-n) = (-1)^n L(n)`. Returns an `Overflow` error if the result does not
+/// fit in an i128, which happens for `|number| > 182`.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::recurrence;
+///
+/// assert_eq!(recurrence::lucas(0), Ok(2));
+/// assert_eq!(recurrence::lucas(-5), Ok(-11));
+/// assert!(recurrence::lucas(183).is_err());
+/// ```
+pub fn lucas(number: i128) -> Result<i128, NumbersError> {
+    let n = number.unsigned_abs();
+    if n > MAX_LUCAS_INDEX {
+        return Err(NumbersError::new("lucas", ErrorKind::Overflow));
+    }
+    // L(n) = F(n - 1) + F(n + 1) = 2 F(n + 1) - F(n)
+    let (f, g) = fibonacci_pair(n);
+    let value = (2 * g - f) as i128;
+    Ok(if number < 0 && n % 2 == 1 {
+        -value
+    } else {
+        value
+    })
+}
+
+/// `fibonacci_mod` function: Takes an index `number` and an i128 `modulus` as input and returns the `number`th Fibonacci number
+/// reduced into `0..|modulus|`. Panics if `modulus` is zero.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::recurrence;
+///
+/// assert_eq!(recurrence::fibonacci_mod(1_000_000_000_000_000_000, 1_000_000_007), 209783453);
+/// ```
+pub fn fibonacci_mod(number: u128, modulus: i128) -> i128 {
+    assert_ne!(modulus, 0, "Modulus must not be zero!");
+    fibonacci_pair_mod(number, modulus.unsigned_abs()).0 as i128
+}
+
+/// `lucas_mod` function: Takes an index `number` and an i128 `modulus` as input and returns the `number`th Lucas number
+/// reduced into `0..|modulus|`. Panics if `modulus` is zero.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::recurrence;
+///
+/// assert_eq!(recurrence::lucas_mod(10, 100), 23);
+/// ```
+pub fn lucas_mod(number: u128, modulus: i128) -> i128 {
+    assert_ne!(modulus, 0, "Modulus must not be zero!");
+    let m = modulus.unsigned_abs();
+    let (f, g) = fibonacci_pair_mod(number, m);
+    subtract_mod(add_mod(g, g, m), f, m) as i128
+}
+
+/// `pisano_period` function: Takes a u64 `modulus` as input and returns the Pisano period, the period of the Fibonacci sequence
+/// modulo `modulus`. The period of each prime power `p^k` in the factorization divides `p^(k - 1)` times 3 (for `p = 2`),
+/// 20 (for `p = 5`), `p - 1` (for `p = 1, 9 mod 10`) or `2 (p + 1)` (otherwise); it is found by removing prime factors from
+/// that bound, and the results are combined with the least common multiple. Panics if `modulus` is zero.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::recurrence;
+///
+/// assert_eq!(recurrence::pisano_period(1), 1);
+/// assert_eq!(recurrence::pisano_period(2), 3);
+/// assert_eq!(recurrence::pisano_period(1_000_000_007), 2_000_000_016);
+/// ```
+pub fn pisano_period(modulus: u64) -> u128 {
+    assert_ne!(modulus, 0, "Modulus must not be zero!");
+    factorization::factorize(modulus as i128)
+        .into_iter()
+        .map(|(p, k)| prime_power_pisano_period(p as u128, k))
+        .fold(1, |period, next| period / gcd_unsigned(period, next) * next)
+}
+
+/// Returns the Pisano period modulo `p^k` for a prime `p`.
+fn prime_power_pisano_period(p: u128, k: u32) -> u128 {
+    let modulus = p.pow(k);
+    let bound = match p {
+        2 => 3,
+        5 => 20,
+        _ if p % 10 == 1 || p % 10 == 9 => p - 1,
+        _ => 2 * (p + 1),
+    };
+    let mut period = p.pow(k - 1) * bound;
+    let mut primes: Vec<u128> = factorization::factorize(bound as i128)
+        .into_keys()
+        .map(|q| q as u128)
+        .collect();
+    if k > 1 && !primes.contains(&p) {
+        primes.push(p);
+    }
+    for q in primes {
+        while period.is_multiple_of(q)
+            && fibonacci_pair_mod(period / q, modulus) == (0, 1 % modulus)
+        {
+            period /= q;
+        }
+    }
+    period
+}
+
+/// `LinearRecurrence` is a linear recurrence with constant coefficients,
+/// `a(n) = coefficients[0] a(n - 1) + coefficients[1] a(n - 2) + ... + coefficients[d - 1] a(n - d)`, together with its first `d`
+/// terms `a(0), ..., a(d - 1)`. Terms are computed with Kitamasa's method: `x^n` is reduced modulo the characteristic polynomial
+/// with `O(d^2 log n)` operations, and its coefficients weight the initial terms.
+///
+/// # Example
+///
+/// ```
+/// use numbers_rus::integers::recurrence::LinearRecurrence;
+///
+/// // Pell numbers: a(n) = 2 a(n - 1) + a(n - 2), starting 0, 1
+/// let pell = LinearRecurrence::new(vec![2, 1], vec![0, 1]);
+/// assert_eq!(pell.nth(10), Ok(2378));
+/// assert_eq!(pell.nth_big(200).to_string().len(), 77);
+/// assert_eq!(pell.nth_mod(1 << 100, 1_000_000_007), pell.nth_mod((1 << 100) % 1_000_000_006, 1_000_000_007));
+/// ```
+///
+/// # Methods
+///
+/// * `new` - creates a recurrence from its coefficients and initial terms.
+/// * `order` - returns the number of coefficients `d`.
+/// * `nth` - returns the nth term as an i128, or an `Overflow` error if it does not fit.
+/// * `nth_big` - returns the nth term exactly as a `BigInt`.
+/// * `nth_mod` - returns the nth term reduced into `0..|modulus|`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinearRecurrence {
+    coefficients: Vec<i128>,
+    initial: Vec<i128>,
+}
+
+impl LinearRecurrence {
+    /// Panics if there are no coefficients or if the number of initial terms differs from the number of coefficients.
+    pub fn new(coefficients: Vec<i128>, initial: Vec<i128>) -> Self {
+        assert!(
+            !coefficients.is_empty(),
+            "A recurrence needs at least one coefficient"
+        );
+        assert_eq!(
+            coefficients.len(),
+            initial.len(),
+            "A recurrence needs as many initial terms as coefficients"
+        );
+        Self {
+            coefficients,
+            initial,
+        }
+    }
+
+    pub fn order(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    pub fn nth(&self, n: u64) -> Result<i128, NumbersError> {
+        self.nth_big(n)
+            .to_i128()
+            .ok_or(NumbersError::new("recurrence", ErrorKind::Overflow))
+    }
+
+    pub fn nth_big(&self, n: u64) -> BigInt {
+        let coefficients: Vec<BigInt> =
+            self.coefficients.iter().map(|&c| BigInt::from(c)).collect();
+        let weights = kitamasa(
+            &coefficients,
+            n as u128,
+            BigInt::zero(),
+            BigInt::one(),
+            |a, b| a + b,
+            |a, b| a * b,
+        );
+        weights
+            .iter()
+            .zip(self.initial.iter())
+            .fold(BigInt::zero(), |total, (weight, &term)| {
+                total + weight * &BigInt::from(term)
+            })
+    }
+
+    /// Panics if `modulus` is zero.
+    pub fn nth_mod(&self, n: u128, modulus: i128) -> i128 {
+        assert_ne!(modulus, 0, "Modulus must not be zero!");
+        let m = modulus.unsigned_abs();
+        let coefficients: Vec<u128> = self
+            .coefficients
+            .iter()
+            .map(|&c| reduce_signed(c, m))
+            .collect();
+        let weights = kitamasa(
+            &coefficients,
+            n,
+            0,
+            1 % m,
+            |a, b| add_mod(*a, *b, m),
+            |a, b| mul_mod(*a, *b, m),
+        );
+        weights
+            .iter()
+            .zip(self.initial.iter())
+            .fold(0, |total, (&weight, &term)| {
+                add_mod(total, mul_mod(weight, reduce_signed(term, m), m), m)
+            }) as i128
+    }
+}
+
+/// Returns the coefficients `r` of `x^n mod (x^d - c[0] x^(d - 1) - ... - c[d - 1])`, so that `a(n) = sum r[j] a(j)`.
+fn kitamasa<T: Clone>(
+    coefficients: &[T],
+    n: u128,
+    zero: T,
+    one: T,
+    add: impl Fn(&T, &T) -> T,
+    multiply: impl Fn(&T, &T) -> T,
+) -> Vec<T> {
+    let d = coefficients.len();
+    // Reduces a polynomial of degree below 2d - 1 from the top, using x^d = c[0] x^(d - 1) + ... + c[d - 1].
+    let reduce = |mut polynomial: Vec<T>| -> Vec<T> {
+        for k in (d..polynomial.len()).rev() {
+            let top = polynomial[k].clone();
+            for (i, c) in coefficients.iter().enumerate() {
+                polynomial[k - 1 - i] = add(&polynomial[k - 1 - i], &multiply(&top, c));
+            }
+        }
+        polynomial.truncate(d);
+        polynomial.resize(d, zero.clone());
+        polynomial
+    };
+    let product = |left: &[T], right: &[T]| -> Vec<T> {
+        let mut result = vec![zero.clone(); 2 * d - 1];
+        for (i, a) in left.iter().enumerate() {
+            for (j, b) in right.iter().enumerate() {
+                result[i + j] = add(&result[i + j], &multiply(a, b));
+            }
+        }
+        reduce(result)
+    };
+
+    let mut result = reduce(vec![one.clone()]);
+    let mut base = reduce(vec![zero.clone(), one]);
+    let mut exponent = n;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = product(&result, &base);
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            base = product(&base, &base);
+        }
+    }
+    result
+}
+
+/// Returns `(F(n), F(n + 1))` for `n <= 184`, where neither value overflows a u128.
+fn fibonacci_pair(n: u128) -> (u128, u128) {
+    let (mut a, mut b) = (0u128, 1u128);
+    for i in (0..128 - n.leading_zeros()).rev() {
+        // F(2k) = F(k) (2 F(k + 1) - F(k)) and F(2k + 1) = F(k)^2 + F(k + 1)^2
+        let c = a * (2 * b - a);
+        let d = a * a + b * b;
+        if (n >> i) & 1 == 1 {
+            (a, b) = (d, c + d);
+        } else {
+            (a, b) = (c, d);
+        }
+    }
+    (a, b)
+}
+
+/// Returns `(F(n) mod m, F(n + 1) mod m)` using fast doubling.
+fn fibonacci_pair_mod(n: u128, m: u128) -> (u128, u128) {
+    let (mut a, mut b) = (0, 1 % m);
+    for i in (0..128 - n.leading_zeros()).rev() {
+        let c = mul_mod(a, subtract_mod(add_mod(b, b, m), a, m), m);
+        let d = add_mod(mul_mod(a, a, m), mul_mod(b, b, m), m);
+        if (n >> i) & 1 == 1 {
+            (a, b) = (d, add_mod(c, d, m));
+        } else {
+            (a, b) = (c, d);
+        }
+    }
+    (a, b)
+}
+
+/// Returns `(a - b) mod m` for `a, b < m`.
+fn subtract_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >= b {
+        a - b
+    } else {
+        a + (m - b)
+    }
+}
+
+/// Returns `value` reduced into `0..m`.
+fn reduce_signed(value: i128, m: u128) -> u128 {
+    let remainder = value.unsigned_abs() % m;
+    if value < 0 && remainder != 0 {
+        m - remainder
+    } else {
+        remainder
+    }
+}
+
+#[cfg(test)]
+pub mod test_recurrence {
+    use super::*;
+    use crate::integers::base;
+
+    #[test]
+    fn test_fibonacci_matches_iteration() {
+        let (mut a, mut b) = (0i128, 1i128);
+        for n in 0..=184 {
+            assert_eq!(fibonacci(n), Ok(a), "n = {}", n);
+            assert_eq!(fibonacci(-n), Ok(if n % 2 == 0 { -a } else { a }));
+            (a, b) = (b, a.wrapping_add(b));
+        }
+        assert_eq!(fibonacci(-185).unwrap_err().kind(), ErrorKind::Overflow);
+        assert_eq!(
+            fibonacci(i128::MIN).unwrap_err().kind(),
+            ErrorKind::Overflow
+        );
+    }
+    #[test]
+    fn test_lucas() {
+        let (mut a, mut b) = (2i128, 1i128);
+        for n in 0..=182 {
+            assert_eq!(lucas(n), Ok(a), "n = {}", n);
+            (a, b) = (b, a.wrapping_add(b));
+        }
+        assert!(lucas(183).is_err());
+        assert_eq!(lucas(-4), Ok(7));
+    }
+    #[test]
+    fn test_modular_variants() {
+        for m in [1i128, 2, 10, 97, 1 << 64, i128::MAX, -1_000_000_007] {
+            for n in 0..=182 {
+                let f = fibonacci(n).unwrap().rem_euclid(m.abs());
+                let l = lucas(n).unwrap().rem_euclid(m.abs());
+                assert_eq!(fibonacci_mod(n as u128, m), f, "n = {}, m = {}", n, m);
+                assert_eq!(lucas_mod(n as u128, m), l, "n = {}, m = {}", n, m);
+            }
+        }
+        // 2^127 - 1 is a prime congruent to 2 mod 5, so F(p) = -1 and F(p + 1) = 0 modulo p.
+        let p = i128::MAX;
+        assert_eq!(fibonacci_mod(p as u128, p), p - 1);
+        assert_eq!(fibonacci_mod(p as u128 + 1, p), 0);
+    }
+    #[test]
+    fn test_pisano_period() {
+        // Brute-force periods for small moduli.
+        for m in 1..300u64 {
+            let (mut a, mut b, mut period) = (0u64, 1 % m, 0u128);
+            loop {
+                (a, b) = (b, (a + b) % m);
+                period += 1;
+                if a == 0 && b == 1 % m {
+                    break;
+                }
+            }
+            assert_eq!(pisano_period(m), period, "m = {}", m);
+        }
+        assert_eq!(pisano_period(1 << 63), 3 << 62);
+        let m = 18446744073709551557; // largest prime below 2^64
+        let period = pisano_period(m);
+        assert_eq!(fibonacci_mod(period, m as i128), 0);
+        assert_eq!(fibonacci_mod(period + 1, m as i128), 1);
+    }
+    #[test]
+    fn test_linear_recurrence() {
+        let fib = LinearRecurrence::new(vec![1, 1], vec![0, 1]);
+        for n in 0..=184u64 {
+            assert_eq!(fib.nth(n), fibonacci(n as i128));
+        }
+        assert!(fib.nth(185).is_err());
+        assert_eq!(fib.nth_big(300), base::fibonacci_big(&BigInt::from(300)));
+        assert_eq!(
+            fib.nth_mod(10u128.pow(30), 1_000_000_007),
+            fibonacci_mod(10u128.pow(30), 1_000_000_007)
+        );
+
+        // First-order and alternating recurrences stay small for huge n.
+        let powers = LinearRecurrence::new(vec![3], vec![1]);
+        assert_eq!(powers.nth(80), Ok(3i128.pow(80)));
+        let alternating = LinearRecurrence::new(vec![0, 1], vec![5, -7]);
+        assert_eq!(alternating.nth(u64::MAX), Ok(-7));
+        assert_eq!(alternating.nth_mod(u128::MAX - 1, 10), 5);
+        let negative = LinearRecurrence::new(vec![-1, -1], vec![1, 0]);
+        assert_eq!(negative.nth(3), Ok(1)); // 1, 0, -1, 1, 0, -1, ...
+        assert_eq!(negative.nth_mod(5, 7), 6);
+    }
+    #[test]
+    #[should_panic]
+    fn test_linear_recurrence_mismatched() {
+        LinearRecurrence::new(vec![1, 1], vec![0]);
+    }
+}
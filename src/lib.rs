@@ -1,9 +1,11 @@
 extern crate core;
 
+pub mod error;
 pub mod integers;
 pub mod numbers;
 pub mod rational;
 pub mod single;
 pub mod solve;
 pub mod structures;
+pub mod traits;
 pub mod vector;
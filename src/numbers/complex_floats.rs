@@ -3,6 +3,10 @@
 //! median, and mode calculation. The results are returned as a comma-separated string representing the element-wise results
 //! of the applied operation.
 //!
+//! `Complex` also has the elementary transcendental functions: polar form, `exp`, logarithms, powers and roots, and the
+//! trigonometric and hyperbolic functions with their inverses. The multivalued ones return principal values, with the
+//! branch cuts of C99 and Python's `cmath`; on a cut, the sign of a zero real or imaginary part picks the side.
+//!
 //! # Examples
 //! ```
 //! use numbers_rus::numbers::complex_floats::Complex;
@@ -12,8 +16,18 @@
 //! let c = a.add(&b);
 //! assert_eq!(c.get_real(), 4.0);
 //! assert_eq!(c.get_imag(), 6.0);
+//!
+//! // The arithmetic operators work on owned values, references and plain `f64`s.
+//! let d: Complex = "1.5-2i".parse().unwrap();
+//! assert_eq!(&a * &b + 2.0 * d, Complex::new(-2.0, 6.0));
 //! ```
+use crate::error::{ErrorKind, NumbersError};
+use crate::integers::complex_integers;
+use crate::rational::{rational_float, rational_integer};
+use crate::traits::{forward_copy_ops, Integer};
 use std::fmt;
+use std::ops;
+use std::str::FromStr;
 /// A complex number is a number that can be expressed in the form a + bi, where a and b are real numbers, and i is a solution of the solve x2 = −1. Because no real number satisfies this solve, i is called an imaginary number. For the complex number a + bi, a is called the real part, and b is called the imaginary part. Despite the historical nomenclature "imaginary", complex numbers are regarded in the mathematical sciences as just as "real" as the real numbers, and are fundamental in many aspects of the scientific description of the natural world.
 ///
 /// Complex numbers allow solutions to certain equations that have no solutions in real numbers.
@@ -30,6 +44,8 @@ use std::fmt;
 /// * `subtract` - subtracts two complex numbers and returns the result as a new `Complex` instance as the difference of the two inputs.
 /// * `multiply` - multiplies two complex numbers and returns the result as a new `Complex` instance as the product of the two inputs.
 /// * `divide` - divides two complex numbers and returns the result as a new `Complex` instance as the quotient of the two inputs.
+/// * `modulus` - returns the absolute value, without overflow in the intermediate squares.
+/// * `mul_add` - returns `self * factor + addend` computed with fused multiply-adds.
 /// * `get_real` - returns the real part of the complex number.
 /// * `get_imag` - returns the imaginary part of the complex number.
 /// * `get` - returns the complex number as a tuple of the real and imaginary parts.
@@ -41,6 +57,12 @@ use std::fmt;
 /// * `from_real` - returns a new `Complex` instance with the real part set to the input value and the imaginary part set to zero.
 /// * `from_imag` - returns a new `Complex` instance with the real part set to zero and the imaginary part set to the input value.
 /// * `copy` - returns a new `Complex` instance with the real and imaginary parts set to the same values as the current instance.
+/// * `arg`, `to_polar`, `from_polar` - the argument in `[-pi, pi]` and conversion to and from polar form.
+/// * `exp`, `ln`, `log` - the exponential and the principal logarithms, with the branch cut `(-inf, 0]`.
+/// * `powc`, `powf` - principal powers with complex and real exponents.
+/// * `sqrt`, `cbrt`, `nth_roots` - principal square and cube roots, and all `n`th roots.
+/// * `sin`, `cos`, `tan`, `sinh`, `cosh`, `tanh` - trigonometric and hyperbolic functions.
+/// * `asin`, `acos`, `atan`, `asinh`, `acosh`, `atanh` - their principal inverses.
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Complex {
     pub real: f64,
@@ -66,6 +88,236 @@ impl fmt::Display for Complex {
     }
 }
 
+/// Error returned when parsing a complex number with `FromStr` fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseComplexError {
+    message: &'static str,
+}
+
+impl fmt::Display for ParseComplexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ParseComplexError {}
+
+/// Parses text of the form `a`, `bi` or `a+bi` (with `-` allowed for either part, spaces ignored and a lone `i` meaning one)
+/// into its real and imaginary parts. Shared with `complex_integers::Complex`.
+pub(crate) fn parse_parts<T>(text: &str) -> Result<(T, T), ParseComplexError>
+where
+    T: FromStr + ops::Neg<Output = T> + From<i8>,
+{
+    let invalid = ParseComplexError {
+        message: "invalid complex number",
+    };
+    let compact: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+    let parse = |part: &str| part.parse::<T>().map_err(|_| invalid.clone());
+    let Some(imaginary) = compact.strip_suffix('i') else {
+        return Ok((parse(&compact)?, T::from(0)));
+    };
+    // The imaginary part starts at the first sign that is neither leading nor part of an exponent.
+    let bytes = imaginary.as_bytes();
+    let split = (1..bytes.len())
+        .find(|&k| matches!(bytes[k], b'+' | b'-') && !matches!(bytes[k - 1], b'e' | b'E'));
+    let (real, sign, magnitude) = match split {
+        Some(k) => (
+            parse(&imaginary[..k])?,
+            &imaginary[k..k + 1],
+            &imaginary[k + 1..],
+        ),
+        None => match imaginary.strip_prefix('-') {
+            Some(rest) => (T::from(0), "-", rest),
+            None => (
+                T::from(0),
+                "+",
+                imaginary.strip_prefix('+').unwrap_or(imaginary),
+            ),
+        },
+    };
+    let imag = match magnitude {
+        "" => T::from(1),
+        "-" => T::from(-1),
+        _ if magnitude.starts_with('+') => return Err(invalid),
+        _ => parse(magnitude)?,
+    };
+    Ok((real, if sign == "-" { -imag } else { imag }))
+}
+
+impl FromStr for Complex {
+    type Err = ParseComplexError;
+
+    /// Parses `"1.5-2i"`, `"3"`, `"-i"` or the `Display` output `"1.5 - 2i"`.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse_parts(text).map(|(real, imag)| Self::new(real, imag))
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(real: f64) -> Self {
+        Self::from_real(real)
+    }
+}
+
+impl TryFrom<i128> for Complex {
+    type Error = NumbersError;
+
+    /// Succeeds when `|real| <= 2^53`, so that every such integer converts exactly.
+    fn try_from(real: i128) -> Result<Self, Self::Error> {
+        if real.unsigned_abs() > 1 << f64::MANTISSA_DIGITS {
+            return Err(NumbersError::new("try_from", ErrorKind::NotRepresentable));
+        }
+        Ok(Self::from_real(real as f64))
+    }
+}
+
+impl From<complex_integers::Complex> for Complex {
+    fn from(value: complex_integers::Complex) -> Self {
+        Self::new(value.get_real() as f64, value.get_imag() as f64)
+    }
+}
+
+impl<T: Integer> From<rational_integer::Rational<T>> for Complex {
+    fn from(value: rational_integer::Rational<T>) -> Self {
+        Self::from_real(value.to_float())
+    }
+}
+
+impl From<rational_float::Rational> for Complex {
+    fn from(value: rational_float::Rational) -> Self {
+        Self::from_real(f64::from(value))
+    }
+}
+
+impl ops::Add for Complex {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Complex::add(&self, &other)
+    }
+}
+
+impl ops::Sub for Complex {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.subtract(&other)
+    }
+}
+
+impl ops::Mul for Complex {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.multiply(&other)
+    }
+}
+
+impl ops::Div for Complex {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self.divide(&other)
+    }
+}
+
+impl ops::Neg for Complex {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.real, -self.imag)
+    }
+}
+
+forward_copy_ops!(Complex, f64, Complex::zero(), Complex::one());
+
+/// Above this magnitude the inverse trigonometric and hyperbolic functions switch to their asymptotic forms, which are exact to
+/// double precision there, since the products in the direct formulas overflow near `f64::MAX`.
+const ASYMPTOTIC: f64 = 1e150;
+
+/// The largest whole exponent with `e^x` below `f64::MAX`.
+const EXP_LIMIT: f64 = 709.0;
+
+/// Returns `e^x * factor`, which can be finite when `e^x` alone overflows, as `e^(x - 709) * factor * e^709`. The
+/// subtraction is exact for `x` up to 1418, beyond which the result overflows for any factor that is not subnormal.
+fn exp_times(x: f64, factor: f64) -> f64 {
+    if x <= EXP_LIMIT {
+        return x.exp() * factor;
+    }
+    ((x - EXP_LIMIT).exp() * factor) * EXP_LIMIT.exp()
+}
+
+/// Returns `x^2 + y^2 - 1` to nearly full relative accuracy even when it cancels, for `x` in `[0.5, 2]` and `|y| <= x`.
+/// The squares are split into their rounded values and exact errors with fused multiply-adds, and the sum keeps the
+/// rounding error of each addition until the end.
+fn squared_modulus_minus_one(x: f64, y: f64) -> f64 {
+    let (x_square, y_square) = (x * x, y * y);
+    let errors = x.mul_add(x, -x_square) + y.mul_add(y, -y_square);
+    // x^2 - 1 is exact by Sterbenz' lemma when x^2 is in [0.5, 2], and two_sum recovers its error otherwise.
+    let (sum, first_error) = two_sum(x_square, -1.0);
+    let (sum, second_error) = two_sum(sum, y_square);
+    sum + (first_error + second_error + errors)
+}
+
+/// Returns `(a + b, e)` where `e` is the exact rounding error of the sum (Knuth's two-sum).
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let b_part = sum - a;
+    (sum, (a - (sum - b_part)) + (b - b_part))
+}
+
+/// Returns `(a + bi) / (c + di)` by Smith's algorithm for `|d| <= |c|`, falling back to dividing `b` and `a` by `c` first
+/// when the ratio `d / c` underflows into the subnormals, and to dividing the parts directly when `d` is zero.
+fn smith_divide(a: f64, b: f64, c: f64, d: f64) -> (f64, f64) {
+    if d == 0.0 {
+        // A real divisor divides each part exactly once, which also keeps the signs of zero parts.
+        return (a / c, b / c);
+    }
+    let ratio = d / c;
+    let t = 1.0 / (c + d * ratio);
+    // A subnormal ratio has lost digits, and when a product with the ratio underflows to zero, multiplying by `t` first
+    // keeps its contribution.
+    let part = |x: f64, y: f64, sign: f64| {
+        if ratio.abs() < f64::MIN_POSITIVE {
+            (x + sign * d * (y / c)) * t
+        } else if y * ratio != 0.0 {
+            (x + sign * y * ratio) * t
+        } else {
+            x * t + sign * (y * t) * ratio
+        }
+    };
+    (part(a, b, 1.0), part(b, a, -1.0))
+}
+
+/// Recovers the infinite or zero quotient of a division whose result came out NaN in both parts, following the example
+/// `_Cdivd` of Annex G of the C99 standard, or returns `None` if the quotient is genuinely undefined.
+fn annex_g_divide(numerator: &Complex, denominator: &Complex) -> Option<Complex> {
+    let (a, b, c, d) = (
+        numerator.real,
+        numerator.imag,
+        denominator.real,
+        denominator.imag,
+    );
+    // Replaces infinite parts by one and finite parts by zero, keeping their signs.
+    let unit = |x: f64| (if x.is_infinite() { 1.0f64 } else { 0.0 }).copysign(x);
+    let finite = |x: f64, y: f64| x.is_finite() && y.is_finite();
+    if c == 0.0 && d == 0.0 && !(a.is_nan() && b.is_nan()) {
+        let infinity = f64::INFINITY.copysign(c);
+        Some(Complex::new(infinity * a, infinity * b))
+    } else if (a.is_infinite() || b.is_infinite()) && finite(c, d) {
+        let (a, b) = (unit(a), unit(b));
+        Some(Complex::new(
+            f64::INFINITY * (a * c + b * d),
+            f64::INFINITY * (b * c - a * d),
+        ))
+    } else if (c.is_infinite() || d.is_infinite()) && finite(a, b) {
+        let (c, d) = (unit(c), unit(d));
+        Some(Complex::new(0.0 * (a * c + b * d), 0.0 * (b * c - a * d)))
+    } else {
+        None
+    }
+}
+
 impl Complex {
     pub fn new(real: f64, imag: f64) -> Self {
         Self { real, imag }
@@ -140,25 +392,33 @@ impl Complex {
         Self::new(real, imag)
     }
 
-    /// divide - divides two complex numbers (numbers)
+    /// modulus - returns the modulus (absolute value) of a complex number (float), computed with `hypot` so that it neither
+    /// overflows nor underflows when the squares of the parts would
     ///
     /// # Example
     ///
     /// ```
     /// use numbers_rus::numbers::complex_floats::Complex;
     ///
-    /// let a = Complex::new(1.0, 2.0);
-    /// let b = Complex::new(3.0, 4.0);
-    /// let c = a.divide(&b);
-    /// assert_eq!(c.get_real(), 0.44);
-    /// assert_eq!(c.get_imag(), 0.08);
+    /// assert_eq!(Complex::new(3.0, 4.0).modulus(), 5.0);
+    /// // Squaring these parts would overflow or underflow.
+    /// let (big, tiny) = (2f64.powi(600), 2f64.powi(-600));
+    /// assert_eq!(Complex::new(3.0 * big, 4.0 * big).modulus(), 5.0 * big);
+    /// assert_eq!(Complex::new(3.0 * tiny, 4.0 * tiny).modulus(), 5.0 * tiny);
     /// ```
     pub fn modulus(&self) -> f64 {
-        (self.real.powi(2) + self.imag.powi(2)).sqrt()
+        self.real.hypot(self.imag)
     }
 
     /// divide - divides two complex numbers (numbers)
     ///
+    /// Uses the Baudin–Smith algorithm: Smith's division, which divides through by the larger part of the divisor instead
+    /// of by its squared modulus, with the operands scaled by powers of two away from overflow and underflow and a
+    /// reordering when the ratio of the divisor's parts underflows. Results are accurate for parts anywhere in the range of
+    /// `f64`. When the result would be NaN in both parts, infinities and zeros are recovered as in Annex G of the C99
+    /// standard: a non-zero finite or infinite number divided by zero is infinite, an infinite number divided by a finite
+    /// one is infinite, and a finite number divided by an infinite one is zero.
+    ///
     /// # Arguments
     ///
     /// * `other` - A reference to another complex float
@@ -173,13 +433,71 @@ impl Complex {
     /// let c = a.divide(&b);
     /// assert_eq!(c.get_real(), 0.44);
     /// assert_eq!(c.get_imag(), 0.08);
+    ///
+    /// // The textbook formula overflows here.
+    /// let d = Complex::new(1e300, 1e300).divide(&Complex::new(1e300, 1e300));
+    /// assert_eq!(d, Complex::new(1.0, 0.0));
     /// ```
     pub fn divide(&self, other: &Self) -> Self {
-        let real = (self.real * other.real + self.imag * other.imag)
-            / (other.real.powi(2) + other.imag.powi(2));
-        let imag = (self.imag * other.real - self.real * other.imag)
-            / (other.real.powi(2) + other.imag.powi(2));
-        Self::new(real, imag)
+        let (mut a, mut b, mut c, mut d) = (self.real, self.imag, other.real, other.imag);
+        let large = f64::MAX / 2.0;
+        let small = f64::MIN_POSITIVE * 2.0 / f64::EPSILON;
+        let boost = 2.0 / (f64::EPSILON * f64::EPSILON);
+        let mut scale = 1.0;
+        let numerator_size = a.abs().max(b.abs());
+        let denominator_size = c.abs().max(d.abs());
+        if numerator_size >= large {
+            (a, b, scale) = (a / 2.0, b / 2.0, scale * 2.0);
+        }
+        if denominator_size >= large {
+            (c, d, scale) = (c / 2.0, d / 2.0, scale / 2.0);
+        }
+        if numerator_size <= small {
+            (a, b, scale) = (a * boost, b * boost, scale / boost);
+        }
+        if denominator_size <= small {
+            (c, d, scale) = (c * boost, d * boost, scale * boost);
+        }
+        let (real, imag) = if d.abs() <= c.abs() {
+            smith_divide(a, b, c, d)
+        } else {
+            // (a + bi) / (c + di) = (b - ai) / (d - ci), conjugated.
+            let (real, imag) = smith_divide(b, a, d, c);
+            (real, -imag)
+        };
+        let quotient = Self::new(real * scale, imag * scale);
+        if quotient.real.is_nan() && quotient.imag.is_nan() {
+            return annex_g_divide(self, other).unwrap_or(quotient);
+        }
+        quotient
+    }
+
+    /// mul_add - returns `self * factor + addend` with each part computed by fused multiply-adds, which round less often
+    /// than the separate operations and keep the cancellation in `ac - bd` exact
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// let e = 2f64.powi(-30);
+    /// let a = Complex::new(1.0 + e, 1.0);
+    /// let b = Complex::new(1.0 - e, 1.0);
+    /// // The real part of a * b is exactly -e^2, which the plain product rounds away.
+    /// assert_eq!(a.mul_add(&b, &Complex::zero()).get_real(), -e * e);
+    /// assert_eq!(a.multiply(&b).get_real(), 0.0);
+    /// ```
+    pub fn mul_add(&self, factor: &Self, addend: &Self) -> Self {
+        let fused = |x: f64, y: f64, u: f64, v: f64, w: f64| {
+            // x y + u v + w, where the rounding error of u v is added back exactly.
+            let product = u * v;
+            let error = u.mul_add(v, -product);
+            x.mul_add(y, product + w) + error
+        };
+        Self::new(
+            fused(self.real, factor.real, -self.imag, factor.imag, addend.real),
+            fused(self.real, factor.imag, self.imag, factor.real, addend.imag),
+        )
     }
 
     /// conjugate - returns the conjugate of a complex number (float)
@@ -200,7 +518,7 @@ impl Complex {
         Self::new(real, imag)
     }
 
-    /// inverse - returns the inverse of a complex number (float)
+    /// inverse - returns the inverse of a complex number (float), with the scaling and special cases of `divide`
     ///
     /// # Example
     ///
@@ -213,8 +531,548 @@ impl Complex {
     /// assert_eq!(b.get_imag(), -0.4);
     /// ```
     pub fn inverse(&self) -> Self {
-        let real = self.real / (self.real.powi(2) + self.imag.powi(2));
-        let imag = -self.imag / (self.real.powi(2) + self.imag.powi(2));
+        Self::one().divide(self)
+    }
+
+    /// arg - returns the argument (phase angle) of a complex number (float) in radians, in the range `[-pi, pi]`. The sign of a
+    /// zero imaginary part picks the end of the range on the negative real axis, so `-1 + 0i` has argument `pi` and `-1 - 0i`
+    /// has argument `-pi`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// assert_eq!(Complex::new(0.0, 2.0).arg(), std::f64::consts::FRAC_PI_2);
+    /// assert_eq!(Complex::new(-1.0, 0.0).arg(), std::f64::consts::PI);
+    /// assert_eq!(Complex::new(-1.0, -0.0).arg(), -std::f64::consts::PI);
+    /// ```
+    pub fn arg(&self) -> f64 {
+        self.imag.atan2(self.real)
+    }
+
+    /// from_polar - returns the complex number (float) with the given modulus and argument
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// let a = Complex::from_polar(2.0, std::f64::consts::FRAC_PI_2);
+    /// assert!((a - Complex::new(0.0, 2.0)).modulus() < 1e-15);
+    /// ```
+    pub fn from_polar(modulus: f64, argument: f64) -> Self {
+        Self::new(modulus * argument.cos(), modulus * argument.sin())
+    }
+
+    /// to_polar - returns the modulus and argument of a complex number (float). The modulus is computed without overflow or
+    /// underflow in the intermediate squares.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// let (modulus, argument) = Complex::new(3e300, 4e300).to_polar();
+    /// assert_eq!(modulus, 5e300);
+    /// assert_eq!(argument, (4.0f64).atan2(3.0));
+    /// ```
+    pub fn to_polar(&self) -> (f64, f64) {
+        (self.real.hypot(self.imag), self.arg())
+    }
+
+    /// exp - returns `e` raised to a complex number (float). Each part is finite whenever it fits, even where `e^a` alone
+    /// overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// let a = Complex::new(0.0, std::f64::consts::PI).exp();
+    /// assert!((a + 1.0).modulus() < 1e-15);
+    /// assert_eq!(Complex::new(1000.0, 0.0).exp(), Complex::new(f64::INFINITY, 0.0));
+    /// ```
+    pub fn exp(&self) -> Self {
+        if self.imag == 0.0 {
+            // Keep a real result real even when the scale overflows, since infinity times zero is NaN.
+            return Self::new(self.real.exp(), self.imag);
+        }
+        Self::new(
+            exp_times(self.real, self.imag.cos()),
+            exp_times(self.real, self.imag.sin()),
+        )
+    }
+
+    /// ln - returns the principal natural logarithm of a complex number (float), whose imaginary part is the argument in
+    /// `[-pi, pi]`. The branch cut is the negative real axis `(-inf, 0]`, where the sign of a zero imaginary part picks the
+    /// side, as in C99 and Python's `cmath`. The real part keeps full accuracy near the unit circle, and does not overflow for
+    /// a modulus above `f64::MAX`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// let a = Complex::new(-1.0, 0.0).ln();
+    /// assert_eq!(a, Complex::new(0.0, std::f64::consts::PI));
+    /// assert!((Complex::new(1.0, 1e-10).ln().get_real() - 5e-21).abs() < 1e-35);
+    /// ```
+    pub fn ln(&self) -> Self {
+        let (large, small) = if self.real.abs() >= self.imag.abs() {
+            (self.real.abs(), self.imag.abs())
+        } else {
+            (self.imag.abs(), self.real.abs())
+        };
+        let real = if (0.5..=2.0).contains(&large) {
+            // ln |z| = ln(1 + (|z|^2 - 1)) / 2, where |z|^2 - 1 is computed without cancellation.
+            squared_modulus_minus_one(large, small).ln_1p() / 2.0
+        } else if large > 1e300 && large.is_finite() {
+            // ln |z| = ln(large) + ln(1 + (small / large)^2) / 2, where the modulus itself could overflow.
+            let ratio = small / large;
+            large.ln() + (ratio * ratio).ln_1p() / 2.0
+        } else {
+            large.hypot(small).ln()
+        };
+        Self::new(real, self.arg())
+    }
+
+    /// log - returns the principal logarithm of a complex number (float) to a real base, `ln(z) / ln(base)`, with the same
+    /// branch cut as `ln`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// assert_eq!(Complex::new(8.0, 0.0).log(2.0), Complex::new(3.0, 0.0));
+    /// let a = Complex::new(0.0, 100.0).log(10.0);
+    /// assert!((a - Complex::new(2.0, std::f64::consts::FRAC_PI_2 / 10f64.ln())).modulus() < 1e-15);
+    /// ```
+    pub fn log(&self, base: f64) -> Self {
+        let ln = self.ln();
+        let scale = base.ln();
+        Self::new(ln.real / scale, ln.imag / scale)
+    }
+
+    /// Returns `ln(2z)`, the value of `acosh(z)`, and of `asinh(z)` for `Re z >= 0`, up to `O(1/z^2)`, without overflowing
+    /// in `2z`.
+    fn ln_double(&self) -> Self {
+        let double = Self::new(2.0 * self.real, 2.0 * self.imag);
+        if double.real.is_finite() && double.imag.is_finite() {
+            return double.ln();
+        }
+        let ln = self.ln();
+        Self::new(ln.real + std::f64::consts::LN_2, ln.imag)
+    }
+
+    /// Returns the power of zero: one for a zero exponent, zero for an exponent with a positive real part and NaN otherwise.
+    fn zero_power(exponent: &Self) -> Self {
+        if exponent.real == 0.0 && exponent.imag == 0.0 {
+            Self::one()
+        } else if exponent.real > 0.0 {
+            Self::zero()
+        } else {
+            Self::new(f64::NAN, f64::NAN)
+        }
+    }
+
+    /// powc - raises a complex number (float) to a complex power using the principal branch, `exp(w ln(z))`, so it has the
+    /// branch cut of `ln`. Zero to a power is one for a zero exponent, zero for an exponent with a positive real part and
+    /// NaN otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// // i^i = e^(-pi/2)
+    /// let i = Complex::new(0.0, 1.0);
+    /// let a = i.powc(&i);
+    /// assert!((a.get_real() - (-std::f64::consts::FRAC_PI_2).exp()).abs() < 1e-16);
+    /// assert_eq!(a.get_imag(), 0.0);
+    /// ```
+    pub fn powc(&self, exponent: &Self) -> Self {
+        if self.real == 0.0 && self.imag == 0.0 {
+            return Self::zero_power(exponent);
+        }
+        exponent.multiply(&self.ln()).exp()
+    }
+
+    /// powf - raises a complex number (float) to a real power using the principal branch, with the branch cut of `ln`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// let a = Complex::new(-4.0, 0.0).powf(0.5);
+    /// assert!((a - Complex::new(0.0, 2.0)).modulus() < 1e-15);
+    /// ```
+    pub fn powf(&self, exponent: f64) -> Self {
+        if self.real == 0.0 && self.imag == 0.0 {
+            return Self::zero_power(&Self::from_real(exponent));
+        }
+        let (modulus, argument) = self.to_polar();
+        Self::from_polar(modulus.powf(exponent), argument * exponent)
+    }
+
+    /// sqrt - returns the principal square root of a complex number (float), the root with a non-negative real part. The
+    /// branch cut is the negative real axis, where the sign of a zero imaginary part picks the sign of the result's
+    /// imaginary part.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// assert_eq!(Complex::new(-4.0, 0.0).sqrt(), Complex::new(0.0, 2.0));
+    /// assert_eq!(Complex::new(-4.0, -0.0).sqrt(), Complex::new(0.0, -2.0));
+    /// assert_eq!(Complex::new(3.0, 4.0).sqrt(), Complex::new(2.0, 1.0));
+    /// ```
+    pub fn sqrt(&self) -> Self {
+        let (a, b) = (self.real, self.imag);
+        if a == 0.0 && b == 0.0 {
+            return Self::new(0.0, b);
+        }
+        if a.abs() > 1e300 || b.abs() > 1e300 {
+            // Scale down so that |a| + |z| cannot overflow.
+            let root = Self::new(a / 4.0, b / 4.0).sqrt();
+            return Self::new(root.real * 2.0, root.imag * 2.0);
+        }
+        if a.abs() < 1e-300 && b.abs() < 1e-300 {
+            // Scale up so that (|a| + |z|) / 2 cannot underflow.
+            let root = Self::new(a * 2f64.powi(600), b * 2f64.powi(600)).sqrt();
+            return Self::new(root.real * 2f64.powi(-300), root.imag * 2f64.powi(-300));
+        }
+        let t = ((a.abs() + a.hypot(b)) / 2.0).sqrt();
+        if a >= 0.0 {
+            Self::new(t, b / (2.0 * t))
+        } else {
+            Self::new(b.abs() / (2.0 * t), t.copysign(b))
+        }
+    }
+
+    /// cbrt - returns the principal cube root of a complex number (float), whose argument is a third of the argument of the
+    /// input, with the branch cut of `ln`. For negative reals this is not the real cube root: the principal cube root of
+    /// `-8` is `1 + 1.732...i`. Use `nth_roots` for all three roots.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// let a = Complex::new(-8.0, 0.0).cbrt();
+    /// assert!((a - Complex::new(1.0, 3f64.sqrt())).modulus() < 1e-15);
+    /// assert_eq!(Complex::new(27.0, 0.0).cbrt(), Complex::new(3.0, 0.0));
+    /// ```
+    pub fn cbrt(&self) -> Self {
+        let (modulus, argument) = self.to_polar();
+        Self::from_polar(modulus.cbrt(), argument / 3.0)
+    }
+
+    /// nth_roots - returns all `n` complex numbers (float) whose `n`th power is the input, starting with the principal root
+    /// (the one whose argument is the input's divided by `n`) and going counterclockwise. Returns an `InvalidRootDegree` error
+    /// if `n` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// let roots = Complex::new(-8.0, 0.0).nth_roots(3).unwrap();
+    /// assert_eq!(roots.len(), 3);
+    /// assert!((roots[1] - Complex::new(-2.0, 0.0)).modulus() < 1e-15);
+    /// assert!(Complex::one().nth_roots(0).is_err());
+    /// ```
+    pub fn nth_roots(&self, n: u32) -> Result<Vec<Self>, NumbersError> {
+        if n == 0 {
+            return Err(NumbersError::new("nth_roots", ErrorKind::InvalidRootDegree));
+        }
+        let (modulus, argument) = self.to_polar();
+        let modulus = modulus.powf(1.0 / n as f64);
+        Ok((0..n)
+            .map(|k| {
+                let angle = (argument + std::f64::consts::TAU * k as f64) / n as f64;
+                Self::from_polar(modulus, angle)
+            })
+            .collect())
+    }
+
+    /// sin - returns the sine of a complex number (float)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// let a = Complex::new(0.0, 1.0).sin();
+    /// assert_eq!(a, Complex::new(0.0, 1f64.sinh()));
+    /// ```
+    pub fn sin(&self) -> Self {
+        // sin z = -i sinh(iz)
+        let t = Self::new(-self.imag, self.real).sinh();
+        Self::new(t.imag, -t.real)
+    }
+
+    /// cos - returns the cosine of a complex number (float)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// let a = Complex::new(0.0, 1.0).cos();
+    /// assert_eq!(a.get_real(), 1f64.cosh());
+    /// ```
+    pub fn cos(&self) -> Self {
+        // cos z = cosh(iz)
+        Self::new(-self.imag, self.real).cosh()
+    }
+
+    /// tan - returns the tangent of a complex number (float), `-i tanh(iz)`. It tends to `i` or `-i` far from the real axis
+    /// instead of overflowing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// let a = Complex::new(std::f64::consts::FRAC_PI_4, 0.0).tan();
+    /// assert!((a - Complex::one()).modulus() < 1e-15);
+    /// assert_eq!(Complex::new(1.0, 1000.0).tan().get_imag(), 1.0);
+    /// ```
+    pub fn tan(&self) -> Self {
+        let t = Self::new(-self.imag, self.real).tanh();
+        Self::new(t.imag, -t.real)
+    }
+
+    /// sinh - returns the hyperbolic sine of a complex number (float). As with `exp`, each part is finite whenever it fits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// let a = Complex::new(0.0, std::f64::consts::FRAC_PI_2).sinh();
+    /// assert_eq!(a, Complex::new(0.0, 1.0));
+    /// ```
+    pub fn sinh(&self) -> Self {
+        let (a, b) = (self.real, self.imag);
+        if b == 0.0 {
+            // Keep a real result real even when cosh a overflows, since infinity times zero is NaN.
+            return Self::new(a.sinh(), b);
+        }
+        if a.abs() > EXP_LIMIT {
+            // sinh a and cosh a are both e^|a| / 2 up to the sign, and e^|a| alone would overflow before the products do.
+            return Self::new(
+                a.signum() * exp_times(a.abs(), b.cos() / 2.0),
+                exp_times(a.abs(), b.sin() / 2.0),
+            );
+        }
+        Self::new(a.sinh() * b.cos(), a.cosh() * b.sin())
+    }
+
+    /// cosh - returns the hyperbolic cosine of a complex number (float). As with `exp`, each part is finite whenever it fits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// let a = Complex::new(1.0, 0.0).cosh();
+    /// assert_eq!(a, Complex::new(1f64.cosh(), 0.0));
+    /// ```
+    pub fn cosh(&self) -> Self {
+        let (a, b) = (self.real, self.imag);
+        if b == 0.0 {
+            // As in `sinh`, with the sign of the zero imaginary part that of sinh(a) sin(b).
+            return Self::new(a.cosh(), b * a.signum());
+        }
+        if a.abs() > EXP_LIMIT {
+            // As in `sinh`.
+            return Self::new(
+                exp_times(a.abs(), b.cos() / 2.0),
+                a.signum() * exp_times(a.abs(), b.sin() / 2.0),
+            );
+        }
+        Self::new(a.cosh() * b.cos(), a.sinh() * b.sin())
+    }
+
+    /// tanh - returns the hyperbolic tangent of a complex number (float), `(sinh 2a + i sin 2b) / (cosh 2a + cos 2b)`. It
+    /// tends to `1` or `-1` far from the imaginary axis instead of overflowing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// let a = Complex::new(1.0, 0.0).tanh();
+    /// assert!((a.get_real() - 1f64.tanh()).abs() < 1e-15);
+    /// assert_eq!(Complex::new(-800.0, 1.0).tanh(), Complex::new(-1.0, 0.0));
+    /// ```
+    pub fn tanh(&self) -> Self {
+        let (a, b) = (self.real, self.imag);
+        if a.abs() > 20.0 {
+            // cosh 2a dominates the denominator, and the imaginary part decays like 4 sin b cos b e^(-2|a|).
+            return Self::new(a.signum(), 4.0 * b.sin() * b.cos() * (-2.0 * a.abs()).exp());
+        }
+        let denominator = (2.0 * a).cosh() + (2.0 * b).cos();
+        Self::new(
+            (2.0 * a).sinh() / denominator,
+            (2.0 * b).sin() / denominator,
+        )
+    }
+
+    /// asin - returns the principal inverse sine of a complex number (float), whose real part is in `[-pi/2, pi/2]`. The
+    /// branch cuts are the real intervals `(-inf, -1)` and `(1, inf)`, where the sign of a zero imaginary part picks the
+    /// side. Computed with Kahan's formulas, which stay accurate near the cuts and for large inputs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// let a = Complex::new(2.0, 0.0).asin();
+    /// assert_eq!(a.get_real(), std::f64::consts::FRAC_PI_2);
+    /// assert!((a.get_imag() - (2.0 + 3f64.sqrt()).ln()).abs() < 1e-15);
+    /// assert!(Complex::new(2.0, -0.0).asin().get_imag() < 0.0);
+    /// ```
+    pub fn asin(&self) -> Self {
+        let (a, b) = (self.real, self.imag);
+        if a.abs().max(b.abs()) > ASYMPTOTIC {
+            // asin z = -i asinh(iz), where asinh w = ln(2w) for Re w >= 0 and asinh is odd.
+            let w = Self::new(-b, a);
+            let t = if w.real.is_sign_negative() {
+                -Self::new(b, -a).ln_double()
+            } else {
+                w.ln_double()
+            };
+            return Self::new(t.imag, -t.real);
+        }
+        let minus = Self::new(1.0 - a, -b).sqrt();
+        let plus = Self::new(1.0 + a, b).sqrt();
+        let real = a.atan2(minus.multiply(&plus).real);
+        let imag = minus.conjugate().multiply(&plus).imag.asinh();
+        Self::new(real, imag)
+    }
+
+    /// acos - returns the principal inverse cosine of a complex number (float), whose real part is in `[0, pi]`. The branch
+    /// cuts are those of `asin`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// assert_eq!(Complex::new(-1.0, 0.0).acos(), Complex::new(std::f64::consts::PI, 0.0));
+    /// let a = Complex::new(0.5, 0.0).acos();
+    /// assert!((a.get_real() - std::f64::consts::FRAC_PI_3).abs() < 1e-15);
+    /// ```
+    pub fn acos(&self) -> Self {
+        let (a, b) = (self.real, self.imag);
+        if a.abs().max(b.abs()) > ASYMPTOTIC {
+            // acos z = -i acosh(z) in the upper half plane and i acosh(z) in the lower one, where acosh z = ln(2z).
+            let t = self.ln_double();
+            return if b.is_sign_negative() {
+                Self::new(-t.imag, t.real)
+            } else {
+                Self::new(t.imag, -t.real)
+            };
+        }
+        let minus = Self::new(1.0 - a, -b).sqrt();
+        let plus = Self::new(1.0 + a, b).sqrt();
+        let real = 2.0 * minus.real.atan2(plus.real);
+        let imag = plus.conjugate().multiply(&minus).imag.asinh();
+        Self::new(real, imag)
+    }
+
+    /// atan - returns the principal inverse tangent of a complex number (float), `-i atanh(iz)`, whose real part is in
+    /// `[-pi/2, pi/2]`. The branch cuts are the imaginary intervals from `i` to `i inf` and from `-i` to `-i inf`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// let a = Complex::new(1.0, 0.0).atan();
+    /// assert!((a.get_real() - std::f64::consts::FRAC_PI_4).abs() < 1e-16);
+    /// assert_eq!(Complex::new(0.0, 2.0).atan().get_real(), std::f64::consts::FRAC_PI_2);
+    /// ```
+    pub fn atan(&self) -> Self {
+        let t = Self::new(-self.imag, self.real).atanh();
+        Self::new(t.imag, -t.real)
+    }
+
+    /// asinh - returns the principal inverse hyperbolic sine of a complex number (float), `-i asin(iz)`. The branch cuts are
+    /// the imaginary intervals from `i` to `i inf` and from `-i` to `-i inf`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// let a = Complex::new(1.0, 0.0).asinh();
+    /// assert_eq!(a, Complex::new(1f64.asinh(), 0.0));
+    /// ```
+    pub fn asinh(&self) -> Self {
+        let t = Self::new(-self.imag, self.real).asin();
+        Self::new(t.imag, -t.real)
+    }
+
+    /// acosh - returns the principal inverse hyperbolic cosine of a complex number (float), whose real part is non-negative
+    /// and whose imaginary part is in `[-pi, pi]`. The branch cut is the real interval `(-inf, 1)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// assert_eq!(Complex::new(1.0, 0.0).acosh(), Complex::zero());
+    /// assert_eq!(Complex::new(-1.0, 0.0).acosh(), Complex::new(0.0, std::f64::consts::PI));
+    /// ```
+    pub fn acosh(&self) -> Self {
+        let (a, b) = (self.real, self.imag);
+        if a.abs().max(b.abs()) > ASYMPTOTIC {
+            return self.ln_double();
+        }
+        let minus = Self::new(a - 1.0, b).sqrt();
+        let plus = Self::new(a + 1.0, b).sqrt();
+        let real = minus.conjugate().multiply(&plus).real.asinh();
+        let imag = 2.0 * minus.imag.atan2(plus.real);
+        Self::new(real, imag)
+    }
+
+    /// atanh - returns the principal inverse hyperbolic tangent of a complex number (float), whose imaginary part is in
+    /// `[-pi/2, pi/2]`. The branch cuts are the real intervals `(-inf, -1]` and `[1, inf)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numbers_rus::numbers::complex_floats::Complex;
+    ///
+    /// let a = Complex::new(0.5, 0.0).atanh();
+    /// assert!((a.get_real() - 0.5f64.atanh()).abs() < 1e-15);
+    /// assert_eq!(Complex::new(2.0, 0.0).atanh().get_imag(), std::f64::consts::FRAC_PI_2);
+    /// ```
+    pub fn atanh(&self) -> Self {
+        let (a, b) = (self.real, self.imag);
+        if a.is_sign_negative() {
+            // atanh is odd, and the formulas below cancel for a negative real part.
+            return -Self::new(-a, -b).atanh();
+        }
+        if a.max(b.abs()) > ASYMPTOTIC {
+            // atanh z = 1/z + i pi/2 + O(1/z^3), with the sign of the imaginary part of z.
+            return Self::new(self.inverse().real, std::f64::consts::FRAC_PI_2.copysign(b));
+        }
+        // Re = ln(|1 + z|^2 / |1 - z|^2) / 4 and Im = arg((1 + z)(1 - conj z)) / 2, arranged to avoid cancellation.
+        let real = if (1.0 - a).hypot(b) < 1e-150 {
+            // Close to 1 the squares would underflow.
+            ((1.0 + a).hypot(b).ln() - (1.0 - a).hypot(b).ln()) / 2.0
+        } else {
+            (4.0 * a / ((1.0 - a) * (1.0 - a) + b * b)).ln_1p() / 4.0
+        };
+        let imag = (2.0 * b).atan2((1.0 - a) * (1.0 + a) - b * b) / 2.0;
         Self::new(real, imag)
     }
 
@@ -275,6 +1133,7 @@ impl Complex {
 #[cfg(test)]
 pub mod test_complex {
     use super::*;
+    use crate::traits::Ring;
     #[test]
     fn test_add() {
         let a = Complex::new(1.0, 2.0);
@@ -394,4 +1253,673 @@ pub mod test_complex {
         let b = Complex::new(1.0, 2.0);
         assert_eq!(a.display(), b.display());
     }
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_operators() {
+        let a = Complex::new(1.0, 2.0);
+        let b = Complex::new(3.0, 4.0);
+        assert_eq!(a + b, a.add(&b));
+        assert_eq!(&a - b, a.subtract(&b));
+        assert_eq!(a * &b, a.multiply(&b));
+        assert_eq!(&a / &b, a.divide(&b));
+        assert_eq!(-a, Complex::new(-1.0, -2.0));
+        assert_eq!(a + 1.0, Complex::new(2.0, 2.0));
+        assert_eq!(2.0 * a, Complex::new(2.0, 4.0));
+        let mut c = a;
+        c += b;
+        c -= &a;
+        c *= 2.0;
+        c /= Complex::new(0.0, 1.0);
+        assert_eq!(c, Complex::new(8.0, -6.0));
+        let values = [a, b, Complex::new(0.0, 1.0)];
+        assert_eq!(values.iter().sum::<Complex>(), Complex::new(4.0, 7.0));
+        assert_eq!(
+            values.into_iter().product::<Complex>(),
+            Complex::new(-10.0, -5.0)
+        );
+    }
+    #[test]
+    fn test_parse() {
+        let parse = |text: &str| text.parse::<Complex>();
+        assert_eq!(parse("1.5-2i"), Ok(Complex::new(1.5, -2.0)));
+        assert_eq!(parse(" 3 "), Ok(Complex::new(3.0, 0.0)));
+        assert_eq!(parse("-i"), Ok(Complex::new(0.0, -1.0)));
+        assert_eq!(parse("+2.5i"), Ok(Complex::new(0.0, 2.5)));
+        assert_eq!(parse("1+i"), Ok(Complex::new(1.0, 1.0)));
+        assert_eq!(parse("-1e-3+2E+2i"), Ok(Complex::new(-0.001, 200.0)));
+        assert_eq!(parse("1 + -2i"), Ok(Complex::new(1.0, -2.0)));
+        for value in [Complex::new(1.25, -3.5), Complex::new(-2.0, 0.5)] {
+            assert_eq!(parse(&value.to_string()), Ok(value));
+        }
+        for text in ["", "i2", "1+", "1++2i", "1+2j", "1-2i-3i", "abc"] {
+            assert!(parse(text).is_err(), "{:?}", text);
+        }
+    }
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Complex::from(2.5), Complex::new(2.5, 0.0));
+        assert_eq!(Complex::try_from(-3i128), Ok(Complex::new(-3.0, 0.0)));
+        assert_eq!(
+            Complex::try_from(1i128 << 53),
+            Ok(Complex::new(9007199254740992.0, 0.0))
+        );
+        assert!(Complex::try_from((1i128 << 53) + 1).is_err());
+        assert!(Complex::try_from(i128::MIN).is_err());
+        assert_eq!(
+            Complex::from(complex_integers::Complex::new(3, -4)),
+            Complex::new(3.0, -4.0)
+        );
+        assert_eq!(
+            Complex::from(rational_integer::Rational::new(1, 4)),
+            Complex::new(0.25, 0.0)
+        );
+        assert_eq!(
+            Complex::from(rational_float::Rational::new(3.0, 2.0)),
+            Complex::new(1.5, 0.0)
+        );
+    }
+    /// Points on and off the branch cuts, with both signs of zero on the cuts.
+    const POINTS: [(f64, f64); 10] = [
+        (0.5, 0.25),
+        (-1.5, 2.0),
+        (3.0, -4.0),
+        (1e-08, 2e-08),
+        (2.0, 0.0),
+        (2.0, -0.0),
+        (-2.0, 0.0),
+        (-2.0, -0.0),
+        (-0.0, 2.0),
+        (-0.0, -2.0),
+    ];
+    /// Reference values at `POINTS` from Python's `cmath`, which follows the C99 branch cut conventions.
+    #[allow(clippy::approx_constant)]
+    const REFERENCE: [(&str, [(f64, f64); 10]); 15] = [
+        (
+            "exp",
+            [
+                (1.5974665191199127, 0.4079001700783598),
+                (-0.09285491028402633, 0.2028916804701695),
+                (-13.128783081462158, 15.200784463067954),
+                (1.0000000099999997, 2.00000002e-08),
+                (7.38905609893065, 0.0),
+                (7.38905609893065, -0.0),
+                (0.1353352832366127, 0.0),
+                (0.1353352832366127, -0.0),
+                (-0.4161468365471424, 0.9092974268256817),
+                (-0.4161468365471424, -0.9092974268256817),
+            ],
+        ),
+        (
+            "ln",
+            [
+                (-0.5815754049028404, 0.4636476090008061),
+                (0.9162907318741551, 2.214297435588181),
+                (1.6094379124341003, -0.9272952180016122),
+                (-17.615961787735316, 1.1071487177940904),
+                (0.6931471805599453, 0.0),
+                (0.6931471805599453, -0.0),
+                (0.6931471805599453, 3.141592653589793),
+                (0.6931471805599453, -3.141592653589793),
+                (0.6931471805599453, 1.5707963267948966),
+                (0.6931471805599453, -1.5707963267948966),
+            ],
+        ),
+        (
+            "sqrt",
+            [
+                (0.7276733451126774, 0.17178037486125622),
+                (0.7071067811865475, 1.4142135623730951),
+                (2.0, -1.0),
+                (0.0001272019649514069, 7.861513777574232e-05),
+                (1.4142135623730951, 0.0),
+                (1.4142135623730951, -0.0),
+                (0.0, 1.4142135623730951),
+                (0.0, -1.4142135623730951),
+                (1.0, 1.0),
+                (1.0, -1.0),
+            ],
+        ),
+        (
+            "sin",
+            [
+                (0.494485780933195, 0.22168816414957482),
+                (-3.752771340479298, 0.2565539560904818),
+                (3.853738037919377, 27.016813258003932),
+                (1.0000000000000002e-08, 2e-08),
+                (0.9092974268256817, -0.0),
+                (0.9092974268256817, 0.0),
+                (-0.9092974268256817, -0.0),
+                (-0.9092974268256817, 0.0),
+                (-0.0, 3.626860407847019),
+                (-0.0, -3.626860407847019),
+            ],
+        ),
+        (
+            "cos",
+            [
+                (0.9051501505596068, -0.12110879604381165),
+                (0.26612719531354573, 3.6177750739401375),
+                (-27.034945603074224, 3.851153334811777),
+                (1.0000000000000002, -2.0000000000000002e-16),
+                (-0.4161468365471424, -0.0),
+                (-0.4161468365471424, 0.0),
+                (-0.4161468365471424, 0.0),
+                (-0.4161468365471424, -0.0),
+                (3.7621956910836314, 0.0),
+                (3.7621956910836314, -0.0),
+            ],
+        ),
+        (
+            "tan",
+            [
+                (0.504500702698564, 0.31242069250258875),
+                (-0.005362060922003057, 1.036920282100185),
+                (-0.0001873462046294784, -0.999355987381473),
+                (9.999999999999997e-09, 1.9999999999999997e-08),
+                (-2.185039863261519, 0.0),
+                (-2.185039863261519, -0.0),
+                (2.185039863261519, 0.0),
+                (2.185039863261519, -0.0),
+                (-0.0, 0.9640275800758169),
+                (-0.0, -0.9640275800758169),
+            ],
+        ),
+        (
+            "sinh",
+            [
+                (0.504895714387995, 0.2789791283502615),
+                (0.8860929093625314, 2.139040009980677),
+                (-6.5481200409110025, 7.61923172032141),
+                (1e-08, 2e-08),
+                (3.626860407847019, 0.0),
+                (3.626860407847019, -0.0),
+                (-3.626860407847019, 0.0),
+                (-3.626860407847019, -0.0),
+                (0.0, 0.9092974268256817),
+                (0.0, -0.9092974268256817),
+            ],
+        ),
+        (
+            "cosh",
+            [
+                (1.0925708047319176, 0.12892104172809826),
+                (-0.9789478196465577, -1.936148329510507),
+                (-6.580663040551157, 7.581552742746545),
+                (0.9999999999999998, 2.0000000000000005e-16),
+                (3.7621956910836314, 0.0),
+                (3.7621956910836314, -0.0),
+                (3.7621956910836314, -0.0),
+                (3.7621956910836314, 0.0),
+                (-0.4161468365471424, -0.0),
+                (-0.4161468365471424, 0.0),
+            ],
+        ),
+        (
+            "tanh",
+            [
+                (0.4854872810241353, 0.19805544995134958),
+                (-1.064144399176537, -0.08039101531016819),
+                (1.000709536067233, -0.00490825806749606),
+                (1.0000000000000005e-08, 2.0000000000000004e-08),
+                (0.9640275800758169, 0.0),
+                (0.9640275800758169, -0.0),
+                (-0.9640275800758169, 0.0),
+                (-0.9640275800758169, -0.0),
+                (-0.0, -2.185039863261519),
+                (-0.0, 2.185039863261519),
+            ],
+        ),
+        (
+            "asin",
+            [
+                (0.5016088532755008, 0.28139605624529274),
+                (-0.6065115181997547, 1.6224941488715938),
+                (0.6339838656391766, -2.305509031243477),
+                (1e-08, 2e-08),
+                (1.5707963267948966, 1.3169578969248166),
+                (1.5707963267948966, -1.3169578969248166),
+                (-1.5707963267948966, 1.3169578969248166),
+                (-1.5707963267948966, -1.3169578969248166),
+                (-0.0, 1.4436354751788103),
+                (-0.0, -1.4436354751788103),
+            ],
+        ),
+        (
+            "acos",
+            [
+                (1.069187473519396, -0.28139605624529274),
+                (2.1773078449946515, -1.6224941488715938),
+                (0.9368124611557198, 2.305509031243477),
+                (1.5707963167948966, -2e-08),
+                (0.0, -1.3169578969248166),
+                (0.0, 1.3169578969248166),
+                (3.141592653589793, -1.3169578969248166),
+                (3.141592653589793, 1.3169578969248166),
+                (1.5707963267948966, -1.4436354751788103),
+                (1.5707963267948966, 1.4436354751788103),
+            ],
+        ),
+        (
+            "atan",
+            [
+                (0.4842544903299662, 0.20058661813123432),
+                (-1.311223269671635, 0.3104282830771958),
+                (1.4483069952314644, -0.15899719167999918),
+                (1.0000000000000004e-08, 2e-08),
+                (1.1071487177940904, 0.0),
+                (1.1071487177940904, -0.0),
+                (-1.1071487177940904, 0.0),
+                (-1.1071487177940904, -0.0),
+                (-1.5707963267948966, 0.5493061443340549),
+                (-1.5707963267948966, -0.5493061443340549),
+            ],
+        ),
+        (
+            "asinh",
+            [
+                (0.4926756834207706, 0.2243284526346675),
+                (-1.6004100552346137, 0.887765146183905),
+                (2.2999140408792695, -0.9176168533514787),
+                (1e-08, 2.0000000000000004e-08),
+                (1.4436354751788103, 0.0),
+                (1.4436354751788103, -0.0),
+                (-1.4436354751788103, 0.0),
+                (-1.4436354751788103, -0.0),
+                (-1.3169578969248166, 1.5707963267948966),
+                (-1.3169578969248166, -1.5707963267948966),
+            ],
+        ),
+        (
+            "acosh",
+            [
+                (0.28139605624529274, 1.069187473519396),
+                (1.6224941488715938, 2.1773078449946515),
+                (2.305509031243477, -0.9368124611557198),
+                (2e-08, 1.5707963167948966),
+                (1.3169578969248166, 0.0),
+                (1.3169578969248166, -0.0),
+                (1.3169578969248166, 3.141592653589793),
+                (1.3169578969248166, -3.141592653589793),
+                (1.4436354751788103, 1.5707963267948966),
+                (1.4436354751788103, -1.5707963267948966),
+            ],
+        ),
+        (
+            "atanh",
+            [
+                (0.5003700000525311, 0.3143981432077165),
+                (-0.22008968066202295, 1.2452579660726568),
+                (0.1175009073114339, -1.4099210495965755),
+                (9.999999999999997e-09, 2.0000000000000004e-08),
+                (0.5493061443340549, 1.5707963267948966),
+                (0.5493061443340549, -1.5707963267948966),
+                (-0.5493061443340549, 1.5707963267948966),
+                (-0.5493061443340549, -1.5707963267948966),
+                (-0.0, 1.1071487177940904),
+                (-0.0, -1.1071487177940904),
+            ],
+        ),
+    ];
+
+    #[test]
+    fn test_transcendental_reference() {
+        for (name, expected) in REFERENCE {
+            for ((a, b), (real, imag)) in POINTS.into_iter().zip(expected) {
+                let z = Complex::new(a, b);
+                let value = match name {
+                    "exp" => z.exp(),
+                    "ln" => z.ln(),
+                    "sqrt" => z.sqrt(),
+                    "sin" => z.sin(),
+                    "cos" => z.cos(),
+                    "tan" => z.tan(),
+                    "sinh" => z.sinh(),
+                    "cosh" => z.cosh(),
+                    "tanh" => z.tanh(),
+                    "asin" => z.asin(),
+                    "acos" => z.acos(),
+                    "atan" => z.atan(),
+                    "asinh" => z.asinh(),
+                    "acosh" => z.acosh(),
+                    _ => z.atanh(),
+                };
+                for (got, want) in [(value.real, real), (value.imag, imag)] {
+                    // Within a few ulps, and with the sign of the reference on the branch cuts.
+                    let close = (got - want).abs() <= 1e-15 * want.abs().max(1e-300) * 4.0;
+                    let same_side = want == 0.0 || got.signum() == want.signum();
+                    assert!(
+                        close && same_side,
+                        "{}({}) = {} but expected {} + {}i",
+                        name,
+                        z,
+                        value,
+                        real,
+                        imag
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_extreme_arguments() {
+        let (max, infinity) = (f64::MAX, f64::INFINITY);
+        // Reference values computed to high precision, near the singularities at ±1 and ±i and at the ends of the range.
+        type Case = (&'static str, (f64, f64), (f64, f64));
+        #[allow(clippy::approx_constant)]
+        let reference: [Case; 31] = [
+            (
+                "ln",
+                (0.7071067811865476, 0.7071067811865476),
+                (6.835808657661923e-17, 0.7853981633974483),
+            ),
+            (
+                "ln",
+                (0.6, 0.8),
+                (2.2204460492503132e-17, 0.9272952180016123),
+            ),
+            (
+                "ln",
+                (0.5000000000000001, 0.8660254037844386),
+                (1.2057961905251974e-17, 1.0471975511965976),
+            ),
+            (
+                "exp",
+                (709.5, -2.0),
+                (-5.638732703474406e307, -1.232085573536797e308),
+            ),
+            (
+                "sinh",
+                (710.0, 1.0),
+                (6.035162617272641e307, 9.399208879688907e307),
+            ),
+            (
+                "cosh",
+                (-710.3, 0.5),
+                (1.3232091189210214e308, -7.228724362505623e307),
+            ),
+            (
+                "sinh",
+                (-709.9, -3.0),
+                (1.0005864340855404e308, -1.4263013722591253e307),
+            ),
+            (
+                "sin",
+                (1.0, 710.0),
+                (9.399208879688907e307, 6.035162617272641e307),
+            ),
+            (
+                "cos",
+                (-0.5, -710.2),
+                (1.1972891226862429e308, -6.54082028786382e307),
+            ),
+            ("atanh", (-0.999999999, 0.0), (-10.708206522644144, 0.0)),
+            ("atanh", (-0.9999999, 0.0), (-8.40562139102231, 0.0)),
+            (
+                "atanh",
+                (-1.0, 1e-8),
+                (-9.556913962256155, 0.7853981658974483),
+            ),
+            (
+                "atanh",
+                (1.0, -1e-200),
+                (230.60508288968455, -0.7853981633974483),
+            ),
+            ("atanh", (1e-8, 1.0), (5e-9, 0.7853981633974484)),
+            ("atan", (1e-8, 1.0), (0.7853981658974483, 9.556913962256155)),
+            (
+                "atan",
+                (-1e-8, -1.0),
+                (-0.7853981658974483, -9.556913962256155),
+            ),
+            ("ln", (max, max), (710.1292864836639, 0.7853981633974483)),
+            ("asin", (max, max), (0.7853981633974483, 710.8224336642239)),
+            ("acos", (max, max), (0.7853981633974483, -710.8224336642239)),
+            ("asinh", (max, max), (710.8224336642239, 0.7853981633974483)),
+            ("acosh", (max, max), (710.8224336642239, 0.7853981633974483)),
+            (
+                "atan",
+                (max, max),
+                (1.5707963267948966, 2.781342323134e-309),
+            ),
+            (
+                "atanh",
+                (max, max),
+                (2.781342323134e-309, 1.5707963267948966),
+            ),
+            (
+                "asin",
+                (-max, -1.0),
+                (-1.5707963267948966, -710.475860073944),
+            ),
+            ("acos", (-max, 1.0), (3.141592653589793, -710.475860073944)),
+            (
+                "acosh",
+                (1.0, -max),
+                (710.475860073944, -1.5707963267948966),
+            ),
+            (
+                "asinh",
+                (1.0, -max),
+                (710.475860073944, -1.5707963267948966),
+            ),
+            (
+                "atanh",
+                (max, 1.0),
+                (5.562684646268003e-309, 1.5707963267948966),
+            ),
+            (
+                "sqrt",
+                (0.0, 5e-324),
+                (1.5717277847026288e-162, 1.5717277847026288e-162),
+            ),
+            (
+                "sqrt",
+                (-5e-324, -1e-323),
+                (1.747424853330061e-162, -2.8273928054743385e-162),
+            ),
+            (
+                "sqrt",
+                (1e-310, 1e-310),
+                (1.0986841134678082e-155, 4.5508986056222663e-156),
+            ),
+        ];
+        for (name, (a, b), (real, imag)) in reference {
+            let z = Complex::new(a, b);
+            let value = match name {
+                "ln" => z.ln(),
+                "exp" => z.exp(),
+                "sinh" => z.sinh(),
+                "cosh" => z.cosh(),
+                "sin" => z.sin(),
+                "cos" => z.cos(),
+                "sqrt" => z.sqrt(),
+                "asin" => z.asin(),
+                "acos" => z.acos(),
+                "atan" => z.atan(),
+                "asinh" => z.asinh(),
+                "acosh" => z.acosh(),
+                _ => z.atanh(),
+            };
+            for (got, want) in [(value.real, real), (value.imag, imag)] {
+                let close = (got - want).abs() <= 1e-15 * want.abs().max(1e-300) * 4.0;
+                let same_side = want == 0.0 || got.signum() == want.signum();
+                assert!(close && same_side, "{}({}) = {}", name, z, value);
+            }
+        }
+
+        // Only the part that overflows becomes infinite.
+        let exp = Complex::new(710.0, 1.0).exp();
+        assert!((exp.real - 1.2070325234545281e308).abs() <= 4e-15 * exp.real);
+        assert_eq!(exp.imag, infinity);
+
+        // Zero parts stay exactly zero where the other factor overflows.
+        assert_eq!(Complex::new(1000.0, 0.0).cosh().get(), (infinity, 0.0));
+        assert_eq!(Complex::new(1000.0, 0.0).sinh().get(), (infinity, 0.0));
+        assert_eq!(Complex::new(0.0, 1000.0).sin().get(), (0.0, infinity));
+        assert_eq!(Complex::new(0.0, 1000.0).cos().get(), (infinity, -0.0));
+        let cosh = Complex::new(-1000.0, 0.0).cosh();
+        assert!(cosh.get_real() == infinity && cosh.get_imag().is_sign_negative());
+        let sinh = Complex::new(-1000.0, -0.0).sinh();
+        assert!(sinh.get_real() == -infinity && sinh.get_imag().is_sign_negative());
+        let sin = Complex::new(-0.0, -1000.0).sin();
+        assert!(sin.get_real().is_sign_negative() && sin.get_imag() == -infinity);
+        let cos = Complex::new(-0.0, 1000.0).cos();
+        assert!(cos.get_real() == infinity && cos.get_imag().is_sign_positive());
+        assert_eq!(
+            Complex::new(max, max).ln().get_imag(),
+            std::f64::consts::FRAC_PI_4
+        );
+        assert_eq!(Complex::new(infinity, 1.0).ln().get_real(), infinity);
+    }
+
+    #[test]
+    fn test_roots_and_powers() {
+        let close = |a: Complex, b: Complex| (a - b).modulus() <= 1e-13 * b.modulus().max(1.0);
+        for z in [
+            Complex::new(3.0, -4.0),
+            Complex::new(-2.0, 0.5),
+            Complex::new(0.0, -1.0),
+        ] {
+            for n in 1..=6 {
+                let roots = z.nth_roots(n).unwrap();
+                assert_eq!(roots.len(), n as usize);
+                assert!(close(roots[0], z.powf(1.0 / n as f64)));
+                for root in roots {
+                    assert!(close(Ring::pow(&root, n), z));
+                }
+            }
+            assert!(close(z.sqrt() * z.sqrt(), z));
+            assert!(close(z.cbrt() * z.cbrt() * z.cbrt(), z));
+            assert!(close(z.ln().exp(), z));
+            assert!(close(z.powc(&Complex::new(2.0, 0.0)), z * z));
+            let (modulus, argument) = z.to_polar();
+            assert!(close(Complex::from_polar(modulus, argument), z));
+            assert!(close(z.sin() * z.sin() + z.cos() * z.cos(), Complex::one()));
+            assert!(close(
+                z.cosh() * z.cosh() - z.sinh() * z.sinh(),
+                Complex::one()
+            ));
+            for (inverse, function) in [
+                (z.asin(), Complex::sin as fn(&Complex) -> Complex),
+                (z.acos(), Complex::cos),
+                (z.atan(), Complex::tan),
+                (z.asinh(), Complex::sinh),
+                (z.acosh(), Complex::cosh),
+                (z.atanh(), Complex::tanh),
+            ] {
+                assert!(close(function(&inverse), z));
+            }
+        }
+        assert_eq!(Complex::zero().powc(&Complex::zero()), Complex::one());
+        assert_eq!(Complex::zero().powf(2.5), Complex::zero());
+        assert!(Complex::zero().powf(-1.0).get_real().is_nan());
+        assert_eq!(
+            Complex::new(1e308, 1e308).sqrt().get_real(),
+            1.09868411346781e154
+        );
+        assert_eq!(Complex::new(0.0, -800.0).tan(), Complex::new(0.0, -1.0));
+        assert_eq!(
+            Complex::new(1e300, 0.0).asinh().get_real(),
+            1e300f64.asinh()
+        );
+    }
+
+    #[test]
+    fn test_robust_division() {
+        // Exponents of the parts of the numerator and denominator, and the correctly rounded quotient.
+        type HardCase = ((i32, i32), (i32, i32), (f64, f64));
+        const HARD_CASES: [HardCase; 10] = [
+            (
+                (0, 0),
+                (0, 1023),
+                (1.1125369292536007e-308, -1.1125369292536007e-308),
+            ),
+            ((0, 0), (-1023, -1023), (8.98846567431158e307, 0.0)),
+            (
+                (1023, -1023),
+                (677, -677),
+                (1.4334366349937947e104, -3.645561009778199e-304),
+            ),
+            ((1023, 1023), (0, 0), (8.98846567431158e307, 0.0)),
+            ((1020, -844), (656, -780), (3.757668132438133e109, -2e-323)),
+            ((-71, 1021), (1001, -323), (2e-323, 1048576.0)),
+            (
+                (-347, -54),
+                (-1037, -1058),
+                (3.8981256045591133e289, 8.174961907852354e295),
+            ),
+            ((-1074, -1074), (-1073, -1074), (0.6, 0.2)),
+            ((1015, -989), (1023, 1023), (0.001953125, -0.001953125)),
+            (
+                (-622, -1071),
+                (-343, -798),
+                (1.0295115178936058e-84, 6.971459875150762e-220),
+            ),
+        ];
+        let power = |exponent: i32| {
+            if exponent < -1022 {
+                2f64.powi(exponent + 60) * 2f64.powi(-60)
+            } else {
+                2f64.powi(exponent)
+            }
+        };
+        let close = |got: f64, want: f64| {
+            (got - want).abs() <= (4.0 * f64::EPSILON * want.abs()).max(4.0 * f64::from_bits(1))
+        };
+        for ((a, b), (c, d), (real, imag)) in HARD_CASES {
+            let quotient =
+                Complex::new(power(a), power(b)).divide(&Complex::new(power(c), power(d)));
+            assert!(close(quotient.get_real(), real), "{:?}", (a, b, c, d));
+            assert!(close(quotient.get_imag(), imag), "{:?}", (a, b, c, d));
+        }
+        // The ratio of the divisor's parts is subnormal.
+        let quotient =
+            Complex::new(8.69e210, 3.19e-164).divide(&Complex::new(-2.43e121, -1.20e-198));
+        assert!(close(quotient.get_real(), -3.5761316872427983e89));
+        assert!(close(quotient.get_imag(), 1.7659909566631102e-230));
+        let huge = Complex::new(1e307, 1e307);
+        assert_eq!(huge.divide(&huge), Complex::one());
+        let huge = Complex::new(2f64.powi(1020), 2f64.powi(1020));
+        assert_eq!(
+            huge.inverse().get(),
+            (2f64.powi(-1021), -(2f64.powi(-1021)))
+        );
+        let tiny = Complex::new(1e-310, -1e-310);
+        assert_eq!(tiny.divide(&tiny), Complex::one());
+
+        let infinity = f64::INFINITY;
+        let nan = f64::NAN;
+        let quotient = Complex::new(1.0, 0.0).divide(&Complex::zero());
+        assert!(quotient.get_real().is_infinite());
+        let quotient = Complex::new(infinity, nan).divide(&Complex::new(2.0, 3.0));
+        assert!(quotient.get_real().is_infinite() || quotient.get_imag().is_infinite());
+        let quotient = Complex::new(2.0, 3.0).divide(&Complex::new(infinity, nan));
+        assert_eq!(quotient.get_real(), 0.0);
+        assert_eq!(quotient.get_imag(), 0.0);
+        let quotient = Complex::new(nan, 1.0).divide(&Complex::new(2.0, 3.0));
+        assert!(quotient.get_real().is_nan() && quotient.get_imag().is_nan());
+        assert!(Complex::zero().divide(&Complex::zero()).get_real().is_nan());
+
+        let quotient = Complex::new(-0.0, 0.0).divide(&Complex::new(1.0, 0.0));
+        assert!(quotient.get_real().is_sign_negative() && quotient.get_imag().is_sign_positive());
+        let quotient = Complex::new(1.0, -0.0).divide(&Complex::new(2.0, 0.0));
+        assert!(quotient.get_real() == 0.5 && quotient.get_imag().is_sign_negative());
+        let quotient = Complex::new(1.0, 0.0).divide(&Complex::new(-1.0, 0.0));
+        assert!(quotient.get_real() == -1.0 && quotient.get_imag().is_sign_negative());
+        let quotient = Complex::new(0.0, 1.0).divide(&Complex::new(0.0, -1.0));
+        assert!(quotient.get_real() == -1.0 && quotient.get_imag().is_sign_positive());
+
+        assert_eq!(Complex::new(3e300, 4e300).modulus(), 5e300);
+        assert_eq!(Complex::new(3e-320, 4e-320).modulus(), 5e-320);
+        assert_eq!(Complex::new(infinity, nan).modulus(), infinity);
+        let e = 2f64.powi(-30);
+        let z = Complex::new(1.0 + e, 1.0 - e);
+        let w = Complex::new(1.0 - e, -1.0 - e);
+        assert_eq!(
+            z.mul_add(&w, &Complex::new(-2.0, 0.0)).get_real(),
+            -2.0 * e * e
+        );
+        assert_eq!(z.multiply(&w).get_real() - 2.0, 0.0);
+    }
 }
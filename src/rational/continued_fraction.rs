@@ -0,0 +1,749 @@
+//! Simple continued fractions `a0 + 1/(a1 + 1/(a2 + ...))`, written `[a0; a1, a2, ...]`.
+//!
+//! A `ContinuedFraction` has a finite list of leading terms followed by an optional period that repeats forever. Rationals and
+//! finite floats have finite expansions; quadratic irrationals `(P + sqrt(D))/Q` have eventually periodic ones, written with the
+//! period in parentheses, as in `[1; (2)]` for the square root of two. Convergents and semiconvergents are produced as
+//! `Rational<T>` for any integer type, stopping when they no longer fit.
+//!
+//! `evaluate_generalized` and `generalized_convergents` handle generalized continued fractions `b0 + a1/(b1 + a2/(b2 + ...))`,
+//! which give quickly converging expansions of constants such as pi and e.
+//!
+//! # Examples
+//! ```
+//! use numbers_rus::rational::continued_fraction::ContinuedFraction;
+//! use numbers_rus::rational::rational_integer::Rational;
+//!
+//! let fraction = ContinuedFraction::from_rational(&Rational::new(415, 93)).unwrap();
+//! assert_eq!(fraction.to_string(), "[4; 2, 6, 7]");
+//!
+//! let root_two = ContinuedFraction::from_quadratic_surd(0, 2, 1).unwrap();
+//! assert_eq!(root_two.to_string(), "[1; (2)]");
+//! let convergents: Vec<Rational<i64>> = root_two.convergents().take(4).collect();
+//! assert_eq!(convergents[3], Rational::new(17, 12));
+//! ```
+use crate::error::{ErrorKind, NumbersError};
+use crate::integers::base;
+use crate::integers::big_integer::BigInt;
+use crate::rational::rational_integer::{self, Rational};
+use crate::traits::Integer;
+use std::collections::HashMap;
+use std::fmt;
+
+/// The largest number of terms `to_f64` reads from a periodic expansion. Every term at least doubles the denominator of
+/// the convergents every two steps, so this is far more than double precision needs.
+const MAX_FLOAT_TERMS: usize = 200;
+
+/// A simple continued fraction `[a0; a1, a2, ...]` with integer terms, where every term after the first is positive. The
+/// expansion is `terms` followed by `period` repeated forever, or just `terms` when `period` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_rus::rational::continued_fraction::ContinuedFraction;
+///
+/// let golden = ContinuedFraction::periodic(vec![1], vec![1]);
+/// assert_eq!(golden.to_string(), "[1; (1)]");
+/// assert_eq!(golden.iter().take(4).collect::<Vec<_>>(), vec![1, 1, 1, 1]);
+/// assert!((golden.to_f64() - (1.0 + 5f64.sqrt()) / 2.0).abs() < 1e-15);
+/// ```
+///
+/// # Methods
+///
+/// * `new(terms: Vec<i128>) -> Self`
+/// * `periodic(terms: Vec<i128>, period: Vec<i128>) -> Self`
+/// * `from_rational<T: Integer>(value: &Rational<T>) -> Result<Self, NumbersError>`
+/// * `from_f64(value: f64) -> Result<Self, NumbersError>`
+/// * `from_quadratic_surd(p: i128, d: i128, q: i128) -> Result<Self, NumbersError>`
+/// * `terms(&self) -> &[i128]`
+/// * `period(&self) -> &[i128]`
+/// * `is_periodic(&self) -> bool`
+/// * `term(&self, index: usize) -> Option<i128>`
+/// * `iter(&self) -> impl Iterator<Item = i128>`
+/// * `convergents<T: Integer>(&self) -> Convergents<T>`
+/// * `semiconvergents<T: Integer>(&self) -> impl Iterator<Item = Rational<T>>`
+/// * `to_rational<T: Integer>(&self) -> Result<Rational<T>, NumbersError>`
+/// * `to_f64(&self) -> f64`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContinuedFraction {
+    terms: Vec<i128>,
+    period: Vec<i128>,
+}
+
+impl fmt::Display for ContinuedFraction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut rest: Vec<String> = self.terms[1..]
+            .iter()
+            .map(|term| term.to_string())
+            .collect();
+        if self.is_periodic() {
+            let period: Vec<String> = self.period.iter().map(|term| term.to_string()).collect();
+            rest.push(format!("({})", period.join(", ")));
+        }
+        if rest.is_empty() {
+            write!(f, "[{}]", self.terms[0])
+        } else {
+            write!(f, "[{}; {}]", self.terms[0], rest.join(", "))
+        }
+    }
+}
+
+/// Panics unless every term is positive.
+fn check_positive(terms: &[i128]) {
+    if terms.iter().any(|&term| term <= 0) {
+        panic!("Continued fraction terms after the first must be positive!");
+    }
+}
+
+/// Converts a term into `T`, or returns `None` if it does not fit.
+fn to_integer<T: Integer>(term: i128) -> Option<T> {
+    T::from_big_int(&BigInt::from(term))
+}
+
+/// Returns `a * current + previous`, or `None` on overflow.
+fn checked_step<T: Integer>(a: &T, current: &T, previous: &T) -> Option<T> {
+    a.checked_mul(current)?.checked_add(previous)
+}
+
+impl ContinuedFraction {
+    /// Creates the finite continued fraction `[terms[0]; terms[1], ...]`. The terms are kept as given, so `[1; 1]` and `[2]`
+    /// are different values of this type even though they have the same value as numbers.
+    /// Panics if `terms` is empty or a term after the first is not positive.
+    ///
+    /// # Example
+    /// ```
+    /// use numbers_rus::rational::continued_fraction::ContinuedFraction;
+    /// use numbers_rus::rational::rational_integer::Rational;
+    ///
+    /// let fraction = ContinuedFraction::new(vec![0, 1, 2]);
+    /// assert_eq!(fraction.to_rational::<i32>(), Ok(Rational::new(2, 3)));
+    /// ```
+    pub fn new(terms: Vec<i128>) -> Self {
+        Self::periodic(terms, Vec::new())
+    }
+
+    /// Creates the continued fraction whose terms are `terms` followed by `period` repeated forever.
+    /// Panics if `terms` is empty or a term after the first is not positive.
+    ///
+    /// # Example
+    /// ```
+    /// use numbers_rus::rational::continued_fraction::ContinuedFraction;
+    ///
+    /// let root_three = ContinuedFraction::periodic(vec![1], vec![1, 2]);
+    /// assert_eq!(root_three, ContinuedFraction::from_quadratic_surd(0, 3, 1).unwrap());
+    /// ```
+    pub fn periodic(terms: Vec<i128>, period: Vec<i128>) -> Self {
+        if terms.is_empty() {
+            panic!("Continued fraction must have a first term!");
+        }
+        check_positive(&terms[1..]);
+        check_positive(&period);
+        ContinuedFraction { terms, period }
+    }
+
+    /// Returns the finite continued fraction of a rational. The last term is greater than one unless the value is an
+    /// integer, so the expansion is unique. Returns an `Overflow` error if a term does not fit in an i128, which can only
+    /// happen for `Rational<BigInt>`.
+    ///
+    /// # Example
+    /// ```
+    /// use numbers_rus::rational::continued_fraction::ContinuedFraction;
+    /// use numbers_rus::rational::rational_integer::Rational;
+    ///
+    /// let fraction = ContinuedFraction::from_rational(&Rational::new(-7, 3)).unwrap();
+    /// assert_eq!(fraction.terms(), &[-3, 1, 2]);
+    /// ```
+    pub fn from_rational<T: Integer>(value: &Rational<T>) -> Result<Self, NumbersError> {
+        let overflow = || NumbersError::new("from_rational", ErrorKind::Overflow);
+        let mut numerator = value.get_numerator().to_big_int();
+        let mut denominator = value.get_denominator().to_big_int();
+        let mut terms = Vec::new();
+        while !denominator.is_zero() {
+            let (quotient, remainder) = rational_integer::floor_div_rem(&numerator, &denominator);
+            terms.push(quotient.to_i128().ok_or_else(overflow)?);
+            (numerator, denominator) = (denominator, remainder);
+        }
+        Ok(ContinuedFraction {
+            terms,
+            period: Vec::new(),
+        })
+    }
+
+    /// Returns the finite continued fraction of the exact value of a float, so `0.1` expands its binary approximation
+    /// 3602879701896397/2^55 rather than 1/10. Returns a `NotRepresentable` error for infinities and NaN, and an `Overflow`
+    /// error if a term does not fit in an i128.
+    ///
+    /// # Example
+    /// ```
+    /// use numbers_rus::rational::continued_fraction::ContinuedFraction;
+    ///
+    /// assert_eq!(ContinuedFraction::from_f64(3.125).unwrap().to_string(), "[3; 8]");
+    /// assert_eq!(ContinuedFraction::from_f64(0.1).unwrap().terms()[..4], [0, 9, 1, 1801439850948197]);
+    /// assert!(ContinuedFraction::from_f64(f64::NAN).is_err());
+    /// ```
+    pub fn from_f64(value: f64) -> Result<Self, NumbersError> {
+        let exact = rational_integer::exact_big(value)
+            .ok_or(NumbersError::new("from_f64", ErrorKind::NotRepresentable))?;
+        Self::from_rational(&exact).map_err(|_| NumbersError::new("from_f64", ErrorKind::Overflow))
+    }
+
+    /// Returns the continued fraction of the quadratic surd `(p + sqrt(d)) / q`. The expansion is eventually periodic when `d`
+    /// is not a perfect square, and the period is found by remembering every complete quotient `(P_n + sqrt(D)) / Q_n`
+    /// until one repeats. Returns a `DivisionByZero` error if `q` is zero, a `NegativeArgument` error if `d` is negative and an
+    /// `Overflow` error if an intermediate value does not fit in an i128.
+    ///
+    /// # Example
+    /// ```
+    /// use numbers_rus::rational::continued_fraction::ContinuedFraction;
+    ///
+    /// assert_eq!(ContinuedFraction::from_quadratic_surd(0, 14, 1).unwrap().to_string(), "[3; (1, 2, 1, 6)]");
+    /// assert_eq!(ContinuedFraction::from_quadratic_surd(1, 5, 2).unwrap().to_string(), "[1; (1)]");
+    /// assert_eq!(ContinuedFraction::from_quadratic_surd(1, 9, 2).unwrap().to_string(), "[2]");
+    /// ```
+    pub fn from_quadratic_surd(p: i128, d: i128, q: i128) -> Result<Self, NumbersError> {
+        let overflow = || NumbersError::new("from_quadratic_surd", ErrorKind::Overflow);
+        if q == 0 {
+            return Err(NumbersError::new(
+                "from_quadratic_surd",
+                ErrorKind::DivisionByZero,
+            ));
+        }
+        if d < 0 {
+            return Err(NumbersError::new(
+                "from_quadratic_surd",
+                ErrorKind::NegativeArgument,
+            ));
+        }
+        let root = base::isqrt(d);
+        if root * root == d {
+            let numerator = p.checked_add(root).ok_or_else(overflow)?;
+            let value = Rational::checked_new(numerator, q).map_err(|_| overflow())?;
+            return Self::from_rational(&value);
+        }
+
+        // The recurrence needs q to divide d - p^2; scaling all three by |q| makes it do so.
+        let (mut p, mut d, mut q) = (p, d, q);
+        let divides = p
+            .checked_mul(p)
+            .and_then(|square| d.checked_sub(square))
+            .is_some_and(|difference| difference % q == 0);
+        if !divides {
+            let scale = q.abs();
+            p = p.checked_mul(scale).ok_or_else(overflow)?;
+            d = d
+                .checked_mul(scale)
+                .and_then(|value| value.checked_mul(scale))
+                .ok_or_else(overflow)?;
+            q = q.checked_mul(scale).ok_or_else(overflow)?;
+        }
+        let root = base::isqrt(d);
+
+        let mut seen = HashMap::new();
+        let mut terms = Vec::new();
+        while let std::collections::hash_map::Entry::Vacant(entry) = seen.entry((p, q)) {
+            entry.insert(terms.len());
+            // sqrt(d) lies strictly between root and root + 1, so the floor only depends on which side q is.
+            let top = p.checked_add(root).ok_or_else(overflow)?;
+            let top = if q > 0 {
+                top
+            } else {
+                top.checked_add(1).ok_or_else(overflow)?
+            };
+            let a = top.div_euclid(q) - i128::from(q < 0 && top.rem_euclid(q) != 0);
+            terms.push(a);
+            p = a
+                .checked_mul(q)
+                .and_then(|value| value.checked_sub(p))
+                .ok_or_else(overflow)?;
+            q = p
+                .checked_mul(p)
+                .and_then(|square| d.checked_sub(square))
+                .ok_or_else(overflow)?
+                / q;
+        }
+        let start = seen[&(p, q)];
+        let period = terms.split_off(start);
+        if terms.is_empty() {
+            // A purely periodic expansion still shows its first term before the semicolon.
+            terms.push(period[0]);
+            let mut period = period;
+            period.rotate_left(1);
+            return Ok(ContinuedFraction { terms, period });
+        }
+        Ok(ContinuedFraction { terms, period })
+    }
+
+    /// Returns the leading terms, which include the first term `a0`.
+    pub fn terms(&self) -> &[i128] {
+        &self.terms
+    }
+
+    /// Returns the repeating terms, which are empty for a finite continued fraction.
+    pub fn period(&self) -> &[i128] {
+        &self.period
+    }
+
+    /// Returns `true` if the expansion is infinite.
+    pub fn is_periodic(&self) -> bool {
+        !self.period.is_empty()
+    }
+
+    /// Returns the term `a_index`, or `None` past the end of a finite expansion.
+    ///
+    /// # Example
+    /// ```
+    /// use numbers_rus::rational::continued_fraction::ContinuedFraction;
+    ///
+    /// let root_seven = ContinuedFraction::from_quadratic_surd(0, 7, 1).unwrap();
+    /// assert_eq!(root_seven.term(0), Some(2));
+    /// assert_eq!(root_seven.term(8), Some(4));
+    /// assert_eq!(ContinuedFraction::new(vec![2, 3]).term(2), None);
+    /// ```
+    pub fn term(&self, index: usize) -> Option<i128> {
+        match self.terms.get(index) {
+            Some(&term) => Some(term),
+            None if self.is_periodic() => {
+                Some(self.period[(index - self.terms.len()) % self.period.len()])
+            }
+            None => None,
+        }
+    }
+
+    /// Returns an iterator over the terms, which never ends for a periodic expansion.
+    pub fn iter(&self) -> impl Iterator<Item = i128> + '_ {
+        self.terms.iter().chain(self.period.iter().cycle()).copied()
+    }
+
+    /// Returns an iterator over the convergents `p_n / q_n`, the values of the expansion cut off after the term `a_n`. The
+    /// iterator ends with the value itself for a finite expansion, and stops early once a convergent no longer fits in `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use numbers_rus::rational::continued_fraction::ContinuedFraction;
+    /// use numbers_rus::rational::rational_integer::Rational;
+    ///
+    /// let fraction = ContinuedFraction::new(vec![3, 7, 15, 1, 292]);
+    /// let convergents: Vec<Rational> = fraction.convergents().collect();
+    /// assert_eq!(convergents[1], Rational::new(22, 7));
+    /// assert_eq!(convergents[3], Rational::new(355, 113));
+    ///
+    /// let root_two = ContinuedFraction::from_quadratic_surd(0, 2, 1).unwrap();
+    /// assert_eq!(root_two.convergents::<i32>().count(), 25);
+    /// ```
+    pub fn convergents<T: Integer>(&self) -> Convergents<T> {
+        Convergents {
+            fraction: self.clone(),
+            index: 0,
+            previous: (T::zero(), T::one()),
+            current: (T::one(), T::zero()),
+        }
+    }
+
+    /// Returns an iterator over the semiconvergents `(p_{n-2} + k p_{n-1}) / (q_{n-2} + k q_{n-1})` for `n >= 1` and
+    /// `1 <= k < a_n`, the intermediate fractions that lie between consecutive convergents. Together with the convergents
+    /// they are all the best one-sided approximations. The iterator stops once a value no longer fits in `T`, or once all the
+    /// remaining terms are 1, as for the golden ratio, since then there are no more semiconvergents.
+    ///
+    /// # Example
+    /// ```
+    /// use numbers_rus::rational::continued_fraction::ContinuedFraction;
+    /// use numbers_rus::rational::rational_integer::Rational;
+    ///
+    /// let fraction = ContinuedFraction::new(vec![0, 1, 3, 2]);
+    /// let semiconvergents: Vec<Rational> = fraction.semiconvergents().collect();
+    /// assert_eq!(semiconvergents, vec![Rational::new(1, 2), Rational::new(2, 3), Rational::new(4, 5)]);
+    /// ```
+    pub fn semiconvergents<T: Integer>(&self) -> impl Iterator<Item = Rational<T>> {
+        let mut convergents = self.convergents::<T>();
+        let mut index = 0;
+        let mut k = T::zero();
+        let mut done = false;
+        // The index from which every term is 1, so that no semiconvergent follows however large `T` is.
+        let ones_from = if self.is_periodic() && self.period.iter().all(|&term| term == 1) {
+            self.terms
+                .iter()
+                .rposition(|&term| term != 1)
+                .map_or(0, |last| last + 1)
+        } else {
+            usize::MAX
+        };
+        std::iter::from_fn(move || {
+            while !done && (index == 0 || index < ones_from) {
+                // At this point convergents.current is p_{n-1}/q_{n-1} for n = index.
+                let term = convergents.fraction.term(index).and_then(to_integer::<T>);
+                let term = match term {
+                    Some(term) if index > 0 => term,
+                    Some(_) => {
+                        done = convergents.next().is_none();
+                        index += 1;
+                        continue;
+                    }
+                    None => break,
+                };
+                k = k.add(&T::one());
+                if k < term {
+                    let (previous, current) = (&convergents.previous, &convergents.current);
+                    let value = checked_step(&k, &current.0, &previous.0).zip(checked_step(
+                        &k,
+                        &current.1,
+                        &previous.1,
+                    ));
+                    match value {
+                        Some((numerator, denominator)) => {
+                            return Some(Rational::new(numerator, denominator))
+                        }
+                        None => break,
+                    }
+                }
+                k = T::zero();
+                done = convergents.next().is_none();
+                index += 1;
+            }
+            done = true;
+            None
+        })
+    }
+
+    /// Returns the value of a finite expansion as a rational. Returns a `NotRepresentable` error for a periodic expansion,
+    /// whose value is irrational, and an `Overflow` error if the value does not fit in `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use numbers_rus::rational::continued_fraction::ContinuedFraction;
+    /// use numbers_rus::rational::rational_integer::Rational;
+    ///
+    /// let fraction = ContinuedFraction::new(vec![4, 2, 6, 7]);
+    /// assert_eq!(fraction.to_rational::<i32>(), Ok(Rational::new(415, 93)));
+    /// assert!(ContinuedFraction::periodic(vec![1], vec![2]).to_rational::<i32>().is_err());
+    /// ```
+    pub fn to_rational<T: Integer>(&self) -> Result<Rational<T>, NumbersError> {
+        if self.is_periodic() {
+            return Err(NumbersError::new(
+                "to_rational",
+                ErrorKind::NotRepresentable,
+            ));
+        }
+        let mut convergents = self.convergents::<T>();
+        let value = convergents.by_ref().last();
+        match value {
+            Some(value) if convergents.index == self.terms.len() => Ok(value),
+            _ => Err(NumbersError::new("to_rational", ErrorKind::Overflow)),
+        }
+    }
+
+    /// Returns the nearest `f64` to the value. Finite expansions are evaluated exactly and rounded once; periodic ones are
+    /// evaluated from their convergents until the value stops changing.
+    ///
+    /// # Example
+    /// ```
+    /// use numbers_rus::rational::continued_fraction::ContinuedFraction;
+    ///
+    /// assert_eq!(ContinuedFraction::new(vec![0, 4]).to_f64(), 0.25);
+    /// assert_eq!(ContinuedFraction::from_quadratic_surd(0, 2, 1).unwrap().to_f64(), 2f64.sqrt());
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        if !self.is_periodic() {
+            return self
+                .to_rational::<BigInt>()
+                .expect("finite continued fractions have a big rational value")
+                .to_float();
+        }
+        let (mut p0, mut q0) = (1.0, 0.0);
+        let (mut p1, mut q1) = (self.terms[0] as f64, 1.0);
+        let mut value = p1;
+        for term in self.iter().skip(1).take(MAX_FLOAT_TERMS) {
+            let term = term as f64;
+            (p0, q0, p1, q1) = (p1, q1, term * p1 + p0, term * q1 + q0);
+            if q1 > 1e150 {
+                // Rescale to keep the recurrence in range; only the ratios matter.
+                (p0, q0, p1, q1) = (p0 * 1e-150, q0 * 1e-150, p1 * 1e-150, q1 * 1e-150);
+            }
+            let next = p1 / q1;
+            if next == value {
+                break;
+            }
+            value = next;
+        }
+        value
+    }
+}
+
+/// An iterator over the convergents of a continued fraction, created by `ContinuedFraction::convergents`.
+///
+/// # Example
+/// ```
+/// use numbers_rus::rational::continued_fraction::ContinuedFraction;
+/// use numbers_rus::rational::rational_integer::Rational;
+///
+/// let golden = ContinuedFraction::periodic(vec![1], vec![1]);
+/// let ratios: Vec<Rational<i64>> = golden.convergents().take(5).collect();
+/// assert_eq!(ratios[4], Rational::new(8, 5));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Convergents<T: Integer> {
+    fraction: ContinuedFraction,
+    index: usize,
+    previous: (T, T),
+    current: (T, T),
+}
+
+impl<T: Integer> Iterator for Convergents<T> {
+    type Item = Rational<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let term = to_integer::<T>(self.fraction.term(self.index)?)?;
+        let numerator = checked_step(&term, &self.current.0, &self.previous.0)?;
+        let denominator = checked_step(&term, &self.current.1, &self.previous.1)?;
+        self.previous = std::mem::replace(&mut self.current, (numerator, denominator));
+        self.index += 1;
+        Some(Rational::new(
+            self.current.0.clone(),
+            self.current.1.clone(),
+        ))
+    }
+}
+
+/// `evaluate_generalized` function: Takes a first term `b0`, a function returning the pair `(a_n, b_n)` for `n = 1, 2, ...`,
+/// a relative tolerance and a maximum number of terms as input and returns the value of the generalized continued fraction
+/// `b0 + a1/(b1 + a2/(b2 + ...))`, or `None` if it has not converged within `max_terms` terms. The fraction is evaluated
+/// forwards with the modified Lentz algorithm, which stops once a term changes the value by a factor within `tolerance`
+/// of one.
+///
+/// # Example
+/// ```
+/// use numbers_rus::rational::continued_fraction::evaluate_generalized;
+///
+/// // pi = 4/(1 + 1^2/(3 + 2^2/(5 + 3^2/(7 + ...))))
+/// let terms = |n: usize| match n {
+///     1 => (4.0, 1.0),
+///     _ => (((n - 1) * (n - 1)) as f64, (2 * n - 1) as f64),
+/// };
+/// let pi = evaluate_generalized(0.0, terms, 1e-15, 100);
+/// assert!((pi.unwrap() - std::f64::consts::PI).abs() < 1e-14);
+///
+/// // e = 2 + 1/(1 + 1/(2 + 2/(3 + 3/(4 + ...))))
+/// let e = evaluate_generalized(2.0, |n| ((n.max(2) - 1) as f64, n as f64), 1e-15, 100);
+/// assert!((e.unwrap() - std::f64::consts::E).abs() < 1e-14);
+/// ```
+pub fn evaluate_generalized<F>(
+    b0: f64,
+    mut terms: F,
+    tolerance: f64,
+    max_terms: usize,
+) -> Option<f64>
+where
+    F: FnMut(usize) -> (f64, f64),
+{
+    // Stands in for zero denominators so the recurrence can step over them.
+    const TINY: f64 = 1e-300;
+    let mut value = if b0 == 0.0 { TINY } else { b0 };
+    let (mut c, mut d) = (value, 0.0);
+    for n in 1..=max_terms {
+        let (a, b) = terms(n);
+        d = b + a * d;
+        if d == 0.0 {
+            d = TINY;
+        }
+        c = b + a / c;
+        if c == 0.0 {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta = c * d;
+        value *= delta;
+        if (delta - 1.0).abs() <= tolerance {
+            return Some(value);
+        }
+    }
+    None
+}
+
+/// `generalized_convergents` function: Takes a first term `b0` and a slice of pairs `(a_n, b_n)` as input and returns the
+/// exact convergents `b0`, `b0 + a1/b1`, `b0 + a1/(b1 + a2/b2)`, ... of the generalized continued fraction as rationals,
+/// computed with the recurrences `A_n = b_n A_{n-1} + a_n A_{n-2}` and `B_n = b_n B_{n-1} + a_n B_{n-2}`. Returns a
+/// `DivisionByZero` error if some `B_n` is zero and an `Overflow` error if a value does not fit in `T`.
+///
+/// # Example
+/// ```
+/// use numbers_rus::rational::continued_fraction::generalized_convergents;
+/// use numbers_rus::rational::rational_integer::Rational;
+///
+/// let e = generalized_convergents(2, &[(1, 1), (1, 2), (2, 3), (3, 4)]).unwrap();
+/// assert_eq!(e, vec![Rational::from(2), Rational::from(3), Rational::new(8, 3), Rational::new(30, 11), Rational::new(144, 53)]);
+/// ```
+pub fn generalized_convergents<T: Integer>(
+    b0: T,
+    terms: &[(T, T)],
+) -> Result<Vec<Rational<T>>, NumbersError> {
+    let overflow = || NumbersError::new("generalized_convergents", ErrorKind::Overflow);
+    let (mut a_previous, mut b_previous) = (T::one(), T::zero());
+    let (mut a_current, mut b_current) = (b0.clone(), T::one());
+    let mut convergents = vec![Rational::from_integer(b0)];
+    for (a, b) in terms {
+        let a_next = b
+            .checked_mul(&a_current)
+            .zip(a.checked_mul(&a_previous))
+            .and_then(|(left, right)| left.checked_add(&right))
+            .ok_or_else(overflow)?;
+        let b_next = b
+            .checked_mul(&b_current)
+            .zip(a.checked_mul(&b_previous))
+            .and_then(|(left, right)| left.checked_add(&right))
+            .ok_or_else(overflow)?;
+        convergents.push(
+            Rational::checked_new(a_next.clone(), b_next.clone())
+                .map_err(|error| NumbersError::new("generalized_convergents", error.kind()))?,
+        );
+        (a_previous, a_current) = (a_current, a_next);
+        (b_previous, b_current) = (b_current, b_next);
+    }
+    Ok(convergents)
+}
+
+#[cfg(test)]
+pub mod test_continued_fraction {
+    use super::*;
+
+    #[test]
+    fn test_from_rational() {
+        let fraction = ContinuedFraction::from_rational(&Rational::new(415, 93)).unwrap();
+        assert_eq!(fraction.terms(), &[4, 2, 6, 7]);
+        assert!(!fraction.is_periodic());
+        assert_eq!(
+            ContinuedFraction::from_rational(&Rational::from(-5))
+                .unwrap()
+                .to_string(),
+            "[-5]"
+        );
+        for numerator in -30i32..=30 {
+            for denominator in 1..=30 {
+                let value = Rational::new(numerator, denominator);
+                let fraction = ContinuedFraction::from_rational(&value).unwrap();
+                assert_eq!(fraction.to_rational::<i32>(), Ok(value));
+                if fraction.terms().len() > 1 {
+                    assert!(*fraction.terms().last().unwrap() > 1);
+                }
+            }
+        }
+        let big = Rational::new(BigInt::one() << 200usize, BigInt::from(3));
+        assert!(ContinuedFraction::from_rational(&big).is_err());
+    }
+    #[test]
+    fn test_from_f64() {
+        for value in [0.1, -2.75, 1e-30, 123456.789, std::f64::consts::PI] {
+            let fraction = ContinuedFraction::from_f64(value).unwrap();
+            assert_eq!(fraction.to_f64(), value);
+        }
+        assert_eq!(
+            ContinuedFraction::from_f64(std::f64::consts::PI)
+                .unwrap()
+                .terms()[..5],
+            [3, 7, 15, 1, 292]
+        );
+        assert!(ContinuedFraction::from_f64(f64::INFINITY).is_err());
+        assert!(ContinuedFraction::from_f64(1e300).is_err());
+    }
+    #[test]
+    fn test_quadratic_surd() {
+        let expansions = [
+            ((0, 2, 1), "[1; (2)]"),
+            ((0, 3, 1), "[1; (1, 2)]"),
+            ((0, 14, 1), "[3; (1, 2, 1, 6)]"),
+            ((0, 31, 1), "[5; (1, 1, 3, 5, 3, 1, 1, 10)]"),
+            ((1, 5, 2), "[1; (1)]"),
+            ((1, 3, 2), "[1; (2, 1)]"),
+            ((0, 2, -1), "[-2; 1, 1, (2)]"),
+            ((3, 7, 5), "[1; 7, (1, 2, 1, 8, 13, 8)]"),
+            ((0, 16, 3), "[1; 3]"),
+        ];
+        for ((p, d, q), expected) in expansions {
+            let fraction = ContinuedFraction::from_quadratic_surd(p, d, q).unwrap();
+            assert_eq!(fraction.to_string(), expected);
+            let value = (p as f64 + (d as f64).sqrt()) / q as f64;
+            assert!((fraction.to_f64() - value).abs() < 1e-14, "{}", expected);
+        }
+        assert!(ContinuedFraction::from_quadratic_surd(0, 2, 0).is_err());
+        assert!(ContinuedFraction::from_quadratic_surd(0, -2, 1).is_err());
+        // -i128::MIN does not fit once the sign moves to the numerator.
+        assert_eq!(
+            ContinuedFraction::from_quadratic_surd(i128::MIN, 0, -1)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::Overflow
+        );
+        assert_eq!(
+            ContinuedFraction::from_quadratic_surd(i128::MIN + 1, 0, -1)
+                .unwrap()
+                .to_string(),
+            format!("[{}]", i128::MAX)
+        );
+    }
+    #[test]
+    fn test_convergents() {
+        let root_two = ContinuedFraction::from_quadratic_surd(0, 2, 1).unwrap();
+        let convergents: Vec<Rational> = root_two.convergents().take(5).collect();
+        let expected = [(1, 1), (3, 2), (7, 5), (17, 12), (41, 29)];
+        for (convergent, (p, q)) in convergents.iter().zip(expected) {
+            assert_eq!(*convergent, Rational::new(p, q));
+        }
+        // Consecutive convergents satisfy p_n q_{n-1} - p_{n-1} q_n = (-1)^(n-1).
+        let convergents: Vec<Rational<i64>> = root_two.convergents().collect();
+        for pair in convergents.windows(2) {
+            let (p0, q0) = (
+                pair[0].get_numerator() as i128,
+                pair[0].get_denominator() as i128,
+            );
+            let (p1, q1) = (
+                pair[1].get_numerator() as i128,
+                pair[1].get_denominator() as i128,
+            );
+            let determinant = p1 * q0 - p0 * q1;
+            assert_eq!(determinant.abs(), 1);
+        }
+        assert!(root_two.convergents::<BigInt>().nth(200).is_some());
+        assert_eq!(
+            ContinuedFraction::new(vec![2]).convergents::<i32>().count(),
+            1
+        );
+    }
+    #[test]
+    fn test_semiconvergents() {
+        let fraction = ContinuedFraction::new(vec![4, 2, 6, 7]);
+        let semiconvergents: Vec<Rational> = fraction.semiconvergents().collect();
+        let mut expected = vec![Rational::from(5)];
+        expected.extend((1..6).map(|k| Rational::new(4 + 9 * k, 1 + 2 * k)));
+        expected.extend((1..7).map(|k| Rational::new(9 + 58 * k, 2 + 13 * k)));
+        assert_eq!(semiconvergents, expected);
+        let root_two = ContinuedFraction::from_quadratic_surd(0, 2, 1).unwrap();
+        let semiconvergents: Vec<Rational> = root_two.semiconvergents().take(3).collect();
+        assert_eq!(
+            semiconvergents,
+            vec![Rational::from(2), Rational::new(4, 3), Rational::new(10, 7)]
+        );
+        // Tails of ones have no semiconvergents, which must not loop forever for unbounded integers.
+        let golden = ContinuedFraction::periodic(vec![1], vec![1]);
+        assert_eq!(golden.semiconvergents::<BigInt>().next(), None);
+        let golden = ContinuedFraction::from_quadratic_surd(1, 5, 2).unwrap();
+        assert_eq!(golden.semiconvergents::<BigInt>().count(), 0);
+        let fraction = ContinuedFraction::periodic(vec![2, 3, 1], vec![1, 1]);
+        let semiconvergents: Vec<Rational<BigInt>> = fraction.semiconvergents().collect();
+        assert_eq!(
+            semiconvergents,
+            vec![
+                Rational::new(BigInt::from(3), BigInt::one()),
+                Rational::new(BigInt::from(5), BigInt::from(2))
+            ]
+        );
+    }
+    #[test]
+    fn test_generalized() {
+        let root_two = evaluate_generalized(1.0, |_| (1.0, 2.0), 1e-16, 100).unwrap();
+        assert!((root_two - 2f64.sqrt()).abs() < 1e-15);
+        assert_eq!(
+            evaluate_generalized(0.0, |n| (1.0, n as f64), 1e-30, 10),
+            None
+        );
+        let convergents = generalized_convergents(1i64, &[(1, 2), (1, 2), (1, 2)]).unwrap();
+        assert_eq!(convergents[3], Rational::new(17, 12));
+        assert!(generalized_convergents(0, &[(1, 1), (-1, 1)]).is_err());
+    }
+}
@@ -0,0 +1,583 @@
+//! Positional expansions of rationals: exact repeating decimals in any base from 2 to 36, rounded decimals with a choice of
+//! rounding mode, and mixed numbers.
+//!
+//! The exact expansion of `n/d` ends or repeats forever. Its non-repeating part has one digit for every factor of the base
+//! that has to be divided out of `d`, and the repeating part is found by running the long division until the remainder
+//! comes back to where the cycle started. Repeating digits are written in parentheses, so `1/6` is `0.1(6)`.
+//!
+//! `Rational::from_str_radix` and `FromStr` read the same notations back, as well as fractions, mixed numbers and
+//! scientific notation.
+//!
+//! # Examples
+//! ```
+//! use numbers_rus::rational::decimal::RoundingMode;
+//! use numbers_rus::rational::rational_integer::Rational;
+//!
+//! let a = Rational::new(1, 7);
+//! assert_eq!(a.to_expansion(10).to_string(), "0.(142857)");
+//! assert_eq!(a.to_decimal_string(10, 3, RoundingMode::HalfEven), "0.143");
+//! assert_eq!(Rational::new(7, 3).to_mixed_string(), "2 1/3");
+//!
+//! let b: Rational = "0.1(6)".parse().unwrap();
+//! assert_eq!(b, Rational::new(1, 6));
+//! assert_eq!("-2 1/3".parse::<Rational>(), Ok(Rational::new(-7, 3)));
+//! assert_eq!("1.25e-2".parse::<Rational>(), Ok(Rational::new(1, 80)));
+//! ```
+use crate::integers::big_integer::BigInt;
+use crate::rational::rational_integer::{self, ParseRationalError, Rational};
+use crate::traits::{self, Integer};
+use std::fmt;
+
+/// The largest exponent magnitude accepted in scientific notation, which keeps parsing from building enormous powers.
+const MAX_EXPONENT: u64 = 10_000;
+
+/// How `to_decimal_string` rounds a value that falls between two representable results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Toward zero.
+    Down,
+    /// Away from zero.
+    Up,
+    /// Toward negative infinity.
+    Floor,
+    /// Toward positive infinity.
+    Ceiling,
+    /// To the nearest result, with ties toward zero.
+    HalfDown,
+    /// To the nearest result, with ties away from zero.
+    HalfUp,
+    /// To the nearest result, with ties to an even last digit.
+    HalfEven,
+}
+
+/// The exact positional expansion of a rational in some base: an integer part, a non-repeating fractional part and a
+/// repeating fractional part, which is empty when the expansion ends.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_rus::rational::rational_integer::Rational;
+///
+/// let expansion = Rational::new(-25, 12).to_expansion(10);
+/// assert_eq!(expansion.to_string(), "-2.08(3)");
+/// assert_eq!(expansion.fraction_digits(), &[0, 8]);
+/// assert_eq!(expansion.repeating_digits(), &[3]);
+/// assert_eq!(Rational::new(5, 8).to_expansion(2).to_string(), "0.101");
+/// ```
+///
+/// # Methods
+///
+/// * `is_negative(&self) -> bool`
+/// * `integer_part(&self) -> &BigInt` - the magnitude of the integer part
+/// * `fraction_digits(&self) -> &[u32]`
+/// * `repeating_digits(&self) -> &[u32]`
+/// * `radix(&self) -> u32`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DecimalExpansion {
+    negative: bool,
+    integer: BigInt,
+    fraction: Vec<u32>,
+    repeating: Vec<u32>,
+    radix: u32,
+}
+
+impl DecimalExpansion {
+    /// Returns `true` if the value is negative.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the magnitude of the integer part.
+    pub fn integer_part(&self) -> &BigInt {
+        &self.integer
+    }
+
+    /// Returns the fractional digits before the repeating part.
+    pub fn fraction_digits(&self) -> &[u32] {
+        &self.fraction
+    }
+
+    /// Returns the digits that repeat forever, which are empty for a terminating expansion.
+    pub fn repeating_digits(&self) -> &[u32] {
+        &self.repeating
+    }
+
+    /// Returns the base of the expansion.
+    pub fn radix(&self) -> u32 {
+        self.radix
+    }
+}
+
+impl fmt::Display for DecimalExpansion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = |digits: &[u32]| -> String {
+            digits
+                .iter()
+                .map(|&digit| std::char::from_digit(digit, self.radix).unwrap())
+                .collect()
+        };
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", self.integer.to_str_radix(self.radix))?;
+        if !self.fraction.is_empty() || !self.repeating.is_empty() {
+            write!(f, ".{}", digits(&self.fraction))?;
+        }
+        if !self.repeating.is_empty() {
+            write!(f, "({})", digits(&self.repeating))?;
+        }
+        Ok(())
+    }
+}
+
+/// Panics unless `radix` is in the range 2 to 36.
+fn check_radix(radix: u32) {
+    assert!((2..=36).contains(&radix), "Radix must be between 2 and 36");
+}
+
+/// Returns the next digit of the long division of `remainder / denominator`, updating the remainder.
+fn next_digit(remainder: &mut BigInt, denominator: &BigInt, radix: &BigInt) -> u32 {
+    let (digit, rest) = (&*remainder * radix).div_rem(denominator);
+    *remainder = rest;
+    digit.to_u64().expect("digits are below the radix") as u32
+}
+
+/// Parses an unsigned run of digits in `radix`, rejecting signs, separators and empty strings.
+fn parse_digits(text: &str, radix: u32) -> Option<BigInt> {
+    if text.is_empty() || !text.chars().all(|c| c.is_digit(radix)) {
+        return None;
+    }
+    BigInt::from_str_radix(text, radix).ok()
+}
+
+/// Parses an integer with an optional sign.
+fn parse_signed(text: &str, radix: u32) -> Option<BigInt> {
+    match text.strip_prefix('-') {
+        Some(digits) => parse_digits(digits, radix).map(|value| -value),
+        None => parse_digits(text.strip_prefix('+').unwrap_or(text), radix),
+    }
+}
+
+/// Parses a fraction `p/q` with signed parts, or a mixed number `w p/q` whose sign is that of the whole part, into its
+/// numerator and denominator.
+fn parse_fraction(
+    numerator: &str,
+    denominator: &str,
+    radix: u32,
+) -> Result<(BigInt, BigInt), ParseRationalError> {
+    let invalid = || ParseRationalError::new("invalid rational number");
+    let (numerator, denominator) = (numerator.trim(), denominator.trim());
+    match numerator.rsplit_once(char::is_whitespace) {
+        Some((whole, part)) => {
+            let whole = whole.trim();
+            let negative = whole.starts_with('-');
+            let whole = parse_signed(whole, radix).ok_or_else(invalid)?;
+            let part = parse_digits(part, radix).ok_or_else(invalid)?;
+            let denominator = parse_digits(denominator, radix).ok_or_else(invalid)?;
+            let magnitude = &(&whole.abs() * &denominator) + &part;
+            Ok((if negative { -magnitude } else { magnitude }, denominator))
+        }
+        None => Ok((
+            parse_signed(numerator, radix).ok_or_else(invalid)?,
+            parse_signed(denominator, radix).ok_or_else(invalid)?,
+        )),
+    }
+}
+
+/// Parses a positional number `int.frac(rep)` with an optional sign and, in bases up to 10, an exponent `e<n>` that scales by
+/// `radix^n`, into its numerator and denominator.
+fn parse_positional(text: &str, radix: u32) -> Result<(BigInt, BigInt), ParseRationalError> {
+    let invalid = || ParseRationalError::new("invalid rational number");
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let (mantissa, exponent) = match text.find(['e', 'E']).filter(|_| radix <= 10) {
+        Some(index) => {
+            let exponent: i64 = text[index + 1..].parse().map_err(|_| invalid())?;
+            if exponent.unsigned_abs() > MAX_EXPONENT {
+                return Err(ParseRationalError::new("exponent out of range"));
+            }
+            (&text[..index], exponent)
+        }
+        None => (text, 0),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let (fraction, repeating) = match fraction.split_once('(') {
+        Some((fraction, repeating)) => (fraction, repeating.strip_suffix(')').ok_or_else(invalid)?),
+        None => (fraction, ""),
+    };
+    if integer.is_empty() && fraction.is_empty() && repeating.is_empty() {
+        return Err(invalid());
+    }
+    let digits = |text: &str| {
+        if text.is_empty() {
+            Some(BigInt::zero())
+        } else {
+            parse_digits(text, radix)
+        }
+    };
+    let integer = digits(integer).ok_or_else(invalid)?;
+    let fraction_value = digits(fraction).ok_or_else(invalid)?;
+    let base = BigInt::from(radix);
+    let scale = base.pow(fraction.len() as u64);
+    let mut numerator = &(&integer * &scale) + &fraction_value;
+    let mut denominator = scale;
+    if mantissa.contains('(') {
+        // x = a/s + r/(s (b^k - 1)), where the repeating block r has k digits.
+        let cycle = &base.pow(repeating.len() as u64) - &BigInt::one();
+        let repeating = parse_digits(repeating, radix).ok_or_else(invalid)?;
+        numerator = &(&numerator * &cycle) + &repeating;
+        denominator = &denominator * &cycle;
+    }
+    let power = base.pow(exponent.unsigned_abs());
+    if exponent >= 0 {
+        numerator = &numerator * &power;
+    } else {
+        denominator = &denominator * &power;
+    }
+    Ok((if negative { -numerator } else { numerator }, denominator))
+}
+
+/// Parses any of the notations accepted by `Rational::from_str_radix` into its numerator and denominator.
+pub(crate) fn parse_exact(text: &str, radix: u32) -> Result<(BigInt, BigInt), ParseRationalError> {
+    let text = text.trim();
+    let (numerator, denominator) = match text.split_once('/') {
+        Some((numerator, denominator)) => parse_fraction(numerator, denominator, radix)?,
+        None => parse_positional(text, radix)?,
+    };
+    if denominator.is_zero() {
+        return Err(ParseRationalError::new("zero denominator"));
+    }
+    Ok((numerator, denominator))
+}
+
+impl<T: Integer> Rational<T> {
+    /// Returns the exact expansion of the rational in base `radix`, with the repeating digits found by running the long
+    /// division until the remainder repeats. The repeating part of `n/d` can have up to `d - 1` digits, and each digit is a
+    /// big integer division, so a large prime denominator such as `2^127 - 1` takes too long to expand; use
+    /// `to_expansion_bounded` for denominators that are not known to be small.
+    /// Panics if `radix` is not in the range 2 to 36.
+    ///
+    /// # Example
+    /// ```
+    /// use numbers_rus::rational::rational_integer::Rational;
+    ///
+    /// assert_eq!(Rational::new(1, 7).to_expansion(10).to_string(), "0.(142857)");
+    /// assert_eq!(Rational::new(1, 3).to_expansion(3).to_string(), "0.1");
+    /// assert_eq!(Rational::new(1, 10).to_expansion(2).to_string(), "0.0(0011)");
+    /// assert_eq!(Rational::new(255, 1).to_expansion(16).to_string(), "ff");
+    /// ```
+    pub fn to_expansion(&self, radix: u32) -> DecimalExpansion {
+        self.to_expansion_bounded(radix, usize::MAX)
+            .expect("an unbounded expansion always ends")
+    }
+
+    /// Returns the exact expansion of the rational in base `radix` like `to_expansion`, or `None` if it needs more than
+    /// `max_digits` digits after the point, counting the repeating digits once. The long division runs for at most
+    /// `max_digits` digits.
+    /// Panics if `radix` is not in the range 2 to 36.
+    ///
+    /// # Example
+    /// ```
+    /// use numbers_rus::rational::rational_integer::Rational;
+    ///
+    /// assert_eq!(Rational::new(1, 7).to_expansion_bounded(10, 6).unwrap().to_string(), "0.(142857)");
+    /// assert_eq!(Rational::new(1, 7).to_expansion_bounded(10, 5), None);
+    /// assert_eq!(Rational::<i128>::new(1, i128::MAX).to_expansion_bounded(10, 1000), None);
+    /// ```
+    pub fn to_expansion_bounded(&self, radix: u32, max_digits: usize) -> Option<DecimalExpansion> {
+        check_radix(radix);
+        let numerator = self.get_numerator().to_big_int();
+        let denominator = self.get_denominator().to_big_int();
+        let base = BigInt::from(radix);
+        let (integer, mut remainder) = numerator.abs().div_rem(&denominator);
+
+        // Each factor of the base divided out of the denominator adds one digit before the cycle.
+        let mut rest = denominator.clone();
+        let mut fraction = Vec::new();
+        loop {
+            let common = traits::gcd(&rest, &base);
+            if common == BigInt::one() {
+                break;
+            }
+            if fraction.len() == max_digits {
+                return None;
+            }
+            rest = &rest / &common;
+            fraction.push(next_digit(&mut remainder, &denominator, &base));
+        }
+
+        let mut repeating = Vec::new();
+        if !remainder.is_zero() {
+            let start = remainder.clone();
+            loop {
+                if fraction.len() + repeating.len() == max_digits {
+                    return None;
+                }
+                repeating.push(next_digit(&mut remainder, &denominator, &base));
+                if remainder == start {
+                    break;
+                }
+            }
+        }
+        Some(DecimalExpansion {
+            negative: numerator.is_negative(),
+            integer,
+            fraction,
+            repeating,
+            radix,
+        })
+    }
+
+    /// Returns the rational in base `radix` rounded to `precision` digits after the point with the given rounding mode. A
+    /// result that rounds to zero is written without a sign.
+    /// Panics if `radix` is not in the range 2 to 36.
+    ///
+    /// # Example
+    /// ```
+    /// use numbers_rus::rational::decimal::RoundingMode;
+    /// use numbers_rus::rational::rational_integer::Rational;
+    ///
+    /// let a = Rational::new(-5, 8);
+    /// assert_eq!(a.to_decimal_string(10, 2, RoundingMode::HalfEven), "-0.62");
+    /// assert_eq!(a.to_decimal_string(10, 2, RoundingMode::HalfUp), "-0.63");
+    /// assert_eq!(a.to_decimal_string(10, 2, RoundingMode::Ceiling), "-0.62");
+    /// assert_eq!(a.to_decimal_string(10, 0, RoundingMode::Down), "0");
+    /// assert_eq!(Rational::new(1, 3).to_decimal_string(2, 4, RoundingMode::HalfUp), "0.0101");
+    /// ```
+    pub fn to_decimal_string(&self, radix: u32, precision: usize, mode: RoundingMode) -> String {
+        check_radix(radix);
+        let scale = BigInt::from(radix).pow(precision as u64);
+        let numerator = &self.get_numerator().to_big_int() * &scale;
+        let denominator = self.get_denominator().to_big_int();
+        let (quotient, remainder) = rational_integer::floor_div_rem(&numerator, &denominator);
+        let negative = numerator.is_negative();
+        let round_up = if remainder.is_zero() {
+            false
+        } else {
+            let half = (&remainder * &BigInt::from(2)).cmp(&denominator);
+            match (mode, half) {
+                (RoundingMode::Floor, _) => false,
+                (RoundingMode::Ceiling, _) => true,
+                (RoundingMode::Down, _) => negative,
+                (RoundingMode::Up, _) => !negative,
+                (_, std::cmp::Ordering::Less) => false,
+                (_, std::cmp::Ordering::Greater) => true,
+                (RoundingMode::HalfDown, _) => negative,
+                (RoundingMode::HalfUp, _) => !negative,
+                (RoundingMode::HalfEven, _) => quotient.is_odd(),
+            }
+        };
+        let rounded = if round_up {
+            &quotient + &BigInt::one()
+        } else {
+            quotient
+        };
+
+        let mut digits = rounded.abs().to_str_radix(radix);
+        if digits.len() <= precision {
+            digits = "0".repeat(precision + 1 - digits.len()) + &digits;
+        }
+        if precision > 0 {
+            digits.insert(digits.len() - precision, '.');
+        }
+        if rounded.is_negative() {
+            digits.insert(0, '-');
+        }
+        digits
+    }
+
+    /// Returns the rational as a mixed number: the integer part and a proper fraction separated by a space, such as
+    /// `"2 1/3"` or `"-2 1/3"`. Integers and proper fractions are written as just `"5"` or `"-1/3"`.
+    ///
+    /// # Example
+    /// ```
+    /// use numbers_rus::rational::rational_integer::Rational;
+    ///
+    /// assert_eq!(Rational::new(-7, 3).to_mixed_string(), "-2 1/3");
+    /// assert_eq!(Rational::new(-1, 3).to_mixed_string(), "-1/3");
+    /// assert_eq!(Rational::new(8, 2).to_mixed_string(), "4");
+    /// ```
+    pub fn to_mixed_string(&self) -> String {
+        let numerator = self.get_numerator().to_big_int();
+        let denominator = self.get_denominator();
+        let sign = if numerator.is_negative() { "-" } else { "" };
+        let (whole, rest) = numerator.abs().div_rem(&denominator.to_big_int());
+        if rest.is_zero() {
+            format!("{}{}", sign, whole)
+        } else if whole.is_zero() {
+            format!("{}{}/{}", sign, rest, denominator)
+        } else {
+            format!("{}{} {}/{}", sign, whole, rest, denominator)
+        }
+    }
+
+    /// Parses a rational written in base `radix` as a fraction `"p/q"`, a mixed number `"w p/q"`, an integer, or a positional
+    /// number with optional repeating digits in parentheses such as `"0.1(6)"`. In bases up to 10 a positional number may
+    /// end with an exponent `e<n>`, written in decimal, that multiplies it by `radix^n`. The value is exact, so `"0.1"` is
+    /// 1/10. Returns an error for malformed input, a zero denominator, or a value that does not fit in `T`.
+    /// Panics if `radix` is not in the range 2 to 36.
+    ///
+    /// # Example
+    /// ```
+    /// use numbers_rus::rational::rational_integer::Rational;
+    ///
+    /// assert_eq!(Rational::from_str_radix("0.(3)", 10), Ok(Rational::new(1, 3)));
+    /// assert_eq!(Rational::from_str_radix("-1.5e2", 10), Ok(Rational::from(-150)));
+    /// assert_eq!(Rational::from_str_radix("0.0(0011)", 2), Ok(Rational::new(1, 10)));
+    /// assert_eq!(Rational::from_str_radix("ff.8", 16), Ok(Rational::new(511, 2)));
+    /// assert!(Rational::<i32>::from_str_radix("1e20", 10).is_err());
+    /// ```
+    pub fn from_str_radix(text: &str, radix: u32) -> Result<Self, ParseRationalError> {
+        check_radix(radix);
+        let (numerator, denominator) = parse_exact(text, radix)?;
+        let value = Rational::new(numerator, denominator);
+        T::from_big_int(&value.get_numerator())
+            .zip(T::from_big_int(&value.get_denominator()))
+            .map(|(numerator, denominator)| Rational::new(numerator, denominator))
+            .ok_or(ParseRationalError::new("rational number out of range"))
+    }
+}
+
+#[cfg(test)]
+pub mod test_decimal {
+    use super::*;
+
+    #[test]
+    fn test_expansion_round_trip() {
+        for radix in [2, 3, 10, 12, 16, 36] {
+            for numerator in -40i64..=40 {
+                for denominator in 1..=40 {
+                    let value = Rational::new(numerator, denominator);
+                    let text = value.to_expansion(radix).to_string();
+                    assert_eq!(
+                        Rational::from_str_radix(&text, radix),
+                        Ok(value),
+                        "{}",
+                        text
+                    );
+                }
+            }
+        }
+    }
+    #[test]
+    fn test_expansion() {
+        let expansion = Rational::new(1, 7).to_expansion(10);
+        assert_eq!(expansion.repeating_digits(), &[1, 4, 2, 8, 5, 7]);
+        assert!(expansion.fraction_digits().is_empty());
+        assert_eq!(
+            Rational::new(22, 7).to_expansion(10).to_string(),
+            "3.(142857)"
+        );
+        assert_eq!(Rational::new(-1, 2).to_expansion(10).to_string(), "-0.5");
+        assert_eq!(Rational::new(1, 6).to_expansion(10).to_string(), "0.1(6)");
+        assert_eq!(Rational::from(0).to_expansion(10).to_string(), "0");
+        let long = Rational::new(1, 97).to_expansion(10);
+        assert_eq!(long.repeating_digits().len(), 96);
+        let big = Rational::<i128>::new(1, 1 << 100).to_expansion(10);
+        assert_eq!(big.fraction_digits().len(), 100);
+        assert!(big.repeating_digits().is_empty());
+    }
+    #[test]
+    fn test_expansion_bounded() {
+        let value = Rational::new(1, 6);
+        assert_eq!(
+            value.to_expansion_bounded(10, 2),
+            Some(value.to_expansion(10))
+        );
+        assert_eq!(value.to_expansion_bounded(10, 1), None);
+        let big = Rational::<i128>::new(1, 1 << 100);
+        assert_eq!(big.to_expansion_bounded(10, 99), None);
+        assert!(big.to_expansion_bounded(10, 100).is_some());
+        assert_eq!(
+            Rational::new(3, 1).to_expansion_bounded(10, 0),
+            Some(Rational::new(3, 1).to_expansion(10))
+        );
+        // The cycle of 1/(2^127 - 1) in base 10 is far too long to expand, but the bound stops it early.
+        assert_eq!(
+            Rational::<i128>::new(1, i128::MAX).to_expansion_bounded(10, 10_000),
+            None
+        );
+    }
+    #[test]
+    fn test_rounding() {
+        use RoundingMode::*;
+        let modes = [Down, Up, Floor, Ceiling, HalfDown, HalfUp, HalfEven];
+        let cases = [
+            ((5, 2), ["2", "3", "2", "3", "2", "3", "2"]),
+            ((-5, 2), ["-2", "-3", "-3", "-2", "-2", "-3", "-2"]),
+            ((7, 2), ["3", "4", "3", "4", "3", "4", "4"]),
+            ((-8, 3), ["-2", "-3", "-3", "-2", "-3", "-3", "-3"]),
+            ((4, 1), ["4", "4", "4", "4", "4", "4", "4"]),
+        ];
+        for ((numerator, denominator), expected) in cases {
+            let value = Rational::new(numerator, denominator);
+            for (mode, expected) in modes.into_iter().zip(expected) {
+                assert_eq!(
+                    value.to_decimal_string(10, 0, mode),
+                    expected,
+                    "{} {:?}",
+                    value,
+                    mode
+                );
+            }
+        }
+        let third = Rational::new(1, 3);
+        assert_eq!(third.to_decimal_string(10, 5, HalfEven), "0.33333");
+        assert_eq!(third.to_decimal_string(10, 5, Up), "0.33334");
+        assert_eq!(
+            Rational::new(-1, 300).to_decimal_string(10, 2, HalfUp),
+            "0.00"
+        );
+        assert_eq!(
+            Rational::new(-1, 300).to_decimal_string(10, 2, Floor),
+            "-0.01"
+        );
+        assert_eq!(
+            Rational::new(1234, 1).to_decimal_string(16, 1, Floor),
+            "4d2.0"
+        );
+    }
+    #[test]
+    fn test_mixed() {
+        assert_eq!(Rational::new(7, 3).to_mixed_string(), "2 1/3");
+        assert_eq!(Rational::from(-3).to_mixed_string(), "-3");
+        assert_eq!(
+            Rational::new(i32::MIN, 3).to_mixed_string(),
+            "-715827882 2/3"
+        );
+        for numerator in -20..=20 {
+            for denominator in 1..=6 {
+                let value = Rational::new(numerator, denominator);
+                assert_eq!(value.to_mixed_string().parse::<Rational>(), Ok(value));
+            }
+        }
+    }
+    #[test]
+    fn test_parse() {
+        let parse = |text: &str| text.parse::<Rational>();
+        assert_eq!(parse("0.1(6)"), Ok(Rational::new(1, 6)));
+        assert_eq!(parse("-.(9)"), Ok(Rational::from(-1)));
+        assert_eq!(parse("+2.5"), Ok(Rational::new(5, 2)));
+        assert_eq!(parse("3."), Ok(Rational::from(3)));
+        assert_eq!(parse("1 1/2"), Ok(Rational::new(3, 2)));
+        assert_eq!(parse("-0 1/2"), Ok(Rational::new(-1, 2)));
+        assert_eq!(parse("2.5E-3"), Ok(Rational::new(1, 400)));
+        assert_eq!(parse("0.(3)e1"), Ok(Rational::new(10, 3)));
+        for text in [
+            ".", "1.2.3", "0.(", "0.()", "0.(1)2", "1 -1/2", "1 1/-2", "1 1 1/2", "e5", "1e",
+            "1_0", "(3)", "1 1/0",
+        ] {
+            assert!(parse(text).is_err(), "{:?}", text);
+        }
+        assert!(parse("1e100000").is_err());
+        assert!("1e-20".parse::<Rational<i64>>().is_err());
+        assert_eq!(
+            "1e-20".parse::<Rational<i128>>(),
+            Ok(Rational::new(1, 10i128.pow(20)))
+        );
+        // 'e' is a digit from base 15 on, so there is no exponent.
+        assert_eq!(Rational::from_str_radix("1e", 16), Ok(Rational::from(30)));
+    }
+}
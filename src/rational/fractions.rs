@@ -0,0 +1,708 @@
+//! Classical constructions on fractions: Egyptian fraction decompositions, Farey sequences, and paths through and
+//! enumerations of the Stern–Brocot and Calkin–Wilf trees.
+//!
+//! * An Egyptian fraction writes a rational in `(0, 1]` as a sum of distinct unit fractions. `egyptian_greedy` takes the
+//!   largest unit fraction that fits at each step (Fibonacci's method), and `egyptian_shortest` searches for a decomposition
+//!   with the fewest terms.
+//! * The Farey sequence of order `n` lists the fractions in `[0, 1]` with denominators up to `n` in increasing order. Any two
+//!   neighbors `a/b < c/d` satisfy `bc - ad = 1`, and the first fraction to appear between them is their mediant.
+//! * Every positive rational appears exactly once in the Stern–Brocot tree, which orders them from left to right, and once
+//!   in the Calkin–Wilf tree, whose breadth-first order is Newman's sequence. A path is the list of left and right turns from
+//!   the root `1/1`.
+//!
+//! # Examples
+//! ```
+//! use numbers_rus::rational::fractions::{self, Direction};
+//! use numbers_rus::rational::rational_integer::Rational;
+//!
+//! let terms = fractions::egyptian_greedy(&Rational::new(4, 13)).unwrap();
+//! assert_eq!(terms, vec![Rational::new(1, 4), Rational::new(1, 18), Rational::new(1, 468)]);
+//!
+//! let farey: Vec<String> = fractions::farey_sequence(4).iter().map(|r| r.to_string()).collect();
+//! assert_eq!(farey.join(" "), "0/1 1/4 1/3 1/2 2/3 3/4 1/1");
+//!
+//! let path = fractions::stern_brocot_path(&Rational::new(3, 5)).unwrap();
+//! assert_eq!(path, vec![(Direction::Left, 1), (Direction::Right, 1), (Direction::Left, 1)]);
+//! ```
+use crate::error::{ErrorKind, NumbersError};
+use crate::integers::big_integer::BigInt;
+use crate::rational::rational_integer::{self, Rational};
+use crate::traits::{self, Integer};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// A turn on the way down a binary tree of fractions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Direction::Left => write!(f, "L"),
+            Direction::Right => write!(f, "R"),
+        }
+    }
+}
+
+/// Returns an error unless the value lies in `(0, 1]`.
+fn check_unit_interval<T: Integer>(
+    value: &Rational<T>,
+    operation: &'static str,
+) -> Result<(), NumbersError> {
+    let numerator = value.get_numerator();
+    if numerator.is_negative() || numerator.is_zero() || numerator > value.get_denominator() {
+        return Err(NumbersError::new(operation, ErrorKind::NotRepresentable));
+    }
+    Ok(())
+}
+
+/// Narrows big parts into a `Rational<T>`, or returns an `Overflow` error.
+fn narrow<T: Integer>(
+    numerator: &BigInt,
+    denominator: &BigInt,
+    operation: &'static str,
+) -> Result<Rational<T>, NumbersError> {
+    match (T::from_big_int(numerator), T::from_big_int(denominator)) {
+        (Some(numerator), Some(denominator)) => Ok(Rational::new(numerator, denominator)),
+        _ => Err(NumbersError::new(operation, ErrorKind::Overflow)),
+    }
+}
+
+/// Returns the ceiling of `left / right` for a positive `right`.
+fn ceil_div(left: &BigInt, right: &BigInt) -> BigInt {
+    let (quotient, remainder) = rational_integer::floor_div_rem(left, right);
+    if remainder.is_zero() {
+        quotient
+    } else {
+        &quotient + &BigInt::one()
+    }
+}
+
+/// `egyptian_greedy` function: Takes a rational in `(0, 1]` as input and returns its greedy Egyptian fraction decomposition,
+/// which subtracts the largest unit fraction that fits until nothing is left. The terms are distinct and in decreasing
+/// order, but their denominators can grow doubly exponentially, so `Rational<BigInt>` may be needed. Returns a
+/// `NotRepresentable` error for values outside `(0, 1]` and an `Overflow` error if a denominator does not fit in `T`.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::big_integer::BigInt;
+/// use numbers_rus::rational::fractions;
+/// use numbers_rus::rational::rational_integer::Rational;
+///
+/// let terms = fractions::egyptian_greedy(&Rational::new(7, 15)).unwrap();
+/// assert_eq!(terms, vec![Rational::new(1, 3), Rational::new(1, 8), Rational::new(1, 120)]);
+///
+/// // The greedy denominators of 5/121 overflow an i64.
+/// assert!(fractions::egyptian_greedy(&Rational::<i64>::new(5, 121)).is_err());
+/// let big = fractions::egyptian_greedy(&Rational::new(BigInt::from(5), BigInt::from(121))).unwrap();
+/// assert_eq!(big.len(), 5);
+/// ```
+pub fn egyptian_greedy<T: Integer>(value: &Rational<T>) -> Result<Vec<Rational<T>>, NumbersError> {
+    check_unit_interval(value, "egyptian_greedy")?;
+    let overflow = |_| NumbersError::new("egyptian_greedy", ErrorKind::Overflow);
+    let mut rest = value.clone();
+    let mut terms = Vec::new();
+    while !rest.get_numerator().is_zero() {
+        let unit = rest.recip().ceil().checked_recip().map_err(overflow)?;
+        rest = rest.checked_sub(&unit).map_err(overflow)?;
+        terms.push(unit);
+    }
+    Ok(terms)
+}
+
+/// Searches for unit fractions with denominators from `min_denominator` on that sum to `numerator / denominator` in exactly
+/// `count` terms, keeping the decomposition with the smallest largest denominator in `best`.
+fn search_shortest(
+    numerator: &BigInt,
+    denominator: &BigInt,
+    count: usize,
+    min_denominator: &BigInt,
+    current: &mut Vec<BigInt>,
+    best: &mut Option<Vec<BigInt>>,
+) {
+    let beats_best = |largest: &BigInt, best: &Option<Vec<BigInt>>| match best {
+        Some(terms) => largest < terms.last().unwrap(),
+        None => true,
+    };
+    if count == 1 {
+        if *numerator == BigInt::one()
+            && denominator >= min_denominator
+            && beats_best(denominator, best)
+        {
+            current.push(denominator.clone());
+            *best = Some(current.clone());
+            current.pop();
+        }
+        return;
+    }
+    // The next denominator d is the smallest of the remaining ones, so 1/d <= rest <= count/d.
+    let mut next = ceil_div(denominator, numerator).max(min_denominator.clone());
+    let last = &(denominator * &BigInt::from(count as u64)) / numerator;
+    while next <= last && beats_best(&next, best) {
+        let rest_numerator = &(numerator * &next) - denominator;
+        let rest_denominator = denominator * &next;
+        let common = traits::gcd(&rest_numerator, &rest_denominator);
+        current.push(next.clone());
+        search_shortest(
+            &(&rest_numerator / &common),
+            &(&rest_denominator / &common),
+            count - 1,
+            &(&next + &BigInt::one()),
+            current,
+            best,
+        );
+        current.pop();
+        next = &next + &BigInt::one();
+    }
+}
+
+/// `egyptian_shortest` function: Takes a rational in `(0, 1]` and a maximum number of terms as input and returns an Egyptian
+/// fraction decomposition with as few terms as possible, and among those the smallest largest denominator. The search
+/// tries one term, then two, and so on, so its running time grows quickly with the length of the answer. Returns a
+/// `NotRepresentable` error for values outside `(0, 1]` or when no decomposition has at most `max_terms` terms, and an
+/// `Overflow` error if a denominator does not fit in `T`.
+///
+/// # Example
+/// ```
+/// use numbers_rus::rational::fractions;
+/// use numbers_rus::rational::rational_integer::Rational;
+///
+/// let terms = fractions::egyptian_shortest(&Rational::<i64>::new(5, 121), 4).unwrap();
+/// assert_eq!(terms, vec![Rational::new(1, 33), Rational::new(1, 121), Rational::new(1, 363)]);
+/// assert!(fractions::egyptian_shortest(&Rational::new(5, 121), 2).is_err());
+/// ```
+pub fn egyptian_shortest<T: Integer>(
+    value: &Rational<T>,
+    max_terms: usize,
+) -> Result<Vec<Rational<T>>, NumbersError> {
+    check_unit_interval(value, "egyptian_shortest")?;
+    let numerator = value.get_numerator().to_big_int();
+    let denominator = value.get_denominator().to_big_int();
+    for count in 1..=max_terms {
+        let mut best = None;
+        search_shortest(
+            &numerator,
+            &denominator,
+            count,
+            &BigInt::one(),
+            &mut Vec::new(),
+            &mut best,
+        );
+        if let Some(terms) = best {
+            return terms
+                .iter()
+                .map(|term| narrow(&BigInt::one(), term, "egyptian_shortest"))
+                .collect();
+        }
+    }
+    Err(NumbersError::new(
+        "egyptian_shortest",
+        ErrorKind::NotRepresentable,
+    ))
+}
+
+/// `farey_sequence` function: Takes a positive order `n` as input and returns the Farey sequence of order `n`, the
+/// fractions in `[0, 1]` with denominators up to `n` in increasing order. Each term is computed from the previous two,
+/// since `a/b, c/d` are followed by `(kc - a)/(kd - b)` with `k = (n + b) / d`.
+/// Panics if `n` is not positive.
+///
+/// # Example
+/// ```
+/// use numbers_rus::rational::fractions;
+/// use numbers_rus::rational::rational_integer::Rational;
+///
+/// let sequence = fractions::farey_sequence(5);
+/// assert_eq!(sequence.len(), 11);
+/// assert_eq!(sequence[3], Rational::new(1, 3));
+/// assert_eq!(fractions::farey_sequence(1), vec![Rational::from(0), Rational::from(1)]);
+/// ```
+pub fn farey_sequence<T: Integer>(order: T) -> Vec<Rational<T>> {
+    if order <= T::zero() {
+        panic!("Farey order must be positive!");
+    }
+    let (mut a, mut b, mut c, mut d) = (T::zero(), T::one(), T::one(), order.clone());
+    let mut sequence = vec![Rational::from_integer(T::zero())];
+    while c <= order {
+        sequence.push(Rational::new(c.clone(), d.clone()));
+        let k = order.add(&b).div_rem(&d).0;
+        let next = (k.multiply(&c).subtract(&a), k.multiply(&d).subtract(&b));
+        (a, b) = (c, d);
+        (c, d) = next;
+    }
+    sequence
+}
+
+/// `mediant` function: Takes two rationals `a/b` and `c/d` in lowest terms as input and returns their mediant
+/// `(a + c) / (b + d)`, which lies strictly between them when they differ. Returns an `Overflow` error if it does not fit.
+///
+/// # Example
+/// ```
+/// use numbers_rus::rational::fractions;
+/// use numbers_rus::rational::rational_integer::Rational;
+///
+/// assert_eq!(fractions::mediant(&Rational::new(1, 3), &Rational::new(1, 2)), Ok(Rational::new(2, 5)));
+/// assert_eq!(fractions::mediant(&Rational::new(1, 2), &Rational::new(1, 2)), Ok(Rational::new(1, 2)));
+/// ```
+pub fn mediant<T: Integer>(
+    left: &Rational<T>,
+    right: &Rational<T>,
+) -> Result<Rational<T>, NumbersError> {
+    let overflow = || NumbersError::new("mediant", ErrorKind::Overflow);
+    let numerator = left
+        .get_numerator()
+        .checked_add(&right.get_numerator())
+        .ok_or_else(overflow)?;
+    let denominator = left
+        .get_denominator()
+        .checked_add(&right.get_denominator())
+        .ok_or_else(overflow)?;
+    Rational::checked_new(numerator, denominator).map_err(|_| overflow())
+}
+
+/// `farey_neighbors` function: Takes a rational `x` and a positive order `n` as input and returns the closest fractions
+/// below and above `x` whose denominators are at most `n`. For `x` in the Farey sequence of order `n` these are its
+/// neighbors there. The search walks down the Stern–Brocot tree, taking runs of turns in the same direction in one step.
+/// Returns an `Overflow` error if a neighbor does not fit in `T`.
+/// Panics if `n` is not positive.
+///
+/// # Example
+/// ```
+/// use numbers_rus::rational::fractions;
+/// use numbers_rus::rational::rational_integer::Rational;
+///
+/// let (left, right) = fractions::farey_neighbors(&Rational::new(1, 3), 5).unwrap();
+/// assert_eq!((left, right), (Rational::new(1, 4), Rational::new(2, 5)));
+///
+/// // Best rational approximations of pi from below and above with denominators up to 100.
+/// let pi = Rational::<i64>::new(314159265, 100000000);
+/// let (below, above) = fractions::farey_neighbors(&pi, 100).unwrap();
+/// assert_eq!((below, above), (Rational::new(311, 99), Rational::new(22, 7)));
+/// ```
+pub fn farey_neighbors<T: Integer>(
+    value: &Rational<T>,
+    order: T,
+) -> Result<(Rational<T>, Rational<T>), NumbersError> {
+    if order <= T::zero() {
+        panic!("Farey order must be positive!");
+    }
+    let n = order.to_big_int();
+    let (whole, p) = rational_integer::floor_div_rem(
+        &value.get_numerator().to_big_int(),
+        &value.get_denominator().to_big_int(),
+    );
+    let q = value.get_denominator().to_big_int();
+    let one = BigInt::one();
+    let shift = |(numerator, denominator): (BigInt, BigInt)| {
+        narrow(
+            &(&numerator + &(&whole * &denominator)),
+            &denominator,
+            "farey_neighbors",
+        )
+    };
+    if p.is_zero() {
+        // Integers are neighbored by the integer plus or minus 1/n.
+        return Ok((shift((-&one, n.clone()))?, shift((one, n))?));
+    }
+
+    // The fractional part p/q lies strictly between a/b and c/d, which are neighbors in the Stern–Brocot tree.
+    let (mut a, mut b, mut c, mut d) = (BigInt::zero(), one.clone(), one.clone(), one.clone());
+    loop {
+        let (mediant_p, mediant_q) = (&a + &c, &b + &d);
+        if mediant_q > n {
+            break;
+        }
+        match (&mediant_p * &q).cmp(&(&p * &mediant_q)) {
+            Ordering::Greater => {
+                // Move the upper end towards a/b as far as it stays above p/q.
+                let steps =
+                    &ceil_div(&(&(&c * &q) - &(&p * &d)), &(&(&p * &b) - &(&a * &q))) - &one;
+                let k = steps.min(&(&n - &d) / &b);
+                c = &c + &(&k * &a);
+                d = &d + &(&k * &b);
+            }
+            Ordering::Less => {
+                let steps =
+                    &ceil_div(&(&(&p * &b) - &(&a * &q)), &(&(&c * &q) - &(&p * &d))) - &one;
+                let k = steps.min(&(&n - &b) / &d);
+                a = &a + &(&k * &c);
+                b = &b + &(&k * &d);
+            }
+            Ordering::Equal => {
+                // The value is in the sequence, and its neighbors are the closest of its descendants on either side.
+                let k = &(&n - &b) / &mediant_q;
+                (a, b) = (&a + &(&k * &mediant_p), &b + &(&k * &mediant_q));
+                let k = &(&n - &d) / &mediant_q;
+                (c, d) = (&c + &(&k * &mediant_p), &d + &(&k * &mediant_q));
+                break;
+            }
+        }
+    }
+    Ok((shift((a, b))?, shift((c, d))?))
+}
+
+/// Returns an error unless the value is positive.
+fn check_positive<T: Integer>(
+    value: &Rational<T>,
+    operation: &'static str,
+) -> Result<(), NumbersError> {
+    if value.get_numerator() <= T::zero() {
+        return Err(NumbersError::new(operation, ErrorKind::NegativeArgument));
+    }
+    Ok(())
+}
+
+/// Returns the runs of turns of the subtractive Euclidean algorithm on a positive rational: `Right` while the numerator is
+/// larger and `Left` while the denominator is, until both are equal. Each run is one step of the division algorithm.
+fn euclid_runs<T: Integer>(value: &Rational<T>) -> Vec<(Direction, T)> {
+    let (mut p, mut q) = (value.get_numerator(), value.get_denominator());
+    let mut runs = Vec::new();
+    while p != q {
+        let (direction, (quotient, remainder)) = if p > q {
+            (Direction::Right, p.div_rem(&q))
+        } else {
+            (Direction::Left, q.div_rem(&p))
+        };
+        if remainder.is_zero() {
+            // The last run stops one short, at 1/1 rather than at a zero remainder.
+            runs.push((direction, quotient.subtract(&T::one())));
+            break;
+        }
+        runs.push((direction, quotient));
+        if p > q {
+            p = remainder;
+        } else {
+            q = remainder;
+        }
+    }
+    runs
+}
+
+/// Follows runs of turns down the Calkin–Wilf tree from the root `1/1`. The numerator and denominator only grow on the way,
+/// so an `Overflow` error means the fraction reached does not fit in `T`.
+fn follow_calkin_wilf<'a, T: Integer + 'a>(
+    runs: impl Iterator<Item = &'a (Direction, T)>,
+    operation: &'static str,
+) -> Result<Rational<T>, NumbersError> {
+    let overflow = || NumbersError::new(operation, ErrorKind::Overflow);
+    let (mut a, mut b) = (T::one(), T::one());
+    for (turn, count) in runs {
+        if count.is_negative() {
+            return Err(NumbersError::new(operation, ErrorKind::NegativeArgument));
+        }
+        match turn {
+            Direction::Left => {
+                b = count
+                    .checked_mul(&a)
+                    .and_then(|step| b.checked_add(&step))
+                    .ok_or_else(overflow)?
+            }
+            Direction::Right => {
+                a = count
+                    .checked_mul(&b)
+                    .and_then(|step| a.checked_add(&step))
+                    .ok_or_else(overflow)?
+            }
+        }
+    }
+    Ok(Rational::new(a, b))
+}
+
+/// `stern_brocot_path` function: Takes a positive rational as input and returns the turns from the root `1/1` of the
+/// Stern–Brocot tree down to it, as runs `(direction, count)` of equal turns. There is one run per continued fraction term,
+/// so the result stays short even when the path, whose length is the sum of the terms minus one, is far too long to list
+/// turn by turn, as for `n/1`. Returns a `NegativeArgument` error if the value is not positive.
+///
+/// # Example
+/// ```
+/// use numbers_rus::rational::fractions::{self, Direction::{Left, Right}};
+/// use numbers_rus::rational::rational_integer::Rational;
+///
+/// assert_eq!(fractions::stern_brocot_path(&Rational::new(7, 3)), Ok(vec![(Right, 2), (Left, 2)]));
+/// assert_eq!(fractions::stern_brocot_path(&Rational::from(i128::MAX)), Ok(vec![(Right, i128::MAX - 1)]));
+/// assert_eq!(fractions::stern_brocot_path(&Rational::from(1)), Ok(vec![]));
+/// ```
+pub fn stern_brocot_path<T: Integer>(
+    value: &Rational<T>,
+) -> Result<Vec<(Direction, T)>, NumbersError> {
+    check_positive(value, "stern_brocot_path")?;
+    Ok(euclid_runs(value))
+}
+
+/// `from_stern_brocot_path` function: Takes runs `(direction, count)` of turns as input and returns the fraction reached by
+/// following them from the root `1/1` of the Stern–Brocot tree, where every node is the mediant of its nearest ancestors on
+/// either side. Returns a `NegativeArgument` error for a negative count and an `Overflow` error if the fraction does not fit
+/// in `T`.
+///
+/// # Example
+/// ```
+/// use numbers_rus::rational::fractions::{self, Direction::{Left, Right}};
+/// use numbers_rus::rational::rational_integer::Rational;
+///
+/// assert_eq!(fractions::from_stern_brocot_path(&[(Right, 2), (Left, 2)]), Ok(Rational::new(7, 3)));
+/// assert_eq!(fractions::from_stern_brocot_path(&[(Right, 1), (Right, 1), (Left, 2)]), Ok(Rational::new(7, 3)));
+/// assert_eq!(fractions::from_stern_brocot_path::<i32>(&[]), Ok(Rational::from(1)));
+/// ```
+pub fn from_stern_brocot_path<T: Integer>(
+    path: &[(Direction, T)],
+) -> Result<Rational<T>, NumbersError> {
+    // The Calkin–Wilf path to the same fraction is this one read backwards.
+    follow_calkin_wilf(path.iter().rev(), "from_stern_brocot_path")
+}
+
+/// `calkin_wilf_path` function: Takes a positive rational as input and returns the turns from the root `1/1` of the
+/// Calkin–Wilf tree down to it, as runs `(direction, count)` of equal turns, where the children of `a/b` are `a/(a + b)` on
+/// the left and `(a + b)/b` on the right. It is the Stern–Brocot path read backwards. Returns a `NegativeArgument` error if
+/// the value is not positive.
+///
+/// # Example
+/// ```
+/// use numbers_rus::rational::fractions::{self, Direction::{Left, Right}};
+/// use numbers_rus::rational::rational_integer::Rational;
+///
+/// assert_eq!(fractions::calkin_wilf_path(&Rational::new(7, 3)), Ok(vec![(Left, 2), (Right, 2)]));
+/// ```
+pub fn calkin_wilf_path<T: Integer>(
+    value: &Rational<T>,
+) -> Result<Vec<(Direction, T)>, NumbersError> {
+    check_positive(value, "calkin_wilf_path")?;
+    let mut runs = euclid_runs(value);
+    runs.reverse();
+    Ok(runs)
+}
+
+/// `from_calkin_wilf_path` function: Takes runs `(direction, count)` of turns as input and returns the fraction reached by
+/// following them from the root `1/1` of the Calkin–Wilf tree. Returns a `NegativeArgument` error for a negative count and
+/// an `Overflow` error if the fraction does not fit in `T`.
+///
+/// # Example
+/// ```
+/// use numbers_rus::rational::fractions::{self, Direction::{Left, Right}};
+/// use numbers_rus::rational::rational_integer::Rational;
+///
+/// assert_eq!(fractions::from_calkin_wilf_path(&[(Left, 2), (Right, 2)]), Ok(Rational::new(7, 3)));
+/// ```
+pub fn from_calkin_wilf_path<T: Integer>(
+    path: &[(Direction, T)],
+) -> Result<Rational<T>, NumbersError> {
+    follow_calkin_wilf(path.iter(), "from_calkin_wilf_path")
+}
+
+/// `stern_brocot_row` function: Takes a depth as input and returns the `2^depth` fractions at that depth of the Stern–Brocot
+/// tree from left to right, which is increasing order. Depth 0 is the root `1/1`.
+///
+/// # Example
+/// ```
+/// use numbers_rus::rational::fractions;
+/// use numbers_rus::rational::rational_integer::Rational;
+///
+/// let row: Vec<String> = fractions::stern_brocot_row::<i32>(2).iter().map(|r| r.to_string()).collect();
+/// assert_eq!(row.join(" "), "1/3 2/3 3/2 3/1");
+/// ```
+pub fn stern_brocot_row<T: Integer>(depth: u32) -> Vec<Rational<T>> {
+    // Each node is kept as the pair of ancestors whose mediant it is.
+    let mut intervals = vec![((T::zero(), T::one()), (T::one(), T::zero()))];
+    for _ in 0..depth {
+        intervals = intervals
+            .into_iter()
+            .flat_map(|(low, high)| {
+                let node = (low.0.add(&high.0), low.1.add(&high.1));
+                [(low, node.clone()), (node, high)]
+            })
+            .collect();
+    }
+    intervals
+        .into_iter()
+        .map(|(low, high)| Rational::new(low.0.add(&high.0), low.1.add(&high.1)))
+        .collect()
+}
+
+/// An iterator over every positive rational exactly once, in the breadth-first order of the Calkin–Wilf tree, created by
+/// `calkin_wilf_sequence`. Each term follows from the last by Newman's formula `x -> 1 / (2 floor(x) - x + 1)`. The
+/// iterator stops once a term no longer fits in `T`.
+///
+/// # Example
+/// ```
+/// use numbers_rus::rational::fractions;
+/// use numbers_rus::rational::rational_integer::Rational;
+///
+/// let terms: Vec<String> = fractions::calkin_wilf_sequence::<i32>().take(7).map(|r| r.to_string()).collect();
+/// assert_eq!(terms.join(" "), "1/1 1/2 2/1 1/3 3/2 2/3 3/1");
+/// ```
+#[derive(Debug, Clone)]
+pub struct CalkinWilfSequence<T: Integer> {
+    next: Option<(T, T)>,
+}
+
+impl<T: Integer> Iterator for CalkinWilfSequence<T> {
+    type Item = Rational<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (a, b) = self.next.take()?;
+        // a/b -> b / ((2 floor(a/b) + 1) b - a)
+        let twice_floor = a.div_rem(&b).0.shift_left(1);
+        self.next = twice_floor
+            .checked_add(&T::one())
+            .and_then(|factor| factor.checked_mul(&b))
+            .and_then(|product| product.checked_sub(&a))
+            .map(|denominator| (b.clone(), denominator));
+        Some(Rational::new(a, b))
+    }
+}
+
+/// `calkin_wilf_sequence` function: Returns a `CalkinWilfSequence` iterator over all positive rationals in Calkin–Wilf
+/// order, starting at `1/1`.
+pub fn calkin_wilf_sequence<T: Integer>() -> CalkinWilfSequence<T> {
+    CalkinWilfSequence {
+        next: Some((T::one(), T::one())),
+    }
+}
+
+#[cfg(test)]
+pub mod test_fractions {
+    use super::*;
+
+    #[test]
+    fn test_egyptian() {
+        for denominator in 2..=30 {
+            for numerator in 1..=denominator {
+                let value = Rational::<i64>::new(numerator, denominator);
+                let greedy = egyptian_greedy(&value).unwrap();
+                let shortest = egyptian_shortest(&value, greedy.len()).unwrap();
+                for terms in [&greedy, &shortest] {
+                    assert_eq!(terms.iter().sum::<Rational<i64>>(), value);
+                    assert!(terms.iter().all(|term| term.get_numerator() == 1));
+                    assert!(terms.windows(2).all(|pair| pair[0] > pair[1]));
+                }
+                assert!(shortest.len() <= greedy.len());
+            }
+        }
+        let four_thirteenths = egyptian_shortest(&Rational::new(4, 13), 3).unwrap();
+        assert_eq!(
+            four_thirteenths,
+            vec![
+                Rational::new(1, 4),
+                Rational::new(1, 26),
+                Rational::new(1, 52)
+            ]
+        );
+        assert!(egyptian_greedy(&Rational::new(3, 2)).is_err());
+        assert!(egyptian_greedy(&Rational::new(-1, 2)).is_err());
+        assert!(egyptian_shortest(&Rational::from(0), 3).is_err());
+        assert_eq!(
+            egyptian_greedy(&Rational::from(1)),
+            Ok(vec![Rational::from(1)])
+        );
+    }
+    #[test]
+    fn test_farey() {
+        for order in 1..=30i64 {
+            let sequence = farey_sequence(order);
+            let mut brute: Vec<Rational<i64>> = (1..=order)
+                .flat_map(|q| (0..=q).map(move |p| Rational::new(p, q)))
+                .collect();
+            brute.sort();
+            brute.dedup();
+            assert_eq!(sequence, brute);
+            for pair in sequence.windows(2) {
+                let (a, b) = (pair[0].get_numerator(), pair[0].get_denominator());
+                let (c, d) = (pair[1].get_numerator(), pair[1].get_denominator());
+                assert_eq!(b * c - a * d, 1);
+            }
+            for window in sequence.windows(3) {
+                assert_eq!(
+                    farey_neighbors(&window[1], order),
+                    Ok((window[0], window[2]))
+                );
+            }
+        }
+    }
+    #[test]
+    fn test_farey_neighbors() {
+        for order in 1..=12i64 {
+            for numerator in -30..=30 {
+                for denominator in 1..=15 {
+                    let value = Rational::new(numerator, denominator);
+                    let floor = value.floor().get_numerator();
+                    let candidates: Vec<Rational<i64>> = (1..=order)
+                        .flat_map(|q| {
+                            ((floor - 1) * q..=(floor + 2) * q).map(move |p| Rational::new(p, q))
+                        })
+                        .collect();
+                    let below = candidates.iter().filter(|&&c| c < value).max().unwrap();
+                    let above = candidates.iter().filter(|&&c| c > value).min().unwrap();
+                    assert_eq!(
+                        farey_neighbors(&value, order),
+                        Ok((*below, *above)),
+                        "{} {}",
+                        value,
+                        order
+                    );
+                }
+            }
+        }
+        let big = Rational::<i32>::new(i32::MAX - 1, 1);
+        assert!(farey_neighbors(&big, 1).is_ok());
+        assert!(farey_neighbors(&Rational::<i32>::from(i32::MAX), 1).is_err());
+    }
+    #[test]
+    fn test_tree_paths() {
+        for (depth, row) in (0..8).map(|depth| (depth, stern_brocot_row::<i64>(depth))) {
+            assert!(row.windows(2).all(|pair| pair[0] < pair[1]));
+            for value in &row {
+                let path = stern_brocot_path(value).unwrap();
+                assert_eq!(
+                    path.iter().map(|&(_, count)| count).sum::<i64>(),
+                    depth as i64
+                );
+                assert!(path
+                    .windows(2)
+                    .all(|pair| pair[0].0 != pair[1].0 && pair[0].1 > 0));
+                assert_eq!(from_stern_brocot_path::<i64>(&path), Ok(*value));
+                let path = calkin_wilf_path(value).unwrap();
+                assert_eq!(from_calkin_wilf_path::<i64>(&path), Ok(*value));
+            }
+        }
+        assert!(stern_brocot_path(&Rational::from(0)).is_err());
+        assert!(from_stern_brocot_path::<i32>(&[(Direction::Right, 40)]).is_ok());
+        let long: Vec<(Direction, i32)> = [(Direction::Left, 1), (Direction::Right, 1)].repeat(30);
+        assert!(from_stern_brocot_path::<i32>(&long).is_err());
+        assert!(from_calkin_wilf_path::<i32>(&[(Direction::Left, -1)]).is_err());
+        assert_eq!(
+            from_stern_brocot_path::<i32>(&[(Direction::Right, i32::MAX - 1)]),
+            Ok(Rational::from(i32::MAX))
+        );
+        assert!(from_stern_brocot_path::<i32>(&[(Direction::Right, i32::MAX)]).is_err());
+    }
+    #[test]
+    fn test_long_tree_paths() {
+        // Paths with about 2^127 turns, which only fit as runs.
+        let value = Rational::<i128>::new(i128::MAX, 2);
+        let path = stern_brocot_path(&value).unwrap();
+        assert_eq!(
+            path,
+            vec![(Direction::Right, i128::MAX / 2), (Direction::Left, 1)]
+        );
+        assert_eq!(from_stern_brocot_path(&path), Ok(value));
+        let path = calkin_wilf_path(&value).unwrap();
+        assert_eq!(from_calkin_wilf_path(&path), Ok(value));
+        let value = Rational::<BigInt>::new(BigInt::one(), BigInt::from(10).pow(60));
+        let path = stern_brocot_path(&value).unwrap();
+        assert_eq!(path.len(), 1);
+        assert_eq!(from_stern_brocot_path(&path), Ok(value));
+    }
+    #[test]
+    fn test_calkin_wilf_sequence() {
+        // Depth d of the tree is terms 2^d - 1 to 2^(d + 1) - 2, the Stern–Brocot row in another order.
+        let terms: Vec<Rational<i64>> = calkin_wilf_sequence().take(255).collect();
+        for depth in 0..8u32 {
+            let mut row = terms[(1 << depth) - 1..(1 << (depth + 1)) - 1].to_vec();
+            row.sort();
+            assert_eq!(row, stern_brocot_row::<i64>(depth));
+        }
+        assert_eq!(terms[9], Rational::new(3, 5));
+        assert!(calkin_wilf_sequence::<i32>().take(1000).count() == 1000);
+    }
+}
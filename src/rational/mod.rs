@@ -11,5 +11,8 @@
 //! assert_eq!(numerator, 1);
 //! assert_eq!(denominator, 2);
 //! ```
+pub mod continued_fraction;
+pub mod decimal;
+pub mod fractions;
 pub mod rational_float;
 pub mod rational_integer;
@@ -8,8 +8,32 @@
 //! let a = Rational::new(1, 2);
 //! ```
 
+use crate::error::{ErrorKind, NumbersError};
 use crate::integers::complex_integers;
 use crate::numbers::complex_floats;
+use crate::rational::rational_integer::{self, ParseRationalError};
+use crate::traits::{forward_copy_ops, Integer};
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops;
+use std::str::FromStr;
+
+/// Returns the greatest common divisor of two finite whole numbers with the Euclidean algorithm, which `%` computes exactly on
+/// floats, or 1 if either of them has a fractional part or is not finite.
+fn integer_gcd(a: f64, b: f64) -> f64 {
+    if !a.is_finite() || !b.is_finite() || a.fract() != 0.0 || b.fract() != 0.0 {
+        return 1.0;
+    }
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0.0 {
+        (a, b) = (b, a % b);
+    }
+    if a == 0.0 {
+        1.0
+    } else {
+        a
+    }
+}
 
 /// A rational number is a number that can be in the form p/q where p and q are integers and q is not equal to zero.
 ///
@@ -38,13 +62,151 @@ use crate::numbers::complex_floats;
 /// * `to_string(&self) -> String`
 /// * `to_string_complex(&self) -> String`
 ///
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// Equality and ordering compare values, so `1/2 == 2/4`. As with `f64`, there is no total order, so only `PartialEq` and
+/// `PartialOrd` are implemented. The operators keep the numerator and denominator as computed; call `simplify` to reduce them.
+#[derive(Debug, Clone, Copy)]
 pub struct Rational {
     numerator: f64,
     denominator: f64,
 }
 
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
+
+impl FromStr for Rational {
+    type Err = ParseRationalError;
+
+    /// Parses `"1.5/2"`, `"3 / 4"` or a single number such as `"0.25"`.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        rational_integer::parse_parts(text)
+            .map(|(numerator, denominator)| Self::new(numerator, denominator))
+    }
+}
+
+impl PartialEq for Rational {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Rational {
+    /// Compares the cross products `a*d` and `c*b`. Rounding is monotonic, so products that differ after rounding are
+    /// ordered correctly, while equal ones, which may have overflowed, underflowed or lost digits, are compared exactly.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let (a, b) = self.normalized();
+        let (c, d) = other.normalized();
+        let ordering = (a * d).partial_cmp(&(c * b))?;
+        if ordering != Ordering::Equal {
+            return Some(ordering);
+        }
+        match [a, b, c, d].map(rational_integer::exact_big) {
+            [Some(a), Some(b), Some(c), Some(d)] => Some((a * d).cmp(&(c * b))),
+            _ => Some(ordering),
+        }
+    }
+}
+
+impl From<f64> for Rational {
+    fn from(value: f64) -> Self {
+        Self::new(value, 1.0)
+    }
+}
+
+impl TryFrom<i128> for Rational {
+    type Error = NumbersError;
+
+    /// Succeeds when `|value| <= 2^53`, so that every such integer converts exactly.
+    fn try_from(value: i128) -> Result<Self, Self::Error> {
+        if value.unsigned_abs() > 1 << f64::MANTISSA_DIGITS {
+            return Err(NumbersError::new("try_from", ErrorKind::NotRepresentable));
+        }
+        Ok(Self::new(value as f64, 1.0))
+    }
+}
+
+impl TryFrom<complex_floats::Complex> for Rational {
+    type Error = NumbersError;
+
+    /// Succeeds when the imaginary part is zero.
+    fn try_from(value: complex_floats::Complex) -> Result<Self, Self::Error> {
+        if value.get_imag() != 0.0 {
+            return Err(NumbersError::new("try_from", ErrorKind::NotRepresentable));
+        }
+        Ok(Self::from(value.get_real()))
+    }
+}
+
+impl<T: Integer> From<rational_integer::Rational<T>> for Rational {
+    fn from(value: rational_integer::Rational<T>) -> Self {
+        Self::new(
+            value.get_numerator().to_f64(),
+            value.get_denominator().to_f64(),
+        )
+    }
+}
+
+impl From<Rational> for f64 {
+    fn from(value: Rational) -> Self {
+        value.to_float()
+    }
+}
+
+impl ops::Add for Rational {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Rational::add(&self, &other)
+    }
+}
+
+impl ops::Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.subtract(&other)
+    }
+}
+
+impl ops::Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.multiply(&other)
+    }
+}
+
+impl ops::Div for Rational {
+    type Output = Self;
+
+    /// Panics if `other` is zero.
+    fn div(self, other: Self) -> Self {
+        self.divide(&other)
+    }
+}
+
+impl ops::Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.numerator, self.denominator)
+    }
+}
+
+forward_copy_ops!(Rational, f64, Rational::from(0.0), Rational::from(1.0));
+
 impl Rational {
+    /// Returns the numerator and denominator with the sign moved to the numerator.
+    fn normalized(&self) -> (f64, f64) {
+        if self.denominator < 0.0 {
+            (-self.numerator, -self.denominator)
+        } else {
+            (self.numerator, self.denominator)
+        }
+    }
+
     pub fn new(numerator: f64, denominator: f64) -> Self {
         assert_ne!(denominator, 0.0, "Denominator must not be zero!");
         Self {
@@ -170,21 +332,12 @@ impl Rational {
     /// assert_eq!(b.get_denominator(), 2);
     /// ```
     pub fn simplify(&self) -> Self {
-        let mut numerator = self.numerator;
-        let mut denominator = self.denominator;
-        let mut gcd = 1.0;
-        for i in 1..=numerator.abs().min(denominator.abs()) as i32 {
-            if numerator % i as f64 == 0.0 && denominator % i as f64 == 0.0 {
-                gcd = i as f64;
-            }
-        }
-        numerator /= gcd;
-        denominator /= gcd;
-        Self::new(numerator, denominator)
+        let gcd = integer_gcd(self.numerator, self.denominator);
+        Self::new(self.numerator / gcd, self.denominator / gcd)
     }
 
-    pub fn to_float(&self) -> i128 {
-        self.numerator as i128 / self.denominator as i128
+    pub fn to_float(&self) -> f64 {
+        self.numerator / self.denominator
     }
 
     /// convert to complex number with real and imaginary parts as numbers
@@ -197,10 +350,6 @@ impl Rational {
         complex_integers::Complex::new(self.numerator as i128, self.denominator as i128)
     }
 
-    pub fn to_string(&self) -> String {
-        format!("{}/{}", self.numerator, self.denominator)
-    }
-
     pub fn to_string_complex(&self) -> String {
         format!("{}/{}i", self.numerator, self.denominator)
     }
@@ -247,12 +396,20 @@ pub mod test_rational {
         let b = a.simplify();
         assert_eq!(b.get_numerator(), 1.0);
         assert_eq!(b.get_denominator(), 2.0);
+
+        let c = Rational::new(-100003.0 * 100019.0, 99991.0 * 100019.0).simplify();
+        assert_eq!(c.get_numerator(), -100003.0);
+        assert_eq!(c.get_denominator(), 99991.0);
+        let d = Rational::new(2.5, 5.0).simplify();
+        assert_eq!((d.get_numerator(), d.get_denominator()), (2.5, 5.0));
+        let e = Rational::new(0.0, 7.0).simplify();
+        assert_eq!((e.get_numerator(), e.get_denominator()), (0.0, 1.0));
     }
     #[test]
     fn test_to_float() {
         let a = Rational::new(1.0, 2.0);
         let b = a.to_float();
-        assert_eq!(b, 0);
+        assert_eq!(b, 0.5);
     }
     #[test]
     fn test_to_complex_float() {
@@ -294,4 +451,73 @@ pub mod test_rational {
         assert_eq!(a.get_numerator(), 1.0);
         assert_eq!(a.get_denominator(), 2.0);
     }
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_operators() {
+        let a = Rational::new(1.0, 2.0);
+        let b = Rational::new(1.0, 3.0);
+        let parts = |r: Rational| (r.get_numerator(), r.get_denominator());
+        assert_eq!(parts(a + b), (5.0, 6.0));
+        assert_eq!(parts(&a - &b), (1.0, 6.0));
+        assert_eq!(parts(a * 2.0), (2.0, 2.0));
+        assert_eq!(parts(-(a / b)), (-3.0, 2.0));
+        let mut c = a;
+        c += 0.5;
+        assert_eq!(c, Rational::from(1.0));
+        assert_eq!([a, a, b].iter().sum::<Rational>(), Rational::new(4.0, 3.0));
+        assert_eq!(
+            [a, b].into_iter().product::<Rational>(),
+            Rational::new(1.0, 6.0)
+        );
+    }
+    #[test]
+    fn test_comparison() {
+        assert_eq!(Rational::new(1.0, 2.0), Rational::new(-2.0, -4.0));
+        assert!(Rational::new(1.0, -2.0) < Rational::new(1.0, 3.0));
+        assert!(Rational::new(1.5, 2.0) > Rational::new(2.0, 3.0));
+        let nan = Rational::new(f64::NAN, 1.0);
+        assert_eq!(nan.partial_cmp(&nan), None);
+        assert_ne!(nan, nan);
+
+        // The cross products overflow or underflow, and must not compare equal.
+        assert!(Rational::new(2e300, 1e300) > Rational::new(1e300, 1e300));
+        assert_ne!(Rational::new(2e300, 1e300), Rational::new(1e300, 1e300));
+        assert_eq!(
+            Rational::new(f64::MAX, f64::MAX),
+            Rational::new(f64::MIN_POSITIVE, f64::MIN_POSITIVE)
+        );
+        assert!(Rational::new(f64::MAX, 3.0) < Rational::new(f64::MAX, 2.0));
+        assert!(Rational::new(1e-300, 1e300) < Rational::new(2e-300, 1e300));
+        // The cross products round to the same value.
+        assert!(
+            Rational::new(8757208318859428.0, 670.0) > Rational::new(8757208318859427.0, 670.0)
+        );
+        assert!(Rational::new(f64::INFINITY, 1.0) > Rational::new(f64::MAX, 1.0));
+    }
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            "1.5/2".parse::<Rational>().map(|r| r.get_numerator()),
+            Ok(1.5)
+        );
+        assert_eq!(" 3 / -4 ".parse(), Ok(Rational::new(-3.0, 4.0)));
+        assert_eq!("0.25".parse(), Ok(Rational::new(1.0, 4.0)));
+        assert!("1/0".parse::<Rational>().is_err());
+        assert!("x".parse::<Rational>().is_err());
+    }
+    #[test]
+    fn test_conversions() {
+        let r = Rational::from(rational_integer::Rational::new(3, 4));
+        assert_eq!((r.get_numerator(), r.get_denominator()), (3.0, 4.0));
+        assert_eq!(f64::from(Rational::new(3.0, 4.0)), 0.75);
+        assert_eq!(Rational::from(2.5), Rational::new(5.0, 2.0));
+        assert_eq!(Rational::try_from(-7i128), Ok(Rational::new(-7.0, 1.0)));
+        assert!(Rational::try_from(-(1i128 << 53)).is_ok());
+        assert!(Rational::try_from(i128::MAX).is_err());
+        assert_eq!(
+            Rational::try_from(complex_floats::Complex::new(0.5, 0.0)),
+            Ok(Rational::new(1.0, 2.0))
+        );
+        assert!(Rational::try_from(complex_floats::Complex::new(0.5, 1.0)).is_err());
+    }
 }
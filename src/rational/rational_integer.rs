@@ -1,15 +1,38 @@
 //! Rational numbers are numbers that can be in the form p/q where p and q are integers and q is not equal to zero.
 //!
+//! `Rational<T>` works over `i32` (the default), `i64`, `i128` and `BigInt`. Every value is kept in lowest terms with a
+//! positive denominator, so two rationals are equal exactly when their parts are. Arithmetic checks for overflow: the
+//! `checked_*` methods return an error and the operators panic.
+//!
 //! # Examples
 //!
 //! ```
 //! use numbers_rus::rational::rational_integer::Rational;
 //!
 //! let a = Rational::new(1, 2);
+//!
+//! // The operators return results in lowest terms, and comparisons go by value.
+//! let b: Rational = "1/3".parse().unwrap();
+//! assert_eq!((a + b).to_string(), "5/6");
+//! assert_eq!(a * 4, Rational::new(6, 3));
+//! assert!(b < a);
+//!
+//! // Wider parts for values that do not fit in an i32.
+//! let big = Rational::<i128>::new(1, 1 << 40).pow(-2);
+//! assert_eq!(big.get_numerator(), 1 << 80);
 //! ```
 
+use crate::error::{ErrorKind, NumbersError};
+use crate::integers::big_integer::BigInt;
 use crate::integers::complex_integers;
 use crate::numbers::complex_floats;
+use crate::rational::rational_float;
+use crate::traits::Integer;
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops;
+use std::str::FromStr;
 
 /// A rational number is a number that can be in the form p/q where p and q are integers and q is not equal to zero.
 ///
@@ -18,39 +41,800 @@ use crate::numbers::complex_floats;
 /// ```
 /// use numbers_rus::rational::rational_integer::Rational;
 ///
-/// let a = Rational::new(1, 2);
+/// let a = Rational::new(2, -4);
+/// assert_eq!((a.get_numerator(), a.get_denominator()), (-1, 2));
+/// assert_eq!(a.floor(), Rational::from(-1));
+/// assert_eq!(a.recip(), Rational::from(-2));
 /// ```
 ///
 /// # Methods
 ///
-/// * `new(numerator: i32, denominator: i32) -> Self`
+/// * `new(numerator: T, denominator: T) -> Self`
+/// * `checked_new(numerator: T, denominator: T) -> Result<Self, NumbersError>`
+/// * `from_integer(value: T) -> Self`
+/// * `from_f64_exact(value: f64) -> Result<Self, NumbersError>`
+/// * `approximate(value: f64, max_denominator: T) -> Result<Self, NumbersError>`
+/// * `simplest_within(value: f64, tolerance: f64) -> Result<Self, NumbersError>`
 /// * `add(&self, other: &Self) -> Self`
 /// * `subtract(&self, other: &Self) -> Self`
 /// * `multiply(&self, other: &Self) -> Self`
 /// * `divide(&self, other: &Self) -> Self`
+/// * `checked_add`, `checked_sub`, `checked_mul`, `checked_div`, `checked_pow`, `checked_recip` - as above, returning an
+///   error instead of panicking
+/// * `pow(&self, exponent: i32) -> Self`
+/// * `recip(&self) -> Self`
+/// * `abs(&self) -> Self`
+/// * `floor(&self) -> Self`
+/// * `ceil(&self) -> Self`
+/// * `round(&self) -> Self`
+/// * `trunc(&self) -> Self`
+/// * `fract(&self) -> Self`
+/// * `is_integer(&self) -> bool`
 /// * `display(&self)`
-/// * `get_numerator(&self) -> i32`
-/// * `get_denominator(&self) -> i32`
+/// * `get_numerator(&self) -> T`
+/// * `get_denominator(&self) -> T`
 /// * `simplify(&self) -> Self`
 /// * `to_float(&self) -> f64`
 /// * `to_complex_float(&self) -> complex_floats::Complex`
 /// * `to_complex_int(&self) -> complex_integers::Complex`
 /// * `to_string(&self) -> String`
+/// * `to_expansion(&self, radix: u32) -> DecimalExpansion`
+/// * `to_expansion_bounded(&self, radix: u32, max_digits: usize) -> Option<DecimalExpansion>`
+/// * `to_decimal_string(&self, radix: u32, precision: usize, mode: RoundingMode) -> String`
+/// * `to_mixed_string(&self) -> String`
+/// * `from_str_radix(text: &str, radix: u32) -> Result<Self, ParseRationalError>`
 /// * `to_string_complex(&self) -> String`
 ///
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Rational {
-    numerator: i32,
-    denominator: i32,
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational<T = i32> {
+    numerator: T,
+    denominator: T,
+}
+
+/// Error returned when parsing a rational number with `FromStr` fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRationalError {
+    message: &'static str,
+}
+
+impl fmt::Display for ParseRationalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ParseRationalError {}
+
+impl ParseRationalError {
+    pub(crate) fn new(message: &'static str) -> Self {
+        ParseRationalError { message }
+    }
+}
+
+/// Splits `"p/q"` or `"p"` into its numerator and denominator and parses both, rejecting a zero denominator. Shared with
+/// `rational_float::Rational`.
+pub(crate) fn parse_parts<T: FromStr + PartialEq + From<i8>>(
+    text: &str,
+) -> Result<(T, T), ParseRationalError> {
+    let invalid = ParseRationalError {
+        message: "invalid rational number",
+    };
+    let parse = |part: &str| part.trim().parse::<T>().map_err(|_| invalid.clone());
+    let (numerator, denominator) = match text.split_once('/') {
+        Some((numerator, denominator)) => (parse(numerator)?, parse(denominator)?),
+        None => (parse(text)?, T::from(1)),
+    };
+    if denominator == T::from(0) {
+        return Err(ParseRationalError {
+            message: "zero denominator",
+        });
+    }
+    Ok((numerator, denominator))
+}
+
+/// Returns the gcd of two non-zero integers using Stein's binary algorithm, or `None` if it does not fit in `T`, which only
+/// happens when both are the minimum value of a primitive type.
+fn binary_gcd<T: Integer>(left: &T, right: &T) -> Option<T> {
+    let (left_zeros, right_zeros) = (left.trailing_zeros(), right.trailing_zeros());
+    let shift = left_zeros.min(right_zeros);
+    // Odd values are never the minimum of a primitive type, so their absolute values cannot overflow.
+    let mut u = left.shift_right(left_zeros).abs();
+    let mut v = right.shift_right(right_zeros).abs();
+    while u != v {
+        if u > v {
+            std::mem::swap(&mut u, &mut v);
+        }
+        v = v.subtract(&u);
+        v = v.shift_right(v.trailing_zeros());
+    }
+    let gcd = u.shift_left(shift);
+    (!gcd.is_negative() && gcd.shift_right(shift) == u).then_some(gcd)
+}
+
+/// Returns the gcd of two non-zero integers of which at least one is positive, so that the gcd always fits.
+fn bounded_gcd<T: Integer>(left: &T, right: &T) -> T {
+    binary_gcd(left, right).expect("gcd is bounded by a positive operand")
+}
+
+/// Returns `left / right` for a division known to be exact.
+fn exact_div<T: Integer>(left: &T, right: &T) -> T {
+    left.div_rem(right).0
+}
+
+/// Returns the floored quotient and the remainder in `0..denominator` for a positive denominator.
+pub(crate) fn floor_div_rem<T: Integer>(numerator: &T, denominator: &T) -> (T, T) {
+    let (quotient, remainder) = numerator.div_rem(denominator);
+    if remainder.is_negative() {
+        (quotient.subtract(&T::one()), remainder.add(denominator))
+    } else {
+        (quotient, remainder)
+    }
+}
+
+/// Returns `base^exponent`, or `None` on overflow.
+fn checked_int_pow<T: Integer>(base: &T, exponent: u32) -> Option<T> {
+    let mut result = T::one();
+    let mut base = base.clone();
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result.checked_mul(&base)?;
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            base = base.checked_mul(&base)?;
+        }
+    }
+    Some(result)
+}
+
+/// Unwraps the result of a checked operation, panicking with the error message.
+fn unwrap_or_panic<T>(result: Result<T, NumbersError>) -> T {
+    result.unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Returns the exact value of a finite `f64` as a rational, or `None` for infinities and NaN.
+pub(crate) fn exact_big(value: f64) -> Option<Rational<BigInt>> {
+    if !value.is_finite() {
+        return None;
+    }
+    let bits = value.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    // value = mantissa * 2^power, with subnormals sharing the smallest exponent.
+    let (mantissa, power) = if exponent == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), exponent - 1075)
+    };
+    let mut mantissa = BigInt::from(mantissa);
+    if value.is_sign_negative() {
+        mantissa = -mantissa;
+    }
+    Some(if power >= 0 {
+        Rational::from_integer(mantissa << power as usize)
+    } else {
+        Rational::new(mantissa, BigInt::one() << power.unsigned_abs() as usize)
+    })
 }
 
-impl Rational {
-    pub fn new(numerator: i32, denominator: i32) -> Self {
-        assert_ne!(denominator, 0, "Denominator must not be zero!");
+/// Returns the simplest rational, the one with the smallest denominator and then the smallest numerator, in the closed
+/// interval `[low, high]`, by expanding both ends as continued fractions until they diverge (a walk down the Stern–Brocot
+/// tree).
+fn simplest_between(low: &Rational<BigInt>, high: &Rational<BigInt>) -> Rational<BigInt> {
+    let zero = BigInt::zero();
+    if high.numerator.is_negative() {
+        return -simplest_between(&-high, &-low);
+    }
+    if low.numerator <= zero {
+        return Rational::from_integer(zero);
+    }
+    let (mut low, mut high) = (low.clone(), high.clone());
+    let mut terms = Vec::new();
+    let last = loop {
+        let ceiling = low.ceil();
+        if ceiling <= high {
+            break ceiling;
+        }
+        // No integer lies in the interval, so both ends share the integer part.
+        let floor = low.floor();
+        terms.push(floor.clone());
+        (low, high) = ((&high - &floor).recip(), (&low - &floor).recip());
+    };
+    terms
+        .iter()
+        .rev()
+        .fold(last, |value, term| term + value.recip())
+}
+
+/// Converts a rational with big parts into one with parts of type `T`, or returns an `Overflow` error.
+fn narrow<T: Integer>(
+    value: &Rational<BigInt>,
+    operation: &'static str,
+) -> Result<Rational<T>, NumbersError> {
+    match (
+        T::from_big_int(&value.numerator),
+        T::from_big_int(&value.denominator),
+    ) {
+        (Some(numerator), Some(denominator)) => Ok(Rational {
+            numerator,
+            denominator,
+        }),
+        _ => Err(NumbersError::new(operation, ErrorKind::Overflow)),
+    }
+}
+
+impl<T: Integer> fmt::Display for Rational<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
+
+impl<T: Integer> FromStr for Rational<T> {
+    type Err = ParseRationalError;
+
+    /// Parses `"3/4"`, `"-6 / 8"`, a whole number such as `"5"`, a mixed number such as `"2 1/3"`, or a decimal such as
+    /// `"0.1(6)"` or `"1.5e-3"`, reducing to lowest terms. See `from_str_radix`.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(text, 10)
+    }
+}
+
+impl<T: Integer> Ord for Rational<T> {
+    /// Compares the integer parts and then, as in a continued fraction expansion, the reciprocals of the fractional parts in
+    /// reverse, so no product is formed and nothing can overflow.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (mut a, mut b) = (self.numerator.clone(), self.denominator.clone());
+        let (mut c, mut d) = (other.numerator.clone(), other.denominator.clone());
+        let mut reversed = false;
+        loop {
+            let (left_floor, left_rest) = floor_div_rem(&a, &b);
+            let (right_floor, right_rest) = floor_div_rem(&c, &d);
+            let ordering = match (left_rest.is_zero(), right_rest.is_zero()) {
+                _ if left_floor != right_floor => left_floor.cmp(&right_floor),
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Less,
+                (false, true) => Ordering::Greater,
+                (false, false) => {
+                    (a, b, c, d) = (b, left_rest, d, right_rest);
+                    reversed = !reversed;
+                    continue;
+                }
+            };
+            return if reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            };
+        }
+    }
+}
+
+impl<T: Integer> PartialOrd for Rational<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Integer> From<T> for Rational<T> {
+    fn from(value: T) -> Self {
+        Self::from_integer(value)
+    }
+}
+
+macro_rules! impl_try_from_i128 {
+    ($($type:ty),*) => {
+        $(
+            impl TryFrom<i128> for Rational<$type> {
+                type Error = NumbersError;
+
+                fn try_from(value: i128) -> Result<Self, Self::Error> {
+                    <$type>::try_from(value)
+                        .map(Self::from)
+                        .map_err(|_| NumbersError::new("try_from", ErrorKind::NotRepresentable))
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_i128!(i32, i64);
+
+impl From<i128> for Rational<BigInt> {
+    fn from(value: i128) -> Self {
+        Self::from_integer(BigInt::from(value))
+    }
+}
+
+impl<T: Integer> TryFrom<rational_float::Rational> for Rational<T> {
+    type Error = NumbersError;
+
+    /// Succeeds when the numerator and denominator are both whole numbers that fit in a `T`.
+    fn try_from(value: rational_float::Rational) -> Result<Self, Self::Error> {
+        let convert = |part: f64| match exact_big(part) {
+            Some(exact) if part.fract() == 0.0 => T::from_big_int(&exact.numerator),
+            _ => None,
+        };
+        let error = || NumbersError::new("try_from", ErrorKind::NotRepresentable);
+        let numerator = convert(value.get_numerator()).ok_or_else(error)?;
+        let denominator = convert(value.get_denominator()).ok_or_else(error)?;
+        Self::checked_new(numerator, denominator).map_err(|_| error())
+    }
+}
+
+impl<T: Integer> TryFrom<complex_integers::Complex> for Rational<T> {
+    type Error = NumbersError;
+
+    /// Succeeds when the imaginary part is zero and the real part fits in a `T`.
+    fn try_from(value: complex_integers::Complex) -> Result<Self, Self::Error> {
+        match T::from_big_int(&BigInt::from(value.get_real())) {
+            Some(real) if value.get_imag() == 0 => Ok(Self::from_integer(real)),
+            _ => Err(NumbersError::new("try_from", ErrorKind::NotRepresentable)),
+        }
+    }
+}
+
+impl<T: Integer> TryFrom<complex_floats::Complex> for Rational<T> {
+    type Error = NumbersError;
+
+    /// Succeeds when the imaginary part is zero and the exact value of the real part fits in a `T`.
+    fn try_from(value: complex_floats::Complex) -> Result<Self, Self::Error> {
+        match exact_big(value.get_real()) {
+            Some(exact) if value.get_imag() == 0.0 => narrow(&exact, "try_from")
+                .map_err(|_| NumbersError::new("try_from", ErrorKind::NotRepresentable)),
+            _ => Err(NumbersError::new("try_from", ErrorKind::NotRepresentable)),
+        }
+    }
+}
+
+impl<T: Integer> From<Rational<T>> for f64 {
+    fn from(value: Rational<T>) -> Self {
+        value.to_float()
+    }
+}
+
+impl<T: Integer> ops::Neg for Rational<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        -&self
+    }
+}
+
+impl<T: Integer> ops::Neg for &Rational<T> {
+    type Output = Rational<T>;
+
+    fn neg(self) -> Rational<T> {
+        unwrap_or_panic(self.checked_negate("negate"))
+    }
+}
+
+/// Implements the owned, borrowed, `*Assign` and right-hand scalar forms of an operator from the corresponding inherent
+/// method.
+macro_rules! impl_binop {
+    ($($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $inherent:ident);* $(;)?) => {
+        $(
+            impl<T: Integer> ops::$trait<&Rational<T>> for &Rational<T> {
+                type Output = Rational<T>;
+
+                fn $method(self, other: &Rational<T>) -> Rational<T> {
+                    Rational::$inherent(self, other)
+                }
+            }
+
+            impl<T: Integer> ops::$trait<Rational<T>> for Rational<T> {
+                type Output = Rational<T>;
+
+                fn $method(self, other: Rational<T>) -> Rational<T> {
+                    Rational::$inherent(&self, &other)
+                }
+            }
+
+            impl<T: Integer> ops::$trait<&Rational<T>> for Rational<T> {
+                type Output = Rational<T>;
+
+                fn $method(self, other: &Rational<T>) -> Rational<T> {
+                    Rational::$inherent(&self, other)
+                }
+            }
+
+            impl<T: Integer> ops::$trait<Rational<T>> for &Rational<T> {
+                type Output = Rational<T>;
+
+                fn $method(self, other: Rational<T>) -> Rational<T> {
+                    Rational::$inherent(self, &other)
+                }
+            }
+
+            impl<T: Integer> ops::$trait<T> for Rational<T> {
+                type Output = Rational<T>;
+
+                fn $method(self, other: T) -> Rational<T> {
+                    Rational::$inherent(&self, &Rational::from_integer(other))
+                }
+            }
+
+            impl<T: Integer> ops::$trait<T> for &Rational<T> {
+                type Output = Rational<T>;
+
+                fn $method(self, other: T) -> Rational<T> {
+                    Rational::$inherent(self, &Rational::from_integer(other))
+                }
+            }
+
+            impl<T: Integer> ops::$assign_trait<Rational<T>> for Rational<T> {
+                fn $assign_method(&mut self, other: Rational<T>) {
+                    *self = Rational::$inherent(self, &other);
+                }
+            }
+
+            impl<T: Integer> ops::$assign_trait<&Rational<T>> for Rational<T> {
+                fn $assign_method(&mut self, other: &Rational<T>) {
+                    *self = Rational::$inherent(self, other);
+                }
+            }
+
+            impl<T: Integer> ops::$assign_trait<T> for Rational<T> {
+                fn $assign_method(&mut self, other: T) {
+                    *self = Rational::$inherent(self, &Rational::from_integer(other));
+                }
+            }
+        )*
+    };
+}
+
+impl_binop!(
+    Add, add, AddAssign, add_assign, add;
+    Sub, sub, SubAssign, sub_assign, subtract;
+    Mul, mul, MulAssign, mul_assign, multiply;
+    Div, div, DivAssign, div_assign, divide;
+);
+
+/// Implements the left-hand scalar forms, such as `1 - a`, for each concrete integer type.
+macro_rules! impl_scalar_binop {
+    ($($type:ty),*) => {
+        $(
+            impl_scalar_binop!(@op $type, Add, add, add);
+            impl_scalar_binop!(@op $type, Sub, sub, subtract);
+            impl_scalar_binop!(@op $type, Mul, mul, multiply);
+            impl_scalar_binop!(@op $type, Div, div, divide);
+        )*
+    };
+    (@op $type:ty, $trait:ident, $method:ident, $inherent:ident) => {
+        impl ops::$trait<Rational<$type>> for $type {
+            type Output = Rational<$type>;
+
+            fn $method(self, other: Rational<$type>) -> Rational<$type> {
+                Rational::from_integer(self).$inherent(&other)
+            }
+        }
+    };
+}
+
+impl_scalar_binop!(i32, i64, i128, BigInt);
+
+impl<T: Integer> Sum for Rational<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::from_integer(T::zero()), |total, value| total + value)
+    }
+}
+
+impl<'a, T: Integer> Sum<&'a Rational<T>> for Rational<T> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::from_integer(T::zero()), |total, value| total + value)
+    }
+}
+
+impl<T: Integer> Product for Rational<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::from_integer(T::one()), |total, value| total * value)
+    }
+}
+
+impl<'a, T: Integer> Product<&'a Rational<T>> for Rational<T> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::from_integer(T::one()), |total, value| total * value)
+    }
+}
+
+impl<T: Integer> Rational<T> {
+    /// Creates `numerator/denominator` in lowest terms with a positive denominator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the denominator is zero or the normalized value does not fit in `T`, such as `i32::MIN / -1`.
+    pub fn new(numerator: T, denominator: T) -> Self {
+        assert!(!denominator.is_zero(), "Denominator must not be zero!");
+        unwrap_or_panic(Self::checked_new(numerator, denominator))
+    }
+
+    /// Creates `numerator/denominator` in lowest terms with a positive denominator, or returns an error if the denominator is
+    /// zero or the normalized value does not fit in `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_rus::error::ErrorKind;
+    /// use numbers_rus::rational::rational_integer::Rational;
+    ///
+    /// assert_eq!(Rational::checked_new(6, -8), Ok(Rational::new(-3, 4)));
+    /// assert_eq!(Rational::checked_new(1, 0).unwrap_err().kind(), ErrorKind::DivisionByZero);
+    /// assert_eq!(Rational::checked_new(i32::MIN, -1).unwrap_err().kind(), ErrorKind::Overflow);
+    /// ```
+    pub fn checked_new(numerator: T, denominator: T) -> Result<Self, NumbersError> {
+        if denominator.is_zero() {
+            return Err(NumbersError::new("new", ErrorKind::DivisionByZero));
+        }
+        if numerator.is_zero() {
+            return Ok(Self::from_integer(numerator));
+        }
+        let (numerator, denominator) = match binary_gcd(&numerator, &denominator) {
+            Some(gcd) => (exact_div(&numerator, &gcd), exact_div(&denominator, &gcd)),
+            // Only both parts being the minimum value makes the gcd overflow, and then the value is one.
+            None => (T::one(), T::one()),
+        };
+        if denominator.is_negative() {
+            let overflow = || NumbersError::new("new", ErrorKind::Overflow);
+            return Ok(Self {
+                numerator: numerator.checked_neg().ok_or_else(overflow)?,
+                denominator: denominator.checked_neg().ok_or_else(overflow)?,
+            });
+        }
+        Ok(Self {
+            numerator,
+            denominator,
+        })
+    }
+
+    /// Creates the rational `value/1`.
+    pub fn from_integer(value: T) -> Self {
         Self {
+            numerator: value,
+            denominator: T::one(),
+        }
+    }
+
+    /// Returns the exact value of a finite `f64`, which is always a fraction with a power of two as denominator. Returns
+    /// a `NotRepresentable` error for infinities and NaN and an `Overflow` error if the parts do not fit in `T`; with
+    /// `BigInt` parts every finite double converts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_rus::integers::big_integer::BigInt;
+    /// use numbers_rus::rational::rational_integer::Rational;
+    ///
+    /// assert_eq!(Rational::<i32>::from_f64_exact(-0.375), Ok(Rational::new(-3, 8)));
+    /// let tenth = Rational::<i64>::from_f64_exact(0.1).unwrap();
+    /// assert_eq!(tenth, Rational::new(3602879701896397, 1 << 55));
+    /// let tiny = Rational::<BigInt>::from_f64_exact(f64::MIN_POSITIVE).unwrap();
+    /// assert_eq!(tiny.get_denominator(), BigInt::from(2).pow(1022));
+    /// assert!(Rational::<i32>::from_f64_exact(f64::NAN).is_err());
+    /// ```
+    pub fn from_f64_exact(value: f64) -> Result<Self, NumbersError> {
+        let exact = exact_big(value).ok_or(NumbersError::new(
+            "from_f64_exact",
+            ErrorKind::NotRepresentable,
+        ))?;
+        narrow(&exact, "from_f64_exact")
+    }
+
+    /// Returns the rational closest to `value` among those with a denominator of at most `max_denominator`, found from the
+    /// convergents and semiconvergents of the continued fraction of the exact value of `value`. When two candidates are equally
+    /// close, the one with the smaller denominator is returned, and between equal denominators the convergent. Errors as
+    /// `from_f64_exact` does.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_denominator` is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_rus::rational::rational_integer::Rational;
+    ///
+    /// let pi = std::f64::consts::PI;
+    /// assert_eq!(Rational::approximate(pi, 10), Ok(Rational::new(22, 7)));
+    /// assert_eq!(Rational::approximate(pi, 1000), Ok(Rational::new(355, 113)));
+    /// assert_eq!(Rational::approximate(-0.1, 100), Ok(Rational::new(-1, 10)));
+    /// ```
+    pub fn approximate(value: f64, max_denominator: T) -> Result<Self, NumbersError> {
+        assert!(
+            !max_denominator.is_negative() && !max_denominator.is_zero(),
+            "Maximum denominator must be positive!"
+        );
+        let exact = exact_big(value).ok_or(NumbersError::new(
+            "approximate",
+            ErrorKind::NotRepresentable,
+        ))?;
+        let limit = max_denominator.to_big_int();
+        if exact.denominator <= limit {
+            return narrow(&exact, "approximate");
+        }
+        // Convergents p/q of the continued fraction, kept as the last two (p0/q0, p1/q1).
+        let (mut p0, mut q0, mut p1, mut q1) =
+            (BigInt::zero(), BigInt::one(), BigInt::one(), BigInt::zero());
+        let (mut numerator, mut denominator) = (exact.numerator.clone(), exact.denominator.clone());
+        loop {
+            let (term, remainder) = floor_div_rem(&numerator, &denominator);
+            let q2 = &q0 + &(&term * &q1);
+            if q2 > limit {
+                break;
+            }
+            (p0, q0, p1, q1) = (p1.clone(), q1, &p0 + &(&term * &p1), q2);
+            (numerator, denominator) = (denominator, remainder);
+        }
+        // The best semiconvergent below the limit competes with the last convergent.
+        let steps = floor_div_rem(&(&limit - &q0), &q1).0;
+        let semiconvergent = Rational::new(&p0 + &(&steps * &p1), &q0 + &(&steps * &q1));
+        let convergent = Rational::new(p1, q1);
+        let best = match (&semiconvergent - &exact)
+            .abs()
+            .cmp(&(&convergent - &exact).abs())
+        {
+            Ordering::Less => semiconvergent,
+            Ordering::Equal if semiconvergent.denominator < convergent.denominator => {
+                semiconvergent
+            }
+            _ => convergent,
+        };
+        narrow(&best, "approximate")
+    }
+
+    /// Returns the simplest rational within `tolerance` of `value`, that is the one with the smallest denominator (and then the
+    /// smallest absolute numerator) in the closed interval `[value - tolerance, value + tolerance]`, computed exactly. Useful
+    /// for recovering a clean fraction from a measured or rounded float. Returns a `NegativeArgument` error for a negative
+    /// tolerance and errors as `from_f64_exact` does otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_rus::rational::rational_integer::Rational;
+    ///
+    /// assert_eq!(Rational::simplest_within(0.3333, 0.001), Ok(Rational::new(1, 3)));
+    /// assert_eq!(Rational::simplest_within(2.71828, 0.0005), Ok(Rational::new(87, 32)));
+    /// assert_eq!(Rational::simplest_within(-0.4, 0.2), Ok(Rational::new(-1, 2)));
+    /// assert_eq!(Rational::<i64>::simplest_within(0.1, 0.0), Rational::from_f64_exact(0.1));
+    /// ```
+    pub fn simplest_within(value: f64, tolerance: f64) -> Result<Self, NumbersError> {
+        if tolerance < 0.0 {
+            return Err(NumbersError::new(
+                "simplest_within",
+                ErrorKind::NegativeArgument,
+            ));
+        }
+        let not_representable =
+            || NumbersError::new("simplest_within", ErrorKind::NotRepresentable);
+        let center = exact_big(value).ok_or_else(not_representable)?;
+        let radius = exact_big(tolerance).ok_or_else(not_representable)?;
+        narrow(
+            &simplest_between(&(&center - &radius), &(&center + &radius)),
+            "simplest_within",
+        )
+    }
+
+    /// Returns `self + other` or `self - other`, reducing by the gcd of the denominators first so that intermediate values
+    /// stay as small as possible.
+    fn checked_combine(
+        &self,
+        other: &Self,
+        subtract: bool,
+        operation: &'static str,
+    ) -> Result<Self, NumbersError> {
+        let overflow = || NumbersError::new(operation, ErrorKind::Overflow);
+        let gcd = bounded_gcd(&self.denominator, &other.denominator);
+        let left_scale = exact_div(&other.denominator, &gcd);
+        let right_scale = exact_div(&self.denominator, &gcd);
+        let left = self
+            .numerator
+            .checked_mul(&left_scale)
+            .ok_or_else(overflow)?;
+        let right = other
+            .numerator
+            .checked_mul(&right_scale)
+            .ok_or_else(overflow)?;
+        let numerator = if subtract {
+            left.checked_sub(&right)
+        } else {
+            left.checked_add(&right)
+        }
+        .ok_or_else(overflow)?;
+        if numerator.is_zero() {
+            return Ok(Self::from_integer(numerator));
+        }
+        // Since both inputs are in lowest terms, only factors of the shared gcd can cancel.
+        let common = bounded_gcd(&numerator, &gcd);
+        let denominator = right_scale
+            .checked_mul(&exact_div(&other.denominator, &common))
+            .ok_or_else(overflow)?;
+        Ok(Self {
+            numerator: exact_div(&numerator, &common),
+            denominator,
+        })
+    }
+
+    /// Returns `self + other`, or an `Overflow` error.
+    pub fn checked_add(&self, other: &Self) -> Result<Self, NumbersError> {
+        self.checked_combine(other, false, "add")
+    }
+
+    /// Returns `self - other`, or an `Overflow` error.
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, NumbersError> {
+        self.checked_combine(other, true, "subtract")
+    }
+
+    /// Returns `self * other`, or an `Overflow` error. Cross-cancels before multiplying.
+    pub fn checked_mul(&self, other: &Self) -> Result<Self, NumbersError> {
+        if self.numerator.is_zero() || other.numerator.is_zero() {
+            return Ok(Self::from_integer(T::zero()));
+        }
+        let overflow = || NumbersError::new("multiply", ErrorKind::Overflow);
+        let left_gcd = bounded_gcd(&self.numerator, &other.denominator);
+        let right_gcd = bounded_gcd(&other.numerator, &self.denominator);
+        let numerator = exact_div(&self.numerator, &left_gcd)
+            .checked_mul(&exact_div(&other.numerator, &right_gcd))
+            .ok_or_else(overflow)?;
+        let denominator = exact_div(&self.denominator, &right_gcd)
+            .checked_mul(&exact_div(&other.denominator, &left_gcd))
+            .ok_or_else(overflow)?;
+        Ok(Self {
             numerator,
             denominator,
+        })
+    }
+
+    /// Returns `self / other`, or a `DivisionByZero` or `Overflow` error.
+    pub fn checked_div(&self, other: &Self) -> Result<Self, NumbersError> {
+        other
+            .checked_recip()
+            .and_then(|inverse| self.checked_mul(&inverse))
+            .map_err(|error| NumbersError::new("divide", error.kind()))
+    }
+
+    /// Returns `1 / self`, or a `DivisionByZero` or `Overflow` error.
+    pub fn checked_recip(&self) -> Result<Self, NumbersError> {
+        if self.numerator.is_zero() {
+            return Err(NumbersError::new("recip", ErrorKind::DivisionByZero));
+        }
+        if !self.numerator.is_negative() {
+            return Ok(Self {
+                numerator: self.denominator.clone(),
+                denominator: self.numerator.clone(),
+            });
         }
+        let overflow = || NumbersError::new("recip", ErrorKind::Overflow);
+        Ok(Self {
+            numerator: self.denominator.checked_neg().ok_or_else(overflow)?,
+            denominator: self.numerator.checked_neg().ok_or_else(overflow)?,
+        })
+    }
+
+    /// Returns `self` raised to `exponent`, which may be negative, or a `DivisionByZero` or `Overflow` error.
+    pub fn checked_pow(&self, exponent: i32) -> Result<Self, NumbersError> {
+        let base = if exponent < 0 {
+            self.checked_recip()
+                .map_err(|error| NumbersError::new("pow", error.kind()))?
+        } else {
+            self.clone()
+        };
+        let overflow = || NumbersError::new("pow", ErrorKind::Overflow);
+        // Powers of coprime parts stay coprime, so the result is already in lowest terms.
+        Ok(Self {
+            numerator: checked_int_pow(&base.numerator, exponent.unsigned_abs())
+                .ok_or_else(overflow)?,
+            denominator: checked_int_pow(&base.denominator, exponent.unsigned_abs())
+                .ok_or_else(overflow)?,
+        })
+    }
+
+    fn checked_negate(&self, operation: &'static str) -> Result<Self, NumbersError> {
+        Ok(Self {
+            numerator: self
+                .numerator
+                .checked_neg()
+                .ok_or(NumbersError::new(operation, ErrorKind::Overflow))?,
+            denominator: self.denominator.clone(),
+        })
     }
 
     /// add two rational numbers
@@ -71,9 +855,7 @@ impl Rational {
     /// assert_eq!(c.get_denominator(), 6);
     /// ```
     pub fn add(&self, other: &Self) -> Self {
-        let numerator = self.numerator * other.denominator + other.numerator * self.denominator;
-        let denominator = self.denominator * other.denominator;
-        Self::new(numerator, denominator)
+        unwrap_or_panic(self.checked_add(other))
     }
 
     /// subtract two rational numbers
@@ -94,9 +876,7 @@ impl Rational {
     /// assert_eq!(c.get_denominator(), 6);
     /// ```
     pub fn subtract(&self, other: &Self) -> Self {
-        let numerator = self.numerator * other.denominator - other.numerator * self.denominator;
-        let denominator = self.denominator * other.denominator;
-        Self::new(numerator, denominator)
+        unwrap_or_panic(self.checked_sub(other))
     }
 
     /// multiply two rational numbers
@@ -117,9 +897,7 @@ impl Rational {
     /// assert_eq!(c.get_denominator(), 6);
     /// ```
     pub fn multiply(&self, other: &Self) -> Self {
-        let numerator = self.numerator * other.numerator;
-        let denominator = self.denominator * other.denominator;
-        Self::new(numerator, denominator)
+        unwrap_or_panic(self.checked_mul(other))
     }
 
     /// divide two rational numbers
@@ -140,24 +918,130 @@ impl Rational {
     /// assert_eq!(c.get_denominator(), 2);
     /// ```
     pub fn divide(&self, other: &Self) -> Self {
-        let numerator = self.numerator * other.denominator;
-        let denominator = self.denominator * other.numerator;
-        Self::new(numerator, denominator)
+        unwrap_or_panic(self.checked_div(other))
+    }
+
+    /// raise to an integer power, which may be negative
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_rus::rational::rational_integer::Rational;
+    ///
+    /// let a = Rational::new(-2, 3);
+    /// assert_eq!(a.pow(3), Rational::new(-8, 27));
+    /// assert_eq!(a.pow(-2), Rational::new(9, 4));
+    /// assert_eq!(a.pow(0), Rational::from(1));
+    /// ```
+    pub fn pow(&self, exponent: i32) -> Self {
+        unwrap_or_panic(self.checked_pow(exponent))
+    }
+
+    /// reciprocal `1 / self`; panics if `self` is zero
+    pub fn recip(&self) -> Self {
+        unwrap_or_panic(self.checked_recip())
+    }
+
+    /// absolute value
+    pub fn abs(&self) -> Self {
+        if self.numerator.is_negative() {
+            unwrap_or_panic(self.checked_negate("abs"))
+        } else {
+            self.clone()
+        }
+    }
+
+    /// largest integer less than or equal to `self`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_rus::rational::rational_integer::Rational;
+    ///
+    /// let a = Rational::new(-7, 2);
+    /// assert_eq!(a.floor(), Rational::from(-4));
+    /// assert_eq!(a.ceil(), Rational::from(-3));
+    /// assert_eq!(a.trunc(), Rational::from(-3));
+    /// assert_eq!(a.round(), Rational::from(-4));
+    /// assert_eq!(a.fract(), Rational::new(-1, 2));
+    /// ```
+    pub fn floor(&self) -> Self {
+        Self::from_integer(floor_div_rem(&self.numerator, &self.denominator).0)
+    }
+
+    /// smallest integer greater than or equal to `self`
+    pub fn ceil(&self) -> Self {
+        let (quotient, remainder) = floor_div_rem(&self.numerator, &self.denominator);
+        if remainder.is_zero() {
+            Self::from_integer(quotient)
+        } else {
+            Self::from_integer(quotient.add(&T::one()))
+        }
+    }
+
+    /// nearest integer, rounding half-way cases away from zero like `f64::round`
+    pub fn round(&self) -> Self {
+        let (quotient, remainder) = floor_div_rem(&self.numerator, &self.denominator);
+        // Compare the remainder with half the denominator without doubling it, which could overflow.
+        let rest = self.denominator.subtract(&remainder);
+        let round_up = match remainder.cmp(&rest) {
+            Ordering::Greater => true,
+            Ordering::Equal => !self.numerator.is_negative(),
+            Ordering::Less => false,
+        };
+        if round_up {
+            Self::from_integer(quotient.add(&T::one()))
+        } else {
+            Self::from_integer(quotient)
+        }
+    }
+
+    /// integer part, rounding towards zero
+    pub fn trunc(&self) -> Self {
+        if self.numerator.is_negative() {
+            self.ceil()
+        } else {
+            self.floor()
+        }
+    }
+
+    /// fractional part `self - self.trunc()`, which has the same sign as `self`
+    pub fn fract(&self) -> Self {
+        let remainder = floor_div_rem(&self.numerator, &self.denominator).1;
+        let numerator = if self.numerator.is_negative() && !remainder.is_zero() {
+            remainder.subtract(&self.denominator)
+        } else {
+            remainder
+        };
+        if numerator.is_zero() {
+            return Self::from_integer(numerator);
+        }
+        // The remainder is coprime to the denominator because the numerator is.
+        Self {
+            numerator,
+            denominator: self.denominator.clone(),
+        }
+    }
+
+    /// whether the denominator is one
+    pub fn is_integer(&self) -> bool {
+        self.denominator == T::one()
     }
 
     pub fn display(&self) {
-        println!("{}/{}", self.numerator, self.denominator);
+        println!("{}", self);
     }
 
-    pub fn get_numerator(&self) -> i32 {
-        self.numerator
+    pub fn get_numerator(&self) -> T {
+        self.numerator.clone()
     }
 
-    pub fn get_denominator(&self) -> i32 {
-        self.denominator
+    pub fn get_denominator(&self) -> T {
+        self.denominator.clone()
     }
 
-    /// simplify by dividing numerator and denominator by their greatest common divisor.  If the gcd is 1, then the rational number is already in simplest form.
+    /// simplify by dividing numerator and denominator by their greatest common divisor. Rationals are always stored in lowest
+    /// terms, so this returns an equal copy.
     ///
     /// # Examples
     ///
@@ -170,35 +1054,67 @@ impl Rational {
     /// assert_eq!(b.get_denominator(), 2);
     /// ```
     pub fn simplify(&self) -> Self {
-        let mut numerator = self.numerator;
-        let mut denominator = self.denominator;
-        let mut gcd = 1;
-        for i in 1..=numerator.abs().min(denominator.abs()) {
-            if numerator % i == 0 && denominator % i == 0 {
-                gcd = i;
-            }
-        }
-        numerator /= gcd;
-        denominator /= gcd;
-        Self::new(numerator, denominator)
+        self.clone()
     }
 
+    /// nearest `f64`, computed through `BigInt` when the parts are too large for a direct division
     pub fn to_float(&self) -> f64 {
-        self.numerator as f64 / self.denominator as f64
+        let limit = (1u64 << 53) as f64;
+        let (numerator, denominator) = (self.numerator.to_f64(), self.denominator.to_f64());
+        if numerator.abs() <= limit && denominator <= limit {
+            return numerator / denominator;
+        }
+        let negative = self.numerator.is_negative();
+        let (numerator, denominator) = (
+            self.numerator.to_big_int().abs(),
+            self.denominator.to_big_int(),
+        );
+        // Scale the quotient to about 64 significant bits, then undo the scaling in steps that stay within range.
+        let mut shift = denominator.bits() as i64 - numerator.bits() as i64 + 64;
+        let (quotient, remainder) = if shift >= 0 {
+            (&numerator << shift as usize).div_rem(&denominator)
+        } else {
+            numerator.div_rem(&(&denominator << shift.unsigned_abs() as usize))
+        };
+        // A sticky bit for the discarded remainder keeps a value just above a tie from rounding as the tie.
+        let quotient = quotient.to_i128().unwrap() as u128 | !remainder.is_zero() as u128;
+        // Below f64::MIN_POSITIVE there are fewer significant bits, so round to a multiple of the smallest subnormal directly
+        // instead of rounding to 53 bits first.
+        let excess = shift - 1074;
+        let mut value = if excess <= 0 || quotient >> excess.min(127) >= 1 << 52 {
+            quotient as f64
+        } else if excess >= 128 {
+            0.0
+        } else {
+            let (units, dropped) = (quotient >> excess, quotient & ((1 << excess) - 1));
+            let half = 1 << (excess - 1);
+            shift = 1074;
+            (units + (dropped > half || (dropped == half && units % 2 == 1)) as u128) as f64
+        };
+        while shift != 0 {
+            let step = shift.clamp(-1000, 1000);
+            value *= 2f64.powi(-step as i32);
+            shift -= step;
+        }
+        if negative {
+            -value
+        } else {
+            value
+        }
     }
 
     /// convert to complex number with real and imaginary parts as numbers
     pub fn to_complex_float(&self) -> complex_floats::Complex {
-        complex_floats::Complex::new(self.numerator as f64, self.denominator as f64)
+        complex_floats::Complex::new(self.numerator.to_f64(), self.denominator.to_f64())
     }
 
-    /// convert to complex number with real and imaginary parts as integers
+    /// convert to complex number with real and imaginary parts as integers; panics if a part does not fit in an i128
     pub fn to_complex_int(&self) -> complex_integers::Complex {
-        complex_integers::Complex::new(self.numerator as i128, self.denominator as i128)
-    }
-
-    pub fn to_string(&self) -> String {
-        format!("{}/{}", self.numerator, self.denominator)
+        let convert = |part: &T| {
+            part.to_i128()
+                .expect("Rational part does not fit in an i128!")
+        };
+        complex_integers::Complex::new(convert(&self.numerator), convert(&self.denominator))
     }
 
     pub fn to_string_complex(&self) -> String {
@@ -255,6 +1171,46 @@ pub mod test_rational {
         assert_eq!(b, 0.5);
     }
     #[test]
+    fn test_to_float_rounding() {
+        let two = BigInt::from(2);
+        let power = |exponent: u64| two.pow(exponent);
+        // Just above the midpoint between 1 and the next f64, which a truncated quotient would round as a tie.
+        let above_tie = Rational::new(power(200) + power(147) + BigInt::one(), power(200));
+        assert_eq!(above_tie.to_float(), 1.0 + f64::EPSILON);
+        assert_eq!(
+            Rational::new(power(200) + power(147), power(200)).to_float(),
+            1.0
+        );
+        assert_eq!((-above_tie).to_float(), -1.0 - f64::EPSILON);
+        // Subnormal results are rounded once, to a multiple of the smallest subnormal.
+        let smallest = f64::from_bits(1);
+        let subnormal = Rational::new(power(70) + power(19) + BigInt::one(), power(1094));
+        assert_eq!(subnormal.to_float(), ((1u64 << 50) + 1) as f64 * smallest);
+        let tie = Rational::new(power(70) + power(19), power(1094));
+        assert_eq!(tie.to_float(), (1u64 << 50) as f64 * smallest);
+        assert_eq!(
+            Rational::new(BigInt::from(3), power(1075)).to_float(),
+            2.0 * smallest
+        );
+        assert_eq!(Rational::new(BigInt::one(), power(1075)).to_float(), 0.0);
+        assert_eq!(Rational::new(BigInt::one(), power(2000)).to_float(), 0.0);
+        let third = Rational::new(power(1100) + BigInt::one(), BigInt::from(3) * power(1100));
+        assert_eq!(third.to_float(), 1.0 / 3.0);
+    }
+    #[test]
+    fn test_to_float_min_numerator() {
+        // The numerator is widened before taking its absolute value, which does not fit in the integer type.
+        assert_eq!(
+            Rational::<i128>::new(i128::MIN, 3).to_float(),
+            -5.671372782015641e37
+        );
+        assert_eq!(Rational::<i128>::new(i128::MIN, i128::MAX).to_float(), -1.0);
+        assert_eq!(
+            Rational::<i64>::new(i64::MIN, 3).to_float(),
+            -3.0744573456182584e18
+        );
+    }
+    #[test]
     fn test_to_complex_float() {
         let a = Rational::new(1, 2);
         let b = a.to_complex_float();
@@ -294,4 +1250,357 @@ pub mod test_rational {
         assert_eq!(a.get_numerator(), 1);
         assert_eq!(a.get_denominator(), 2);
     }
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_operators() {
+        let a = Rational::new(1, 2);
+        let b = Rational::new(1, 3);
+        let parts = |r: Rational| (r.get_numerator(), r.get_denominator());
+        assert_eq!(parts(a + b), (5, 6));
+        assert_eq!(parts(&b - a), (-1, 6));
+        assert_eq!(parts(a * &b), (1, 6));
+        assert_eq!(parts(&a / &b), (3, 2));
+        assert_eq!(parts(Rational::new(3, -6) / Rational::new(-1, 4)), (2, 1));
+        assert_eq!(parts(-Rational::new(i32::MIN + 1, 3)), (i32::MAX, 3));
+        assert_eq!(parts(1 - a), (1, 2));
+        let mut c = a;
+        c += b;
+        c *= 6;
+        c -= &a;
+        c /= Rational::new(-3, 1);
+        assert_eq!(parts(c), (-3, 2));
+        let values = [a, b, Rational::new(1, 6)];
+        assert_eq!(values.iter().sum::<Rational>(), Rational::from(1));
+        assert_eq!(parts(values.into_iter().product()), (1, 36));
+    }
+    #[test]
+    #[should_panic(expected = "divide failed: division by zero")]
+    fn test_divide_by_zero() {
+        let _ = Rational::new(1, 2) / Rational::new(0, 5);
+    }
+    #[test]
+    fn test_comparison() {
+        use std::collections::HashSet;
+
+        assert_eq!(Rational::new(1, 2), Rational::new(-2, -4));
+        assert_ne!(Rational::new(1, 2), Rational::new(-1, 2));
+        assert!(Rational::new(-1, 2) < Rational::new(1, -3));
+        assert!(Rational::new(i32::MAX, i32::MAX - 1) < Rational::new(i32::MAX - 1, i32::MAX - 2));
+        assert!(Rational::new(i32::MIN, i32::MAX) < Rational::new(i32::MIN + 1, i32::MAX));
+        let mut values = vec![
+            Rational::new(3, 4),
+            Rational::new(-5, 2),
+            Rational::new(2, 3),
+        ];
+        values.sort();
+        assert_eq!(
+            values,
+            vec![
+                Rational::new(-5, 2),
+                Rational::new(2, 3),
+                Rational::new(3, 4)
+            ]
+        );
+        let set: HashSet<Rational> = [
+            Rational::new(1, 2),
+            Rational::new(2, 4),
+            Rational::new(-3, -6),
+        ]
+        .into();
+        assert_eq!(set.len(), 1);
+    }
+    #[test]
+    fn test_parse() {
+        let parse = |text: &str| text.parse::<Rational>();
+        assert_eq!(parse("3/4").map(|r| r.to_string()), Ok("3/4".to_string()));
+        assert_eq!(parse(" -6 / 8 "), Ok(Rational::new(-3, 4)));
+        assert_eq!(parse("5"), Ok(Rational::from(5)));
+        assert_eq!(parse("1.5"), Ok(Rational::new(3, 2)));
+        for text in ["", "1/0", "1/2/3", "a/b", "3/"] {
+            assert!(parse(text).is_err(), "{:?}", text);
+        }
+    }
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Rational::try_from(7i128), Ok(Rational::from(7)));
+        assert!(Rational::<i32>::try_from(1i128 << 40).is_err());
+        let float = rational_float::Rational::new(3.0, 4.0);
+        assert_eq!(Rational::try_from(float), Ok(Rational::new(3, 4)));
+        assert!(Rational::<i32>::try_from(rational_float::Rational::new(1.5, 2.0)).is_err());
+        assert_eq!(
+            Rational::try_from(complex_integers::Complex::new(-4, 0)),
+            Ok(Rational::from(-4))
+        );
+        assert!(Rational::<i32>::try_from(complex_integers::Complex::new(1, 1)).is_err());
+        assert_eq!(f64::from(Rational::new(1, 8)), 0.125);
+
+        assert_eq!(
+            Rational::<i64>::try_from(1i128 << 40),
+            Ok(Rational::from(1i64 << 40))
+        );
+        assert!(Rational::<i64>::try_from(1i128 << 70).is_err());
+        assert_eq!(Rational::<i128>::from(1i128 << 70).get_numerator(), 1 << 70);
+        let big = Rational::<BigInt>::from(i128::MIN);
+        assert_eq!(big.get_numerator(), BigInt::from(i128::MIN));
+        let large = rational_float::Rational::new(1e20, 3.0);
+        assert!(Rational::<i32>::try_from(large).is_err());
+        assert_eq!(
+            Rational::<i128>::try_from(large),
+            Ok(Rational::new(100_000_000_000_000_000_000, 3))
+        );
+        assert!(Rational::<i128>::try_from(complex_integers::Complex::new(i128::MAX, 0)).is_ok());
+        assert!(Rational::<i64>::try_from(complex_integers::Complex::new(i128::MAX, 0)).is_err());
+        assert_eq!(
+            Rational::<i32>::try_from(complex_floats::Complex::new(-0.375, 0.0)),
+            Ok(Rational::new(-3, 8))
+        );
+        assert!(Rational::<i32>::try_from(complex_floats::Complex::new(0.1, 0.0)).is_err());
+        assert_eq!(
+            Rational::<i64>::try_from(complex_floats::Complex::new(0.1, 0.0)),
+            Ok(Rational::new(3602879701896397, 1 << 55))
+        );
+        assert!(Rational::<i64>::try_from(complex_floats::Complex::new(0.1, 1.0)).is_err());
+        assert!(Rational::<i64>::try_from(complex_floats::Complex::new(f64::NAN, 0.0)).is_err());
+    }
+    #[test]
+    fn test_normalization() {
+        for numerator in -40..=40 {
+            for denominator in (-40i32..=40).filter(|&d| d != 0) {
+                let r = Rational::new(numerator, denominator);
+                let gcd =
+                    crate::integers::modular::gcd(numerator as i128, denominator as i128) as i32;
+                let sign = denominator.signum();
+                assert_eq!(
+                    (r.get_numerator(), r.get_denominator()),
+                    (sign * numerator / gcd, sign * denominator / gcd)
+                );
+            }
+        }
+        assert_eq!(Rational::new(i32::MIN, i32::MIN), Rational::from(1));
+        assert_eq!(Rational::new(i32::MIN, 2).get_numerator(), -(1 << 30));
+        assert_eq!(Rational::new(0, -7).get_denominator(), 1);
+        let err = Rational::checked_new(1, i32::MIN).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Overflow);
+    }
+    #[test]
+    fn test_binary_gcd() {
+        for a in (-60i64..=60).filter(|&a| a != 0) {
+            for b in (-60i64..=60).filter(|&b| b != 0) {
+                let expected = crate::integers::modular::gcd(a as i128, b as i128) as i64;
+                assert_eq!(binary_gcd(&a, &b), Some(expected));
+            }
+        }
+        assert_eq!(binary_gcd(&i32::MIN, &i32::MIN), None);
+        assert_eq!(binary_gcd(&i32::MIN, &(3 << 20)), Some(1 << 20));
+        let big = BigInt::from(2).pow(100) * BigInt::from(15);
+        assert_eq!(binary_gcd(&big, &BigInt::from(-40)), Some(BigInt::from(40)));
+    }
+    #[test]
+    fn test_arithmetic_matches_brute_force() {
+        let values: Vec<Rational<i64>> = (-6..=6)
+            .flat_map(|n| (1..=6).map(move |d| Rational::new(n, d)))
+            .collect();
+        for a in &values {
+            for b in &values {
+                let (p, q) = (a.get_numerator(), a.get_denominator());
+                let (r, s) = (b.get_numerator(), b.get_denominator());
+                assert_eq!(a + b, Rational::new(p * s + r * q, q * s));
+                assert_eq!(a - b, Rational::new(p * s - r * q, q * s));
+                assert_eq!(a * b, Rational::new(p * r, q * s));
+                if r != 0 {
+                    assert_eq!(a / b, Rational::new(p * s, q * r));
+                }
+                assert_eq!(a.cmp(b), (p * s).cmp(&(r * q)));
+            }
+        }
+    }
+    #[test]
+    fn test_overflow() {
+        let max = Rational::from_integer(i32::MAX);
+        assert_eq!(
+            max.checked_add(&Rational::from(1)).unwrap_err().kind(),
+            ErrorKind::Overflow
+        );
+        assert_eq!(
+            max.checked_mul(&Rational::from(2)).unwrap_err().operation(),
+            "multiply"
+        );
+        assert_eq!(
+            Rational::new(1, 2).checked_pow(40).unwrap_err().kind(),
+            ErrorKind::Overflow
+        );
+        let zero = Rational::from(0);
+        assert_eq!(
+            zero.checked_pow(-1).unwrap_err().kind(),
+            ErrorKind::DivisionByZero
+        );
+        assert_eq!(
+            zero.checked_recip().unwrap_err().kind(),
+            ErrorKind::DivisionByZero
+        );
+        // Cancelling before multiplying keeps results that fit from overflowing.
+        let a = Rational::new(i32::MAX, 3);
+        assert_eq!(a * Rational::new(3, i32::MAX), Rational::from(1));
+        let b = Rational::new(1, 1 << 30);
+        assert_eq!(b + b, Rational::new(1, 1 << 29));
+    }
+    #[test]
+    fn test_rounding() {
+        for numerator in -30..=30 {
+            for denominator in 1..=7 {
+                let r = Rational::new(numerator, denominator);
+                let value = numerator as f64 / denominator as f64;
+                assert_eq!(r.floor().to_float(), value.floor());
+                assert_eq!(r.ceil().to_float(), value.ceil());
+                assert_eq!(r.trunc().to_float(), value.trunc());
+                assert_eq!(r.round().to_float(), value.round(), "{}", r);
+                assert!((r.fract().to_float() - value.fract()).abs() < 1e-12);
+                assert_eq!(r.fract() + r.trunc(), r);
+            }
+        }
+        let near_max = Rational::new(i32::MAX, 2);
+        assert_eq!(near_max.round(), Rational::from(i32::MAX / 2 + 1));
+        assert_eq!(
+            Rational::new(i32::MIN + 1, 2).round(),
+            Rational::from(i32::MIN / 2)
+        );
+    }
+    #[test]
+    fn test_generic_parts() {
+        let a = Rational::<i128>::new(1, 3);
+        assert_eq!(a.pow(-80).get_numerator(), 3i128.pow(80));
+        let big = Rational::new(BigInt::from(10).pow(30), BigInt::from(-4));
+        assert_eq!(big.get_denominator(), BigInt::from(1));
+        assert_eq!(big.to_string(), format!("-25{}/1", "0".repeat(28)));
+        let third = Rational::new(BigInt::from(1), BigInt::from(3));
+        let sum: Rational<BigInt> = std::iter::repeat_n(third.clone(), 3).sum();
+        assert_eq!(sum, Rational::from(BigInt::from(1)));
+        assert_eq!(
+            BigInt::from(1) - &third * BigInt::from(3),
+            Rational::from(BigInt::from(0))
+        );
+        assert_eq!(
+            third.recip().pow(200),
+            Rational::from(BigInt::from(3).pow(200))
+        );
+        let parsed: Rational<i64> = "-10000000000/4".parse().unwrap();
+        assert_eq!(parsed, Rational::new(-2_500_000_000, 1));
+        assert!("-2147483648/-1".parse::<Rational>().is_err());
+    }
+    #[test]
+    fn test_from_f64_exact() {
+        for value in [
+            0.0,
+            -0.0,
+            1.0,
+            -2.5,
+            0.1,
+            1e-300,
+            5e-324,
+            1e300,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+        ] {
+            let exact = Rational::<BigInt>::from_f64_exact(value).unwrap();
+            assert_eq!(exact.to_float(), value, "{}", value);
+        }
+        assert_eq!(
+            Rational::<i32>::from_f64_exact(1e10).unwrap_err().kind(),
+            ErrorKind::Overflow
+        );
+        assert_eq!(
+            Rational::<i64>::from_f64_exact(1e10),
+            Ok(Rational::from(10_000_000_000))
+        );
+        let kind = Rational::<BigInt>::from_f64_exact(f64::INFINITY)
+            .unwrap_err()
+            .kind();
+        assert_eq!(kind, ErrorKind::NotRepresentable);
+    }
+    #[test]
+    fn test_approximate_is_best() {
+        for k in 1..40 {
+            let value = (k as f64 * 0.618_033_988_749_894_9).fract() * 7.0 - 3.0;
+            let exact = exact_big(value).unwrap();
+            for max_denominator in [1i64, 2, 7, 50, 333] {
+                let best = Rational::approximate(value, max_denominator).unwrap();
+                assert!(best.get_denominator() <= max_denominator);
+                let distance = |r: &Rational<BigInt>| (r - &exact).abs();
+                let best_distance = distance(&Rational::new(
+                    BigInt::from(best.get_numerator()),
+                    BigInt::from(best.get_denominator()),
+                ));
+                for q in 1..=max_denominator {
+                    let floor = (&exact * BigInt::from(q)).floor().get_numerator();
+                    for p in [floor.clone(), floor + BigInt::from(1)] {
+                        assert!(distance(&Rational::new(p, BigInt::from(q))) >= best_distance);
+                    }
+                }
+            }
+        }
+        // Ties between equal denominators go to the last convergent, as in Python's `limit_denominator`.
+        assert_eq!(Rational::approximate(0.5, 1), Ok(Rational::from(0)));
+        assert_eq!(Rational::approximate(-0.5, 1), Ok(Rational::from(-1)));
+        assert_eq!(
+            Rational::approximate(1e20, 5i64).unwrap_err().kind(),
+            ErrorKind::Overflow
+        );
+        let big = Rational::<BigInt>::approximate(1e20, BigInt::from(5)).unwrap();
+        assert_eq!(big, Rational::from(BigInt::from(10).pow(20)));
+    }
+    #[test]
+    #[should_panic(expected = "Maximum denominator must be positive!")]
+    fn test_approximate_zero_denominator() {
+        let _ = Rational::approximate(0.5, 0);
+    }
+    #[test]
+    fn test_simplest_within() {
+        for k in 1..30 {
+            let value = (k as f64 * 0.414_213_562_373_095_1).fract() * 5.0 - 2.5;
+            let tolerance = 0.0007 * k as f64;
+            let simplest = Rational::<i64>::simplest_within(value, tolerance).unwrap();
+            let center = exact_big(value).unwrap();
+            let radius = exact_big(tolerance).unwrap();
+            let (low, high) = (&center - &radius, &center + &radius);
+            // The first denominator with a fraction in the interval, and its smallest numerator in absolute value.
+            let expected = (1i64..)
+                .find_map(|q| {
+                    let q = BigInt::from(q);
+                    let first = (&low * q.clone()).ceil().get_numerator();
+                    let last = (&high * q.clone()).floor().get_numerator();
+                    if first > last {
+                        return None;
+                    }
+                    let zero = BigInt::from(0);
+                    let p = if first > zero {
+                        first
+                    } else if last < zero {
+                        last
+                    } else {
+                        zero
+                    };
+                    Some(Rational::new(p, q))
+                })
+                .unwrap();
+            assert_eq!(
+                simplest.to_string(),
+                expected.to_string(),
+                "{} {}",
+                value,
+                tolerance
+            );
+        }
+        assert_eq!(
+            Rational::<i32>::simplest_within(7.0, 0.5),
+            Ok(Rational::from(7))
+        );
+        assert_eq!(
+            Rational::<i32>::simplest_within(0.1, 0.05),
+            Ok(Rational::new(1, 7))
+        );
+        let kind = Rational::<i32>::simplest_within(1.0, -1.0)
+            .unwrap_err()
+            .kind();
+        assert_eq!(kind, ErrorKind::NegativeArgument);
+    }
 }
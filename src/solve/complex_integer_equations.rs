@@ -3,6 +3,7 @@ use crate::integers::complex_integers::Complex;
 
 /// Equation struct for complex integers that holds the left and right side of the equation, the operation, and the solution.
 /// The solution is calculated during initialization.  The left and right side of the equation are immutable.
+/// Division gives the Euclidean quotient, rounded to the nearest Gaussian integer, and panics if the right side is zero.
 ///
 /// # Examples
 /// ```
@@ -29,7 +30,7 @@ impl Equation {
             '+' => left.add(&right),
             '-' => left.subtract(&right),
             '*' => left.multiply(&right),
-            '/' => left.divide(&right),
+            '/' => left / right,
             _ => panic!("Invalid operation"),
         };
         Equation {
@@ -69,7 +70,7 @@ impl Equation {
             '+' => self.left.add(&self.right),
             '-' => self.left.subtract(&self.right),
             '*' => self.left.multiply(&self.right),
-            '/' => self.left.divide(&self.right),
+            '/' => self.left / self.right,
             _ => panic!("Invalid operation"),
         };
     }
@@ -87,7 +88,7 @@ impl Equation {
             '+' => self.left.add(&self.right),
             '-' => self.left.subtract(&self.right),
             '*' => self.left.multiply(&self.right),
-            '/' => self.left.divide(&self.right),
+            '/' => self.left / self.right,
             _ => panic!("Invalid operation"),
         };
     }
@@ -105,7 +106,7 @@ impl Equation {
             '+' => self.left.add(&self.right),
             '-' => self.left.subtract(&self.right),
             '*' => self.left.multiply(&self.right),
-            '/' => self.left.divide(&self.right),
+            '/' => self.left / self.right,
             _ => panic!("Invalid operation"),
         };
     }
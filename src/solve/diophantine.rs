@@ -0,0 +1,722 @@
+//! # Diophantine Module
+//! The `diophantine` module finds integer solutions of equations, where `equation` only evaluates them:
+//!
+//! * `solve_linear` returns the one-parameter family of solutions of `ax + by = c`, and `solve_linear_form` the
+//!   `(n - 1)`-parameter family of `a_1 x_1 + ... + a_n x_n = c`.
+//! * `pell_fundamental` returns the smallest non-trivial solution of `x^2 - Dy^2 = 1`, and `pell_solutions` iterates over
+//!   every non-negative solution of the generalized equation `x^2 - Dy^2 = N`.
+//! * `sum_of_two_squares` and `sums_of_two_squares` write a number as `a^2 + b^2`.
+//!
+//! # Examples
+//! ```
+//! use numbers_rus::solve::diophantine;
+//!
+//! let family = diophantine::solve_linear(6, 10, 8).unwrap();
+//! assert_eq!(family.at(0), (3, -1)); // 6 * 3 + 10 * (-1) = 8
+//!
+//! assert_eq!(diophantine::pell_fundamental(61), Ok((1766319049, 226153980)));
+//! assert_eq!(diophantine::sums_of_two_squares(25), vec![(0, 5), (3, 4)]);
+//! ```
+use std::collections::HashSet;
+
+use crate::error::{ErrorKind, NumbersError};
+use crate::integers::base;
+use crate::integers::big_integer::BigInt;
+use crate::integers::complex_integers::Complex;
+use crate::integers::factorization;
+use crate::integers::modular::{extended_gcd, mod_mul};
+use crate::integers::quadratic_residues;
+use crate::traits::Ring;
+
+/// The solutions `(x + t * step_x, y + t * step_y)` of `ax + by = c`, one for each integer `t`. `x` is the smallest
+/// non-negative `x` among them unless `b` is zero.
+///
+/// # Example
+/// ```
+/// use numbers_rus::solve::diophantine;
+///
+/// let family = diophantine::solve_linear(3, 5, 7).unwrap();
+/// assert_eq!((family.x, family.y), (4, -1));
+/// assert_eq!((family.step_x, family.step_y), (5, -3));
+/// let first: Vec<_> = family.iter().take(3).collect();
+/// assert_eq!(first, vec![(4, -1), (9, -4), (-1, 2)]);
+/// ```
+///
+/// # Methods
+///
+/// * `at` - returns the solution for the parameter `t`.
+/// * `iter` - iterates over the solutions for `t = 0, 1, -1, 2, -2, ...`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinearSolution {
+    pub x: i128,
+    pub y: i128,
+    pub step_x: i128,
+    pub step_y: i128,
+}
+
+impl LinearSolution {
+    /// Returns the solution for the parameter `t`. Panics if it does not fit in an i128.
+    pub fn at(&self, t: i128) -> (i128, i128) {
+        self.checked_at(t)
+            .expect("solution does not fit in an i128")
+    }
+
+    /// Iterates over the solutions for `t = 0, 1, -1, 2, -2, ...`, stopping once they no longer fit in an i128.
+    pub fn iter(&self) -> impl Iterator<Item = (i128, i128)> + '_ {
+        std::iter::once(0)
+            .chain((1..).flat_map(|t| [t, -t]))
+            .map_while(|t| self.checked_at(t))
+    }
+
+    fn checked_at(&self, t: i128) -> Option<(i128, i128)> {
+        let x = self.x.checked_add(t.checked_mul(self.step_x)?)?;
+        let y = self.y.checked_add(t.checked_mul(self.step_y)?)?;
+        Some((x, y))
+    }
+}
+
+/// `solve_linear` function: Takes three i128 integers `a`, `b` and `c` as input and returns every integer solution of
+/// `ax + by = c` as a `LinearSolution`, or `None` if `gcd(a, b)` does not divide `c`. Panics if `a` and `b` are both zero, or if
+/// the particular solution does not fit in an i128.
+///
+/// # Example
+/// ```
+/// use numbers_rus::solve::diophantine;
+///
+/// let family = diophantine::solve_linear(12, -18, 30).unwrap();
+/// for t in -3..3 {
+///     let (x, y) = family.at(t);
+///     assert_eq!(12 * x - 18 * y, 30);
+/// }
+/// assert!(diophantine::solve_linear(4, 6, 5).is_none());
+/// ```
+pub fn solve_linear(a: i128, b: i128, c: i128) -> Option<LinearSolution> {
+    assert!(a != 0 || b != 0, "Coefficients must not all be zero");
+    let (g, bezout_x, _) = extended_gcd(a, b);
+    if c % g != 0 {
+        return None;
+    }
+    let (a, b, c) = (a / g, b / g, c / g);
+    // With b = 0 the coefficient a is 1 or -1 and every y works, so y = 0 is picked.
+    let (x, y) = if b == 0 {
+        (c / a, 0)
+    } else {
+        let x = mod_mul(bezout_x, c, b);
+        let y = a
+            .checked_mul(x)
+            .and_then(|ax| c.checked_sub(ax))
+            .expect("solution does not fit in an i128")
+            / b;
+        (x, y)
+    };
+    Some(LinearSolution {
+        x,
+        y,
+        step_x: b,
+        step_y: -a,
+    })
+}
+
+/// The solutions `particular + t_1 * basis[0] + ... + t_k * basis[k - 1]` of a linear equation in several variables, one for
+/// each choice of integers `t_i`. The basis vectors span every integer solution of the homogeneous equation.
+///
+/// # Example
+/// ```
+/// use numbers_rus::solve::diophantine;
+///
+/// let family = diophantine::solve_linear_form(&[6, 10, 15], 1).unwrap();
+/// assert_eq!(family.basis.len(), 2);
+/// let point = family.at(&[4, -7]);
+/// assert_eq!(6 * point[0] + 10 * point[1] + 15 * point[2], 1);
+/// ```
+///
+/// # Methods
+///
+/// * `at` - returns the solution for the given parameters.
+/// * `dimension` - returns the number of free parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinearFormSolution {
+    pub particular: Vec<i128>,
+    pub basis: Vec<Vec<i128>>,
+}
+
+impl LinearFormSolution {
+    /// Returns the solution for the given parameters. Panics if there is not exactly one parameter per basis vector or if the
+    /// solution does not fit in an i128.
+    pub fn at(&self, parameters: &[i128]) -> Vec<i128> {
+        assert_eq!(
+            parameters.len(),
+            self.basis.len(),
+            "Expected one parameter per basis vector"
+        );
+        let mut point = self.particular.clone();
+        for (&t, vector) in parameters.iter().zip(&self.basis) {
+            for (coordinate, &v) in point.iter_mut().zip(vector) {
+                *coordinate = t
+                    .checked_mul(v)
+                    .and_then(|tv| coordinate.checked_add(tv))
+                    .expect("solution does not fit in an i128");
+            }
+        }
+        point
+    }
+
+    /// Returns the number of free parameters.
+    pub fn dimension(&self) -> usize {
+        self.basis.len()
+    }
+}
+
+/// `solve_linear_form` function: Takes a slice of i128 coefficients `a_1, ..., a_n` and an i128 integer `c` as input and returns
+/// every integer solution of `a_1 x_1 + ... + a_n x_n = c` as a `LinearFormSolution` with `n - 1` basis vectors, or `None` if
+/// the gcd of the coefficients does not divide `c`. The coefficients are reduced to their gcd with unimodular column
+/// operations. Panics if every coefficient is zero, or if an intermediate value does not fit in an i128.
+///
+/// # Example
+/// ```
+/// use numbers_rus::solve::diophantine;
+///
+/// let family = diophantine::solve_linear_form(&[2, 4, 6], 10).unwrap();
+/// let point = family.at(&[1, 1]);
+/// assert_eq!(2 * point[0] + 4 * point[1] + 6 * point[2], 10);
+/// assert!(diophantine::solve_linear_form(&[2, 4, 6], 7).is_none());
+/// ```
+pub fn solve_linear_form(coefficients: &[i128], c: i128) -> Option<LinearFormSolution> {
+    assert!(
+        coefficients.iter().any(|&a| a != 0),
+        "Coefficients must not all be zero"
+    );
+    let n = coefficients.len();
+    // Column j of `columns` is a vector u_j with coefficients . u_j = values[j]; at the end values = (g, 0, ..., 0).
+    let mut values = coefficients.to_vec();
+    let mut columns: Vec<Vec<i128>> = (0..n)
+        .map(|j| (0..n).map(|i| i128::from(i == j)).collect())
+        .collect();
+    for j in 1..n {
+        if values[j] == 0 {
+            continue;
+        }
+        let (g, x, y) = extended_gcd(values[0], values[j]);
+        let (first, other) = (values[0] / g, values[j] / g);
+        let combined = combine_columns(&columns[0], x, &columns[j], y);
+        let kernel = combine_columns(&columns[0], other, &columns[j], -first);
+        columns[0] = combined;
+        columns[j] = kernel;
+        values[0] = g;
+        values[j] = 0;
+    }
+    if values[0] < 0 {
+        values[0] = -values[0];
+        columns[0] = columns[0].iter().map(|&v| -v).collect();
+    }
+    let g = values[0];
+    if c % g != 0 {
+        return None;
+    }
+    let particular = columns[0]
+        .iter()
+        .map(|&u| {
+            u.checked_mul(c / g)
+                .expect("solution does not fit in an i128")
+        })
+        .collect();
+    Some(LinearFormSolution {
+        particular,
+        basis: columns.split_off(1),
+    })
+}
+
+/// Returns `s * u + t * v`.
+fn combine_columns(u: &[i128], s: i128, v: &[i128], t: i128) -> Vec<i128> {
+    u.iter()
+        .zip(v)
+        .map(|(&u, &v)| {
+            s.checked_mul(u)
+                .zip(t.checked_mul(v))
+                .and_then(|(su, tv)| su.checked_add(tv))
+                .expect("intermediate value does not fit in an i128")
+        })
+        .collect()
+}
+
+/// `pell_fundamental` function: Takes an i128 integer `d` as input and returns the fundamental solution of Pell's equation
+/// `x^2 - dy^2 = 1`, the solution with the smallest positive `y`. It is read off the first period of the continued fraction of
+/// `sqrt(d)`. Returns an `Overflow` error if the solution does not fit in an i128, which already happens for some `d` below
+/// 2000. Panics if `d` is not a positive non-square.
+///
+/// # Example
+/// ```
+/// use numbers_rus::error::ErrorKind;
+/// use numbers_rus::solve::diophantine;
+///
+/// assert_eq!(diophantine::pell_fundamental(2), Ok((3, 2)));
+/// assert_eq!(diophantine::pell_fundamental(991), Ok((379516400906811930638014896080, 12055735790331359447442538767)));
+/// assert_eq!(diophantine::pell_fundamental(1621).unwrap_err().kind(), ErrorKind::Overflow);
+/// ```
+pub fn pell_fundamental(d: i128) -> Result<(i128, i128), NumbersError> {
+    pell_units(d, "pell_fundamental").map(|(unit, _)| unit)
+}
+
+/// Returns the fundamental solutions of `x^2 - dy^2 = 1` and, if it is solvable, of `x^2 - dy^2 = -1`.
+#[allow(clippy::type_complexity)]
+fn pell_units(
+    d: i128,
+    operation: &'static str,
+) -> Result<((i128, i128), Option<(i128, i128)>), NumbersError> {
+    assert!(
+        d > 0 && !base::is_perfect_square(d),
+        "D must be a positive non-square"
+    );
+    let overflow = || NumbersError::new(operation, ErrorKind::Overflow);
+    let root = base::isqrt(d);
+    // sqrt(d) = [a_0; a_1, ..., a_l] with a_l = 2 a_0; the convergent p/q before a_l solves x^2 - dy^2 = (-1)^l.
+    let (mut m, mut denominator, mut a) = (0i128, 1i128, root);
+    let (mut p_previous, mut p) = (1i128, root);
+    let (mut q_previous, mut q) = (0i128, 1i128);
+    let mut period = 0;
+    loop {
+        m = denominator * a - m;
+        denominator = (d - m * m) / denominator;
+        a = (root + m) / denominator;
+        period += 1;
+        if a == 2 * root {
+            break;
+        }
+        (p_previous, p) = (p, checked_step(a, p, p_previous).ok_or_else(overflow)?);
+        (q_previous, q) = (q, checked_step(a, q, q_previous).ok_or_else(overflow)?);
+    }
+    if period % 2 == 0 {
+        return Ok(((p, q), None));
+    }
+    // Squaring the solution of the negative equation gives the positive one.
+    let unit = quadratic_multiply(d, (p, q), (p, q)).ok_or_else(overflow)?;
+    Ok((unit, Some((p, q))))
+}
+
+/// Returns `a * current + previous`.
+fn checked_step(a: i128, current: i128, previous: i128) -> Option<i128> {
+    a.checked_mul(current)?.checked_add(previous)
+}
+
+/// An iterator over the non-negative solutions `(x, y)` of `x^2 - Dy^2 = N` in increasing order, created by `pell_solutions`.
+/// Every solution is `(x_0 + y_0 sqrt(D)) (x_1 + y_1 sqrt(D))^k` for one of finitely many fundamental solutions `(x_0, y_0)`,
+/// where `(x_1, y_1)` is the fundamental solution of `x^2 - Dy^2 = 1`; the iterator merges these sequences. It stops once the
+/// next solution does not fit in an i128.
+///
+/// # Example
+/// ```
+/// use numbers_rus::solve::diophantine;
+///
+/// let solutions: Vec<_> = diophantine::pell_solutions(7, 2).unwrap().take(4).collect();
+/// assert_eq!(solutions, vec![(3, 1), (45, 17), (717, 271), (11427, 4319)]);
+/// ```
+///
+/// # Methods
+///
+/// * `unit` - returns the fundamental solution of `x^2 - Dy^2 = 1` used to step between solutions.
+#[derive(Debug, Clone)]
+pub struct PellSolutions {
+    d: i128,
+    unit: (i128, i128),
+    heads: Vec<(i128, i128)>,
+}
+
+impl PellSolutions {
+    /// Returns the fundamental solution of `x^2 - Dy^2 = 1` used to step between solutions.
+    pub fn unit(&self) -> (i128, i128) {
+        self.unit
+    }
+
+    /// Returns `(x + y sqrt(D)) (x_1 + y_1 sqrt(D))`.
+    fn step_up(&self, solution: (i128, i128)) -> Option<(i128, i128)> {
+        quadratic_multiply(self.d, solution, self.unit)
+    }
+
+    /// Returns `(x + y sqrt(D)) / (x_1 + y_1 sqrt(D))`, which is `(x + y sqrt(D)) (x_1 - y_1 sqrt(D))`.
+    fn step_down(&self, solution: (i128, i128)) -> Option<(i128, i128)> {
+        quadratic_multiply(self.d, solution, (self.unit.0, -self.unit.1))
+    }
+
+    /// Moves `solution` to the smallest solution of its class with both coordinates non-negative.
+    fn normalize(&self, solution: (i128, i128)) -> Option<(i128, i128)> {
+        let mut current = solution;
+        while current.0 < 0 || current.1 < 0 {
+            current = self.step_up(current)?;
+        }
+        while let Some(lower) = self.step_down(current).filter(|&(x, y)| x >= 0 && y >= 0) {
+            current = lower;
+        }
+        Some(current)
+    }
+}
+
+impl Iterator for PellSolutions {
+    type Item = (i128, i128);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = (0..self.heads.len()).min_by_key(|&i| self.heads[i])?;
+        let solution = self.heads[index];
+        match self.step_up(solution).filter(|&next| next != solution) {
+            Some(next) => self.heads[index] = next,
+            None => {
+                self.heads.swap_remove(index);
+            }
+        }
+        Some(solution)
+    }
+}
+
+/// `pell_solutions` function: Takes two i128 integers `d` and `n` as input and returns a `PellSolutions` iterator over the
+/// non-negative solutions of `x^2 - dy^2 = n` in increasing order. The fundamental solutions are found with the
+/// Lagrange-Matthews-Mollin algorithm, which runs a continued fraction expansion of `(z + sqrt(d)) / |m|` for every `m = n / f^2`
+/// and every square root `z` of `d` modulo `|m|`, where the square divisors `f^2` come from the prime factorization of `n`.
+/// Returns an `Overflow` error if an intermediate value does not fit in an i128, as for `n = i128::MIN`, whose `|m|` is
+/// `2^127` for `f = 1`. Panics if `d` is not a positive non-square.
+///
+/// # Example
+/// ```
+/// use numbers_rus::solve::diophantine;
+///
+/// // x^2 - 13y^2 = -1 is solvable, so every odd power of 18 + 5 sqrt(13) gives a solution.
+/// let mut solutions = diophantine::pell_solutions(13, -1).unwrap();
+/// assert_eq!(solutions.next(), Some((18, 5)));
+/// assert_eq!(solutions.next(), Some((23382, 6485)));
+///
+/// assert_eq!(diophantine::pell_solutions(3, -1).unwrap().next(), None);
+/// ```
+pub fn pell_solutions(d: i128, n: i128) -> Result<PellSolutions, NumbersError> {
+    const OPERATION: &str = "pell_solutions";
+    let overflow = || NumbersError::new(OPERATION, ErrorKind::Overflow);
+    let (unit, negative_unit) = pell_units(d, OPERATION)?;
+    let mut solutions = PellSolutions {
+        d,
+        unit,
+        heads: Vec::new(),
+    };
+    if n == 0 {
+        solutions.heads.push((0, 0));
+        return Ok(solutions);
+    }
+    let root = base::isqrt(d);
+    let mut fundamental = Vec::new();
+    for f in square_divisor_roots(n) {
+        let m = n / (f * f);
+        let modulus = m.checked_abs().ok_or_else(overflow)?;
+        for z in quadratic_residues::sqrt_mod_all(d, modulus) {
+            let z = if z > modulus - z { z - modulus } else { z };
+            let Some((r, s, value)) = lmm_solution(d, root, z, modulus)? else {
+                continue;
+            };
+            let (r, s) = if value == m {
+                (r, s)
+            } else if let Some((t, u)) = negative_unit {
+                // Multiplying by a solution of x^2 - dy^2 = -1 flips the sign of the norm.
+                quadratic_multiply(d, (r, s), (t, u)).ok_or_else(overflow)?
+            } else {
+                continue;
+            };
+            let (r, s) = (
+                r.checked_mul(f).ok_or_else(overflow)?,
+                s.checked_mul(f).ok_or_else(overflow)?,
+            );
+            fundamental.push((r, s));
+        }
+    }
+    for (r, s) in fundamental {
+        // The class of r + s sqrt(d) and its conjugate; positive multiples of r - s sqrt(d) have the sign of n.
+        let (r, s) = (r.abs(), s.abs());
+        let conjugate = if n > 0 { (r, -s) } else { (-r, s) };
+        for candidate in [(r, s), conjugate] {
+            let head = solutions.normalize(candidate).ok_or_else(overflow)?;
+            if !solutions.heads.contains(&head) {
+                solutions.heads.push(head);
+            }
+        }
+    }
+    Ok(solutions)
+}
+
+/// Returns every `f > 0` with `f^2` dividing `n`, in increasing order, from the prime factorization of `n`.
+fn square_divisor_roots(n: i128) -> Vec<i128> {
+    let mut roots = vec![1];
+    for (prime, exponent) in factorization::factorize(n) {
+        let mut powers = Vec::new();
+        let mut power = 1;
+        for _ in 0..exponent / 2 {
+            power *= prime;
+            powers.extend(roots.iter().map(|root| root * power));
+        }
+        roots.extend(powers);
+    }
+    roots.sort_unstable();
+    roots
+}
+
+/// Returns `(a + b sqrt(d)) (c + e sqrt(d))`, or `None` if it does not fit in an i128. The products are taken exactly, since the
+/// terms can overflow even when the result fits, as when multiplying a unit by its inverse.
+fn quadratic_multiply(d: i128, (a, b): (i128, i128), (c, e): (i128, i128)) -> Option<(i128, i128)> {
+    let (a, b, c, e, d) = (
+        BigInt::from(a),
+        BigInt::from(b),
+        BigInt::from(c),
+        BigInt::from(e),
+        BigInt::from(d),
+    );
+    let x = &(&a * &c) + &(&(&b * &e) * &d);
+    let y = &(&a * &e) + &(&b * &c);
+    Some((x.to_i128()?, y.to_i128()?))
+}
+
+/// Runs the continued fraction expansion of `(z + sqrt(d)) / modulus` until `Q_i = 1` or `-1` for some `i >= 1`, returning
+/// `(G_{i - 1}, B_{i - 1}, (-1)^i Q_i modulus)`, where the last entry equals `G_{i - 1}^2 - dB_{i - 1}^2`. Returns `None` once
+/// the expansion repeats without such a `Q_i`.
+#[allow(clippy::type_complexity)]
+fn lmm_solution(
+    d: i128,
+    root: i128,
+    z: i128,
+    modulus: i128,
+) -> Result<Option<(i128, i128, i128)>, NumbersError> {
+    let overflow = || NumbersError::new("pell_solutions", ErrorKind::Overflow);
+    let (mut p, mut q) = (z, modulus);
+    let (mut g_previous, mut g) = (-z, modulus);
+    let (mut b_previous, mut b) = (1i128, 0i128);
+    let mut sign = 1;
+    let mut seen = HashSet::new();
+    loop {
+        if !seen.insert((p, q)) {
+            return Ok(None);
+        }
+        // a = floor((p + sqrt(d)) / q), using that sqrt(d) lies strictly between root and root + 1.
+        let a = if q > 0 {
+            (p + root).div_euclid(q)
+        } else {
+            -(p + root).div_euclid(-q) - 1
+        };
+        (g_previous, g) = (g, checked_step(a, g, g_previous).ok_or_else(overflow)?);
+        (b_previous, b) = (b, checked_step(a, b, b_previous).ok_or_else(overflow)?);
+        p = a.checked_mul(q).ok_or_else(overflow)? - p;
+        q = (d - p.checked_mul(p).ok_or_else(overflow)?) / q;
+        sign = -sign;
+        if q.abs() == 1 {
+            return Ok(Some((g, b, sign * q * modulus)));
+        }
+    }
+}
+
+/// `sum_of_two_squares` function: Takes an i128 integer `number` as input and returns `(a, b)` with `0 <= a <= b` and
+/// `a^2 + b^2 = number`, choosing the one with the smallest `a`, or `None` if there is none. A positive number is a sum of two
+/// squares exactly when every prime `3 (mod 4)` divides it to an even power.
+///
+/// # Example
+/// ```
+/// use numbers_rus::solve::diophantine;
+///
+/// assert_eq!(diophantine::sum_of_two_squares(1_000_000_009), Some((3747, 31400)));
+/// assert_eq!(diophantine::sum_of_two_squares(21), None);
+/// ```
+pub fn sum_of_two_squares(number: i128) -> Option<(i128, i128)> {
+    sums_of_two_squares(number).first().copied()
+}
+
+/// `sums_of_two_squares` function: Takes an i128 integer `number` as input and returns every `(a, b)` with `0 <= a <= b` and
+/// `a^2 + b^2 = number`, sorted by `a`. Each prime `p = 1 (mod 4)` of `number` is split as `p = (x + yi)(x - yi)` over the
+/// Gaussian integers, and the representations come from distributing the factors of every `p^e` between a Gaussian integer and
+/// its conjugate. Returns an empty vector for negative numbers.
+///
+/// # Example
+/// ```
+/// use numbers_rus::solve::diophantine;
+///
+/// assert_eq!(diophantine::sums_of_two_squares(325), vec![(1, 18), (6, 17), (10, 15)]);
+/// assert_eq!(diophantine::sums_of_two_squares(0), vec![(0, 0)]);
+/// assert!(diophantine::sums_of_two_squares(-2).is_empty());
+/// ```
+pub fn sums_of_two_squares(number: i128) -> Vec<(i128, i128)> {
+    if number < 0 {
+        return Vec::new();
+    }
+    if number == 0 {
+        return vec![(0, 0)];
+    }
+    // Gaussian integers dividing `number` with norm equal to the part of `number` handled so far.
+    let mut partial = vec![Complex::one()];
+    for (p, e) in factorization::factorize(number) {
+        let factors = if p == 2 {
+            vec![Ring::pow(&Complex::new(1, 1), e)]
+        } else if p % 4 == 3 {
+            if e % 2 == 1 {
+                return Vec::new();
+            }
+            vec![Complex::from_real(p.pow(e / 2))]
+        } else {
+            let (x, y) = quadratic_residues::two_squares_of_prime(p);
+            let (prime, conjugate) = (Complex::new(x, y), Complex::new(x, -y));
+            (0..=e)
+                .map(|j| Ring::pow(&prime, j) * Ring::pow(&conjugate, e - j))
+                .collect()
+        };
+        partial = factors
+            .iter()
+            .flat_map(|&factor| partial.iter().map(move |&z| z * factor))
+            .collect();
+    }
+    let mut representations: Vec<(i128, i128)> = partial
+        .into_iter()
+        .map(|z| {
+            let (x, y) = (z.get_real().abs(), z.get_imag().abs());
+            (x.min(y), x.max(y))
+        })
+        .collect();
+    representations.sort_unstable();
+    representations.dedup();
+    representations
+}
+
+#[cfg(test)]
+mod test_diophantine {
+    use super::*;
+
+    #[test]
+    fn test_solve_linear() {
+        for a in -12i128..12 {
+            for b in -12i128..12 {
+                if a == 0 && b == 0 {
+                    continue;
+                }
+                for c in -30..30 {
+                    let brute = (-40..40).any(|x| (-40..40).any(|y| a * x + b * y == c));
+                    match solve_linear(a, b, c) {
+                        Some(family) => {
+                            assert!(brute);
+                            for (x, y) in family.iter().take(10) {
+                                assert_eq!(a * x + b * y, c);
+                            }
+                            if b != 0 {
+                                assert!((0..b.abs()).contains(&family.x));
+                            }
+                            assert_eq!(family.step_x * a + family.step_y * b, 0);
+                        }
+                        None => assert!(!brute, "{}x + {}y = {}", a, b, c),
+                    }
+                }
+            }
+        }
+        let family = solve_linear(i128::MAX, i128::MAX - 1, 1).unwrap();
+        assert_eq!(family.at(0), (1, -1));
+    }
+    #[test]
+    #[should_panic]
+    fn test_solve_linear_zero() {
+        solve_linear(0, 0, 0);
+    }
+    #[test]
+    fn test_solve_linear_form() {
+        let coefficients = [12, -20, 30, 0, 7];
+        let family = solve_linear_form(&coefficients, 5).unwrap();
+        assert_eq!(family.dimension(), 4);
+        for t in [[0, 0, 0, 0], [1, -2, 3, 4], [-5, 5, 2, 1]] {
+            let point = family.at(&t);
+            let value: i128 = point.iter().zip(coefficients).map(|(x, a)| x * a).sum();
+            assert_eq!(value, 5);
+        }
+        // The basis spans the integer kernel: its vectors are independent, and the unimodular construction makes them a basis.
+        for vector in family.basis.iter() {
+            let value: i128 = vector.iter().zip(coefficients).map(|(x, a)| x * a).sum();
+            assert_eq!(value, 0);
+        }
+        assert!(solve_linear_form(&[4, 6, 10], 3).is_none());
+        assert_eq!(solve_linear_form(&[-3], 6).unwrap().particular, vec![-2]);
+    }
+    #[test]
+    fn test_pell_fundamental() {
+        for d in 2..200i128 {
+            if base::is_perfect_square(d) {
+                continue;
+            }
+            let (x, y) = pell_fundamental(d).unwrap();
+            assert_eq!(x * x - d * y * y, 1);
+            if y < 1000 {
+                assert!((1..y).all(|smaller| !base::is_perfect_square(1 + d * smaller * smaller)));
+            }
+        }
+    }
+    #[test]
+    fn test_pell_solutions_brute_force() {
+        for d in 2..30i128 {
+            if base::is_perfect_square(d) {
+                continue;
+            }
+            for n in -40..40i128 {
+                let brute: Vec<(i128, i128)> = (0..2000i128)
+                    .filter_map(|y| {
+                        let square = n + d * y * y;
+                        (square >= 0 && base::is_perfect_square(square))
+                            .then(|| (base::isqrt(square), y))
+                    })
+                    .filter(|&(x, _)| x <= 2000)
+                    .collect();
+                let mut brute = brute;
+                brute.sort_unstable();
+                let found: Vec<(i128, i128)> = pell_solutions(d, n)
+                    .unwrap()
+                    .take_while(|&(x, _)| x <= 2000)
+                    .collect();
+                if n == 0 {
+                    assert_eq!(found, vec![(0, 0)]);
+                } else {
+                    assert_eq!(found, brute, "x^2 - {}y^2 = {}", d, n);
+                }
+            }
+        }
+    }
+    #[test]
+    fn test_pell_solutions_large() {
+        let mut solutions = pell_solutions(991, 1).unwrap();
+        let unit = solutions.unit();
+        assert_eq!(unit, pell_fundamental(991).unwrap());
+        assert_eq!(solutions.next(), Some((1, 0)));
+        assert_eq!(solutions.next(), Some(unit));
+        let count = pell_solutions(2, -7).unwrap().count();
+        assert!(count > 40);
+        for (x, y) in pell_solutions(2, -7).unwrap() {
+            let value = quadratic_multiply(2, (x, y), (x, -y)).map(|(norm, _)| norm);
+            assert_eq!(value, Some(-7));
+        }
+    }
+    #[test]
+    fn test_pell_solutions_highly_divisible() {
+        // The square divisors of 2^70 come from its factorization rather than a loop up to 2^35.
+        let solutions: Vec<_> = pell_solutions(2, 1 << 70).unwrap().take(3).collect();
+        assert_eq!(solutions[0], (1 << 35, 0));
+        for &(x, y) in &solutions {
+            let value = quadratic_multiply(2, (x, y), (x, -y)).map(|(norm, _)| norm);
+            assert_eq!(value, Some(1 << 70));
+        }
+        assert_eq!(
+            pell_solutions(2, i128::MIN).unwrap_err().kind(),
+            ErrorKind::Overflow
+        );
+        assert_eq!(square_divisor_roots(-72), vec![1, 2, 3, 6]);
+    }
+    #[test]
+    fn test_sums_of_two_squares() {
+        for n in 0..2000i128 {
+            let brute: Vec<(i128, i128)> = (0..=base::isqrt(n))
+                .filter_map(|a| {
+                    let rest = n - a * a;
+                    let b = base::isqrt(rest);
+                    (b * b == rest && a <= b).then_some((a, b))
+                })
+                .collect();
+            assert_eq!(sums_of_two_squares(n), brute, "n = {}", n);
+            assert_eq!(sum_of_two_squares(n), brute.first().copied());
+        }
+        let p = 170141183460469231731687303715884105727; // 2^127 - 1 = 3 (mod 4)
+        assert!(sums_of_two_squares(p).is_empty());
+        let n = 1_000_000_009 * 998244353 * 25;
+        let all = sums_of_two_squares(n);
+        assert_eq!(all.len(), 6);
+        assert!(all.iter().all(|&(a, b)| a * a + b * b == n));
+    }
+}
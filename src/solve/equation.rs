@@ -1,6 +1,7 @@
 //! equation.rs is a module that contains the Equation, EquationF, and ZeroEquation structs.
 //! These structs are used to solve equations with numbers and an operator (+, -, *, /, %, ^)
-//! or to solve equations that equal zero.
+//! or to solve equations that equal zero. `Equation` holds `i128` values by default and any other
+//! `Operand` of the crate through `Equation::with_operands`.
 //!
 //! # Examples
 //! ```
@@ -8,70 +9,231 @@
 //! ```
 //!
 
-use crate::integers::base::*;
+use crate::integers::complex_integers;
+use crate::integers::mod_int::ModInt;
+use crate::numbers::complex_floats;
+use crate::rational::{rational_float, rational_integer};
+use crate::traits::{Field, Integer, Ring};
+
+/// The values an `Equation` can hold: a ring with the type's own quotient and remainder for `/` and `%`, and a power
+/// operation for `^`. Division in a field is exact, so the remainder of the field types is always zero.
+pub trait Operand: Ring {
+    /// Returns the quotient of `self` and `other`, as the type's `/` operator computes it.
+    fn quotient(&self, other: &Self) -> Self;
+    /// Returns the remainder of `self` divided by `other`, as the type's `%` operator computes it.
+    fn remainder(&self, other: &Self) -> Self;
+    /// Returns `self` raised to the power of `exponent`.
+    fn power(&self, exponent: &Self) -> Self;
+}
+
+macro_rules! impl_integer_operand {
+    ($($t:ty),*) => {
+        $(
+            impl Operand for $t {
+                fn quotient(&self, other: &Self) -> Self {
+                    self / other
+                }
+                fn remainder(&self, other: &Self) -> Self {
+                    self % other
+                }
+                /// Only supports non-negative exponent values. Panics if the exponent is negative.
+                fn power(&self, exponent: &Self) -> Self {
+                    if *exponent < 0 {
+                        panic!("Exponentiation with negative exponent is not supported");
+                    }
+                    Ring::pow(self, u32::try_from(*exponent).expect("Exponent is too large"))
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_operand!(i32, i64, i128);
+
+impl Operand for f64 {
+    fn quotient(&self, other: &Self) -> Self {
+        self / other
+    }
+    fn remainder(&self, other: &Self) -> Self {
+        self % other
+    }
+    fn power(&self, exponent: &Self) -> Self {
+        self.powf(*exponent)
+    }
+}
+
+impl Operand for complex_integers::Complex {
+    /// The Euclidean quotient, rounded to the nearest Gaussian integer. Panics if `other` is zero.
+    fn quotient(&self, other: &Self) -> Self {
+        *self / *other
+    }
+    /// The Euclidean remainder. Panics if `other` is zero.
+    fn remainder(&self, other: &Self) -> Self {
+        *self % *other
+    }
+    /// Only supports non-negative real exponents. Panics for any other exponent.
+    fn power(&self, exponent: &Self) -> Self {
+        if exponent.get_imag() != 0 || exponent.get_real() < 0 {
+            panic!("Exponentiation is only supported with non-negative real exponents");
+        }
+        Ring::pow(
+            self,
+            u32::try_from(exponent.get_real()).expect("Exponent is too large"),
+        )
+    }
+}
+
+impl Operand for complex_floats::Complex {
+    fn quotient(&self, other: &Self) -> Self {
+        Field::divide(self, other)
+    }
+    fn remainder(&self, _other: &Self) -> Self {
+        Self::zero()
+    }
+    /// The principal power, as `powc` computes it.
+    fn power(&self, exponent: &Self) -> Self {
+        self.powc(exponent)
+    }
+}
+
+impl Operand for rational_float::Rational {
+    fn quotient(&self, other: &Self) -> Self {
+        Field::divide(self, other)
+    }
+    fn remainder(&self, _other: &Self) -> Self {
+        Self::new(0.0, 1.0)
+    }
+    /// Only supports integer exponents. Panics if the exponent is not an integer.
+    fn power(&self, exponent: &Self) -> Self {
+        let exponent = exponent.to_float();
+        if exponent.fract() != 0.0 {
+            panic!("Exponentiation is only supported with integer exponents");
+        }
+        let power = Ring::pow(
+            self,
+            u32::try_from(exponent.abs() as i64).expect("Exponent is too large"),
+        );
+        if exponent < 0.0 {
+            power.inverse()
+        } else {
+            power
+        }
+    }
+}
+
+impl<T: Integer> Operand for rational_integer::Rational<T> {
+    fn quotient(&self, other: &Self) -> Self {
+        Field::divide(self, other)
+    }
+    fn remainder(&self, _other: &Self) -> Self {
+        Self::from_integer(T::zero())
+    }
+    /// Only supports integer exponents that fit in an `i32`. Panics for any other exponent.
+    fn power(&self, exponent: &Self) -> Self {
+        if !exponent.is_integer() {
+            panic!("Exponentiation is only supported with integer exponents");
+        }
+        let exponent = exponent
+            .get_numerator()
+            .to_i128()
+            .and_then(|exponent| i32::try_from(exponent).ok())
+            .expect("Exponent is too large");
+        self.pow(exponent)
+    }
+}
+
+impl<const M: u64> Operand for ModInt<M> {
+    /// Multiplies by the inverse of `other`. Panics if `other` is not invertible modulo `M`.
+    fn quotient(&self, other: &Self) -> Self {
+        *self / *other
+    }
+    fn remainder(&self, _other: &Self) -> Self {
+        Self::new(0)
+    }
+    /// Uses the representative of `exponent` in `0..M`.
+    fn power(&self, exponent: &Self) -> Self {
+        ModInt::pow(*self, exponent.value())
+    }
+}
 
 /// Equation struct that can be used to solve equations with two numbers and an operator (+, -, *, /, %, ^)
 /// Sol is the solution to the solve and is calculated when get_sol() is called.
 ///
+/// `Equation::new` takes `i128` values. `Equation::with_operands` takes any `Operand`: the primitive
+/// integers, `f64`, both complex types, both rational types and `ModInt`.
+///
 /// # Example
 /// ```
+/// use numbers_rus::integers::mod_int::ModInt;
+/// use numbers_rus::rational::rational_integer::Rational;
 /// use numbers_rus::solve::equation::Equation;
+///
 /// let mut solve = Equation::new(1, 2, '+');
 /// assert_eq!(solve.get_sol(), 3);
+/// let mut solve = Equation::with_operands(Rational::new(1, 2), Rational::new(2, 3), '/');
+/// assert_eq!(solve.get_sol(), Rational::new(3, 4));
+/// let mut solve = Equation::with_operands(ModInt::<13>::new(2), ModInt::new(10), '^');
+/// assert_eq!(solve.get_sol(), ModInt::new(10)); // 2^10 = 1024 = 78 * 13 + 10
 /// ```
-pub struct Equation {
-    a: i128,
-    b: i128,
+pub struct Equation<T: Operand = i128> {
+    a: T,
+    b: T,
     operator: char,
-    sol: i128,
+    sol: T,
 }
 impl Equation {
-    /// Creates a new equation
+    /// Creates a new equation of two `i128` values
     pub fn new(a: i128, b: i128, operator: char) -> Equation {
+        Equation::with_operands(a, b, operator)
+    }
+}
+impl<T: Operand> Equation<T> {
+    /// Creates a new equation of any two `Operand` values
+    pub fn with_operands(a: T, b: T, operator: char) -> Equation<T> {
         Equation {
             a,
             b,
             operator,
-            sol: 0,
+            sol: T::zero(),
         }
     }
     /// Returns the solution to the equation
-    pub fn get_sol(&mut self) -> i128 {
-        if self.sol == 0 {
+    pub fn get_sol(&mut self) -> T {
+        if self.sol.is_zero() {
             self.sol = match self.operator {
-                '+' => add(self.a, self.b),
-                '-' => subtract(self.a, self.b),
-                '*' => multiply(self.a, self.b),
-                '/' => divide(self.a, self.b),
-                '%' => modulo(self.a, self.b),
-                '^' => power(self.a, self.b),
-                _ => 0,
+                '+' => self.a.add(&self.b),
+                '-' => self.a.subtract(&self.b),
+                '*' => self.a.multiply(&self.b),
+                '/' => self.a.quotient(&self.b),
+                '%' => self.a.remainder(&self.b),
+                '^' => self.a.power(&self.b),
+                _ => T::zero(),
             };
         }
-        self.sol
+        self.sol.clone()
     }
     /// sets the a value (first number) in the equation struct.  This will reset the solution to 0 so that it will be recalculated when get_sol() is called.
-    pub fn set_a(&mut self, a: i128) {
+    pub fn set_a(&mut self, a: T) {
         self.a = a;
-        self.sol = 0; // reset the solution
+        self.sol = T::zero(); // reset the solution
     }
     /// sets the b value (second number) in the equation struct.  This will reset the solution to 0 so that it will be recalculated when get_sol() is called.
-    pub fn set_b(&mut self, b: i128) {
+    pub fn set_b(&mut self, b: T) {
         self.b = b;
-        self.sol = 0; // reset the solution
+        self.sol = T::zero(); // reset the solution
     }
     /// sets the operator in the equation struct.  This will reset the solution to 0 so that it will be recalculated when get_sol() is called.
     pub fn set_operator(&mut self, operator: char) {
         self.operator = operator;
-        self.sol = 0; // reset the solution
+        self.sol = T::zero(); // reset the solution
     }
     /// returns the a value
-    pub fn get_a(&self) -> i128 {
-        self.a
+    pub fn get_a(&self) -> T {
+        self.a.clone()
     }
     /// returns the b value
-    pub fn get_b(&self) -> i128 {
-        self.b
+    pub fn get_b(&self) -> T {
+        self.b.clone()
     }
     /// returns the operator
     pub fn get_operator(&self) -> char {
@@ -122,95 +284,184 @@ mod test_equation {
         println!("b: {}", equation.get_b());
         assert_eq!(equation.get_sol(), 6);
     }
+    #[test]
+    fn new_takes_i128() {
+        let mut equation = Equation::new(100000, 100000, '*');
+        assert_eq!(equation.get_sol(), 10_000_000_000);
+        let mut equation = Equation::new(i128::MAX, 2, '/');
+        assert_eq!(equation.get_sol(), i128::MAX / 2);
+    }
+    #[test]
+    fn primitive_operands() {
+        assert_eq!(Equation::with_operands(7i32, 3, '%').get_sol(), 1);
+        assert_eq!(Equation::with_operands(2i64, 40, '^').get_sol(), 1 << 40);
+        assert_eq!(Equation::with_operands(7.5, 2.0, '%').get_sol(), 1.5);
+    }
+    #[test]
+    fn complex_integer_operands() {
+        use crate::integers::complex_integers::Complex;
+
+        let a = Complex::new(7, 3);
+        let b = Complex::new(2, -1);
+        assert_eq!(
+            Equation::with_operands(a, b, '*').get_sol(),
+            Complex::new(17, -1)
+        );
+        let quotient = Equation::with_operands(a, b, '/').get_sol();
+        let remainder = Equation::with_operands(a, b, '%').get_sol();
+        assert_eq!(quotient * b + remainder, a);
+        assert!(remainder.norm() < b.norm());
+        assert_eq!(
+            Equation::with_operands(Complex::new(1, 1), Complex::new(4, 0), '^').get_sol(),
+            Complex::new(-4, 0)
+        );
+    }
+    #[test]
+    #[should_panic]
+    fn complex_integer_imaginary_exponent() {
+        use crate::integers::complex_integers::Complex;
+
+        Equation::with_operands(Complex::new(1, 1), Complex::new(0, 1), '^').get_sol();
+    }
+    #[test]
+    fn complex_float_operands() {
+        use crate::numbers::complex_floats::Complex;
+
+        let a = Complex::new(1.0, 2.0);
+        let b = Complex::new(3.0, -4.0);
+        assert_eq!(
+            Equation::with_operands(a, b, '/').get_sol(),
+            Complex::new(-0.2, 0.4)
+        );
+        assert_eq!(
+            Equation::with_operands(a, b, '%').get_sol(),
+            Complex::new(0.0, 0.0)
+        );
+        let square = Equation::with_operands(a, Complex::new(2.0, 0.0), '^').get_sol();
+        assert!((square.real + 3.0).abs() < 1e-12 && (square.imag - 4.0).abs() < 1e-12);
+    }
+    #[test]
+    fn rational_operands() {
+        use crate::integers::big_integer::BigInt;
+        use crate::rational::{rational_float, rational_integer};
+
+        let a = rational_integer::Rational::new(2, 3);
+        let b = rational_integer::Rational::new(-3, 1);
+        assert_eq!(
+            Equation::with_operands(a, b, '^').get_sol(),
+            rational_integer::Rational::new(27, 8)
+        );
+        assert_eq!(
+            Equation::with_operands(a, b, '%').get_sol(),
+            rational_integer::Rational::from(0)
+        );
+        let big = rational_integer::Rational::<BigInt>::from(1i128 << 100);
+        let mut equation = Equation::with_operands(big.clone(), big, '*');
+        assert_eq!(equation.get_sol().get_numerator(), BigInt::from(1) << 200);
+
+        let a = rational_float::Rational::new(1.0, 2.0);
+        let b = rational_float::Rational::new(-2.0, 1.0);
+        assert_eq!(
+            Equation::with_operands(a, b, '^').get_sol(),
+            rational_float::Rational::new(4.0, 1.0)
+        );
+        assert_eq!(
+            Equation::with_operands(a, b, '/').get_sol(),
+            rational_float::Rational::new(-1.0, 4.0)
+        );
+    }
+    #[test]
+    #[should_panic]
+    fn rational_fractional_exponent() {
+        use crate::rational::rational_integer::Rational;
+
+        Equation::with_operands(Rational::new(2, 1), Rational::new(1, 2), '^').get_sol();
+    }
+    #[test]
+    fn mod_int_operands() {
+        type Mint = ModInt<1_000_000_007>;
+
+        let left = Mint::new(10);
+        let right = Mint::new(4);
+        assert_eq!(
+            Equation::with_operands(left, right, '-').get_sol(),
+            Mint::new(6)
+        );
+        assert_eq!(
+            Equation::with_operands(left, right, '*').get_sol(),
+            Mint::new(40)
+        );
+        assert_eq!(
+            Equation::with_operands(left, right, '^').get_sol(),
+            Mint::new(10_000)
+        );
+        assert_eq!(
+            Equation::with_operands(left, right, '%').get_sol(),
+            Mint::new(0)
+        );
+        let quotient = Equation::with_operands(left, right, '/').get_sol();
+        assert_eq!(quotient * right, left);
+
+        let mut equation = Equation::with_operands(Mint::new(3), Mint::new(4), '+');
+        equation.set_a(Mint::new(-1));
+        assert_eq!(equation.get_a(), Mint::new(1_000_000_006));
+        assert_eq!(equation.get_sol(), Mint::new(3));
+    }
+    #[test]
+    #[should_panic]
+    fn mod_int_divide_by_zero() {
+        Equation::with_operands(ModInt::<7>::new(1), ModInt::new(0), '/').get_sol();
+    }
 }
 
 /// EquationF is an solve similar to Equation, but with f64 values.  Sol is calculated when get_sol() is called.
+/// It is a separate struct, rather than an alias of `Equation<f64>`, so that `Equation::new` keeps taking `i128` values.
 /// # Example
 /// ```
 /// use numbers_rus::solve::equation::EquationF;
 /// let mut solve = EquationF::new(1.092, 2.435, '+');
 /// assert_eq!(solve.get_sol(), 3.527);
 /// ```
-
 pub struct EquationF {
-    a: f64,
-    b: f64,
-    operator: char,
-    sol: f64,
+    equation: Equation<f64>,
 }
 impl EquationF {
+    /// Creates a new equation of two `f64` values
     pub fn new(a: f64, b: f64, operator: char) -> EquationF {
         EquationF {
-            a,
-            b,
-            operator,
-            sol: 0.0,
+            equation: Equation::with_operands(a, b, operator),
         }
     }
+    /// Returns the solution to the equation
     pub fn get_sol(&mut self) -> f64 {
-        if self.sol == 0.0 {
-            self.sol = match self.operator {
-                '+' => add_float(self.a, self.b),
-                '-' => subtract_float(self.a, self.b),
-                '*' => multiply_float(self.a, self.b),
-                '/' => divide_float(self.a, self.b),
-                '%' => modulo_float(self.a, self.b),
-                '^' => power_float(self.a, self.b),
-                _ => 0.0,
-            };
-        }
-        self.sol
+        self.equation.get_sol()
     }
     /// sets the a value (first number) in the equation struct.  This will reset the solution to 0 so that it will be recalculated when get_sol() is called.
     pub fn set_a(&mut self, a: f64) {
-        self.a = a;
-        self.sol = 0.0; // reset the solution
+        self.equation.set_a(a);
     }
     /// sets the b value (second number) in the equation struct.  This will reset the solution to 0 so that it will be recalculated when get_sol() is called.
     pub fn set_b(&mut self, b: f64) {
-        self.b = b;
-        self.sol = 0.0; // reset the solution
+        self.equation.set_b(b);
     }
     /// sets the operator in the equation struct.  This will reset the solution to 0 so that it will be recalculated when get_sol() is called.
     pub fn set_operator(&mut self, operator: char) {
-        self.operator = operator;
-        self.sol = 0.0; // reset the solution
+        self.equation.set_operator(operator);
     }
+    /// returns the a value
     pub fn get_a(&self) -> f64 {
-        self.a
+        self.equation.get_a()
     }
+    /// returns the b value
     pub fn get_b(&self) -> f64 {
-        self.b
+        self.equation.get_b()
     }
+    /// returns the operator
     pub fn get_operator(&self) -> char {
-        self.operator
+        self.equation.get_operator()
     }
 }
 
-fn add_float(p0: f64, p1: f64) -> f64 {
-    p0 + p1
-}
-
-fn subtract_float(p0: f64, p1: f64) -> f64 {
-    p0 - p1
-}
-
-fn multiply_float(p0: f64, p1: f64) -> f64 {
-    p0 * p1
-}
-
-fn divide_float(p0: f64, p1: f64) -> f64 {
-    p0 / p1
-}
-
-fn modulo_float(p0: f64, p1: f64) -> f64 {
-    p0 % p1
-}
-
-fn power_float(p0: f64, p1: f64) -> f64 {
-    p0.powf(p1)
-}
-
 #[cfg(test)]
 mod test_equation_f {
     use super::*;
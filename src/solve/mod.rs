@@ -1,8 +1,9 @@
 //! Solve module contains structures that hold both values and operations to be automatically solved.
 //! Module contains:
-//! * Base Equation struct
+//! * Base Equation struct, for every number type of the crate (including modular integers)
 //! * Equation struct for Complex numbers
 //! * Equation struct for Complex integers
+//! * Diophantine solvers for linear, Pell and sum-of-two-squares equations
 //! * Equation struct for Rational numbers
 //! * Equation struct for Rational integers
 //!
@@ -17,6 +18,7 @@
 //!
 pub mod complex_float_equations;
 pub mod complex_integer_equations;
+pub mod diophantine;
 pub mod equation;
 pub mod rational_float_equation;
 pub mod rational_integer_equation;
@@ -0,0 +1,1049 @@
+//! The `traits` module defines the algebraic traits shared by the number types of the crate, so that an algorithm can be
+//! written once and run on every one of them:
+//!
+//! * `Zero` and `One` - the additive and multiplicative identities.
+//! * `Ring` - addition, subtraction, multiplication and negation, with `pow` for free.
+//! * `EuclideanRing` - a ring with division with remainder, which is enough for `gcd`.
+//! * `Field` - a ring where every non-zero element can be divided by.
+//! * `Signed` - absolute value and sign.
+//! * `Real` - an ordered field that can be approximated by an `f64`.
+//! * `Integer` - a totally ordered Euclidean ring with checked arithmetic, usable as the parts of a `Rational<T>`.
+//!
+//! | type                                                | traits                               |
+//! |-----------------------------------------------------|--------------------------------------|
+//! | `i32`, `i64`, `i128`, `BigInt`                      | `EuclideanRing`, `Signed`, `Integer` |
+//! | `f64`                                               | `Field`, `Signed`, `Real`            |
+//! | `integers::complex_integers::Complex`               | `EuclideanRing`                      |
+//! | `integers::eisenstein_integers::Eisenstein`         | `EuclideanRing`                      |
+//! | `integers::quadratic_integers::QuadraticInteger<D>` | `Ring`                               |
+//! | `numbers::complex_floats::Complex`                  | `Field`                              |
+//! | `rational::rational_integer::Rational<T>`           | `Field`, `Signed`, `Real`            |
+//! | `rational::rational_float::Rational`                | `Field`, `Signed`, `Real`            |
+//! | `integers::mod_int::ModInt<M>`                      | `Field` (for a prime `M`)            |
+//!
+//! The trait methods use the same names as the inherent methods of `Complex` and `Rational` (`add`, `subtract`, `multiply`,
+//! `divide`), so existing code keeps compiling when the traits are imported.
+//!
+//! # Examples
+//! ```
+//! use numbers_rus::numbers::complex_floats::Complex;
+//! use numbers_rus::rational::rational_integer::Rational;
+//! use numbers_rus::traits;
+//!
+//! // 1 + 2x + 3x^2 at x = 2, for integers, rationals and complex numbers alike.
+//! assert_eq!(traits::evaluate_polynomial(&[1i128, 2, 3], &2), 17);
+//! let half = Rational::new(1, 2);
+//! let value = traits::evaluate_polynomial(&[Rational::new(1, 1), Rational::new(2, 1), Rational::new(3, 1)], &half);
+//! assert_eq!(value.to_float(), 2.75);
+//! let i = Complex::new(0.0, 1.0);
+//! assert_eq!(traits::evaluate_polynomial(&[Complex::one(), Complex::zero(), Complex::one()], &i), Complex::zero());
+//! ```
+use crate::integers::big_integer::BigInt;
+use crate::integers::complex_integers;
+use crate::integers::eisenstein_integers::Eisenstein;
+use crate::integers::mod_int::ModInt;
+use crate::integers::quadratic_integers::QuadraticInteger;
+use crate::numbers::complex_floats;
+use crate::rational::{rational_float, rational_integer};
+use std::fmt;
+
+/// The additive identity.
+pub trait Zero: Sized {
+    /// Returns zero.
+    fn zero() -> Self;
+    /// Returns whether `self` is zero.
+    fn is_zero(&self) -> bool;
+}
+
+/// The multiplicative identity.
+pub trait One: Sized {
+    /// Returns one.
+    fn one() -> Self;
+}
+
+/// A commutative ring: values that can be added, subtracted and multiplied.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::complex_integers::Complex;
+/// use numbers_rus::traits::Ring;
+///
+/// let a = Complex::new(1, 1);
+/// assert_eq!(Ring::pow(&a, 4), Complex::new(-4, 0));
+/// ```
+pub trait Ring: Zero + One + Clone + PartialEq {
+    /// Returns `self + other`.
+    fn add(&self, other: &Self) -> Self;
+    /// Returns `self - other`.
+    fn subtract(&self, other: &Self) -> Self;
+    /// Returns `self * other`.
+    fn multiply(&self, other: &Self) -> Self;
+    /// Returns `-self`.
+    fn negate(&self) -> Self {
+        Self::zero().subtract(self)
+    }
+    /// Returns `self` raised to the power of `exponent` by repeated squaring.
+    fn pow(&self, exponent: u32) -> Self {
+        let mut result = Self::one();
+        let mut base = self.clone();
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.multiply(&base);
+            }
+            exponent >>= 1;
+            // squaring past the last bit could overflow even when the result fits
+            if exponent > 0 {
+                base = base.multiply(&base);
+            }
+        }
+        result
+    }
+}
+
+/// A ring with division with remainder, where the remainder is smaller than the divisor.
+pub trait EuclideanRing: Ring {
+    /// Returns `(quotient, remainder)` with `self = quotient * other + remainder`. Panics if `other` is zero.
+    fn div_rem(&self, other: &Self) -> (Self, Self);
+}
+
+/// A field: a ring where every non-zero value has an inverse.
+pub trait Field: Ring {
+    /// Returns `self / other`.
+    fn divide(&self, other: &Self) -> Self;
+    /// Returns `1 / self`.
+    fn inverse(&self) -> Self {
+        Self::one().divide(self)
+    }
+}
+
+/// Values with a sign.
+pub trait Signed: Ring {
+    /// Returns the absolute value.
+    fn abs(&self) -> Self;
+    /// Returns -1, 0 or 1 according to the sign.
+    fn signum(&self) -> Self;
+    /// Returns whether the value is below zero.
+    fn is_negative(&self) -> bool;
+}
+
+/// An ordered field of real numbers that can be approximated by an `f64`.
+pub trait Real: Field + Signed {
+    /// Returns the nearest `f64`.
+    fn to_f64(&self) -> f64;
+}
+
+/// Integers that can be the parts of a generic `Rational`: a totally ordered Euclidean ring with overflow-checked
+/// arithmetic and the shifts used by the binary gcd. `BigInt` never overflows, so its checked methods always succeed.
+pub trait Integer: EuclideanRing + Signed + Ord + fmt::Debug + fmt::Display {
+    /// Returns `self + other`, or `None` on overflow.
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    /// Returns `self - other`, or `None` on overflow.
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+    /// Returns `self * other`, or `None` on overflow.
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+    /// Returns `-self`, or `None` on overflow.
+    fn checked_neg(&self) -> Option<Self>;
+    /// Returns the number of trailing zero bits. Only meaningful for non-zero values.
+    fn trailing_zeros(&self) -> u32;
+    /// Returns `self * 2^bits`, which the caller guarantees does not overflow.
+    fn shift_left(&self, bits: u32) -> Self;
+    /// Returns `self / 2^bits`, which the caller guarantees is exact.
+    fn shift_right(&self, bits: u32) -> Self;
+    /// Returns the nearest `f64`.
+    fn to_f64(&self) -> f64;
+    /// Returns the value as an `i128` if it fits.
+    fn to_i128(&self) -> Option<i128>;
+    /// Returns the value as a `BigInt`.
+    fn to_big_int(&self) -> BigInt;
+    /// Returns the value of a `BigInt` if it fits.
+    fn from_big_int(value: &BigInt) -> Option<Self>;
+}
+
+macro_rules! impl_identities {
+    ($type:ty, $zero:expr, $one:expr) => {
+        impl Zero for $type {
+            fn zero() -> Self {
+                $zero
+            }
+            fn is_zero(&self) -> bool {
+                *self == $zero
+            }
+        }
+
+        impl One for $type {
+            fn one() -> Self {
+                $one
+            }
+        }
+    };
+}
+
+impl_identities!(f64, 0.0, 1.0);
+
+macro_rules! impl_primitive_integer {
+    ($($type:ty),*) => {
+        $(
+            impl_identities!($type, 0, 1);
+
+            impl Ring for $type {
+                fn add(&self, other: &Self) -> Self {
+                    self + other
+                }
+                fn subtract(&self, other: &Self) -> Self {
+                    self - other
+                }
+                fn multiply(&self, other: &Self) -> Self {
+                    self * other
+                }
+                fn negate(&self) -> Self {
+                    -self
+                }
+            }
+
+            impl EuclideanRing for $type {
+                fn div_rem(&self, other: &Self) -> (Self, Self) {
+                    (self.div_euclid(*other), self.rem_euclid(*other))
+                }
+            }
+
+            impl Signed for $type {
+                fn abs(&self) -> Self {
+                    <$type>::abs(*self)
+                }
+                fn signum(&self) -> Self {
+                    <$type>::signum(*self)
+                }
+                fn is_negative(&self) -> bool {
+                    *self < 0
+                }
+            }
+
+            impl Integer for $type {
+                fn checked_add(&self, other: &Self) -> Option<Self> {
+                    <$type>::checked_add(*self, *other)
+                }
+                fn checked_sub(&self, other: &Self) -> Option<Self> {
+                    <$type>::checked_sub(*self, *other)
+                }
+                fn checked_mul(&self, other: &Self) -> Option<Self> {
+                    <$type>::checked_mul(*self, *other)
+                }
+                fn checked_neg(&self) -> Option<Self> {
+                    <$type>::checked_neg(*self)
+                }
+                fn trailing_zeros(&self) -> u32 {
+                    <$type>::trailing_zeros(*self)
+                }
+                fn shift_left(&self, bits: u32) -> Self {
+                    self << bits
+                }
+                fn shift_right(&self, bits: u32) -> Self {
+                    self >> bits
+                }
+                fn to_f64(&self) -> f64 {
+                    *self as f64
+                }
+                fn to_i128(&self) -> Option<i128> {
+                    i128::try_from(*self).ok()
+                }
+                fn to_big_int(&self) -> BigInt {
+                    BigInt::from(*self)
+                }
+                fn from_big_int(value: &BigInt) -> Option<Self> {
+                    value.to_i128().and_then(|value| <$type>::try_from(value).ok())
+                }
+            }
+        )*
+    };
+}
+
+impl_primitive_integer!(i32, i64, i128);
+
+impl Ring for f64 {
+    fn add(&self, other: &Self) -> Self {
+        self + other
+    }
+    fn subtract(&self, other: &Self) -> Self {
+        self - other
+    }
+    fn multiply(&self, other: &Self) -> Self {
+        self * other
+    }
+    fn negate(&self) -> Self {
+        -self
+    }
+    fn pow(&self, exponent: u32) -> Self {
+        self.powf(exponent as f64)
+    }
+}
+
+impl Field for f64 {
+    fn divide(&self, other: &Self) -> Self {
+        self / other
+    }
+}
+
+impl Signed for f64 {
+    fn abs(&self) -> Self {
+        f64::abs(*self)
+    }
+    fn signum(&self) -> Self {
+        if *self == 0.0 {
+            0.0
+        } else {
+            f64::signum(*self)
+        }
+    }
+    fn is_negative(&self) -> bool {
+        *self < 0.0
+    }
+}
+
+impl Real for f64 {
+    fn to_f64(&self) -> f64 {
+        *self
+    }
+}
+
+impl Zero for BigInt {
+    fn zero() -> Self {
+        BigInt::zero()
+    }
+    fn is_zero(&self) -> bool {
+        BigInt::is_zero(self)
+    }
+}
+
+impl One for BigInt {
+    fn one() -> Self {
+        BigInt::one()
+    }
+}
+
+impl Ring for BigInt {
+    fn add(&self, other: &Self) -> Self {
+        self + other
+    }
+    fn subtract(&self, other: &Self) -> Self {
+        self - other
+    }
+    fn multiply(&self, other: &Self) -> Self {
+        self * other
+    }
+    fn negate(&self) -> Self {
+        -self
+    }
+}
+
+impl EuclideanRing for BigInt {
+    fn div_rem(&self, other: &Self) -> (Self, Self) {
+        BigInt::div_rem(self, other)
+    }
+}
+
+impl Integer for BigInt {
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(self - other)
+    }
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self * other)
+    }
+    fn checked_neg(&self) -> Option<Self> {
+        Some(-self)
+    }
+    fn trailing_zeros(&self) -> u32 {
+        BigInt::trailing_zeros(self).unwrap_or(0) as u32
+    }
+    fn shift_left(&self, bits: u32) -> Self {
+        self << bits as usize
+    }
+    fn shift_right(&self, bits: u32) -> Self {
+        self >> bits as usize
+    }
+    fn to_f64(&self) -> f64 {
+        BigInt::to_f64(self)
+    }
+    fn to_i128(&self) -> Option<i128> {
+        BigInt::to_i128(self)
+    }
+    fn to_big_int(&self) -> BigInt {
+        self.clone()
+    }
+    fn from_big_int(value: &BigInt) -> Option<Self> {
+        Some(value.clone())
+    }
+}
+
+impl Signed for BigInt {
+    fn abs(&self) -> Self {
+        BigInt::abs(self)
+    }
+    fn signum(&self) -> Self {
+        BigInt::from(BigInt::signum(self))
+    }
+    fn is_negative(&self) -> bool {
+        BigInt::is_negative(self)
+    }
+}
+
+impl Zero for complex_integers::Complex {
+    fn zero() -> Self {
+        complex_integers::Complex::zero()
+    }
+    fn is_zero(&self) -> bool {
+        self.get() == (0, 0)
+    }
+}
+
+impl One for complex_integers::Complex {
+    fn one() -> Self {
+        complex_integers::Complex::one()
+    }
+}
+
+impl Ring for complex_integers::Complex {
+    fn add(&self, other: &Self) -> Self {
+        complex_integers::Complex::add(self, other)
+    }
+    fn subtract(&self, other: &Self) -> Self {
+        complex_integers::Complex::subtract(self, other)
+    }
+    fn multiply(&self, other: &Self) -> Self {
+        complex_integers::Complex::multiply(self, other)
+    }
+    fn negate(&self) -> Self {
+        complex_integers::Complex::new(-self.get_real(), -self.get_imag())
+    }
+}
+
+impl EuclideanRing for complex_integers::Complex {
+    fn div_rem(&self, other: &Self) -> (Self, Self) {
+        complex_integers::Complex::div_rem(self, other)
+    }
+}
+
+impl Zero for Eisenstein {
+    fn zero() -> Self {
+        Eisenstein::zero()
+    }
+    fn is_zero(&self) -> bool {
+        *self == Eisenstein::zero()
+    }
+}
+
+impl One for Eisenstein {
+    fn one() -> Self {
+        Eisenstein::one()
+    }
+}
+
+impl Ring for Eisenstein {
+    fn add(&self, other: &Self) -> Self {
+        Eisenstein::add(self, other)
+    }
+    fn subtract(&self, other: &Self) -> Self {
+        Eisenstein::subtract(self, other)
+    }
+    fn multiply(&self, other: &Self) -> Self {
+        Eisenstein::multiply(self, other)
+    }
+    fn negate(&self) -> Self {
+        -*self
+    }
+}
+
+impl EuclideanRing for Eisenstein {
+    fn div_rem(&self, other: &Self) -> (Self, Self) {
+        Eisenstein::div_rem(self, other)
+    }
+}
+
+impl<const D: i128> Zero for QuadraticInteger<D> {
+    fn zero() -> Self {
+        QuadraticInteger::zero()
+    }
+    fn is_zero(&self) -> bool {
+        *self == QuadraticInteger::zero()
+    }
+}
+
+impl<const D: i128> One for QuadraticInteger<D> {
+    fn one() -> Self {
+        QuadraticInteger::one()
+    }
+}
+
+impl<const D: i128> Ring for QuadraticInteger<D> {
+    fn add(&self, other: &Self) -> Self {
+        QuadraticInteger::add(self, other)
+    }
+    fn subtract(&self, other: &Self) -> Self {
+        QuadraticInteger::subtract(self, other)
+    }
+    fn multiply(&self, other: &Self) -> Self {
+        QuadraticInteger::multiply(self, other)
+    }
+    fn negate(&self) -> Self {
+        -*self
+    }
+}
+
+impl Zero for complex_floats::Complex {
+    fn zero() -> Self {
+        complex_floats::Complex::zero()
+    }
+    fn is_zero(&self) -> bool {
+        self.get() == (0.0, 0.0)
+    }
+}
+
+impl One for complex_floats::Complex {
+    fn one() -> Self {
+        complex_floats::Complex::one()
+    }
+}
+
+impl Ring for complex_floats::Complex {
+    fn add(&self, other: &Self) -> Self {
+        complex_floats::Complex::add(self, other)
+    }
+    fn subtract(&self, other: &Self) -> Self {
+        complex_floats::Complex::subtract(self, other)
+    }
+    fn multiply(&self, other: &Self) -> Self {
+        complex_floats::Complex::multiply(self, other)
+    }
+    fn negate(&self) -> Self {
+        complex_floats::Complex::new(-self.get_real(), -self.get_imag())
+    }
+}
+
+impl Field for complex_floats::Complex {
+    fn divide(&self, other: &Self) -> Self {
+        complex_floats::Complex::divide(self, other)
+    }
+}
+
+impl<T: Integer> Zero for rational_integer::Rational<T> {
+    fn zero() -> Self {
+        Self::from_integer(T::zero())
+    }
+    fn is_zero(&self) -> bool {
+        self.get_numerator().is_zero()
+    }
+}
+
+impl<T: Integer> One for rational_integer::Rational<T> {
+    fn one() -> Self {
+        Self::from_integer(T::one())
+    }
+}
+
+impl<T: Integer> Ring for rational_integer::Rational<T> {
+    fn add(&self, other: &Self) -> Self {
+        rational_integer::Rational::add(self, other)
+    }
+    fn subtract(&self, other: &Self) -> Self {
+        rational_integer::Rational::subtract(self, other)
+    }
+    fn multiply(&self, other: &Self) -> Self {
+        rational_integer::Rational::multiply(self, other)
+    }
+    fn negate(&self) -> Self {
+        -self
+    }
+}
+
+impl<T: Integer> Field for rational_integer::Rational<T> {
+    fn divide(&self, other: &Self) -> Self {
+        rational_integer::Rational::divide(self, other)
+    }
+    fn inverse(&self) -> Self {
+        self.recip()
+    }
+}
+
+impl<T: Integer> Signed for rational_integer::Rational<T> {
+    fn abs(&self) -> Self {
+        rational_integer::Rational::abs(self)
+    }
+    fn signum(&self) -> Self {
+        Self::from_integer(self.get_numerator().signum())
+    }
+    fn is_negative(&self) -> bool {
+        self.get_numerator().is_negative()
+    }
+}
+
+impl<T: Integer> Real for rational_integer::Rational<T> {
+    fn to_f64(&self) -> f64 {
+        self.to_float()
+    }
+}
+
+// The float rationals are simplified after each operation so that repeated operations do not let the numerator and
+// denominator grow without bound.
+impl Zero for rational_float::Rational {
+    fn zero() -> Self {
+        rational_float::Rational::new(0.0, 1.0)
+    }
+    fn is_zero(&self) -> bool {
+        self.get_numerator() == 0.0
+    }
+}
+
+impl One for rational_float::Rational {
+    fn one() -> Self {
+        rational_float::Rational::new(1.0, 1.0)
+    }
+}
+
+impl Ring for rational_float::Rational {
+    fn add(&self, other: &Self) -> Self {
+        rational_float::Rational::add(self, other).simplify()
+    }
+    fn subtract(&self, other: &Self) -> Self {
+        rational_float::Rational::subtract(self, other).simplify()
+    }
+    fn multiply(&self, other: &Self) -> Self {
+        rational_float::Rational::multiply(self, other).simplify()
+    }
+    fn negate(&self) -> Self {
+        rational_float::Rational::new(-self.get_numerator(), self.get_denominator())
+    }
+}
+
+impl Field for rational_float::Rational {
+    fn divide(&self, other: &Self) -> Self {
+        rational_float::Rational::divide(self, other).simplify()
+    }
+}
+
+impl Signed for rational_float::Rational {
+    fn abs(&self) -> Self {
+        rational_float::Rational::new(self.get_numerator().abs(), self.get_denominator().abs())
+    }
+    fn signum(&self) -> Self {
+        if Zero::is_zero(self) {
+            return Self::zero();
+        }
+        let sign = self.get_numerator().signum() * self.get_denominator().signum();
+        rational_float::Rational::new(sign, 1.0)
+    }
+    fn is_negative(&self) -> bool {
+        (self.get_numerator() < 0.0) != (self.get_denominator() < 0.0)
+            && self.get_numerator() != 0.0
+    }
+}
+
+impl Real for rational_float::Rational {
+    fn to_f64(&self) -> f64 {
+        self.get_numerator() / self.get_denominator()
+    }
+}
+
+impl<const M: u64> Zero for ModInt<M> {
+    fn zero() -> Self {
+        ModInt::new(0)
+    }
+    fn is_zero(&self) -> bool {
+        self.value() == 0
+    }
+}
+
+impl<const M: u64> One for ModInt<M> {
+    fn one() -> Self {
+        ModInt::new(1)
+    }
+}
+
+impl<const M: u64> Ring for ModInt<M> {
+    fn add(&self, other: &Self) -> Self {
+        *self + *other
+    }
+    fn subtract(&self, other: &Self) -> Self {
+        *self - *other
+    }
+    fn multiply(&self, other: &Self) -> Self {
+        *self * *other
+    }
+    fn negate(&self) -> Self {
+        -*self
+    }
+    fn pow(&self, exponent: u32) -> Self {
+        ModInt::pow(*self, exponent as u64)
+    }
+}
+
+impl<const M: u64> Field for ModInt<M> {
+    fn divide(&self, other: &Self) -> Self {
+        *self / *other
+    }
+}
+
+/// Implements the borrowed and `*Assign` operator forms for a `Copy` number type from its owned `Add`, `Sub`, `Mul` and `Div`
+/// implementations, the mixed forms with a `$scalar` type it converts from, `Neg` on a reference, and `Sum` and `Product`
+/// over owned and borrowed values.
+macro_rules! forward_copy_ops {
+    ($type:ty, $scalar:ty, $zero:expr, $one:expr) => {
+        $crate::traits::forward_copy_ops!(@binop $type, $scalar, Add, add, AddAssign, add_assign);
+        $crate::traits::forward_copy_ops!(@binop $type, $scalar, Sub, sub, SubAssign, sub_assign);
+        $crate::traits::forward_copy_ops!(@binop $type, $scalar, Mul, mul, MulAssign, mul_assign);
+        $crate::traits::forward_copy_ops!(@binop $type, $scalar, Div, div, DivAssign, div_assign);
+
+        impl std::ops::Neg for &$type {
+            type Output = $type;
+
+            fn neg(self) -> $type {
+                -*self
+            }
+        }
+
+        impl std::iter::Sum for $type {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold($zero, |total, value| total + value)
+            }
+        }
+
+        impl<'a> std::iter::Sum<&'a $type> for $type {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold($zero, |total, value| total + *value)
+            }
+        }
+
+        impl std::iter::Product for $type {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold($one, |total, value| total * value)
+            }
+        }
+
+        impl<'a> std::iter::Product<&'a $type> for $type {
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold($one, |total, value| total * *value)
+            }
+        }
+    };
+    (@binop $type:ty, $scalar:ty, $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl std::ops::$trait<&$type> for $type {
+            type Output = $type;
+
+            fn $method(self, other: &$type) -> $type {
+                std::ops::$trait::$method(self, *other)
+            }
+        }
+
+        impl std::ops::$trait<$type> for &$type {
+            type Output = $type;
+
+            fn $method(self, other: $type) -> $type {
+                std::ops::$trait::$method(*self, other)
+            }
+        }
+
+        impl std::ops::$trait<&$type> for &$type {
+            type Output = $type;
+
+            fn $method(self, other: &$type) -> $type {
+                std::ops::$trait::$method(*self, *other)
+            }
+        }
+
+        impl std::ops::$trait<$scalar> for $type {
+            type Output = $type;
+
+            fn $method(self, other: $scalar) -> $type {
+                std::ops::$trait::$method(self, <$type>::from(other))
+            }
+        }
+
+        impl std::ops::$trait<$type> for $scalar {
+            type Output = $type;
+
+            fn $method(self, other: $type) -> $type {
+                std::ops::$trait::$method(<$type>::from(self), other)
+            }
+        }
+
+        impl std::ops::$assign_trait for $type {
+            fn $assign_method(&mut self, other: $type) {
+                *self = std::ops::$trait::$method(*self, other);
+            }
+        }
+
+        impl std::ops::$assign_trait<&$type> for $type {
+            fn $assign_method(&mut self, other: &$type) {
+                *self = std::ops::$trait::$method(*self, *other);
+            }
+        }
+
+        impl std::ops::$assign_trait<$scalar> for $type {
+            fn $assign_method(&mut self, other: $scalar) {
+                *self = std::ops::$trait::$method(*self, <$type>::from(other));
+            }
+        }
+    };
+}
+
+pub(crate) use forward_copy_ops;
+
+/// `gcd` function: Takes two values of a Euclidean ring as input and returns a greatest common divisor computed with the
+/// Euclidean algorithm. It is unique only up to a unit, so for integers it may come out negative.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::big_integer::BigInt;
+/// use numbers_rus::traits;
+///
+/// assert_eq!(traits::gcd(&48i128, &18), 6);
+/// let big = BigInt::from(2).pow(100);
+/// assert_eq!(traits::gcd(&big, &BigInt::from(96)), BigInt::from(32));
+/// ```
+pub fn gcd<T: EuclideanRing>(left: &T, right: &T) -> T {
+    let mut a = left.clone();
+    let mut b = right.clone();
+    while !b.is_zero() {
+        let (_, remainder) = a.div_rem(&b);
+        a = b;
+        b = remainder;
+    }
+    a
+}
+
+/// `evaluate_polynomial` function: Takes the coefficients of a polynomial, constant term first, and a point `x` as input and
+/// returns the value of the polynomial at `x` using Horner's rule. An empty slice is the zero polynomial.
+///
+/// # Example
+/// ```
+/// use numbers_rus::integers::mod_int::ModInt;
+/// use numbers_rus::traits;
+///
+/// type Mint = ModInt<7>;
+/// let coefficients = [Mint::new(1), Mint::new(0), Mint::new(1)]; // x^2 + 1
+/// assert_eq!(traits::evaluate_polynomial(&coefficients, &Mint::new(3)), Mint::new(3));
+/// ```
+pub fn evaluate_polynomial<T: Ring>(coefficients: &[T], x: &T) -> T {
+    coefficients
+        .iter()
+        .rev()
+        .fold(T::zero(), |acc, coefficient| {
+            acc.multiply(x).add(coefficient)
+        })
+}
+
+/// `matrix_multiply` function: Takes two matrices, given as rows, as input and returns their product. Panics if the number of
+/// columns of `left` differs from the number of rows of `right`.
+///
+/// # Example
+/// ```
+/// use numbers_rus::traits;
+///
+/// let a = vec![vec![1i128, 2], vec![3, 4]];
+/// let b = vec![vec![0i128, 1], vec![1, 0]];
+/// assert_eq!(traits::matrix_multiply(&a, &b), vec![vec![2, 1], vec![4, 3]]);
+/// ```
+pub fn matrix_multiply<T: Ring>(left: &[Vec<T>], right: &[Vec<T>]) -> Vec<Vec<T>> {
+    let columns = right.first().map_or(0, Vec::len);
+    left.iter()
+        .map(|row| {
+            assert_eq!(row.len(), right.len(), "Matrix dimensions do not match");
+            (0..columns)
+                .map(|j| {
+                    row.iter()
+                        .zip(right)
+                        .fold(T::zero(), |acc, (a, right_row)| {
+                            acc.add(&a.multiply(&right_row[j]))
+                        })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// `determinant` function: Takes a square matrix, given as rows, as input and returns its determinant, computed by Gaussian
+/// elimination over the field. Panics if the matrix is not square.
+///
+/// # Example
+/// ```
+/// use numbers_rus::rational::rational_integer::Rational;
+/// use numbers_rus::traits::{self, Real};
+///
+/// let r = |n| Rational::new(n, 1);
+/// let matrix = vec![vec![r(2), r(1)], vec![r(1), r(3)]];
+/// assert_eq!(traits::determinant(&matrix).to_f64(), 5.0);
+/// ```
+pub fn determinant<T: Field>(matrix: &[Vec<T>]) -> T {
+    let mut rows = matrix.to_vec();
+    let n = rows.len();
+    assert!(
+        rows.iter().all(|row| row.len() == n),
+        "Matrix must be square"
+    );
+    let mut result = T::one();
+    for column in 0..n {
+        let Some(pivot) = (column..n).find(|&i| !rows[i][column].is_zero()) else {
+            return T::zero();
+        };
+        if pivot != column {
+            rows.swap(pivot, column);
+            result = result.negate();
+        }
+        result = result.multiply(&rows[column][column]);
+        eliminate_below(&mut rows, column);
+    }
+    result
+}
+
+/// `solve_linear_system` function: Takes a square matrix `A`, given as rows, and a vector `b` as input and returns the unique `x`
+/// with `Ax = b`, or `None` if `A` is singular. Uses Gaussian elimination over the field. Panics if the dimensions do not match.
+///
+/// # Example
+/// ```
+/// use numbers_rus::numbers::complex_floats::Complex;
+/// use numbers_rus::traits;
+///
+/// // x + iy = 1 + i and x - y = 0.
+/// let c = Complex::new;
+/// let matrix = vec![vec![c(1.0, 0.0), c(0.0, 1.0)], vec![c(1.0, 0.0), c(-1.0, 0.0)]];
+/// let solution = traits::solve_linear_system(&matrix, &[c(1.0, 1.0), c(0.0, 0.0)]).unwrap();
+/// assert_eq!(solution, vec![c(1.0, 0.0), c(1.0, 0.0)]);
+/// ```
+pub fn solve_linear_system<T: Field>(matrix: &[Vec<T>], rhs: &[T]) -> Option<Vec<T>> {
+    let n = matrix.len();
+    assert_eq!(rhs.len(), n, "Matrix dimensions do not match");
+    assert!(
+        matrix.iter().all(|row| row.len() == n),
+        "Matrix must be square"
+    );
+    // Eliminate on the augmented matrix [A | b], then substitute backwards.
+    let mut rows: Vec<Vec<T>> = matrix
+        .iter()
+        .zip(rhs)
+        .map(|(row, value)| {
+            let mut row = row.clone();
+            row.push(value.clone());
+            row
+        })
+        .collect();
+    for column in 0..n {
+        let pivot = (column..n).find(|&i| !rows[i][column].is_zero())?;
+        rows.swap(pivot, column);
+        eliminate_below(&mut rows, column);
+    }
+    let mut solution = vec![T::zero(); n];
+    for i in (0..n).rev() {
+        let known = (i + 1..n).fold(T::zero(), |acc, j| {
+            acc.add(&rows[i][j].multiply(&solution[j]))
+        });
+        solution[i] = rows[i][n].subtract(&known).divide(&rows[i][i]);
+    }
+    Some(solution)
+}
+
+/// Subtracts multiples of row `column` from the rows below it to clear their entries in `column`.
+fn eliminate_below<T: Field>(rows: &mut [Vec<T>], column: usize) {
+    let (upper, lower) = rows.split_at_mut(column + 1);
+    let pivot_row = &upper[column];
+    for row in lower {
+        let factor = row[column].divide(&pivot_row[column]);
+        for (entry, pivot_entry) in row.iter_mut().zip(pivot_row).skip(column) {
+            *entry = entry.subtract(&factor.multiply(pivot_entry));
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_traits {
+    use super::*;
+
+    #[test]
+    fn test_identities() {
+        assert!(i128::zero().is_zero());
+        assert_eq!(<f64 as One>::one(), 1.0);
+        assert!(Zero::is_zero(&complex_floats::Complex::new(0.0, 0.0)));
+        assert!(!Zero::is_zero(&rational_integer::Rational::new(1, 3)));
+        assert!(Zero::is_zero(&rational_integer::Rational::new(0, 3)));
+        assert!(<ModInt<5> as Zero>::zero().is_zero());
+    }
+    #[test]
+    fn test_ring_pow() {
+        assert_eq!(Ring::pow(&3i128, 5), 243);
+        assert_eq!(Ring::pow(&2i64, 62), 1 << 62);
+        assert_eq!(Ring::pow(&2.0f64, 10), 1024.0);
+        assert_eq!(
+            Ring::pow(&BigInt::from(10), 30).to_string(),
+            format!("1{}", "0".repeat(30))
+        );
+        let i = complex_integers::Complex::new(0, 1);
+        assert_eq!(Ring::pow(&i, 2), complex_integers::Complex::new(-1, 0));
+        let two_thirds = rational_integer::Rational::new(2, 3);
+        let cube = Ring::pow(&two_thirds, 3);
+        assert_eq!((cube.get_numerator(), cube.get_denominator()), (8, 27));
+        let ratio = Ring::pow(&rational_float::Rational::new(1000.0, 999.0), 3);
+        assert_eq!(
+            (ratio.get_numerator(), ratio.get_denominator()),
+            (1e9, 997002999.0)
+        );
+        let product = Ring::multiply(
+            &rational_float::Rational::new(100003.0, 99991.0),
+            &rational_float::Rational::new(100019.0, 99989.0),
+        );
+        assert_eq!(product.get_numerator(), 100003.0 * 100019.0);
+        assert_eq!(Ring::pow(&ModInt::<13>::new(2), 12), ModInt::new(1));
+    }
+    #[test]
+    fn test_signed() {
+        assert_eq!(Signed::abs(&-5i128), 5);
+        assert_eq!(Signed::signum(&0.0f64), 0.0);
+        assert!(Signed::is_negative(&rational_integer::Rational::new(1, -2)));
+        assert!(!Signed::is_negative(&rational_integer::Rational::new(
+            -1, -2
+        )));
+        assert!(!Signed::is_negative(&rational_float::Rational::new(
+            0.0, -2.0
+        )));
+        assert_eq!(
+            Signed::abs(&rational_float::Rational::new(-1.0, 2.0)).to_f64(),
+            0.5
+        );
+        assert_eq!(Signed::signum(&BigInt::from(-7)), BigInt::from(-1));
+    }
+    #[test]
+    fn test_gcd() {
+        for a in -30i128..30 {
+            for b in -30i128..30 {
+                assert_eq!(gcd(&a, &b).abs(), crate::integers::modular::gcd(a, b));
+            }
+        }
+    }
+    #[test]
+    fn test_linear_algebra() {
+        let matrix = vec![
+            vec![2.0, 1.0, -1.0],
+            vec![-3.0, -1.0, 2.0],
+            vec![-2.0, 1.0, 2.0],
+        ];
+        let solution = solve_linear_system(&matrix, &[8.0, -11.0, -3.0]).unwrap();
+        for (value, expected) in solution.iter().zip([2.0, 3.0, -1.0]) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+        assert!((determinant(&matrix) + 1.0).abs() < 1e-12);
+
+        type Mint = ModInt<1_000_000_007>;
+        let m = |rows: [[i128; 2]; 2]| -> Vec<Vec<Mint>> {
+            rows.iter()
+                .map(|row| row.iter().map(|&v| Mint::new(v)).collect())
+                .collect()
+        };
+        let singular = m([[1, 2], [2, 4]]);
+        assert_eq!(determinant(&singular), Mint::new(0));
+        assert!(solve_linear_system(&singular, &[Mint::new(1), Mint::new(1)]).is_none());
+        let a = m([[0, 1], [1, 1]]);
+        assert_eq!(determinant(&a), Mint::new(-1));
+        let fib = (0..9).fold(m([[1, 0], [0, 1]]), |acc, _| matrix_multiply(&acc, &a));
+        assert_eq!(fib[0][1], Mint::new(34));
+    }
+}